@@ -0,0 +1,400 @@
+//! Rendering of all the templates of a directory to a mirrored output tree,
+//! and rendering them again when they change.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use clap::Parser;
+use mrml::mjml::Mjml;
+use mrml::prelude::parser::{ParseOutput, ParserOptions};
+use mrml::prelude::render::RenderOptions;
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use crate::{format_parser_error, Options, Render};
+
+/// How long to wait for other changes before rendering again, editors usually
+/// write a file in several steps.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Parser)]
+pub(crate) struct Build {
+    /// Directory containing the templates, or glob pattern matching them
+    pub source: String,
+    /// Directory where the html files are written, mirroring the source tree
+    #[clap(short, long)]
+    pub output: PathBuf,
+    /// Render the templates again when they, or the templates they include,
+    /// change
+    #[clap(short, long)]
+    pub watch: bool,
+    #[clap(flatten)]
+    pub render: Render,
+}
+
+impl Build {
+    /// The directory of the templates and the glob pattern matching them.
+    fn source(&self) -> (PathBuf, String) {
+        let path = Path::new(&self.source);
+        if path.is_dir() {
+            let pattern = path.join("**").join("*.mjml");
+            return (path.to_path_buf(), pattern.to_string_lossy().into_owned());
+        }
+        let base = path
+            .components()
+            .take_while(|component| match component {
+                Component::Normal(name) => !name.to_string_lossy().contains(['*', '?', '[']),
+                _ => true,
+            })
+            .collect::<PathBuf>();
+        let base = if base.is_file() {
+            base.parent().map(Path::to_path_buf).unwrap_or_default()
+        } else {
+            base
+        };
+        let base = if base.as_os_str().is_empty() {
+            PathBuf::from(".")
+        } else {
+            base
+        };
+        (base, self.source.clone())
+    }
+
+    pub fn execute(&self, options: &Options) -> Result<(), String> {
+        let (base, pattern) = self.source();
+        let base = base
+            .canonicalize()
+            .map_err(|err| format!("couldn't find {base:?}: {err}"))?;
+        let root = match options.local_loader_root()? {
+            Some(root) => root
+                .canonicalize()
+                .map_err(|err| format!("couldn't find {root:?}: {err}"))?,
+            None => base.clone(),
+        };
+        let mut builder = Builder {
+            options,
+            base,
+            root,
+            output: self.output.clone(),
+            render: RenderOptions::from(self.render.clone()),
+            dependencies: HashMap::new(),
+        };
+
+        let templates = find_templates(&pattern)?;
+        let failures = builder.build(&templates);
+        if self.watch {
+            builder.watch(&pattern)
+        } else if failures > 0 {
+            Err(format!("unable to build {failures} template(s)"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn find_templates(pattern: &str) -> Result<Vec<PathBuf>, String> {
+    let paths = glob::glob(pattern).map_err(|err| format!("invalid pattern {pattern:?}: {err}"))?;
+    Ok(paths
+        .filter_map(Result::ok)
+        .filter(|path| path.is_file())
+        .filter_map(|path| path.canonicalize().ok())
+        .collect())
+}
+
+struct Builder<'a> {
+    options: &'a Options,
+    base: PathBuf,
+    /// The directory of the templates that can be included.
+    root: PathBuf,
+    output: PathBuf,
+    render: RenderOptions,
+    /// The local files included by each template, directly or not.
+    dependencies: HashMap<PathBuf, HashSet<PathBuf>>,
+}
+
+impl Builder<'_> {
+    fn output_path(&self, template: &Path) -> PathBuf {
+        let relative = template.strip_prefix(&self.base).unwrap_or(template);
+        self.output.join(relative).with_extension("html")
+    }
+
+    /// The local file matching the path of an `mj-include`.
+    fn include_path(&self, path: &str) -> Option<PathBuf> {
+        if path.starts_with("http://") || path.starts_with("https://") {
+            return None;
+        }
+        let path = self.root.join(path.trim_start_matches("file:///"));
+        Some(path.canonicalize().unwrap_or(path))
+    }
+
+    fn parse(&self, template: &Path) -> Result<ParseOutput<Mjml>, String> {
+        let input = std::fs::read_to_string(template)
+            .map_err(|err| format!("couldn't read {template:?}: {err}"))?;
+        let options = ParserOptions {
+            include_loader: self.options.build_include_loader(self.root.clone()),
+        };
+        Mjml::parse_with_options(input, &options).map_err(format_parser_error)
+    }
+
+    fn write(&self, template: &Path, html: String) -> Result<(), String> {
+        let output = self.output_path(template);
+        if let Some(parent) = output.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|err| format!("couldn't create {parent:?}: {err}"))?;
+        }
+        std::fs::write(&output, html).map_err(|err| format!("couldn't write {output:?}: {err}"))
+    }
+
+    /// Renders a template, keeping the files it includes.
+    fn render_template(&mut self, template: &Path) -> Result<String, String> {
+        let root = match self.parse(template) {
+            Ok(root) => root,
+            Err(error) => {
+                // the files included by a template that can't be parsed are
+                // unknown, so it is rendered again on every change until it
+                // is fixed, like a new template
+                self.dependencies.remove(template);
+                return Err(error);
+            }
+        };
+        let dependencies = root
+            .includes
+            .iter()
+            .filter_map(|include| self.include_path(&include.path))
+            .collect();
+        self.dependencies
+            .insert(template.to_path_buf(), dependencies);
+        for warning in root.warnings {
+            log::warn!("{template:?}: {warning}");
+        }
+        root.element
+            .render(&self.render)
+            .map_err(|err| format!("couldn't render template: {err}"))
+    }
+
+    /// Renders the templates, logging the errors so that one broken template
+    /// doesn't stop the others. The templates included by other templates are
+    /// partials, they are not written. Returns the number of failures.
+    fn build(&mut self, templates: &[PathBuf]) -> usize {
+        let results = templates
+            .iter()
+            .map(|template| (template, self.render_template(template)))
+            .collect::<Vec<_>>();
+        let partials = self
+            .dependencies
+            .values()
+            .flatten()
+            .cloned()
+            .collect::<HashSet<_>>();
+        let mut failures = 0;
+        for (template, result) in results {
+            if partials.contains(template) {
+                log::debug!("{template:?} is included in another template, skipping");
+                continue;
+            }
+            match result.and_then(|html| self.write(template, html)) {
+                Ok(_) => log::info!("{template:?} rendered"),
+                Err(error) => {
+                    failures += 1;
+                    log::error!("{template:?}: {error}");
+                }
+            }
+        }
+        failures
+    }
+
+    /// Whether the template, or one of the templates it includes, changed.
+    fn is_affected(&self, template: &Path, changes: &HashSet<PathBuf>) -> bool {
+        changes.contains(template)
+            || self
+                .dependencies
+                .get(template)
+                .is_some_and(|dependencies| !dependencies.is_disjoint(changes))
+    }
+
+    /// The templates to render again after some changes, including the new
+    /// ones.
+    fn affected(&self, templates: &[PathBuf], changes: &HashSet<PathBuf>) -> Vec<PathBuf> {
+        templates
+            .iter()
+            .filter(|template| {
+                !self.dependencies.contains_key(*template) || self.is_affected(template, changes)
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn watch(&mut self, pattern: &str) -> Result<(), String> {
+        let (sender, receiver) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)
+            .map_err(|err| format!("couldn't watch the templates: {err}"))?;
+        let mut directories = vec![self.base.as_path()];
+        if !self.root.starts_with(&self.base) {
+            directories.push(self.root.as_path());
+        }
+        for directory in directories {
+            watcher
+                .watch(directory, RecursiveMode::Recursive)
+                .map_err(|err| format!("couldn't watch {directory:?}: {err}"))?;
+        }
+        log::info!("watching {:?} for changes...", self.base);
+
+        let collect =
+            |changes: &mut HashSet<PathBuf>, event: notify::Result<notify::Event>| match event {
+                Ok(event) if !matches!(event.kind, EventKind::Access(_)) => {
+                    changes.extend(
+                        event
+                            .paths
+                            .into_iter()
+                            .map(|path| path.canonicalize().unwrap_or(path)),
+                    );
+                }
+                Ok(_) => {}
+                Err(err) => log::warn!("couldn't watch the templates: {err}"),
+            };
+        while let Ok(event) = receiver.recv() {
+            let mut changes = HashSet::new();
+            collect(&mut changes, event);
+            while let Ok(event) = receiver.recv_timeout(DEBOUNCE) {
+                collect(&mut changes, event);
+            }
+            let templates = find_templates(pattern)?;
+            self.dependencies
+                .retain(|template, _| templates.contains(template));
+            let affected = self.affected(&templates, &changes);
+            self.build(&affected);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use std::path::{Path, PathBuf};
+
+    use clap::Parser;
+
+    use super::Builder;
+    use crate::{Options, SubCommand};
+
+    fn resources() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("resources")
+            .join("build")
+            .canonicalize()
+            .unwrap()
+    }
+
+    fn execute(name: &str, source: &str) -> (PathBuf, Result<(), String>) {
+        let output = std::env::temp_dir().join(format!("mrml-cli-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&output);
+        let options = Options::parse_from([
+            "mrml-cli",
+            "build",
+            source,
+            "--output",
+            output.to_str().unwrap(),
+        ]);
+        let SubCommand::Build(ref build) = options.subcmd else {
+            unreachable!();
+        };
+        let result = build.execute(&options);
+        (output, result)
+    }
+
+    #[test]
+    fn should_build_directory() {
+        let source = resources();
+        let (output, result) = execute("build-directory", source.to_str().unwrap());
+        // the broken template doesn't stop the others
+        assert_eq!(result, Err("unable to build 1 template(s)".into()));
+        assert!(output.join("welcome.html").is_file());
+        let html = std::fs::read_to_string(output.join("nested").join("newsletter.html")).unwrap();
+        assert!(html.contains("Included header"));
+        assert!(!output.join("broken.html").exists());
+        std::fs::remove_dir_all(output).unwrap();
+    }
+
+    #[test]
+    fn should_build_glob() {
+        let pattern = resources().join("*.mjml");
+        let (output, _) = execute("build-glob", pattern.to_str().unwrap());
+        assert!(output.join("welcome.html").is_file());
+        assert!(!output.join("nested").exists());
+        std::fs::remove_dir_all(output).unwrap();
+    }
+
+    #[test]
+    fn should_keep_included_templates() {
+        let options = Options::parse_from(["mrml-cli", "validate"]);
+        let base = resources();
+        let mut builder = Builder {
+            options: &options,
+            base: base.clone(),
+            root: base.clone(),
+            output: PathBuf::from("dist"),
+            render: Default::default(),
+            dependencies: HashMap::new(),
+        };
+        let newsletter = base.join("nested").join("newsletter.mjml");
+        assert!(builder.render_template(&newsletter).is_ok());
+        assert_eq!(
+            builder.dependencies[&newsletter],
+            HashSet::from([base.join("partials").join("header.mjml")])
+        );
+        // a broken template is rendered again on every change
+        let broken = base.join("broken.mjml");
+        builder.dependencies.insert(broken.clone(), HashSet::new());
+        assert!(builder.render_template(&broken).is_err());
+        assert!(!builder.dependencies.contains_key(&broken));
+    }
+
+    #[test]
+    fn should_find_affected_templates() {
+        let options = Options::parse_from(["mrml-cli", "validate"]);
+        let base = resources();
+        let welcome = base.join("welcome.mjml");
+        let newsletter = base.join("nested").join("newsletter.mjml");
+        let header = base.join("partials").join("header.mjml");
+        let builder = Builder {
+            options: &options,
+            base: base.clone(),
+            root: base.clone(),
+            output: PathBuf::from("dist"),
+            render: Default::default(),
+            dependencies: HashMap::from([
+                (welcome.clone(), HashSet::new()),
+                (newsletter.clone(), HashSet::from([header.clone()])),
+            ]),
+        };
+        assert_eq!(
+            builder.output_path(&newsletter),
+            Path::new("dist").join("nested").join("newsletter.html")
+        );
+        assert_eq!(
+            builder.include_path("file:///partials/header.mjml"),
+            Some(header.clone())
+        );
+        assert_eq!(
+            builder.include_path("https://example.com/header.mjml"),
+            None
+        );
+
+        let templates = [welcome.clone(), newsletter.clone()];
+        let changes = HashSet::from([header]);
+        assert_eq!(builder.affected(&templates, &changes), [newsletter]);
+        let changes = HashSet::from([welcome.clone()]);
+        assert_eq!(
+            builder.affected(&templates, &changes),
+            std::slice::from_ref(&welcome)
+        );
+        // the new templates are rendered
+        let other = base.join("other.mjml");
+        assert_eq!(
+            builder.affected(&[welcome, other.clone()], &HashSet::new()),
+            [other]
+        );
+    }
+}
@@ -1,3 +1,5 @@
+mod build;
+
 use std::borrow::Cow;
 use std::collections::HashSet;
 use std::error::Error;
@@ -7,15 +9,23 @@ use std::iter::FromIterator;
 use std::path::PathBuf;
 
 use clap::{Parser, Subcommand, ValueEnum};
+use mrml::mj_include::body::MjIncludeBodyKind;
+use mrml::mj_include::head::MjIncludeHeadKind;
 use mrml::mjml::Mjml;
+use mrml::prelude::lint::{Client, LintIssue};
 use mrml::prelude::parser::http_loader::{HttpIncludeLoader, UreqFetcher};
 use mrml::prelude::parser::loader::IncludeLoader;
 use mrml::prelude::parser::local_loader::LocalIncludeLoader;
 use mrml::prelude::parser::multi_loader::MultiIncludeLoader;
 use mrml::prelude::parser::noop_loader::NoopIncludeLoader;
-use mrml::prelude::parser::{Error as ParserError, ParseOutput, ParserOptions};
+use mrml::prelude::parser::{
+    Error as ParserError, Include, IncludeKind, Origin, ParseOutput, ParserOptions,
+};
 use mrml::prelude::print::Printable;
-use mrml::prelude::render::RenderOptions;
+use mrml::prelude::render::{
+    IdStrategy, OutputMode, RenderOptions, TextRenderOptions, GMAIL_CLIP_LIMIT,
+};
+use mrml::prelude::validate::{Diagnostic, Severity, ValidationLevel};
 
 fn format_parser_error(error: ParserError) -> String {
     if let Some(src) = error.source() {
@@ -31,6 +41,59 @@ pub enum HttpLoaderMode {
     Deny,
 }
 
+#[derive(ValueEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Level {
+    Strict,
+    #[default]
+    Soft,
+    Skip,
+}
+
+impl From<Level> for ValidationLevel {
+    fn from(value: Level) -> Self {
+        match value {
+            Level::Strict => Self::Strict,
+            Level::Soft => Self::Soft,
+            Level::Skip => Self::Skip,
+        }
+    }
+}
+
+#[derive(ValueEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Output {
+    #[default]
+    Raw,
+    Minify,
+    Pretty,
+}
+
+impl From<Output> for OutputMode {
+    fn from(value: Output) -> Self {
+        match value {
+            Output::Raw => Self::Raw,
+            Output::Minify => Self::Minify,
+            Output::Pretty => Self::Pretty,
+        }
+    }
+}
+
+#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EmailClient {
+    Outlook,
+    Gmail,
+    AppleMail,
+}
+
+impl From<EmailClient> for Client {
+    fn from(value: EmailClient) -> Self {
+        match value {
+            EmailClient::Outlook => Self::Outlook,
+            EmailClient::Gmail => Self::Gmail,
+            EmailClient::AppleMail => Self::AppleMail,
+        }
+    }
+}
+
 #[derive(Debug, Parser)]
 #[clap(author, version, about, long_about = None)]
 struct Options {
@@ -85,22 +148,35 @@ impl Options {
         }
     }
 
-    fn local_include_loader(&self) -> Result<Option<Box<dyn IncludeLoader>>, String> {
+    fn local_loader_root(&self) -> Result<Option<PathBuf>, String> {
         Ok(match self.local_loader {
-            Some(ref path) => {
-                let path = if path.is_absolute() {
-                    path.to_path_buf()
-                } else {
-                    std::env::current_dir()
-                        .map_err(|err| format!("unable to detect current directory: {err:?}"))?
-                        .join(path)
-                };
-                Some(Box::new(LocalIncludeLoader::new(path)))
-            }
+            Some(ref path) if path.is_absolute() => Some(path.to_path_buf()),
+            Some(ref path) => Some(
+                std::env::current_dir()
+                    .map_err(|err| format!("unable to detect current directory: {err:?}"))?
+                    .join(path),
+            ),
             None => None,
         })
     }
 
+    fn local_include_loader(&self) -> Result<Option<Box<dyn IncludeLoader>>, String> {
+        Ok(self
+            .local_loader_root()?
+            .map(|path| Box::new(LocalIncludeLoader::new(path)) as Box<dyn IncludeLoader>))
+    }
+
+    /// When building several templates, the includes that are not urls are
+    /// loaded from the given directory.
+    fn build_include_loader(&self, root: PathBuf) -> Box<dyn IncludeLoader> {
+        Box::new(
+            MultiIncludeLoader::new()
+                .with_starts_with("http://", self.http_include_loader())
+                .with_starts_with("https://", self.http_include_loader())
+                .with_any(Box::new(LocalIncludeLoader::new(root))),
+        )
+    }
+
     fn include_loader(&self) -> Result<Box<dyn IncludeLoader>, String> {
         Ok(match self.local_include_loader()? {
             Some(local) => Box::new(
@@ -122,25 +198,18 @@ impl Options {
         Mjml::parse_with_options(input, &options).map_err(format_parser_error)
     }
 
-    fn parse_input(&self, input: String) -> Result<ParseOutput<Mjml>, String> {
+    fn parse_input(&self, input: &str) -> Result<ParseOutput<Mjml>, String> {
         if let Some(ref filename) = self.input {
             if filename.ends_with(".json") {
-                self.parse_json(&input).map(|element| ParseOutput {
-                    element,
-                    warnings: Vec::new(),
-                })
+                self.parse_json(input).map(ParseOutput::new)
             } else if filename.ends_with(".mjml") {
-                self.parse_mjml(&input)
+                self.parse_mjml(input)
             } else {
                 Err(format!("unable to detect file type for {filename:?}"))
             }
         } else {
-            self.parse_mjml(&input).or_else(|_| {
-                self.parse_json(&input).map(|element| ParseOutput {
-                    element,
-                    warnings: Vec::new(),
-                })
-            })
+            self.parse_mjml(input)
+                .or_else(|_| self.parse_json(input).map(ParseOutput::new))
         }
     }
 
@@ -152,29 +221,119 @@ impl Options {
         }
     }
 
+    fn is_json_input(&self, input: &str) -> bool {
+        match self.input {
+            Some(ref filename) => filename.ends_with(".json"),
+            None => input.trim_start().starts_with('{'),
+        }
+    }
+
+    fn validate_json(&self, input: &str) -> Result<(), String> {
+        log::debug!("validate json against the schema");
+        mrml::prelude::json::schema::from_str(input)
+            .map(|_| ())
+            .map_err(|err| format!("template is invalid: {err}"))
+    }
+
+    fn validate_rules(&self, input: &str, level: Level) -> Result<Vec<Diagnostic>, String> {
+        log::debug!("validate rules");
+        let options = ParserOptions {
+            include_loader: self.include_loader()?,
+        };
+        mrml::validate_with_options(input, &options, level.into()).map_err(format_parser_error)
+    }
+
     pub fn execute(self) -> Result<(), String> {
-        let root = self.read_input()?;
-        let root = self.parse_input(root)?;
+        if let SubCommand::Build(ref build) = self.subcmd {
+            return build.execute(&self);
+        }
+        if let SubCommand::JsonSchema(ref opts) = self.subcmd {
+            opts.execute();
+            return Ok(());
+        }
+        let input = self.read_input()?;
+        self.execute_input(&input)
+    }
 
-        self.subcmd.execute(root)
+    fn execute_input(self, input: &str) -> Result<(), String> {
+        if matches!(self.subcmd, SubCommand::Validate(_)) && self.is_json_input(input) {
+            // checked against the schema first, to point to the invalid value
+            self.validate_json(input)?;
+        }
+        let root = self.parse_input(input)?;
+        let diagnostics = match self.subcmd {
+            // the rules are checked on the mjml source, to report the positions
+            SubCommand::Validate(ref validate) if !self.is_json_input(input) => {
+                self.validate_rules(input, validate.level)?
+            }
+            _ => Vec::new(),
+        };
+
+        self.subcmd.execute(root)?;
+
+        let mut errors = 0;
+        for diagnostic in diagnostics {
+            match diagnostic.severity {
+                Severity::Error => {
+                    errors += 1;
+                    log::error!("{diagnostic}");
+                }
+                Severity::Warning => log::warn!("{diagnostic}"),
+            }
+        }
+        if errors > 0 {
+            return Err(format!("template is invalid, found {errors} error(s)"));
+        }
+        Ok(())
     }
 }
 
 #[derive(Debug, Subcommand)]
 enum SubCommand {
+    /// Render all the templates of a directory, or matching a glob pattern,
+    /// to HTML files
+    Build(build::Build),
+    /// List the templates loaded with mj-include, directly or not
+    Deps(Deps),
     /// Format template to JSON
     FormatJSON(Format),
     /// Format template to Mjml
     FormatMjml(Format),
+    /// Print the JSON Schema of the json format of the templates
+    JsonSchema(JsonSchema),
+    /// Check the css of the rendered template against its support by the
+    /// main email clients
+    Lint(Lint),
     /// Render template to HTML
     Render(Render),
+    /// Render template to plain text
+    RenderText(RenderText),
+    /// Render template to HTML and report its size, failing when it exceeds
+    /// the limit
+    Report(Report),
     /// Read input file and validate its structure
-    Validate,
+    Validate(Validate),
 }
 
 impl SubCommand {
     pub fn execute(self, root: ParseOutput<Mjml>) -> Result<(), String> {
         match self {
+            Self::Build(_) => unreachable!("the build command doesn't read a single template"),
+            Self::JsonSchema(_) => unreachable!("the json-schema command doesn't read a template"),
+            Self::Deps(opts) => {
+                log::debug!("list dependencies");
+                if opts.json {
+                    let output = root.includes.iter().map(include_json).collect::<Vec<_>>();
+                    println!("{}", serde_json::Value::Array(output));
+                } else {
+                    let mut printed = HashSet::new();
+                    for include in root.includes {
+                        if printed.insert(include.path.clone()) {
+                            println!("{}", include.path);
+                        }
+                    }
+                }
+            }
             Self::FormatJSON(opts) => {
                 log::debug!("format to json");
                 let output = if opts.pretty {
@@ -194,6 +353,10 @@ impl SubCommand {
                 .expect("couldn't format mjml");
                 println!("{output}");
             }
+            Self::Lint(lint) => {
+                log::debug!("lint");
+                lint.execute(&root.element)?;
+            }
             Self::Render(render) => {
                 log::debug!("render");
                 let render_opts = RenderOptions::from(render);
@@ -203,7 +366,16 @@ impl SubCommand {
                     .expect("couldn't render template");
                 println!("{output}");
             }
-            Self::Validate => {
+            Self::RenderText(render) => {
+                log::debug!("render text");
+                let text_opts = TextRenderOptions::from(render);
+                print!("{}", root.element.render_text(&text_opts));
+            }
+            Self::Report(report) => {
+                log::debug!("report");
+                report.execute(&root.element)?;
+            }
+            Self::Validate(_) => {
                 log::debug!("validate");
                 for warning in root.warnings {
                     log::warn!("{warning}");
@@ -214,6 +386,39 @@ impl SubCommand {
     }
 }
 
+#[derive(Debug, Parser)]
+struct Deps {
+    /// Print every mj-include as JSON, with its type, the template containing
+    /// it and its position
+    #[clap(long)]
+    pub json: bool,
+}
+
+fn include_json(include: &Include) -> serde_json::Value {
+    let kind = match include.kind {
+        IncludeKind::Mjml
+        | IncludeKind::Body(MjIncludeBodyKind::Mjml)
+        | IncludeKind::Head(MjIncludeHeadKind::Mjml) => "mjml",
+        IncludeKind::Body(MjIncludeBodyKind::Html) | IncludeKind::Head(MjIncludeHeadKind::Html) => {
+            "html"
+        }
+        IncludeKind::Head(MjIncludeHeadKind::Css { .. }) => "css",
+    };
+    let parent = match include.origin {
+        Origin::Root => None,
+        Origin::Include { ref path } => Some(path.as_str()),
+    };
+    serde_json::json!({
+        "path": include.path,
+        "type": kind,
+        "parent": parent,
+        "span": {
+            "start": include.span.start,
+            "end": include.span.end,
+        },
+    })
+}
+
 #[derive(Debug, Parser)]
 struct Format {
     /// Pretty print
@@ -222,6 +427,31 @@ struct Format {
 }
 
 #[derive(Debug, Parser)]
+struct JsonSchema {
+    /// Print the typescript definitions instead of the JSON Schema
+    #[clap(long)]
+    pub typescript: bool,
+}
+
+impl JsonSchema {
+    fn execute(&self) {
+        if self.typescript {
+            print!("{}", mrml::prelude::json::schema::typescript());
+        } else {
+            let schema = mrml::prelude::json::schema::schema();
+            println!("{}", serde_json::to_string_pretty(&schema).unwrap());
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+struct Validate {
+    /// How strict the validation against the mjml rules is
+    #[clap(long, value_enum, default_value_t)]
+    pub level: Level,
+}
+
+#[derive(Clone, Debug, Parser)]
 struct Render {
     /// Remove comments from html output
     #[clap(short, long)]
@@ -229,18 +459,204 @@ struct Render {
     /// Base url for social icons
     #[clap(short, long)]
     pub social_icon_origin: Option<String>,
+    /// Base url prefixing the relative urls of the images and fonts
+    #[clap(long)]
+    pub base_url: Option<String>,
+    /// Prefix of the ids generated for the carousels and navbars
+    #[clap(long)]
+    pub id_prefix: Option<String>,
+    /// How the html is written
+    #[clap(long, value_enum, default_value_t)]
+    pub output_mode: Output,
 }
 
 impl From<Render> for RenderOptions {
     fn from(value: Render) -> Self {
-        Self {
-            disable_comments: value.disable_comments,
-            social_icon_origin: value.social_icon_origin.map(Cow::Owned),
-            ..Default::default()
+        let mut opts = Self::default()
+            .with_disable_comments(value.disable_comments)
+            .with_output_mode(value.output_mode.into());
+        if let Some(origin) = value.social_icon_origin {
+            opts = opts.with_social_icon_origin(origin);
+        }
+        if let Some(base_url) = value.base_url {
+            opts = opts.with_base_url(base_url);
+        }
+        if let Some(prefix) = value.id_prefix {
+            opts = opts.with_id_strategy(IdStrategy::Prefixed(Cow::Owned(prefix)));
+        }
+        opts
+    }
+}
+
+#[derive(Debug, Parser)]
+struct Report {
+    /// Size in bytes from which the template fails, Gmail clipping the
+    /// messages over 102KB
+    #[clap(long, default_value_t = GMAIL_CLIP_LIMIT)]
+    pub limit: usize,
+    /// Print the report as JSON
+    #[clap(long)]
+    pub json: bool,
+    #[clap(flatten)]
+    pub render: Render,
+}
+
+impl Report {
+    fn execute(self, root: &Mjml) -> Result<(), String> {
+        let render_opts = RenderOptions::from(self.render);
+        let (_, report) = root
+            .render_with_report(&render_opts, self.limit)
+            .map_err(|err| format!("couldn't render template: {err}"))?;
+        if self.json {
+            let sections = report
+                .sections
+                .iter()
+                .map(|section| {
+                    serde_json::json!({
+                        "index": section.index,
+                        "tag": section.tag,
+                        "size": section.size,
+                    })
+                })
+                .collect::<Vec<_>>();
+            let warnings = report
+                .warnings
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            println!(
+                "{}",
+                serde_json::json!({
+                    "size": report.size,
+                    "limit": self.limit,
+                    "head_size": report.head_size,
+                    "style_size": report.style_size,
+                    "sections": sections,
+                    "warnings": warnings,
+                })
+            );
+        } else {
+            println!("size: {} bytes (limit {} bytes)", report.size, self.limit);
+            println!("head: {} bytes", report.head_size);
+            println!("styles: {} bytes", report.style_size);
+            for section in report.sections.iter() {
+                println!(
+                    "#{} {}: {} bytes",
+                    section.index,
+                    section.tag.as_deref().unwrap_or("text"),
+                    section.size
+                );
+            }
+        }
+        for warning in report.warnings.iter() {
+            log::warn!("{warning}");
+        }
+        if report.warnings.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "the rendered template exceeds the limit of {} bytes",
+                self.limit
+            ))
         }
     }
 }
 
+#[derive(Debug, Parser)]
+struct Lint {
+    /// Only report the issues of these clients, all of them by default
+    #[clap(long, value_enum)]
+    pub client: Vec<EmailClient>,
+    /// Print the issues as JSON
+    #[clap(long)]
+    pub json: bool,
+    /// Fail when an issue is found
+    #[clap(long)]
+    pub deny: bool,
+    #[clap(flatten)]
+    pub render: Render,
+}
+
+fn issue_json(issue: &LintIssue) -> serde_json::Value {
+    let element = issue.element.as_ref().map(|element| {
+        let origin = match element.origin {
+            Origin::Root => None,
+            Origin::Include { ref path } => Some(path.as_str()),
+        };
+        serde_json::json!({
+            "tag": element.tag,
+            "origin": origin,
+            "span": {
+                "start": element.span.start,
+                "end": element.span.end,
+            },
+        })
+    });
+    serde_json::json!({
+        "property": issue.property,
+        "value": issue.value,
+        "clients": issue.clients.iter().map(Client::as_str).collect::<Vec<_>>(),
+        "source": issue.source.to_string(),
+        "element": element,
+    })
+}
+
+impl Lint {
+    fn execute(self, root: &Mjml) -> Result<(), String> {
+        let clients = self
+            .client
+            .iter()
+            .copied()
+            .map(Client::from)
+            .collect::<Vec<_>>();
+        let issues = root
+            .lint(&RenderOptions::from(self.render))
+            .map_err(|err| format!("couldn't render template: {err}"))?
+            .into_iter()
+            .filter(|issue| {
+                clients.is_empty() || clients.iter().any(|client| issue.affects(*client))
+            })
+            .collect::<Vec<_>>();
+        if self.json {
+            let output = issues.iter().map(issue_json).collect::<Vec<_>>();
+            println!("{}", serde_json::Value::Array(output));
+        } else {
+            for issue in issues.iter() {
+                println!("{issue}");
+            }
+        }
+        if self.deny && !issues.is_empty() {
+            return Err(format!(
+                "found {} unsupported css declaration(s)",
+                issues.len()
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Parser)]
+struct RenderText {
+    /// Maximum length of a line, 0 to disable the wrapping
+    #[clap(long, default_value_t = 78)]
+    pub line_width: usize,
+    /// Do not write the url of the links
+    #[clap(long)]
+    pub no_links: bool,
+    /// Do not start with the preview text
+    #[clap(long)]
+    pub no_preview: bool,
+}
+
+impl From<RenderText> for TextRenderOptions {
+    fn from(value: RenderText) -> Self {
+        Self::default()
+            .with_line_width(Some(value.line_width).filter(|width| *width > 0))
+            .with_include_links(!value.no_links)
+            .with_include_preview(!value.no_preview)
+    }
+}
+
 fn main() {
     env_logger::init();
     if let Err(error) = Options::parse().execute() {
@@ -438,8 +854,7 @@ mod tests {
 
     fn execute_stdin<const N: usize, I: Into<String>>(args: [&str; N], input: I) {
         let opts = Options::parse_from(args);
-        let root = opts.parse_input(input.into()).unwrap();
-        opts.subcmd.execute(root).unwrap()
+        opts.execute_input(&input.into()).unwrap()
     }
 
     #[test]
@@ -501,16 +916,148 @@ mod tests {
         execute(["mrml-cli", "./resources/amario.mjml", "render"]);
     }
 
+    #[test]
+    fn render_amario_with_options() {
+        execute([
+            "mrml-cli",
+            "./resources/amario.mjml",
+            "render",
+            "--base-url",
+            "https://cdn.example.com",
+            "--id-prefix",
+            "amario-",
+            "--output-mode",
+            "minify",
+        ]);
+    }
+
+    #[test]
+    fn report_amario() {
+        execute(["mrml-cli", "./resources/amario.mjml", "report"]);
+        execute(["mrml-cli", "./resources/amario.mjml", "report", "--json"]);
+    }
+
+    #[test]
+    fn report_amario_over_limit() {
+        let result = Options::parse_from([
+            "mrml-cli",
+            "./resources/amario.mjml",
+            "report",
+            "--limit",
+            "1024",
+        ])
+        .execute();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn lint_amario() {
+        execute(["mrml-cli", "./resources/amario.mjml", "lint"]);
+        execute([
+            "mrml-cli",
+            "./resources/amario.mjml",
+            "lint",
+            "--client",
+            "gmail",
+            "--json",
+        ]);
+    }
+
+    #[test]
+    fn lint_amario_denied() {
+        execute([
+            "mrml-cli",
+            "./resources/amario.mjml",
+            "lint",
+            "--client",
+            "gmail",
+            "--deny",
+        ]);
+        let result = Options::parse_from([
+            "mrml-cli",
+            "./resources/amario.mjml",
+            "lint",
+            "--client",
+            "outlook",
+            "--deny",
+        ])
+        .execute();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn render_text_amario() {
+        execute(["mrml-cli", "./resources/amario.mjml", "render-text"]);
+    }
+
+    #[test]
+    fn render_text_amario_without_links() {
+        execute([
+            "mrml-cli",
+            "./resources/amario.mjml",
+            "render-text",
+            "--line-width",
+            "0",
+            "--no-links",
+            "--no-preview",
+        ]);
+    }
+
     #[test]
     fn validate_amario_json() {
         execute(["mrml-cli", "./resources/amario.json", "validate"]);
     }
 
+    #[test]
+    fn validate_invalid_json_stdin() {
+        let opts = Options::parse_from(["mrml-cli", "validate"]);
+        let input =
+            r#"{"type":"mjml","children":[{"type":"mj-body","children":[{"type":"mj-text"}]}]}"#;
+        assert_eq!(
+            opts.execute_input(input).unwrap_err(),
+            "template is invalid: element \"mj-text\" is not allowed in \"mj-body\" at \"/children/0/children/0\""
+        );
+    }
+
+    #[test]
+    fn json_schema() {
+        execute(["mrml-cli", "json-schema"]);
+        execute(["mrml-cli", "json-schema", "--typescript"]);
+    }
+
     #[test]
     fn validate_amario_mjml() {
         execute(["mrml-cli", "./resources/amario.mjml", "validate"]);
     }
 
+    #[test]
+    fn validate_amario_mjml_strict() {
+        execute([
+            "mrml-cli",
+            "./resources/amario.mjml",
+            "validate",
+            "--level",
+            "strict",
+        ]);
+    }
+
+    #[test]
+    fn validate_stdin_soft() {
+        execute_stdin(
+            ["mrml-cli", "validate"],
+            "<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>",
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn validate_stdin_strict() {
+        execute_stdin(
+            ["mrml-cli", "validate", "--level", "strict"],
+            "<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>",
+        );
+    }
+
     #[test]
     fn render_with_multi_include() {
         execute([
@@ -537,6 +1084,35 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn deps_with_local_include() {
+        execute([
+            "mrml-cli",
+            "--local-loader",
+            "./resources/partials",
+            "./resources/with-local-include.mjml",
+            "deps",
+            "--json",
+        ]);
+    }
+
+    #[test]
+    fn include_json_should_describe_include() {
+        use mrml::mj_include::head::MjIncludeHeadKind;
+        use mrml::prelude::parser::{Include, IncludeKind};
+
+        let include = Include {
+            path: String::from("style.css"),
+            kind: IncludeKind::Head(MjIncludeHeadKind::Css { inline: false }),
+            origin: origin_include(),
+            span: any_span(),
+        };
+        assert_eq!(
+            super::include_json(&include).to_string(),
+            r#"{"parent":"foo.mjml","path":"style.css","span":{"end":20,"start":10},"type":"css"}"#
+        );
+    }
+
     #[test]
     fn render_with_http_include() {
         execute([
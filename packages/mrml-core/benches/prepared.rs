@@ -0,0 +1,23 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use mrml::prelude::render::RenderOptions;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let opts = RenderOptions::default();
+    let data = include_str!("../resources/template/amario.mjml");
+    let root = mrml::mjml::Mjml::parse(data).unwrap();
+    let prepared = root.element.prepare().unwrap();
+
+    let mut group = c.benchmark_group("amario");
+    group.bench_function("render", |b| {
+        b.iter(|| root.element.render(black_box(&opts)).unwrap())
+    });
+    group.bench_function("prepared render", |b| {
+        b.iter(|| prepared.render(black_box(&opts)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -4,10 +4,14 @@ use crate::prelude::{Component, StaticTag};
 
 #[cfg(feature = "json")]
 mod json;
+#[cfg(feature = "parse")]
+mod parse;
 #[cfg(feature = "print")]
 mod print;
 #[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "render")]
+mod text;
 
 pub struct CommentTag;
 
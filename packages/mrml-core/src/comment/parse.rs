@@ -0,0 +1,22 @@
+use std::marker::PhantomData;
+
+use super::Comment;
+use crate::prelude::parser::MrmlCursor;
+
+impl Comment {
+    /// Builds the comment from its token, keeping its location with the
+    /// `span` feature.
+    #[cfg_attr(not(feature = "span"), allow(unused_variables))]
+    pub(crate) fn from_token(
+        cursor: &MrmlCursor<'_>,
+        token: &crate::prelude::parser::Comment<'_>,
+    ) -> Self {
+        Self {
+            tag: PhantomData,
+            attributes: (),
+            children: token.text.to_string(),
+            #[cfg(feature = "span")]
+            location: Some(cursor.token_location(token.span.into())),
+        }
+    }
+}
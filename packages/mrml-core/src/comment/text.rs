@@ -0,0 +1,6 @@
+use super::Comment;
+use crate::prelude::render::text::{TextBuffer, TextRender};
+
+impl TextRender for Comment {
+    fn render_text(&self, _buffer: &mut TextBuffer<'_>) {}
+}
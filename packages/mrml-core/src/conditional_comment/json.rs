@@ -10,7 +10,7 @@ impl Serialize for ConditionalComment {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.0)
+        serializer.serialize_str(&self.value)
     }
 }
 
@@ -28,7 +28,7 @@ impl Visitor<'_> for ConditionalCommentVisitor {
     where
         E: Error,
     {
-        Ok(ConditionalComment(value.to_string()))
+        Ok(ConditionalComment::from(value))
     }
 }
 
@@ -47,15 +47,15 @@ mod tests {
 
     #[test]
     fn serialize() {
-        let elt = ConditionalComment("<![endif]-->".to_string());
+        let elt = ConditionalComment::from("<![endif]-->");
         assert_eq!(serde_json::to_string(&elt).unwrap(), r#""<![endif]-->""#);
     }
 
     #[test]
     fn deserialize() {
-        let elt = ConditionalComment("<!--[if IE]>".to_string());
+        let elt = ConditionalComment::from("<!--[if IE]>");
         let json = serde_json::to_string(&elt).unwrap();
         let res: ConditionalComment = serde_json::from_str(&json).unwrap();
-        assert_eq!(res.0, elt.0);
+        assert_eq!(res.value, elt.value);
     }
 }
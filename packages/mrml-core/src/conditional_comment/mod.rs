@@ -1,21 +1,42 @@
 #[cfg(feature = "json")]
 mod json;
+#[cfg(feature = "parse")]
+mod parse;
 #[cfg(feature = "print")]
 mod print;
 #[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "template")]
+mod template;
+#[cfg(feature = "render")]
+mod text;
 
 #[derive(Clone, Debug, Default)]
-pub struct ConditionalComment(String);
+pub struct ConditionalComment {
+    value: String,
+    #[cfg(feature = "span")]
+    location: Option<crate::prelude::Location>,
+}
 
 impl ConditionalComment {
     pub fn inner_str(&self) -> &str {
-        &self.0
+        &self.value
+    }
+
+    /// Where the conditional comment has been parsed from, `None` when it has
+    /// been built another way.
+    #[cfg(feature = "span")]
+    pub fn location(&self) -> Option<&crate::prelude::Location> {
+        self.location.as_ref()
     }
 }
 
 impl<V: Into<String>> From<V> for ConditionalComment {
     fn from(value: V) -> Self {
-        Self(value.into())
+        Self {
+            value: value.into(),
+            #[cfg(feature = "span")]
+            location: None,
+        }
     }
 }
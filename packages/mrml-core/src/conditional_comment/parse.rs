@@ -0,0 +1,17 @@
+use htmlparser::StrSpan;
+
+use super::ConditionalComment;
+use crate::prelude::parser::MrmlCursor;
+
+impl ConditionalComment {
+    /// Builds the conditional comment from the span of its token, keeping its
+    /// location with the `span` feature.
+    #[cfg_attr(not(feature = "span"), allow(unused_variables))]
+    pub(crate) fn from_token(cursor: &MrmlCursor<'_>, span: &StrSpan<'_>) -> Self {
+        Self {
+            value: span.to_string(),
+            #[cfg(feature = "span")]
+            location: Some(cursor.token_location(span.into())),
+        }
+    }
+}
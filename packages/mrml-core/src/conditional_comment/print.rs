@@ -3,8 +3,12 @@ use crate::prelude::print::{Printable, Printer};
 
 impl Printable for ConditionalComment {
     fn print<P: Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        #[cfg(feature = "span")]
+        if printer.print_source(self.location(), || self.print_dense())? {
+            return Ok(());
+        }
         printer.push_indent();
-        printer.push_str(self.0.as_str());
+        printer.push_str(self.value.as_str());
         printer.push_new_line();
         Ok(())
     }
@@ -66,7 +66,7 @@ mod tests {
         let opts = RenderOptions::default();
         let mj_head = Some(MjHead::default());
         let header = Header::new(mj_head.as_ref(), "und", "auto");
-        let context = RenderContext::new(&opts, header);
+        let context = RenderContext::new(&opts, &header);
 
         let content = "<!--[if mso]><span>Test Content</span><![endif]-->";
         let elt = ConditionalComment::from(content);
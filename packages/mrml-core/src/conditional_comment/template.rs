@@ -0,0 +1,9 @@
+use super::ConditionalComment;
+use crate::prelude::render::Error;
+use crate::prelude::template::Template;
+
+impl Template for ConditionalComment {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        self.value.resolve(context)
+    }
+}
@@ -0,0 +1,6 @@
+use super::ConditionalComment;
+use crate::prelude::render::text::{TextBuffer, TextRender};
+
+impl TextRender for ConditionalComment {
+    fn render_text(&self, _buffer: &mut TextBuffer<'_>) {}
+}
@@ -0,0 +1,494 @@
+//! Colors of the attributes like `background-color`, parsed from the css
+//! formats that can be found in the templates.
+
+use std::convert::TryFrom;
+
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ColorParserError {
+    #[error("invalid hexadecimal color {0:?}")]
+    InvalidHex(String),
+    #[error("invalid color function {0:?}")]
+    InvalidFunction(String),
+    #[error("unknown color {0:?}")]
+    UnknownName(String),
+}
+
+/// The named colors of css, sorted by name.
+const NAMED_COLORS: [(&str, [u8; 3]); 148] = [
+    ("aliceblue", [0xf0, 0xf8, 0xff]),
+    ("antiquewhite", [0xfa, 0xeb, 0xd7]),
+    ("aqua", [0x00, 0xff, 0xff]),
+    ("aquamarine", [0x7f, 0xff, 0xd4]),
+    ("azure", [0xf0, 0xff, 0xff]),
+    ("beige", [0xf5, 0xf5, 0xdc]),
+    ("bisque", [0xff, 0xe4, 0xc4]),
+    ("black", [0x00, 0x00, 0x00]),
+    ("blanchedalmond", [0xff, 0xeb, 0xcd]),
+    ("blue", [0x00, 0x00, 0xff]),
+    ("blueviolet", [0x8a, 0x2b, 0xe2]),
+    ("brown", [0xa5, 0x2a, 0x2a]),
+    ("burlywood", [0xde, 0xb8, 0x87]),
+    ("cadetblue", [0x5f, 0x9e, 0xa0]),
+    ("chartreuse", [0x7f, 0xff, 0x00]),
+    ("chocolate", [0xd2, 0x69, 0x1e]),
+    ("coral", [0xff, 0x7f, 0x50]),
+    ("cornflowerblue", [0x64, 0x95, 0xed]),
+    ("cornsilk", [0xff, 0xf8, 0xdc]),
+    ("crimson", [0xdc, 0x14, 0x3c]),
+    ("cyan", [0x00, 0xff, 0xff]),
+    ("darkblue", [0x00, 0x00, 0x8b]),
+    ("darkcyan", [0x00, 0x8b, 0x8b]),
+    ("darkgoldenrod", [0xb8, 0x86, 0x0b]),
+    ("darkgray", [0xa9, 0xa9, 0xa9]),
+    ("darkgreen", [0x00, 0x64, 0x00]),
+    ("darkgrey", [0xa9, 0xa9, 0xa9]),
+    ("darkkhaki", [0xbd, 0xb7, 0x6b]),
+    ("darkmagenta", [0x8b, 0x00, 0x8b]),
+    ("darkolivegreen", [0x55, 0x6b, 0x2f]),
+    ("darkorange", [0xff, 0x8c, 0x00]),
+    ("darkorchid", [0x99, 0x32, 0xcc]),
+    ("darkred", [0x8b, 0x00, 0x00]),
+    ("darksalmon", [0xe9, 0x96, 0x7a]),
+    ("darkseagreen", [0x8f, 0xbc, 0x8f]),
+    ("darkslateblue", [0x48, 0x3d, 0x8b]),
+    ("darkslategray", [0x2f, 0x4f, 0x4f]),
+    ("darkslategrey", [0x2f, 0x4f, 0x4f]),
+    ("darkturquoise", [0x00, 0xce, 0xd1]),
+    ("darkviolet", [0x94, 0x00, 0xd3]),
+    ("deeppink", [0xff, 0x14, 0x93]),
+    ("deepskyblue", [0x00, 0xbf, 0xff]),
+    ("dimgray", [0x69, 0x69, 0x69]),
+    ("dimgrey", [0x69, 0x69, 0x69]),
+    ("dodgerblue", [0x1e, 0x90, 0xff]),
+    ("firebrick", [0xb2, 0x22, 0x22]),
+    ("floralwhite", [0xff, 0xfa, 0xf0]),
+    ("forestgreen", [0x22, 0x8b, 0x22]),
+    ("fuchsia", [0xff, 0x00, 0xff]),
+    ("gainsboro", [0xdc, 0xdc, 0xdc]),
+    ("ghostwhite", [0xf8, 0xf8, 0xff]),
+    ("gold", [0xff, 0xd7, 0x00]),
+    ("goldenrod", [0xda, 0xa5, 0x20]),
+    ("gray", [0x80, 0x80, 0x80]),
+    ("green", [0x00, 0x80, 0x00]),
+    ("greenyellow", [0xad, 0xff, 0x2f]),
+    ("grey", [0x80, 0x80, 0x80]),
+    ("honeydew", [0xf0, 0xff, 0xf0]),
+    ("hotpink", [0xff, 0x69, 0xb4]),
+    ("indianred", [0xcd, 0x5c, 0x5c]),
+    ("indigo", [0x4b, 0x00, 0x82]),
+    ("ivory", [0xff, 0xff, 0xf0]),
+    ("khaki", [0xf0, 0xe6, 0x8c]),
+    ("lavender", [0xe6, 0xe6, 0xfa]),
+    ("lavenderblush", [0xff, 0xf0, 0xf5]),
+    ("lawngreen", [0x7c, 0xfc, 0x00]),
+    ("lemonchiffon", [0xff, 0xfa, 0xcd]),
+    ("lightblue", [0xad, 0xd8, 0xe6]),
+    ("lightcoral", [0xf0, 0x80, 0x80]),
+    ("lightcyan", [0xe0, 0xff, 0xff]),
+    ("lightgoldenrodyellow", [0xfa, 0xfa, 0xd2]),
+    ("lightgray", [0xd3, 0xd3, 0xd3]),
+    ("lightgreen", [0x90, 0xee, 0x90]),
+    ("lightgrey", [0xd3, 0xd3, 0xd3]),
+    ("lightpink", [0xff, 0xb6, 0xc1]),
+    ("lightsalmon", [0xff, 0xa0, 0x7a]),
+    ("lightseagreen", [0x20, 0xb2, 0xaa]),
+    ("lightskyblue", [0x87, 0xce, 0xfa]),
+    ("lightslategray", [0x77, 0x88, 0x99]),
+    ("lightslategrey", [0x77, 0x88, 0x99]),
+    ("lightsteelblue", [0xb0, 0xc4, 0xde]),
+    ("lightyellow", [0xff, 0xff, 0xe0]),
+    ("lime", [0x00, 0xff, 0x00]),
+    ("limegreen", [0x32, 0xcd, 0x32]),
+    ("linen", [0xfa, 0xf0, 0xe6]),
+    ("magenta", [0xff, 0x00, 0xff]),
+    ("maroon", [0x80, 0x00, 0x00]),
+    ("mediumaquamarine", [0x66, 0xcd, 0xaa]),
+    ("mediumblue", [0x00, 0x00, 0xcd]),
+    ("mediumorchid", [0xba, 0x55, 0xd3]),
+    ("mediumpurple", [0x93, 0x70, 0xdb]),
+    ("mediumseagreen", [0x3c, 0xb3, 0x71]),
+    ("mediumslateblue", [0x7b, 0x68, 0xee]),
+    ("mediumspringgreen", [0x00, 0xfa, 0x9a]),
+    ("mediumturquoise", [0x48, 0xd1, 0xcc]),
+    ("mediumvioletred", [0xc7, 0x15, 0x85]),
+    ("midnightblue", [0x19, 0x19, 0x70]),
+    ("mintcream", [0xf5, 0xff, 0xfa]),
+    ("mistyrose", [0xff, 0xe4, 0xe1]),
+    ("moccasin", [0xff, 0xe4, 0xb5]),
+    ("navajowhite", [0xff, 0xde, 0xad]),
+    ("navy", [0x00, 0x00, 0x80]),
+    ("oldlace", [0xfd, 0xf5, 0xe6]),
+    ("olive", [0x80, 0x80, 0x00]),
+    ("olivedrab", [0x6b, 0x8e, 0x23]),
+    ("orange", [0xff, 0xa5, 0x00]),
+    ("orangered", [0xff, 0x45, 0x00]),
+    ("orchid", [0xda, 0x70, 0xd6]),
+    ("palegoldenrod", [0xee, 0xe8, 0xaa]),
+    ("palegreen", [0x98, 0xfb, 0x98]),
+    ("paleturquoise", [0xaf, 0xee, 0xee]),
+    ("palevioletred", [0xdb, 0x70, 0x93]),
+    ("papayawhip", [0xff, 0xef, 0xd5]),
+    ("peachpuff", [0xff, 0xda, 0xb9]),
+    ("peru", [0xcd, 0x85, 0x3f]),
+    ("pink", [0xff, 0xc0, 0xcb]),
+    ("plum", [0xdd, 0xa0, 0xdd]),
+    ("powderblue", [0xb0, 0xe0, 0xe6]),
+    ("purple", [0x80, 0x00, 0x80]),
+    ("rebeccapurple", [0x66, 0x33, 0x99]),
+    ("red", [0xff, 0x00, 0x00]),
+    ("rosybrown", [0xbc, 0x8f, 0x8f]),
+    ("royalblue", [0x41, 0x69, 0xe1]),
+    ("saddlebrown", [0x8b, 0x45, 0x13]),
+    ("salmon", [0xfa, 0x80, 0x72]),
+    ("sandybrown", [0xf4, 0xa4, 0x60]),
+    ("seagreen", [0x2e, 0x8b, 0x57]),
+    ("seashell", [0xff, 0xf5, 0xee]),
+    ("sienna", [0xa0, 0x52, 0x2d]),
+    ("silver", [0xc0, 0xc0, 0xc0]),
+    ("skyblue", [0x87, 0xce, 0xeb]),
+    ("slateblue", [0x6a, 0x5a, 0xcd]),
+    ("slategray", [0x70, 0x80, 0x90]),
+    ("slategrey", [0x70, 0x80, 0x90]),
+    ("snow", [0xff, 0xfa, 0xfa]),
+    ("springgreen", [0x00, 0xff, 0x7f]),
+    ("steelblue", [0x46, 0x82, 0xb4]),
+    ("tan", [0xd2, 0xb4, 0x8c]),
+    ("teal", [0x00, 0x80, 0x80]),
+    ("thistle", [0xd8, 0xbf, 0xd8]),
+    ("tomato", [0xff, 0x63, 0x47]),
+    ("turquoise", [0x40, 0xe0, 0xd0]),
+    ("violet", [0xee, 0x82, 0xee]),
+    ("wheat", [0xf5, 0xde, 0xb3]),
+    ("white", [0xff, 0xff, 0xff]),
+    ("whitesmoke", [0xf5, 0xf5, 0xf5]),
+    ("yellow", [0xff, 0xff, 0x00]),
+    ("yellowgreen", [0x9a, 0xcd, 0x32]),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Color {
+    /// From `#f00`, `#ff0000` or `rgb(255, 0, 0)`, with an opacity from 0 to
+    /// 1 when there is one, like in `#ff000080` or `rgba(255, 0, 0, 0.5)`.
+    Rgb {
+        red: u8,
+        green: u8,
+        blue: u8,
+        alpha: Option<f32>,
+    },
+    /// From `hsl(0, 100%, 50%)`, with the hue in degrees and the saturation
+    /// and the lightness in percents.
+    Hsl {
+        hue: f32,
+        saturation: f32,
+        lightness: f32,
+        alpha: Option<f32>,
+    },
+    /// A named color, like `red` or `transparent`, in lowercase.
+    Named(&'static str),
+}
+
+impl Color {
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::Rgb {
+            red,
+            green,
+            blue,
+            alpha: None,
+        }
+    }
+
+    /// The red, green and blue components of the color, `None` for
+    /// `transparent`.
+    pub fn to_rgb(&self) -> Option<[u8; 3]> {
+        match self {
+            Self::Rgb {
+                red, green, blue, ..
+            } => Some([*red, *green, *blue]),
+            Self::Hsl {
+                hue,
+                saturation,
+                lightness,
+                ..
+            } => Some(hsl_to_rgb(*hue, *saturation, *lightness)),
+            Self::Named(name) => find_named(name).map(|(_, rgb)| rgb),
+        }
+    }
+
+    /// The color as `#rrggbb`, without its opacity.
+    pub fn to_hex(&self) -> Option<String> {
+        self.to_rgb()
+            .map(|[red, green, blue]| format!("#{red:02x}{green:02x}{blue:02x}"))
+    }
+
+    /// The color for the VML elements of Outlook, that only understand the
+    /// names and the `#rrggbb` format.
+    pub fn to_vml(&self) -> String {
+        match self {
+            Self::Named(name) => name.to_string(),
+            other => other.to_hex().unwrap_or_else(|| other.to_string()),
+        }
+    }
+}
+
+fn find_named(name: &str) -> Option<(&'static str, [u8; 3])> {
+    NAMED_COLORS
+        .binary_search_by(|(item, _)| (*item).cmp(name))
+        .ok()
+        .map(|index| NAMED_COLORS[index])
+}
+
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> [u8; 3] {
+    let saturation = saturation.clamp(0.0, 100.0) / 100.0;
+    let lightness = lightness.clamp(0.0, 100.0) / 100.0;
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let second = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (red, green, blue) = match sector as u8 {
+        0 => (chroma, second, 0.0),
+        1 => (second, chroma, 0.0),
+        2 => (0.0, chroma, second),
+        3 => (0.0, second, chroma),
+        4 => (second, 0.0, chroma),
+        _ => (chroma, 0.0, second),
+    };
+    let offset = lightness - chroma / 2.0;
+    let channel = |value: f32| ((value + offset) * 255.0).round() as u8;
+    [channel(red), channel(green), channel(blue)]
+}
+
+fn parse_hex(input: &str) -> Result<Color, ColorParserError> {
+    let error = || ColorParserError::InvalidHex(input.to_string());
+    let digits = input.strip_prefix('#').ok_or_else(error)?;
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(error());
+    }
+    let values = match digits.len() {
+        // the short formats repeat each digit, `#f00` is `#ff0000`
+        3 | 4 => digits
+            .chars()
+            .map(|c| u8::from_str_radix(&format!("{c}{c}"), 16))
+            .collect::<Result<Vec<_>, _>>(),
+        6 | 8 => (0..digits.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&digits[index..index + 2], 16))
+            .collect::<Result<Vec<_>, _>>(),
+        _ => return Err(error()),
+    }
+    .map_err(|_| error())?;
+    Ok(Color::Rgb {
+        red: values[0],
+        green: values[1],
+        blue: values[2],
+        alpha: values.get(3).map(|alpha| *alpha as f32 / 255.0),
+    })
+}
+
+/// A number, or a percentage of `max`.
+fn parse_number(input: &str, max: f32) -> Option<f32> {
+    match input.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok().map(|value| value * max / 100.0),
+        None => input.parse::<f32>().ok(),
+    }
+    .filter(|value| value.is_finite())
+}
+
+fn parse_function(input: &str) -> Result<Color, ColorParserError> {
+    let error = || ColorParserError::InvalidFunction(input.to_string());
+    let lowercase = input.to_ascii_lowercase();
+    let (name, arguments) = lowercase
+        .strip_suffix(')')
+        .and_then(|inner| inner.split_once('('))
+        .ok_or_else(error)?;
+    // both `rgb(0, 0, 0, 0.5)` and `rgb(0 0 0 / 50%)` are valid
+    let arguments = arguments.replace([',', '/'], " ");
+    let arguments = arguments.split_whitespace().collect::<Vec<_>>();
+    if !matches!(arguments.len(), 3 | 4) {
+        return Err(error());
+    }
+    let alpha = match arguments.get(3) {
+        Some(value) => Some(parse_number(value, 1.0).ok_or_else(error)?.clamp(0.0, 1.0)),
+        None => None,
+    };
+    match name.trim() {
+        "rgb" | "rgba" => {
+            let channel = |value: &str| {
+                parse_number(value, 255.0).map(|value| value.clamp(0.0, 255.0).round() as u8)
+            };
+            Ok(Color::Rgb {
+                red: channel(arguments[0]).ok_or_else(error)?,
+                green: channel(arguments[1]).ok_or_else(error)?,
+                blue: channel(arguments[2]).ok_or_else(error)?,
+                alpha,
+            })
+        }
+        "hsl" | "hsla" => {
+            let hue = arguments[0].strip_suffix("deg").unwrap_or(arguments[0]);
+            let percent = |value: &str| {
+                let value = value.strip_suffix('%').unwrap_or(value);
+                parse_number(value, 100.0)
+            };
+            Ok(Color::Hsl {
+                hue: parse_number(hue, 360.0).ok_or_else(error)?,
+                saturation: percent(arguments[1]).ok_or_else(error)?,
+                lightness: percent(arguments[2]).ok_or_else(error)?,
+                alpha,
+            })
+        }
+        _ => Err(error()),
+    }
+}
+
+impl TryFrom<&str> for Color {
+    type Error = ColorParserError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        if value.starts_with('#') {
+            return parse_hex(value);
+        }
+        if value.contains('(') {
+            return parse_function(value);
+        }
+        let lowercase = value.to_ascii_lowercase();
+        if lowercase == "transparent" {
+            return Ok(Self::Named("transparent"));
+        }
+        find_named(&lowercase)
+            .map(|(name, _)| Self::Named(name))
+            .ok_or_else(|| ColorParserError::UnknownName(value.to_string()))
+    }
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rgb {
+                red,
+                green,
+                blue,
+                alpha: None,
+            } => write!(f, "#{red:02x}{green:02x}{blue:02x}"),
+            Self::Rgb {
+                red,
+                green,
+                blue,
+                alpha: Some(alpha),
+            } => write!(f, "rgba({red}, {green}, {blue}, {alpha})"),
+            Self::Hsl {
+                hue,
+                saturation,
+                lightness,
+                alpha: None,
+            } => write!(f, "hsl({hue}, {saturation}%, {lightness}%)"),
+            Self::Hsl {
+                hue,
+                saturation,
+                lightness,
+                alpha: Some(alpha),
+            } => write!(f, "hsla({hue}, {saturation}%, {lightness}%, {alpha})"),
+            Self::Named(name) => f.write_str(name),
+        }
+    }
+}
+
+/// The value of a color attribute for the VML elements of Outlook, unchanged
+/// when it's not a color.
+#[cfg(feature = "render")]
+pub fn vml_color(value: &str) -> String {
+    Color::try_from(value)
+        .map(|color| color.to_vml())
+        .unwrap_or_else(|_| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_keep_named_colors_sorted() {
+        assert!(NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn should_parse_hex() {
+        assert_eq!(Color::try_from("#f00").unwrap(), Color::rgb(255, 0, 0));
+        assert_eq!(
+            Color::try_from("#A1B2C3").unwrap(),
+            Color::rgb(161, 178, 195)
+        );
+        assert_eq!(
+            Color::try_from("#ff000080").unwrap(),
+            Color::Rgb {
+                red: 255,
+                green: 0,
+                blue: 0,
+                alpha: Some(128.0 / 255.0)
+            }
+        );
+        assert_eq!(
+            Color::try_from("#f008").unwrap().to_hex().unwrap(),
+            "#ff0000"
+        );
+        for value in ["#ff", "#ggg", "#fffff", "#"] {
+            assert!(Color::try_from(value).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn should_parse_functions() {
+        assert_eq!(
+            Color::try_from("rgb(0, 128, 255)").unwrap(),
+            Color::rgb(0, 128, 255)
+        );
+        assert_eq!(
+            Color::try_from("RGBA(100%, 0%, 0%, 50%)").unwrap(),
+            Color::Rgb {
+                red: 255,
+                green: 0,
+                blue: 0,
+                alpha: Some(0.5)
+            }
+        );
+        assert_eq!(
+            Color::try_from("rgb(0 0 0 / 0.2)").unwrap().to_string(),
+            "rgba(0, 0, 0, 0.2)"
+        );
+        assert_eq!(
+            Color::try_from("hsl(120deg, 100%, 25%)")
+                .unwrap()
+                .to_hex()
+                .unwrap(),
+            "#008000"
+        );
+        for value in [
+            "rgb(0, 0, 0",
+            "rgb(0, 0)",
+            "rgb(a, b, c)",
+            "cmyk(0, 0, 0, 0)",
+        ] {
+            assert!(Color::try_from(value).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn should_parse_names() {
+        assert_eq!(Color::try_from("Red").unwrap(), Color::Named("red"));
+        assert_eq!(Color::try_from("transparent").unwrap().to_rgb(), None);
+        assert_eq!(
+            Color::try_from("rebeccapurple").unwrap().to_hex().unwrap(),
+            "#663399"
+        );
+        assert!(Color::try_from("redd").is_err());
+        assert!(Color::try_from("red blue").is_err());
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn should_normalize_for_vml() {
+        assert_eq!(vml_color("#abc"), "#aabbcc");
+        assert_eq!(vml_color("rgba(255, 0, 0, 0.5)"), "#ff0000");
+        assert_eq!(vml_color("red"), "red");
+        assert_eq!(vml_color("transparent"), "transparent");
+        assert_eq!(vml_color("{{ color }}"), "{{ color }}");
+    }
+}
@@ -0,0 +1,474 @@
+//! Minifies or indents the rendered html, see
+//! [`OutputMode`](crate::prelude::render::OutputMode).
+//!
+//! Like the [`html`](super::html) scanner, this only splits the document in
+//! tags, texts and comments. The conditional comments, holding the markup for
+//! Outlook, are never rewritten apart from the whitespaces between their tags.
+
+use super::html::{
+    find_from, is_raw_text_element, read_attributes, read_name, skip_whitespaces, HtmlAttribute,
+};
+use crate::prelude::is_void_element;
+
+/// Elements laid out inline, for which the whitespaces around matter.
+const INLINE_ELEMENTS: &[&str] = &[
+    "a", "abbr", "b", "bdi", "bdo", "button", "cite", "code", "data", "dfn", "em", "font", "i",
+    "img", "input", "kbd", "label", "mark", "q", "s", "samp", "select", "small", "span", "strike",
+    "strong", "sub", "sup", "textarea", "time", "u", "var",
+];
+
+#[derive(Debug)]
+enum Token<'a> {
+    /// A doctype or processing instruction.
+    Declaration(&'a str),
+    Comment(&'a str),
+    Open {
+        name: &'a str,
+        source: &'a str,
+        attributes: Vec<HtmlAttribute>,
+        self_closing: bool,
+    },
+    Close {
+        name: &'a str,
+        source: &'a str,
+    },
+    Text(&'a str),
+    /// The content of a `style`, `script`, `textarea` or `title` element.
+    Raw(&'a str),
+}
+
+impl Token<'_> {
+    fn is_inline(&self) -> bool {
+        match self {
+            Self::Text(_) => true,
+            Self::Open { name, .. } | Self::Close { name, .. } => INLINE_ELEMENTS
+                .iter()
+                .any(|inline| name.eq_ignore_ascii_case(inline)),
+            _ => false,
+        }
+    }
+
+    fn is_open(&self, tag: &str) -> bool {
+        matches!(self, Self::Open { name, self_closing: false, .. } if name.eq_ignore_ascii_case(tag))
+    }
+
+    fn is_close(&self, tag: &str) -> bool {
+        matches!(self, Self::Close { name, .. } if name.eq_ignore_ascii_case(tag))
+    }
+}
+
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < source.len() {
+        let Some(start) = find_from(source, pos, "<") else {
+            tokens.push(Token::Text(&source[pos..]));
+            break;
+        };
+        if start > pos {
+            tokens.push(Token::Text(&source[pos..start]));
+        }
+        let rest = &source[start..];
+        if rest.starts_with("<!--") {
+            pos = find_from(source, start + 4, "-->")
+                .map(|end| end + 3)
+                .unwrap_or(source.len());
+            tokens.push(Token::Comment(&source[start..pos]));
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            pos = find_from(source, start, ">")
+                .map(|end| end + 1)
+                .unwrap_or(source.len());
+            tokens.push(Token::Declaration(&source[start..pos]));
+        } else if rest.starts_with("</")
+            && bytes.get(start + 2).is_some_and(u8::is_ascii_alphabetic)
+        {
+            let name_end = read_name(bytes, start + 2);
+            pos = find_from(source, name_end, ">")
+                .map(|end| end + 1)
+                .unwrap_or(source.len());
+            tokens.push(Token::Close {
+                name: &source[start + 2..name_end],
+                source: &source[start..pos],
+            });
+        } else if bytes.get(start + 1).is_some_and(u8::is_ascii_alphabetic) {
+            let name_end = read_name(bytes, start + 1);
+            let name = &source[start + 1..name_end];
+            let (attributes, _, self_closing, end) = read_attributes(source, name_end);
+            tokens.push(Token::Open {
+                name,
+                source: &source[start..end],
+                attributes,
+                self_closing,
+            });
+            pos = end;
+            let tag = name.to_ascii_lowercase();
+            if !self_closing && is_raw_text_element(&tag) {
+                let closing = format!("</{tag}");
+                let content_end = source[end..]
+                    .to_ascii_lowercase()
+                    .find(&closing)
+                    .map(|found| end + found)
+                    .unwrap_or(source.len());
+                tokens.push(Token::Raw(&source[end..content_end]));
+                pos = content_end;
+            }
+        } else {
+            // a `<` that doesn't start a tag is part of the text
+            pos = find_from(source, start + 1, "<").unwrap_or(source.len());
+            tokens.push(Token::Text(&source[start..pos]));
+        }
+    }
+    tokens
+}
+
+/// Whether the comment holds markup for some clients only, like
+/// `<!--[if mso | IE]><table><![endif]-->`, unlike the comments around the
+/// markup hidden from those clients, like `<!--[if !mso]><!-->`.
+fn is_conditional_comment(value: &str) -> bool {
+    value.starts_with("<!--[if") && !value.ends_with("<!-->")
+}
+
+/// Removes the whitespaces between the tags of a conditional comment, leaving
+/// the rest untouched.
+fn minify_conditional_comment(value: &str, output: &mut String) {
+    let bytes = value.as_bytes();
+    let mut last = 0;
+    for (found, _) in value.match_indices('>') {
+        let next = skip_whitespaces(bytes, found + 1);
+        if next > found + 1 && bytes.get(next) == Some(&b'<') {
+            output.push_str(&value[last..=found]);
+            last = next;
+        }
+    }
+    output.push_str(&value[last..]);
+}
+
+/// Removes the comments and the whitespaces that are not needed in a
+/// stylesheet or a `style` attribute.
+fn minify_css(css: &str) -> String {
+    let mut output = String::with_capacity(css.len());
+    let mut chars = css.chars().peekable();
+    let mut space = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+                space = true;
+            }
+            c if c.is_whitespace() => space = true,
+            c => {
+                if space
+                    && !output.is_empty()
+                    && !output.ends_with(['{', '}', ';', ':', ',', '('])
+                    && !matches!(c, '{' | '}' | ';' | ',' | ')')
+                {
+                    output.push(' ');
+                }
+                space = false;
+                if c == '}' && output.ends_with(';') {
+                    output.pop();
+                }
+                output.push(c);
+                if matches!(c, '"' | '\'') {
+                    let mut escaped = false;
+                    for inner in chars.by_ref() {
+                        output.push(inner);
+                        if escaped {
+                            escaped = false;
+                        } else if inner == '\\' {
+                            escaped = true;
+                        } else if inner == c {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    output
+}
+
+fn can_unquote(value: &str) -> bool {
+    !value.is_empty()
+        && !value.ends_with('/')
+        && value.bytes().all(|c| {
+            !c.is_ascii_whitespace() && !matches!(c, b'"' | b'\'' | b'=' | b'<' | b'>' | b'`')
+        })
+}
+
+fn minify_open_tag(
+    document: &str,
+    name: &str,
+    attributes: &[HtmlAttribute],
+    self_closing: bool,
+    output: &mut String,
+) {
+    output.push('<');
+    output.push_str(name);
+    let mut unquoted = false;
+    for attribute in attributes {
+        output.push(' ');
+        // the name keeps its case, which matters for svg
+        let name_start = attribute.span.start;
+        output.push_str(&document[name_start..name_start + attribute.name.len()]);
+        unquoted = false;
+        let Some(range) = attribute.value.clone() else {
+            continue;
+        };
+        let value = &document[range];
+        let value = if attribute.name == "style" {
+            let mut css = minify_css(value);
+            if css.ends_with(';') {
+                css.pop();
+            }
+            std::borrow::Cow::Owned(css)
+        } else {
+            std::borrow::Cow::Borrowed(value)
+        };
+        output.push('=');
+        if can_unquote(&value) {
+            output.push_str(&value);
+            unquoted = true;
+        } else {
+            let quote = if value.contains('"') { '\'' } else { '"' };
+            output.push(quote);
+            output.push_str(&value);
+            output.push(quote);
+        }
+    }
+    if self_closing {
+        output.push_str(if unquoted { " />" } else { "/>" });
+    } else {
+        output.push('>');
+    }
+}
+
+/// Collapses the whitespaces of a text, keeping one at its start or end only
+/// when it's next to inline content.
+fn minify_text(value: &str, keep_start: bool, keep_end: bool, output: &mut String) {
+    let mut words = value.split_ascii_whitespace().peekable();
+    if words.peek().is_none() {
+        if keep_start && keep_end && !value.is_empty() {
+            output.push(' ');
+        }
+        return;
+    }
+    if keep_start && value.starts_with(|c: char| c.is_ascii_whitespace()) {
+        output.push(' ');
+    }
+    if let Some(first) = words.next() {
+        output.push_str(first);
+    }
+    for word in words {
+        output.push(' ');
+        output.push_str(word);
+    }
+    if keep_end && value.ends_with(|c: char| c.is_ascii_whitespace()) {
+        output.push(' ');
+    }
+}
+
+pub(crate) fn minify(html: &str) -> String {
+    let tokens = tokenize(html);
+    let mut output = String::with_capacity(html.len());
+    // the whitespaces are kept in the `pre` elements
+    let mut preformatted = 0usize;
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Declaration(value) => output.push_str(value),
+            Token::Comment(value) if is_conditional_comment(value) => {
+                minify_conditional_comment(value, &mut output)
+            }
+            Token::Comment(value) => output.push_str(value),
+            Token::Open {
+                name,
+                attributes,
+                self_closing,
+                ..
+            } => {
+                if token.is_open("pre") {
+                    preformatted += 1;
+                }
+                // the positions of the attributes are relative to the document
+                minify_open_tag(html, name, attributes, *self_closing, &mut output);
+            }
+            Token::Close { name, .. } => {
+                if token.is_close("pre") {
+                    preformatted = preformatted.saturating_sub(1);
+                }
+                output.push_str("</");
+                output.push_str(name);
+                output.push('>');
+            }
+            Token::Raw(value) if index > 0 && tokens[index - 1].is_open("style") => {
+                output.push_str(&minify_css(value));
+            }
+            Token::Raw(value) => output.push_str(value),
+            Token::Text(value) if preformatted > 0 => output.push_str(value),
+            Token::Text(value) => minify_text(
+                value,
+                index > 0 && tokens[index - 1].is_inline(),
+                tokens.get(index + 1).is_some_and(Token::is_inline),
+                &mut output,
+            ),
+        }
+    }
+    output
+}
+
+fn push_line(output: &mut String, depth: usize, value: &str) {
+    if !output.is_empty() {
+        output.push('\n');
+    }
+    for _ in 0..depth {
+        output.push_str("  ");
+    }
+    output.push_str(value);
+}
+
+pub(crate) fn prettify(html: &str) -> String {
+    let tokens = tokenize(html);
+    let mut output = String::with_capacity(html.len() * 2);
+    let mut depth = 0usize;
+    let mut index = 0;
+    while index < tokens.len() {
+        match &tokens[index] {
+            Token::Declaration(value) | Token::Comment(value) => {
+                push_line(&mut output, depth, value.trim())
+            }
+            Token::Open {
+                name,
+                source,
+                self_closing,
+                ..
+            } => {
+                if *self_closing || is_void_element(&name.to_ascii_lowercase()) {
+                    push_line(&mut output, depth, source);
+                } else if let Some(Token::Close { source: close, .. }) =
+                    tokens.get(index + 1).filter(|next| next.is_close(name))
+                {
+                    push_line(&mut output, depth, &format!("{source}{close}"));
+                    index += 1;
+                } else if let (
+                    Some(Token::Text(text) | Token::Raw(text)),
+                    Some(Token::Close { source: close, .. }),
+                ) = (
+                    tokens.get(index + 1),
+                    tokens.get(index + 2).filter(|next| next.is_close(name)),
+                ) {
+                    // an element with a single line of text stays on one line
+                    if text.trim().contains('\n') {
+                        push_line(&mut output, depth, source);
+                        depth += 1;
+                    } else {
+                        push_line(
+                            &mut output,
+                            depth,
+                            &format!("{source}{}{close}", text.trim()),
+                        );
+                        index += 2;
+                    }
+                } else {
+                    push_line(&mut output, depth, source);
+                    depth += 1;
+                }
+            }
+            Token::Close { source, .. } => {
+                depth = depth.saturating_sub(1);
+                push_line(&mut output, depth, source);
+            }
+            Token::Text(value) | Token::Raw(value) => {
+                for line in value.lines().map(str::trim).filter(|line| !line.is_empty()) {
+                    push_line(&mut output, depth, line);
+                }
+            }
+        }
+        index += 1;
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{minify, minify_css, prettify};
+
+    #[test]
+    fn should_minify_css() {
+        assert_eq!(
+            minify_css(
+                "/* comment */\n.a , .b > p {\n  color : red;\n  font-family: 'Open  Sans', sans-serif;\n}\n@media only screen and (min-width: 480px) { .c { width: 100% !important; } }"
+            ),
+            ".a,.b > p{color :red;font-family:'Open  Sans',sans-serif}@media only screen and (min-width:480px){.c{width:100% !important}}"
+        );
+    }
+
+    #[test]
+    fn should_minify_html() {
+        let html = r#"<!doctype html><html><head><title> Hello </title><style type="text/css">
+  p { margin: 0; }
+</style></head><body style="word-spacing: normal; background-color: #fff;">
+  <div class="a b" data-id="42">
+    Hello   <b>World</b> !
+  </div>
+  <img src="logo.png" alt="" />
+  <p>  </p>
+</body></html>"#;
+        assert_eq!(
+            minify(html),
+            r#"<!doctype html><html><head><title> Hello </title><style type=text/css>p{margin:0}</style></head><body style=word-spacing:normal;background-color:#fff><div class="a b" data-id=42>Hello <b>World</b> !</div><img src=logo.png alt=""/><p></p></body></html>"#
+        );
+    }
+
+    #[test]
+    fn should_keep_conditional_comments() {
+        let html = "<div>\n  <!--[if mso | IE]><table role=\"presentation\" >\n  <tr><td style=\"width:600px;\" ><![endif]-->\n  <p>Hello</p>\n  <!--[if !mso]><!-->\n  <span>World</span>\n  <!--<![endif]-->\n</div>";
+        assert_eq!(
+            minify(html),
+            "<div><!--[if mso | IE]><table role=\"presentation\" ><tr><td style=\"width:600px;\" ><![endif]--><p>Hello</p><!--[if !mso]><!--><span>World</span><!--<![endif]--></div>"
+        );
+    }
+
+    #[test]
+    fn should_keep_preformatted_text() {
+        assert_eq!(
+            minify("<div>\n  <pre>  a\n  b </pre>\n</div>"),
+            "<div><pre>  a\n  b </pre></div>"
+        );
+    }
+
+    #[test]
+    fn should_prettify_html() {
+        let html = r#"<!doctype html><html><head><title>Hello</title><style>
+p { margin: 0; }
+</style></head><body><div class="a"><!--[if mso]><table><![endif]--><p>Hello <b>World</b></p><br><span></span></div></body></html>"#;
+        assert_eq!(
+            prettify(html),
+            r#"<!doctype html>
+<html>
+  <head>
+    <title>Hello</title>
+    <style>p { margin: 0; }</style>
+  </head>
+  <body>
+    <div class="a">
+      <!--[if mso]><table><![endif]-->
+      <p>
+        Hello
+        <b>World</b>
+      </p>
+      <br>
+      <span></span>
+    </div>
+  </body>
+</html>"#
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! Lightweight scanner over the generated html, used to post-process the
+//! rendered document (e.g. to apply `mj-html-attributes`).
+//!
+//! This is not a complete html parser: it only keeps track of the elements
+//! and their attributes, with their position in the source. Comments,
+//! including the MSO conditional comments, are skipped like a browser would.
+
+use std::ops::Range;
+
+use crate::prelude::is_void_element;
+
+#[derive(Debug)]
+pub(crate) struct HtmlAttribute {
+    pub name: String,
+    pub value: Option<Range<usize>>,
+    /// Position of the whole attribute, name and value included.
+    pub span: Range<usize>,
+}
+
+#[derive(Debug)]
+pub(crate) struct HtmlElement {
+    pub tag: String,
+    pub attributes: Vec<HtmlAttribute>,
+    pub parent: Option<usize>,
+    /// Index of the element amongst the element children of its parent.
+    pub index: usize,
+    /// Position of the end of the opening tag, before `>` or `/>`.
+    pub open_end: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct HtmlDocument<'a> {
+    source: &'a str,
+    elements: Vec<HtmlElement>,
+    children: Vec<Vec<usize>>,
+    roots: Vec<usize>,
+}
+
+pub(crate) fn is_raw_text_element(tag: &str) -> bool {
+    matches!(tag, "script" | "style" | "textarea" | "title")
+}
+
+pub(crate) fn find_from(source: &str, from: usize, pattern: &str) -> Option<usize> {
+    source
+        .get(from..)
+        .and_then(|rest| rest.find(pattern))
+        .map(|found| from + found)
+}
+
+pub(crate) fn skip_whitespaces(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    pos
+}
+
+pub(crate) fn read_name(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len()
+        && !bytes[pos].is_ascii_whitespace()
+        && !matches!(bytes[pos], b'>' | b'/' | b'=')
+    {
+        pos += 1;
+    }
+    pos
+}
+
+/// Reads the attributes of an opening tag, from the end of its name. Returns
+/// them with the position of the end of the opening tag, before `>` or `/>`,
+/// whether the tag is self closing and the position after the tag.
+pub(crate) fn read_attributes(
+    source: &str,
+    mut pos: usize,
+) -> (Vec<HtmlAttribute>, usize, bool, usize) {
+    let bytes = source.as_bytes();
+    let mut attributes = Vec::new();
+    let (open_end, self_closing, end) = loop {
+        pos = skip_whitespaces(bytes, pos);
+        match bytes.get(pos) {
+            None => break (source.len(), false, source.len()),
+            Some(b'>') => break (pos, false, pos + 1),
+            Some(b'/') if bytes.get(pos + 1) == Some(&b'>') => break (pos, true, pos + 2),
+            Some(b'/') | Some(b'=') => pos += 1,
+            Some(_) => {
+                let attr_start = pos;
+                let attr_name_end = read_name(bytes, pos);
+                let name = source[attr_start..attr_name_end].to_ascii_lowercase();
+                pos = skip_whitespaces(bytes, attr_name_end);
+                let value = if bytes.get(pos) == Some(&b'=') {
+                    pos = skip_whitespaces(bytes, pos + 1);
+                    match bytes.get(pos) {
+                        Some(quote @ (b'"' | b'\'')) => {
+                            let value_start = pos + 1;
+                            let value_end = bytes[value_start..]
+                                .iter()
+                                .position(|c| c == quote)
+                                .map(|found| value_start + found)
+                                .unwrap_or(source.len());
+                            pos = (value_end + 1).min(source.len());
+                            Some(value_start..value_end)
+                        }
+                        _ => {
+                            let value_start = pos;
+                            while pos < bytes.len()
+                                && !bytes[pos].is_ascii_whitespace()
+                                && bytes[pos] != b'>'
+                            {
+                                pos += 1;
+                            }
+                            Some(value_start..pos)
+                        }
+                    }
+                } else {
+                    pos = attr_name_end;
+                    None
+                };
+                attributes.push(HtmlAttribute {
+                    name,
+                    value,
+                    span: attr_start..pos,
+                });
+            }
+        }
+    };
+    (attributes, open_end, self_closing, end)
+}
+
+impl<'a> HtmlDocument<'a> {
+    pub fn parse(source: &'a str) -> Self {
+        let mut doc = Self {
+            source,
+            elements: Vec::new(),
+            children: Vec::new(),
+            roots: Vec::new(),
+        };
+        let bytes = source.as_bytes();
+        let mut stack: Vec<usize> = Vec::new();
+        let mut pos = 0;
+        while let Some(start) = find_from(source, pos, "<") {
+            let rest = &source[start..];
+            if rest.starts_with("<!--") {
+                pos = find_from(source, start + 4, "-->")
+                    .map(|end| end + 3)
+                    .unwrap_or(source.len());
+            } else if rest.starts_with("<!") || rest.starts_with("<?") {
+                pos = find_from(source, start, ">")
+                    .map(|end| end + 1)
+                    .unwrap_or(source.len());
+            } else if rest.starts_with("</") {
+                let name_end = read_name(bytes, start + 2);
+                let tag = source[start + 2..name_end].to_ascii_lowercase();
+                if let Some(found) = stack.iter().rposition(|idx| doc.elements[*idx].tag == tag) {
+                    stack.truncate(found);
+                }
+                pos = find_from(source, name_end, ">")
+                    .map(|end| end + 1)
+                    .unwrap_or(source.len());
+            } else if bytes.get(start + 1).is_some_and(u8::is_ascii_alphabetic) {
+                pos = doc.parse_element(start, &mut stack);
+            } else {
+                pos = start + 1;
+            }
+        }
+        doc
+    }
+
+    fn parse_element(&mut self, start: usize, stack: &mut Vec<usize>) -> usize {
+        let source = self.source;
+        let bytes = source.as_bytes();
+        let name_end = read_name(bytes, start + 1);
+        let tag = source[start + 1..name_end].to_ascii_lowercase();
+        let (attributes, open_end, self_closing, end) = read_attributes(source, name_end);
+
+        let parent = stack.last().copied();
+        let siblings = match parent {
+            Some(parent) => &mut self.children[parent],
+            None => &mut self.roots,
+        };
+        let index = self.elements.len();
+        let position = siblings.len();
+        siblings.push(index);
+        self.children.push(Vec::new());
+        self.elements.push(HtmlElement {
+            tag,
+            attributes,
+            parent,
+            index: position,
+            open_end,
+        });
+
+        let tag = self.elements[index].tag.as_str();
+        if self_closing || is_void_element(tag) {
+            end
+        } else if is_raw_text_element(tag) {
+            let closing = format!("</{tag}");
+            let lowercase = source[end..].to_ascii_lowercase();
+            match lowercase.find(&closing) {
+                Some(found) => {
+                    stack.push(index);
+                    end + found
+                }
+                None => source.len(),
+            }
+        } else {
+            stack.push(index);
+            end
+        }
+    }
+
+    pub fn elements(&self) -> &[HtmlElement] {
+        &self.elements
+    }
+
+    pub fn element(&self, index: usize) -> &HtmlElement {
+        &self.elements[index]
+    }
+
+    /// Returns the element children of the given element, or the root
+    /// elements when `None`.
+    pub fn children(&self, parent: Option<usize>) -> &[usize] {
+        match parent {
+            Some(parent) => &self.children[parent],
+            None => &self.roots,
+        }
+    }
+
+    pub fn attribute(&self, index: usize, name: &str) -> Option<&'a str> {
+        self.elements[index]
+            .attributes
+            .iter()
+            .find(|attr| attr.name == name)
+            .map(|attr| {
+                attr.value
+                    .as_ref()
+                    .map(|range| &self.source[range.clone()])
+                    .unwrap_or("")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HtmlDocument;
+
+    #[test]
+    fn should_build_tree() {
+        let doc = HtmlDocument::parse(
+            r#"<!doctype html><html><head><meta charset="utf-8"><style>p > a { color: red; }</style></head><body class="a b"><p id=intro>Hello<br/><a href='x'>link</a></p></body></html>"#,
+        );
+        let tags = doc
+            .elements()
+            .iter()
+            .map(|elt| elt.tag.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tags,
+            vec!["html", "head", "meta", "style", "body", "p", "br", "a"]
+        );
+        assert_eq!(doc.element(7).parent, Some(5));
+        assert_eq!(doc.element(7).index, 1);
+        assert_eq!(doc.attribute(4, "class"), Some("a b"));
+        assert_eq!(doc.attribute(5, "id"), Some("intro"));
+        assert_eq!(doc.attribute(7, "href"), Some("x"));
+    }
+
+    #[test]
+    fn should_skip_conditional_comments() {
+        let doc = HtmlDocument::parse(
+            "<div><!--[if mso | IE]><table><tr><td><![endif]--><p>Hello</p><!--[if mso | IE]></td></tr></table><![endif]--></div>",
+        );
+        let tags = doc
+            .elements()
+            .iter()
+            .map(|elt| elt.tag.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(tags, vec!["div", "p"]);
+        assert_eq!(doc.element(1).parent, Some(0));
+    }
+
+    #[test]
+    fn should_keep_negation_conditional_content() {
+        let doc =
+            HtmlDocument::parse("<!--[if !mso]><!--><div><span></span></div><!--<![endif]-->");
+        assert_eq!(doc.elements().len(), 2);
+        assert_eq!(doc.element(1).parent, Some(0));
+    }
+}
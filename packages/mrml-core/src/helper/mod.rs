@@ -1,8 +1,19 @@
+#[cfg(any(feature = "render", feature = "validate"))]
+#[cfg_attr(not(feature = "validate"), allow(dead_code))]
+pub mod color;
 #[cfg(feature = "render")]
+pub mod format;
+#[cfg(feature = "render")]
+pub mod html;
+#[cfg(feature = "render")]
+pub mod selector;
+#[cfg(any(feature = "builder", feature = "render", feature = "validate"))]
+#[cfg_attr(not(feature = "render"), allow(dead_code))]
 pub mod size;
 #[cfg(feature = "render")]
 pub mod sort;
-#[cfg(feature = "render")]
+#[cfg(any(feature = "builder", feature = "render", feature = "validate"))]
+#[cfg_attr(not(feature = "render"), allow(dead_code))]
 pub mod spacing;
 #[cfg(feature = "render")]
 pub mod style;
@@ -0,0 +1,466 @@
+//! Subset of the css selectors, matched against an [`HtmlDocument`].
+//!
+//! Supported: type (`div`), universal (`*`), class (`.a`), id (`#a`),
+//! attribute (`[a]`, `[a=b]`, `[a~=b]`, `[a|=b]`, `[a^=b]`, `[a$=b]`,
+//! `[a*=b]`), `:first-child`, `:last-child`, `:nth-child(an+b)`, the
+//! descendant, child (`>`), adjacent (`+`) and sibling (`~`) combinators, and
+//! selector lists (`a, b`).
+
+use super::html::HtmlDocument;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct InvalidSelector(pub String);
+
+impl std::fmt::Display for InvalidSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid selector {:?}", self.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+    Adjacent,
+    Sibling,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum AttributeOperator {
+    Exists,
+    Equals(String),
+    Includes(String),
+    DashMatch(String),
+    Prefix(String),
+    Suffix(String),
+    Substring(String),
+}
+
+impl AttributeOperator {
+    fn matches(&self, value: &str) -> bool {
+        match self {
+            Self::Exists => true,
+            Self::Equals(expected) => value == expected,
+            Self::Includes(expected) => value.split_whitespace().any(|item| item == expected),
+            Self::DashMatch(expected) => {
+                value == expected
+                    || value
+                        .strip_prefix(expected.as_str())
+                        .is_some_and(|rest| rest.starts_with('-'))
+            }
+            Self::Prefix(expected) => !expected.is_empty() && value.starts_with(expected.as_str()),
+            Self::Suffix(expected) => !expected.is_empty() && value.ends_with(expected.as_str()),
+            Self::Substring(expected) => !expected.is_empty() && value.contains(expected.as_str()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum PseudoClass {
+    First,
+    Last,
+    /// `:nth-child(an+b)`
+    Nth(i32, i32),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Compound {
+    tag: Option<String>,
+    ids: Vec<String>,
+    classes: Vec<String>,
+    attributes: Vec<(String, AttributeOperator)>,
+    pseudo_classes: Vec<PseudoClass>,
+}
+
+impl Compound {
+    fn is_empty(&self) -> bool {
+        self.tag.is_none()
+            && self.ids.is_empty()
+            && self.classes.is_empty()
+            && self.attributes.is_empty()
+            && self.pseudo_classes.is_empty()
+    }
+
+    fn matches(&self, doc: &HtmlDocument<'_>, index: usize) -> bool {
+        let element = doc.element(index);
+        if let Some(ref tag) = self.tag {
+            if tag != "*" && *tag != element.tag {
+                return false;
+            }
+        }
+        if !self
+            .ids
+            .iter()
+            .all(|id| doc.attribute(index, "id") == Some(id.as_str()))
+        {
+            return false;
+        }
+        if !self.classes.is_empty() {
+            let classes = doc.attribute(index, "class").unwrap_or("");
+            if !self
+                .classes
+                .iter()
+                .all(|name| classes.split_whitespace().any(|item| item == name))
+            {
+                return false;
+            }
+        }
+        if !self.attributes.iter().all(|(name, operator)| {
+            doc.attribute(index, name)
+                .is_some_and(|value| operator.matches(value))
+        }) {
+            return false;
+        }
+        self.pseudo_classes.iter().all(|pseudo| {
+            let position = element.index as i32 + 1;
+            match pseudo {
+                PseudoClass::First => position == 1,
+                PseudoClass::Last => position as usize == doc.children(element.parent).len(),
+                PseudoClass::Nth(0, b) => position == *b,
+                PseudoClass::Nth(a, b) => {
+                    let diff = position - b;
+                    diff % a == 0 && diff / a >= 0
+                }
+            }
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Complex {
+    /// Each compound with the combinator linking it to the previous one.
+    parts: Vec<(Combinator, Compound)>,
+}
+
+impl Complex {
+    fn matches_at(&self, doc: &HtmlDocument<'_>, index: usize, position: usize) -> bool {
+        let (combinator, compound) = &self.parts[position];
+        if !compound.matches(doc, index) {
+            return false;
+        }
+        if position == 0 {
+            return true;
+        }
+        let element = doc.element(index);
+        match combinator {
+            Combinator::Child => element
+                .parent
+                .is_some_and(|parent| self.matches_at(doc, parent, position - 1)),
+            Combinator::Descendant => {
+                let mut current = element.parent;
+                while let Some(parent) = current {
+                    if self.matches_at(doc, parent, position - 1) {
+                        return true;
+                    }
+                    current = doc.element(parent).parent;
+                }
+                false
+            }
+            Combinator::Adjacent => element.index.checked_sub(1).is_some_and(|previous| {
+                let sibling = doc.children(element.parent)[previous];
+                self.matches_at(doc, sibling, position - 1)
+            }),
+            Combinator::Sibling => doc.children(element.parent)[..element.index]
+                .iter()
+                .any(|sibling| self.matches_at(doc, *sibling, position - 1)),
+        }
+    }
+
+    fn matches(&self, doc: &HtmlDocument<'_>, index: usize) -> bool {
+        self.matches_at(doc, index, self.parts.len() - 1)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Selector(Vec<Complex>);
+
+impl Selector {
+    pub fn matches(&self, doc: &HtmlDocument<'_>, index: usize) -> bool {
+        self.0.iter().any(|complex| complex.matches(doc, index))
+    }
+
+    /// Returns the indexes of the matching elements, in document order.
+    pub fn select(&self, doc: &HtmlDocument<'_>) -> Vec<usize> {
+        (0..doc.elements().len())
+            .filter(|index| self.matches(doc, *index))
+            .collect()
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '-' | '_' | '\\') || !c.is_ascii()
+}
+
+struct SelectorParser<'a> {
+    input: &'a str,
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl<'a> SelectorParser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            chars: input.char_indices().peekable(),
+        }
+    }
+
+    fn error(&self) -> InvalidSelector {
+        InvalidSelector(self.input.to_string())
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|(_, c)| *c)
+    }
+
+    fn skip_whitespaces(&mut self) -> bool {
+        let mut found = false;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.chars.next();
+            found = true;
+        }
+        found
+    }
+
+    fn identifier(&mut self) -> Result<String, InvalidSelector> {
+        let mut result = String::new();
+        while let Some(c) = self.peek().filter(|c| is_identifier_char(*c)) {
+            self.chars.next();
+            result.push(c);
+        }
+        if result.is_empty() {
+            Err(self.error())
+        } else {
+            Ok(result)
+        }
+    }
+
+    fn attribute_value(&mut self) -> Result<String, InvalidSelector> {
+        self.skip_whitespaces();
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.chars.next();
+                let mut result = String::new();
+                loop {
+                    match self.chars.next() {
+                        Some((_, c)) if c == quote => return Ok(result),
+                        Some((_, c)) => result.push(c),
+                        None => return Err(self.error()),
+                    }
+                }
+            }
+            _ => self.identifier(),
+        }
+    }
+
+    fn attribute(&mut self) -> Result<(String, AttributeOperator), InvalidSelector> {
+        self.skip_whitespaces();
+        let name = self.identifier()?.to_ascii_lowercase();
+        self.skip_whitespaces();
+        let operator = match self.chars.next() {
+            Some((_, ']')) => return Ok((name, AttributeOperator::Exists)),
+            Some((_, '=')) => AttributeOperator::Equals(self.attribute_value()?),
+            Some((_, c @ ('~' | '|' | '^' | '$' | '*'))) => {
+                if !matches!(self.chars.next(), Some((_, '='))) {
+                    return Err(self.error());
+                }
+                let value = self.attribute_value()?;
+                match c {
+                    '~' => AttributeOperator::Includes(value),
+                    '|' => AttributeOperator::DashMatch(value),
+                    '^' => AttributeOperator::Prefix(value),
+                    '$' => AttributeOperator::Suffix(value),
+                    _ => AttributeOperator::Substring(value),
+                }
+            }
+            _ => return Err(self.error()),
+        };
+        self.skip_whitespaces();
+        match self.chars.next() {
+            Some((_, ']')) => Ok((name, operator)),
+            _ => Err(self.error()),
+        }
+    }
+
+    fn nth(&mut self) -> Result<(i32, i32), InvalidSelector> {
+        let mut content = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, ')')) => break,
+                Some((_, c)) if !c.is_whitespace() => content.push(c),
+                Some(_) => {}
+                None => return Err(self.error()),
+            }
+        }
+        let content = content.to_ascii_lowercase();
+        match content.as_str() {
+            "odd" => return Ok((2, 1)),
+            "even" => return Ok((2, 0)),
+            _ => {}
+        }
+        if let Some((a, b)) = content.split_once('n') {
+            let a = match a {
+                "" | "+" => 1,
+                "-" => -1,
+                other => other.parse().map_err(|_| self.error())?,
+            };
+            let b = match b {
+                "" => 0,
+                other => other
+                    .strip_prefix('+')
+                    .unwrap_or(other)
+                    .parse()
+                    .map_err(|_| self.error())?,
+            };
+            Ok((a, b))
+        } else {
+            Ok((0, content.parse().map_err(|_| self.error())?))
+        }
+    }
+
+    fn pseudo_class(&mut self) -> Result<PseudoClass, InvalidSelector> {
+        match self.identifier()?.to_ascii_lowercase().as_str() {
+            "first-child" => Ok(PseudoClass::First),
+            "last-child" => Ok(PseudoClass::Last),
+            "nth-child" if matches!(self.chars.next(), Some((_, '('))) => {
+                let (a, b) = self.nth()?;
+                Ok(PseudoClass::Nth(a, b))
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    fn compound(&mut self) -> Result<Compound, InvalidSelector> {
+        let mut result = Compound::default();
+        if self.peek() == Some('*') {
+            self.chars.next();
+            result.tag = Some("*".to_string());
+        } else if self.peek().is_some_and(is_identifier_char) {
+            result.tag = Some(self.identifier()?.to_ascii_lowercase());
+        }
+        loop {
+            match self.peek() {
+                Some('.') => {
+                    self.chars.next();
+                    result.classes.push(self.identifier()?);
+                }
+                Some('#') => {
+                    self.chars.next();
+                    result.ids.push(self.identifier()?);
+                }
+                Some('[') => {
+                    self.chars.next();
+                    result.attributes.push(self.attribute()?);
+                }
+                Some(':') => {
+                    self.chars.next();
+                    result.pseudo_classes.push(self.pseudo_class()?);
+                }
+                _ => break,
+            }
+        }
+        if result.is_empty() {
+            Err(self.error())
+        } else {
+            Ok(result)
+        }
+    }
+
+    fn complex(&mut self) -> Result<Complex, InvalidSelector> {
+        self.skip_whitespaces();
+        let mut parts = vec![(Combinator::Descendant, self.compound()?)];
+        loop {
+            let had_whitespace = self.skip_whitespaces();
+            let combinator = match self.peek() {
+                None | Some(',') => return Ok(Complex { parts }),
+                Some('>') => Combinator::Child,
+                Some('+') => Combinator::Adjacent,
+                Some('~') => Combinator::Sibling,
+                Some(_) if had_whitespace => Combinator::Descendant,
+                Some(_) => return Err(self.error()),
+            };
+            if combinator != Combinator::Descendant {
+                self.chars.next();
+                self.skip_whitespaces();
+            }
+            parts.push((combinator, self.compound()?));
+        }
+    }
+
+    fn parse(mut self) -> Result<Selector, InvalidSelector> {
+        let mut result = vec![self.complex()?];
+        while let Some((_, ',')) = self.chars.next() {
+            result.push(self.complex()?);
+        }
+        Ok(Selector(result))
+    }
+}
+
+impl TryFrom<&str> for Selector {
+    type Error = InvalidSelector;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        SelectorParser::new(value).parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Selector;
+    use crate::helper::html::HtmlDocument;
+
+    const HTML: &str = r#"<div class="root" id="main"><table class="custom"><tr><td lang="en-US">a</td><td data-x="foo bar">b</td><td>c</td></tr></table><p>d</p><span>e</span></div>"#;
+
+    fn select(selector: &str) -> Vec<String> {
+        let doc = HtmlDocument::parse(HTML);
+        let selector = Selector::try_from(selector).unwrap();
+        selector
+            .select(&doc)
+            .into_iter()
+            .map(|index| {
+                let elt = doc.element(index);
+                format!("{}:{}", elt.tag, elt.index)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn simple_selectors() {
+        assert_eq!(select("td"), vec!["td:0", "td:1", "td:2"]);
+        assert_eq!(select(".custom"), vec!["table:0"]);
+        assert_eq!(select("#main"), vec!["div:0"]);
+        assert_eq!(select("div.root#main"), vec!["div:0"]);
+        assert_eq!(select("*").len(), 8);
+    }
+
+    #[test]
+    fn attribute_selectors() {
+        assert_eq!(select("[lang]"), vec!["td:0"]);
+        assert_eq!(select("[lang|=en]"), vec!["td:0"]);
+        assert_eq!(select("[data-x~=bar]"), vec!["td:1"]);
+        assert_eq!(select("[data-x^='foo']"), vec!["td:1"]);
+        assert_eq!(select("[data-x$=\"bar\"]"), vec!["td:1"]);
+        assert_eq!(select("[data-x*=o]"), vec!["td:1"]);
+        assert_eq!(select("[data-x=foo]"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn combinators() {
+        assert_eq!(select(".custom td"), vec!["td:0", "td:1", "td:2"]);
+        assert_eq!(select(".root > td"), Vec::<String>::new());
+        assert_eq!(select("tr > td:first-child"), vec!["td:0"]);
+        assert_eq!(select("td:last-child"), vec!["td:2"]);
+        assert_eq!(select("td:nth-child(2)"), vec!["td:1"]);
+        assert_eq!(select("td:nth-child(odd)"), vec!["td:0", "td:2"]);
+        assert_eq!(select("table + p"), vec!["p:1"]);
+        assert_eq!(select("table ~ span"), vec!["span:2"]);
+        assert_eq!(select("p, span"), vec!["p:1", "span:2"]);
+    }
+
+    #[test]
+    fn invalid_selectors() {
+        assert!(Selector::try_from("").is_err());
+        assert!(Selector::try_from("div >").is_err());
+        assert!(Selector::try_from("[foo").is_err());
+        assert!(Selector::try_from("div:hover").is_err());
+    }
+}
@@ -21,6 +21,7 @@ pub mod mj_font;
 pub mod mj_group;
 pub mod mj_head;
 pub mod mj_hero;
+pub mod mj_html_attributes;
 pub mod mj_image;
 pub mod mj_include;
 pub mod mj_navbar;
@@ -73,14 +74,7 @@ pub fn parse_with_options<T: AsRef<str>>(
     input: T,
     opts: &crate::prelude::parser::ParserOptions,
 ) -> Result<crate::prelude::parser::ParseOutput<mjml::Mjml>, prelude::parser::Error> {
-    let root = crate::root::Root::parse_with_options(input, opts)?;
-    Ok(crate::prelude::parser::ParseOutput {
-        element: root
-            .element
-            .into_mjml()
-            .ok_or(prelude::parser::Error::NoRootNode)?,
-        warnings: root.warnings,
-    })
+    crate::prelude::parser::MrmlParser::new(opts).parse_template(input)
 }
 
 #[cfg(all(feature = "parse", feature = "async"))]
@@ -112,14 +106,9 @@ pub async fn async_parse_with_options<T: AsRef<str>>(
     input: T,
     opts: std::sync::Arc<crate::prelude::parser::AsyncParserOptions>,
 ) -> Result<crate::prelude::parser::ParseOutput<mjml::Mjml>, prelude::parser::Error> {
-    let root = crate::root::Root::async_parse_with_options(input, opts).await?;
-    Ok(crate::prelude::parser::ParseOutput {
-        element: root
-            .element
-            .into_mjml()
-            .ok_or(prelude::parser::Error::NoRootNode)?,
-        warnings: root.warnings,
-    })
+    crate::prelude::parser::AsyncMrmlParser::new(opts)
+        .parse_template(input)
+        .await
 }
 
 #[cfg(feature = "parse")]
@@ -158,6 +147,47 @@ pub async fn async_parse<T: AsRef<str>>(
     async_parse_with_options(input, opts).await
 }
 
+#[cfg(feature = "validate")]
+/// Function to validate a raw mjml template against the rules of mjml, with
+/// some parsing [options](crate::prelude::parser::ParserOptions) to load the
+/// [`mj-include`](crate::mj_include) elements.
+///
+/// ```rust
+/// use mrml::prelude::parser::ParserOptions;
+/// use mrml::prelude::validate::ValidationLevel;
+///
+/// let options = ParserOptions::default();
+/// let template = r#"<mjml><mj-body><mj-section><mj-column><mj-image /></mj-column></mj-section></mj-body></mjml>"#;
+/// for diagnostic in mrml::validate_with_options(template, &options, ValidationLevel::Strict).unwrap() {
+///     eprintln!("{diagnostic}");
+/// }
+/// ```
+pub fn validate_with_options<T: AsRef<str>>(
+    input: T,
+    opts: &crate::prelude::parser::ParserOptions,
+    level: crate::prelude::validate::ValidationLevel,
+) -> Result<Vec<crate::prelude::validate::Diagnostic>, prelude::parser::Error> {
+    crate::prelude::validate::validate_with_options(input.as_ref(), opts, level)
+}
+
+#[cfg(feature = "validate")]
+/// Function to validate a raw mjml template against the rules of mjml, using
+/// the default parsing [options](crate::prelude::parser::ParserOptions).
+///
+/// ```rust
+/// use mrml::prelude::validate::ValidationLevel;
+///
+/// let diagnostics = mrml::validate("<mjml><mj-body /></mjml>", ValidationLevel::Strict).unwrap();
+/// assert!(diagnostics.is_empty());
+/// ```
+pub fn validate<T: AsRef<str>>(
+    input: T,
+    level: crate::prelude::validate::ValidationLevel,
+) -> Result<Vec<crate::prelude::validate::Diagnostic>, prelude::parser::Error> {
+    let opts = crate::prelude::parser::ParserOptions::default();
+    validate_with_options(input, &opts, level)
+}
+
 #[cfg(all(test, feature = "parse"))]
 mod tests {
     #[test]
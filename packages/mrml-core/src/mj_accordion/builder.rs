@@ -0,0 +1,34 @@
+use super::{MjAccordion, MjAccordionChild, MjAccordionTag};
+use crate::comment::Comment;
+use crate::mj_accordion_element::MjAccordionElement;
+use crate::prelude::builder::{
+    attributes, children, padding_attributes, Builder, IconPosition, Size, VerticalAlign,
+};
+
+impl MjAccordion {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjAccordion> {
+    padding_attributes!();
+    attributes! {
+        border: "border" => string,
+        container_background_color: "container-background-color" => string,
+        font_family: "font-family" => string,
+        icon_align: "icon-align" => value(VerticalAlign),
+        icon_height: "icon-height" => value(Size),
+        icon_position: "icon-position" => value(IconPosition),
+        icon_unwrapped_alt: "icon-unwrapped-alt" => string,
+        icon_unwrapped_url: "icon-unwrapped-url" => string,
+        icon_width: "icon-width" => value(Size),
+        icon_wrapped_alt: "icon-wrapped-alt" => string,
+        icon_wrapped_url: "icon-wrapped-url" => string,
+    }
+}
+
+children!(MjAccordionTag => MjAccordionChild {
+    Comment(Comment),
+    MjAccordionElement(MjAccordionElement),
+});
@@ -5,6 +5,7 @@ use crate::mj_accordion_element::MjAccordionElement;
 #[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json", serde(untagged))]
 #[cfg_attr(feature = "print", enum_dispatch::enum_dispatch)]
+#[cfg_attr(feature = "span", allow(clippy::large_enum_variant))]
 pub enum MjAccordionChild {
     Comment(Comment),
     MjAccordionElement(MjAccordionElement),
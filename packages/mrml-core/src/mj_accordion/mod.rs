@@ -10,6 +10,8 @@
 //! };
 //! ```
 
+#[cfg(feature = "builder")]
+mod builder;
 mod children;
 #[cfg(feature = "json")]
 mod json;
@@ -19,6 +21,12 @@ mod parse;
 mod print;
 #[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "template")]
+mod template;
+#[cfg(feature = "render")]
+mod text;
+#[cfg(feature = "validate")]
+pub(crate) mod validate;
 
 use std::marker::PhantomData;
 
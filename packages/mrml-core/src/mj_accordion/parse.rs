@@ -14,8 +14,8 @@ impl ParseChildren<Vec<MjAccordionChild>> for MrmlParser<'_> {
         loop {
             match cursor.assert_next()? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjAccordionChild::Comment(Comment::from(
-                        inner.text.as_str(),
+                    result.push(MjAccordionChild::Comment(Comment::from_token(
+                        cursor, &inner,
                     )));
                 }
                 MrmlToken::ElementStart(inner) => {
@@ -58,8 +58,8 @@ impl AsyncParseChildren<Vec<MjAccordionChild>> for AsyncMrmlParser {
         loop {
             match cursor.assert_next()? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjAccordionChild::Comment(Comment::from(
-                        inner.text.as_str(),
+                    result.push(MjAccordionChild::Comment(Comment::from_token(
+                        cursor, &inner,
                     )));
                 }
                 MrmlToken::ElementStart(inner) => {
@@ -0,0 +1,21 @@
+use super::{MjAccordion, MjAccordionChild};
+use crate::prelude::render::text::{TextBuffer, TextRender};
+
+impl TextRender for MjAccordion {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>) {
+        for child in self.children.iter() {
+            if let MjAccordionChild::MjAccordionElement(element) = child {
+                if let Some(ref title) = element.children.title {
+                    buffer.end_block();
+                    title.children.render_text(buffer);
+                    buffer.end_block();
+                }
+                if let Some(ref text) = element.children.text {
+                    buffer.end_block();
+                    text.children.render_text(buffer);
+                    buffer.end_block();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use super::{MjAccordionElement, MjAccordionElementTag};
+use crate::mj_accordion_text::MjAccordionText;
+use crate::mj_accordion_title::MjAccordionTitle;
+use crate::prelude::builder::{attributes, Builder, ChildOf, IconPosition, Size, VerticalAlign};
+
+impl MjAccordionElement {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjAccordionElement> {
+    attributes! {
+        background_color: "background-color" => string,
+        border: "border" => string,
+        font_family: "font-family" => string,
+        icon_align: "icon-align" => value(VerticalAlign),
+        icon_height: "icon-height" => value(Size),
+        icon_position: "icon-position" => value(IconPosition),
+        icon_unwrapped_alt: "icon-unwrapped-alt" => string,
+        icon_unwrapped_url: "icon-unwrapped-url" => string,
+        icon_width: "icon-width" => value(Size),
+        icon_wrapped_alt: "icon-wrapped-alt" => string,
+        icon_wrapped_url: "icon-wrapped-url" => string,
+    }
+
+    pub fn title(mut self, title: impl ChildOf<MjAccordionElementTag, MjAccordionTitle>) -> Self {
+        self.element.children.title = Some(title.into_child());
+        self
+    }
+
+    pub fn text(mut self, text: impl ChildOf<MjAccordionElementTag, MjAccordionText>) -> Self {
+        self.element.children.text = Some(text.into_child());
+        self
+    }
+}
+
+impl ChildOf<MjAccordionElementTag, MjAccordionTitle> for MjAccordionTitle {
+    fn into_child(self) -> MjAccordionTitle {
+        self
+    }
+}
+
+impl ChildOf<MjAccordionElementTag, MjAccordionText> for MjAccordionText {
+    fn into_child(self) -> MjAccordionText {
+        self
+    }
+}
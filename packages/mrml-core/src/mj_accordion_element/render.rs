@@ -125,13 +125,14 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjAccordionElement
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(
+        Renderer::new(
             context,
             self,
             MjAccordionElementExtra {
                 attributes: Map::new(),
             },
-        ))
+        )
+        .boxed()
     }
 }
 
@@ -148,7 +149,7 @@ mod tests {
     fn basic() {
         let opts = RenderOptions::default();
         let head = Header::new(None, "und", "auto");
-        let ctx = RenderContext::new(&opts, head);
+        let ctx = RenderContext::new(&opts, &head);
 
         let element = MjAccordionElement::new(
             Default::default(),
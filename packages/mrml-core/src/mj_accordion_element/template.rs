@@ -0,0 +1,10 @@
+use super::MjAccordionElementChildren;
+use crate::prelude::render::Error;
+use crate::prelude::template::Template;
+
+impl Template for MjAccordionElementChildren {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        self.title.resolve(context)?;
+        self.text.resolve(context)
+    }
+}
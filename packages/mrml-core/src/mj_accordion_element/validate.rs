@@ -0,0 +1,28 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[&[
+        ("background-color", AttributeType::Color),
+        ("border", AttributeType::String),
+        ("font-family", AttributeType::String),
+        (
+            "icon-align",
+            AttributeType::Enum(&["top", "middle", "bottom"]),
+        ),
+        ("icon-height", AttributeType::Size),
+        ("icon-position", AttributeType::Enum(&["left", "right"])),
+        ("icon-unwrapped-alt", AttributeType::String),
+        ("icon-unwrapped-url", AttributeType::String),
+        ("icon-width", AttributeType::Size),
+        ("icon-wrapped-alt", AttributeType::String),
+        ("icon-wrapped-url", AttributeType::String),
+    ]]),
+    required: &[],
+    children: Children::Only(&[
+        crate::mj_accordion_text::NAME,
+        crate::mj_accordion_title::NAME,
+        crate::mj_raw::NAME,
+    ]),
+};
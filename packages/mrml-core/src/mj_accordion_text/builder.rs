@@ -0,0 +1,33 @@
+use super::{MjAccordionText, MjAccordionTextTag};
+use crate::comment::Comment;
+use crate::conditional_comment::ConditionalComment;
+use crate::mj_raw::MjRawChild;
+use crate::node::Node;
+use crate::prelude::builder::{attributes, children, padding_attributes, Builder, Pixel, Size};
+use crate::text::Text;
+
+impl MjAccordionText {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjAccordionText> {
+    padding_attributes!();
+    attributes! {
+        background_color: "background-color" => string,
+        color: "color" => string,
+        font_family: "font-family" => string,
+        font_size: "font-size" => value(Pixel),
+        font_weight: "font-weight" => string,
+        letter_spacing: "letter-spacing" => string,
+        line_height: "line-height" => value(Size),
+    }
+}
+
+children!(MjAccordionTextTag => MjRawChild {
+    Comment(Comment),
+    ConditionalComment(ConditionalComment),
+    Node(Node<MjRawChild>),
+    Text(Text),
+});
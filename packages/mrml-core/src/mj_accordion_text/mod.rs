@@ -1,3 +1,5 @@
+#[cfg(feature = "builder")]
+mod builder;
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "parse")]
@@ -6,6 +8,8 @@ mod parse;
 mod print;
 #[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "validate")]
+pub(crate) mod validate;
 
 use std::marker::PhantomData;
 
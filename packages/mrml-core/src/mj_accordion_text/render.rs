@@ -99,12 +99,13 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjAccordionText {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(
+        Renderer::new(
             context,
             self,
             MjAccordionTextExtra {
                 attributes: Map::new(),
             },
-        ))
+        )
+        .boxed()
     }
 }
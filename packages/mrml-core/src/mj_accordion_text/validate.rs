@@ -0,0 +1,20 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        &[
+            ("background-color", AttributeType::Color),
+            ("color", AttributeType::Color),
+            ("font-family", AttributeType::String),
+            ("font-size", AttributeType::Pixel),
+            ("font-weight", AttributeType::String),
+            ("letter-spacing", AttributeType::LetterSpacing),
+            ("line-height", AttributeType::LineHeight),
+        ],
+    ]),
+    required: &[],
+    children: Children::Raw,
+};
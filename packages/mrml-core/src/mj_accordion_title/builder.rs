@@ -0,0 +1,25 @@
+use super::{MjAccordionTitle, MjAccordionTitleTag};
+use crate::prelude::builder::{attributes, padding_attributes, Builder, ChildOf, Pixel};
+use crate::text::Text;
+
+impl MjAccordionTitle {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjAccordionTitle> {
+    padding_attributes!();
+    attributes! {
+        background_color: "background-color" => string,
+        color: "color" => string,
+        font_family: "font-family" => string,
+        font_size: "font-size" => value(Pixel),
+    }
+}
+
+impl ChildOf<MjAccordionTitleTag, Text> for Text {
+    fn into_child(self) -> Text {
+        self
+    }
+}
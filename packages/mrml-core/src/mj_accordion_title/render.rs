@@ -141,12 +141,13 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjAccordionTitle {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(
+        Renderer::new(
             context,
             self,
             MjAccordionTitleExtra {
                 attributes: Map::new(),
             },
-        ))
+        )
+        .boxed()
     }
 }
@@ -0,0 +1,17 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        &[
+            ("background-color", AttributeType::Color),
+            ("color", AttributeType::Color),
+            ("font-family", AttributeType::String),
+            ("font-size", AttributeType::Pixel),
+        ],
+    ]),
+    required: &[],
+    children: Children::Raw,
+};
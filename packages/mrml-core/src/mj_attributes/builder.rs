@@ -0,0 +1,17 @@
+use super::{MjAttributes, MjAttributesChild, MjAttributesTag};
+use crate::mj_attributes_all::MjAttributesAll;
+use crate::mj_attributes_class::MjAttributesClass;
+use crate::mj_attributes_element::MjAttributesElement;
+use crate::prelude::builder::{children, Builder};
+
+impl MjAttributes {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+children!(MjAttributesTag => MjAttributesChild {
+    MjAttributesAll(MjAttributesAll),
+    MjAttributesClass(MjAttributesClass),
+    MjAttributesElement(MjAttributesElement),
+});
@@ -1,3 +1,5 @@
+#[cfg(feature = "builder")]
+mod builder;
 mod children;
 #[cfg(feature = "json")]
 mod json;
@@ -5,6 +7,10 @@ mod json;
 mod parse;
 #[cfg(feature = "print")]
 mod print;
+#[cfg(feature = "template")]
+mod template;
+#[cfg(feature = "validate")]
+pub(crate) mod validate;
 
 use std::marker::PhantomData;
 
@@ -3,11 +3,12 @@ use htmlparser::StrSpan;
 use super::MjAttributesChild;
 use crate::mj_attributes_all::NAME as MJ_ALL;
 use crate::mj_attributes_class::NAME as MJ_CLASS;
+use crate::mj_include::head::MjIncludeHeadKind;
 use crate::mj_include::NAME as MJ_INCLUDE;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement, WarningKind,
+    Error, IncludeKind, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement, WarningKind,
 };
 
 const FRAGMENT_OPEN: &str = "<mrml-fragment>";
@@ -80,6 +81,11 @@ impl MrmlParser<'_> {
                 position: tag.into(),
                 source,
             })?;
+        cursor.add_include(
+            IncludeKind::Head(MjIncludeHeadKind::Mjml),
+            &path,
+            tag.into(),
+        );
 
         let wrapped = format!("{FRAGMENT_OPEN}{content}{FRAGMENT_CLOSE}");
         let offset = FRAGMENT_OPEN.len();
@@ -91,7 +97,7 @@ impl MrmlParser<'_> {
         let children: Vec<MjAttributesChild> =
             self.parse_children(&mut sub).map_err(&with_position)?;
         sub.assert_element_close().map_err(&with_position)?;
-        cursor.with_warnings(sub.warnings());
+        cursor.with_child(sub);
         Ok(children)
     }
 }
@@ -164,6 +170,11 @@ impl AsyncMrmlParser {
                 position: tag.into(),
                 source,
             })?;
+        cursor.add_include(
+            IncludeKind::Head(MjIncludeHeadKind::Mjml),
+            &path,
+            tag.into(),
+        );
 
         let wrapped = format!("{FRAGMENT_OPEN}{content}{FRAGMENT_CLOSE}");
         let offset = FRAGMENT_OPEN.len();
@@ -177,7 +188,7 @@ impl AsyncMrmlParser {
             .await
             .map_err(&with_position)?;
         sub.assert_element_close().map_err(&with_position)?;
-        cursor.with_warnings(sub.warnings());
+        cursor.with_child(sub);
         Ok(children)
     }
 }
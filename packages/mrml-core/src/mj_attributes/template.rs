@@ -0,0 +1,13 @@
+use super::MjAttributesChild;
+use crate::prelude::render::Error;
+use crate::prelude::template::Template;
+
+impl Template for MjAttributesChild {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        match self {
+            Self::MjAttributesAll(elt) => elt.resolve(context),
+            Self::MjAttributesClass(elt) => elt.resolve(context),
+            Self::MjAttributesElement(elt) => elt.resolve(context),
+        }
+    }
+}
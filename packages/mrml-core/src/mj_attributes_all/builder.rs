@@ -0,0 +1,10 @@
+use super::MjAttributesAll;
+use crate::prelude::builder::Builder;
+
+impl MjAttributesAll {
+    /// Starts a builder of the default attributes of all the elements, set
+    /// with [`Builder::attribute`].
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
@@ -2,12 +2,16 @@ use std::marker::PhantomData;
 
 use crate::prelude::{Component, StaticTag};
 
+#[cfg(feature = "builder")]
+mod builder;
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "parse")]
 mod parse;
 #[cfg(feature = "print")]
 mod print;
+#[cfg(feature = "validate")]
+pub(crate) mod validate;
 
 pub const NAME: &str = "mj-all";
 
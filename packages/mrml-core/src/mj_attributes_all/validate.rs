@@ -0,0 +1,9 @@
+use super::NAME;
+use crate::prelude::validate::{Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Any,
+    required: &[],
+    children: Children::Raw,
+};
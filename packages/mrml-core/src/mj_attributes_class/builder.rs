@@ -0,0 +1,26 @@
+use super::{MjAttributesClass, MjAttributesClassAttributes};
+use crate::prelude::builder::Builder;
+use crate::prelude::AttributeMap;
+
+impl MjAttributesClass {
+    pub fn builder(name: impl Into<String>) -> Builder<Self> {
+        Builder::new(Self::new(
+            MjAttributesClassAttributes {
+                name: name.into(),
+                others: AttributeMap::new(),
+            },
+            (),
+        ))
+    }
+}
+
+impl Builder<MjAttributesClass> {
+    /// Sets an attribute of the elements having the class.
+    pub fn attribute(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.element
+            .attributes
+            .others
+            .insert(name.to_string(), Some(value.into()));
+        self
+    }
+}
@@ -2,12 +2,18 @@ use std::marker::PhantomData;
 
 use crate::prelude::{AttributeMap, Component, StaticTag};
 
+#[cfg(feature = "builder")]
+mod builder;
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "parse")]
 mod parse;
 #[cfg(feature = "print")]
 mod print;
+#[cfg(feature = "template")]
+mod template;
+#[cfg(feature = "validate")]
+pub(crate) mod validate;
 
 pub const NAME: &str = "mj-class";
 
@@ -0,0 +1,10 @@
+use super::MjAttributesClassAttributes;
+use crate::prelude::render::Error;
+use crate::prelude::template::{resolve_attribute, Template};
+
+impl Template for MjAttributesClassAttributes {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        resolve_attribute("name", &mut self.name, context)?;
+        self.others.resolve(context)
+    }
+}
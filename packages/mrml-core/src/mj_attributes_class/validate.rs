@@ -0,0 +1,18 @@
+use super::{MjAttributesClassAttributes, NAME};
+use crate::prelude::validate::{AttributeValues, Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Any,
+    required: &["name"],
+    children: Children::Raw,
+};
+
+impl AttributeValues for MjAttributesClassAttributes {
+    fn attribute_value(&self, name: &str) -> Option<&str> {
+        match name {
+            "name" => Some(self.name.as_str()),
+            other => self.others.attribute_value(other),
+        }
+    }
+}
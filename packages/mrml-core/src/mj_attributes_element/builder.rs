@@ -0,0 +1,19 @@
+use super::MjAttributesElement;
+use crate::prelude::builder::Builder;
+
+impl MjAttributesElement {
+    /// Starts a builder of the default attributes of the elements with the
+    /// tag `name`, like `mj-text`.
+    pub fn builder(name: impl Into<String>) -> Builder<Self> {
+        Builder::new(Self::new(name.into()))
+    }
+}
+
+impl Builder<MjAttributesElement> {
+    pub fn attribute(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.element
+            .attributes
+            .insert(name.to_string(), Some(value.into()));
+        self
+    }
+}
@@ -1,12 +1,16 @@
 use crate::prelude::hash::Map;
 use crate::prelude::AttributeMap;
 
+#[cfg(feature = "builder")]
+mod builder;
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "parse")]
 mod parse;
 #[cfg(feature = "print")]
 mod print;
+#[cfg(feature = "template")]
+mod template;
 
 #[derive(Clone, Debug, Default)]
 pub struct MjAttributesElement {
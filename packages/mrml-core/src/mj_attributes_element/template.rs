@@ -0,0 +1,9 @@
+use super::MjAttributesElement;
+use crate::prelude::render::Error;
+use crate::prelude::template::Template;
+
+impl Template for MjAttributesElement {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        self.attributes.resolve(context)
+    }
+}
@@ -0,0 +1,28 @@
+use super::{MjBody, MjBodyChild, MjBodyTag};
+use crate::comment::Comment;
+use crate::mj_hero::MjHero;
+use crate::mj_raw::MjRaw;
+use crate::mj_section::MjSection;
+use crate::mj_wrapper::MjWrapper;
+use crate::prelude::builder::{attributes, children, Builder, Pixel};
+
+impl MjBody {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjBody> {
+    attributes! {
+        background_color: "background-color" => string,
+        width: "width" => value(Pixel),
+    }
+}
+
+children!(MjBodyTag => MjBodyChild {
+    Comment(Comment),
+    MjHero(MjHero),
+    MjRaw(MjRaw),
+    MjSection(MjSection),
+    MjWrapper(MjWrapper),
+});
@@ -21,6 +21,8 @@ use crate::mj_text::NAME as MJ_TEXT;
 use crate::mj_wrapper::NAME as MJ_WRAPPER;
 use crate::node::Node;
 use crate::prelude::is_void_element;
+#[cfg(feature = "span")]
+use crate::prelude::parser::element_start;
 use crate::prelude::parser::{
     parse_attributes_map, Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement,
 };
@@ -34,6 +36,8 @@ impl ParseElement<Node<MjBodyChild>> for MrmlParser<'_> {
         cursor: &mut MrmlCursor<'a>,
         tag: StrSpan<'a>,
     ) -> Result<Node<MjBodyChild>, Error> {
+        #[cfg(feature = "span")]
+        let start = element_start(&tag);
         let tag = tag.to_string();
         let attributes = parse_attributes_map(cursor)?;
         let ending = cursor.assert_element_end()?;
@@ -42,6 +46,8 @@ impl ParseElement<Node<MjBodyChild>> for MrmlParser<'_> {
                 tag,
                 attributes,
                 children: Vec::new(),
+                #[cfg(feature = "span")]
+                location: Some(cursor.location(start)),
             });
         }
         let children = self.parse_children(cursor)?;
@@ -52,6 +58,8 @@ impl ParseElement<Node<MjBodyChild>> for MrmlParser<'_> {
             tag,
             attributes,
             children,
+            #[cfg(feature = "span")]
+            location: Some(cursor.location(start)),
         })
     }
 }
@@ -65,6 +73,8 @@ impl AsyncParseElement<Node<MjBodyChild>> for AsyncMrmlParser {
         cursor: &mut MrmlCursor<'a>,
         tag: StrSpan<'a>,
     ) -> Result<Node<MjBodyChild>, Error> {
+        #[cfg(feature = "span")]
+        let start = element_start(&tag);
         let tag = tag.to_string();
         let attributes = parse_attributes_map(cursor)?;
         let ending = cursor.assert_element_end()?;
@@ -73,6 +83,8 @@ impl AsyncParseElement<Node<MjBodyChild>> for AsyncMrmlParser {
                 tag,
                 attributes,
                 children: Vec::new(),
+                #[cfg(feature = "span")]
+                location: Some(cursor.location(start)),
             });
         }
         let children = self.async_parse_children(cursor).await?;
@@ -83,6 +95,8 @@ impl AsyncParseElement<Node<MjBodyChild>> for AsyncMrmlParser {
             tag,
             attributes,
             children,
+            #[cfg(feature = "span")]
+            location: Some(cursor.location(start)),
         })
     }
 }
@@ -158,14 +172,17 @@ impl ParseChildren<Vec<MjBodyChild>> for MrmlParser<'_> {
         while let Some(token) = cursor.next_token() {
             match token? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjBodyChild::Comment(Comment::from(inner.text.as_str())));
+                    result.push(MjBodyChild::Comment(Comment::from_token(cursor, &inner)));
                 }
                 MrmlToken::Text(inner) => {
-                    result.push(MjBodyChild::Text(Text::from(inner.text.as_str())));
-                }
-                MrmlToken::ElementStart(inner) => {
-                    result.push(self.parse(cursor, inner.local)?);
+                    result.push(MjBodyChild::Text(Text::from_token(cursor, &inner)));
                 }
+                MrmlToken::ElementStart(inner) => match self.components.get(inner.local.as_str()) {
+                    Some(component) => {
+                        result.extend(self.parse_component(cursor, inner.local, component)?);
+                    }
+                    None => result.push(self.parse(cursor, inner.local)?),
+                },
                 MrmlToken::ElementClose(close) => {
                     cursor.rewind(MrmlToken::ElementClose(close));
                     return Ok(result);
@@ -194,14 +211,20 @@ impl AsyncParseChildren<Vec<MjBodyChild>> for AsyncMrmlParser {
         while let Some(token) = cursor.next_token() {
             match token? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjBodyChild::Comment(Comment::from(inner.text.as_str())));
+                    result.push(MjBodyChild::Comment(Comment::from_token(cursor, &inner)));
                 }
                 MrmlToken::Text(inner) => {
-                    result.push(MjBodyChild::Text(Text::from(inner.text.as_str())));
-                }
-                MrmlToken::ElementStart(inner) => {
-                    result.push(self.async_parse(cursor, inner.local).await?);
+                    result.push(MjBodyChild::Text(Text::from_token(cursor, &inner)));
                 }
+                MrmlToken::ElementStart(inner) => match self.components.get(inner.local.as_str()) {
+                    Some(component) => {
+                        let children = self
+                            .async_parse_component(cursor, inner.local, component)
+                            .await?;
+                        result.extend(children);
+                    }
+                    None => result.push(self.async_parse(cursor, inner.local).await?),
+                },
                 MrmlToken::ElementClose(close) => {
                     cursor.rewind(MrmlToken::ElementClose(close));
                     return Ok(result);
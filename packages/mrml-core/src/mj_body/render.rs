@@ -5,7 +5,7 @@ use crate::helper::size::Pixel;
 use crate::prelude::render::*;
 
 impl<'root> Renderer<'root, MjBody, ()> {
-    fn get_width(&self) -> Option<Pixel> {
+    pub(crate) fn get_width(&self) -> Option<Pixel> {
         self.attribute("width")
             .and_then(|value| Pixel::try_from(value).ok())
     }
@@ -57,7 +57,12 @@ impl<'root> Renderer<'root, MjBody, ()> {
             renderer.set_index(index);
             renderer.set_raw_siblings(raw_siblings);
             renderer.set_siblings(self.element.children.len());
+            let start = cursor.buffer.len();
             renderer.render(cursor)?;
+            let end = cursor.buffer.len();
+            if let Some(sections) = cursor.sections.as_mut() {
+                sections.push(start..end);
+            }
         }
         div.render_close(&mut cursor.buffer);
         Ok(())
@@ -98,7 +103,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjBody {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Renderer::new(context, self, ()).boxed()
     }
 }
 
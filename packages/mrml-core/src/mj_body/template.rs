@@ -0,0 +1,30 @@
+use super::MjBodyChild;
+use crate::prelude::render::Error;
+use crate::prelude::template::Template;
+
+impl Template for MjBodyChild {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        match self {
+            Self::Comment(elt) => elt.resolve(context),
+            Self::MjAccordion(elt) => elt.resolve(context),
+            Self::MjButton(elt) => elt.resolve(context),
+            Self::MjCarousel(elt) => elt.resolve(context),
+            Self::MjColumn(elt) => elt.resolve(context),
+            Self::MjDivider(elt) => elt.resolve(context),
+            Self::MjGroup(elt) => elt.resolve(context),
+            Self::MjHero(elt) => elt.resolve(context),
+            Self::MjInclude(elt) => elt.resolve(context),
+            Self::MjImage(elt) => elt.resolve(context),
+            Self::MjNavbar(elt) => elt.resolve(context),
+            Self::MjRaw(elt) => elt.resolve(context),
+            Self::MjSection(elt) => elt.resolve(context),
+            Self::MjSocial(elt) => elt.resolve(context),
+            Self::MjSpacer(elt) => elt.resolve(context),
+            Self::MjTable(elt) => elt.resolve(context),
+            Self::MjText(elt) => elt.resolve(context),
+            Self::MjWrapper(elt) => elt.resolve(context),
+            Self::Node(elt) => elt.resolve(context),
+            Self::Text(elt) => elt.resolve(context),
+        }
+    }
+}
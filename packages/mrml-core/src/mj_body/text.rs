@@ -0,0 +1,31 @@
+use super::MjBodyChild;
+use crate::prelude::render::text::{
+    render_block_text, render_divider_text, TextBuffer, TextRender,
+};
+
+impl TextRender for MjBodyChild {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>) {
+        match self {
+            Self::Comment(elt) => elt.render_text(buffer),
+            Self::MjAccordion(elt) => elt.render_text(buffer),
+            Self::MjButton(elt) => elt.render_text(buffer),
+            Self::MjCarousel(elt) => elt.render_text(buffer),
+            Self::MjColumn(elt) => elt.children.render_text(buffer),
+            Self::MjDivider(_) => render_divider_text(buffer),
+            Self::MjGroup(elt) => elt.children.render_text(buffer),
+            Self::MjHero(elt) => elt.children.render_text(buffer),
+            Self::MjInclude(elt) => elt.render_text(buffer),
+            Self::MjImage(elt) => elt.render_text(buffer),
+            Self::MjNavbar(elt) => elt.render_text(buffer),
+            Self::MjRaw(elt) => render_block_text(buffer, &elt.children),
+            Self::MjSection(elt) => elt.children.render_text(buffer),
+            Self::MjSocial(elt) => elt.render_text(buffer),
+            Self::MjSpacer(_) => {}
+            Self::MjTable(elt) => render_block_text(buffer, &elt.children),
+            Self::MjText(elt) => render_block_text(buffer, &elt.children),
+            Self::MjWrapper(elt) => elt.children.render_text(buffer),
+            Self::Node(elt) => elt.render_text(buffer),
+            Self::Text(elt) => elt.render_text(buffer),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[&[
+        ("background-color", AttributeType::Color),
+        ("width", AttributeType::Pixel),
+    ]]),
+    required: &[],
+    children: Children::Only(&[
+        crate::mj_hero::NAME,
+        crate::mj_raw::NAME,
+        crate::mj_section::NAME,
+        crate::mj_wrapper::NAME,
+    ]),
+};
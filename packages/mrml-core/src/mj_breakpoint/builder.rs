@@ -0,0 +1,13 @@
+use super::{MjBreakpoint, MjBreakpointAttributes};
+use crate::prelude::builder::{Builder, Pixel};
+
+impl MjBreakpoint {
+    pub fn builder(width: Pixel) -> Builder<Self> {
+        Builder::new(Self::new(
+            MjBreakpointAttributes {
+                width: width.to_string(),
+            },
+            (),
+        ))
+    }
+}
@@ -0,0 +1,9 @@
+use super::MjBreakpointAttributes;
+use crate::prelude::render::Error;
+use crate::prelude::template::{resolve_attribute, Template};
+
+impl Template for MjBreakpointAttributes {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        resolve_attribute("width", &mut self.width, context)
+    }
+}
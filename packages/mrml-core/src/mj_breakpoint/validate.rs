@@ -0,0 +1,15 @@
+use super::{MjBreakpointAttributes, NAME};
+use crate::prelude::validate::{AttributeType, AttributeValues, Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[&[("width", AttributeType::Pixel)]]),
+    required: &[],
+    children: Children::Raw,
+};
+
+impl AttributeValues for MjBreakpointAttributes {
+    fn attribute_value(&self, name: &str) -> Option<&str> {
+        (name == "width").then_some(self.width.as_str())
+    }
+}
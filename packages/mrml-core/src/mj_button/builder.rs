@@ -0,0 +1,43 @@
+use super::{MjButton, MjButtonTag};
+use crate::comment::Comment;
+use crate::mj_body::MjBodyChild;
+use crate::node::Node;
+use crate::prelude::builder::{
+    attributes, border_attributes, children, font_attributes, padding_attributes, Align, Builder,
+    Size, Spacing, VerticalAlign,
+};
+use crate::text::Text;
+
+impl MjButton {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjButton> {
+    padding_attributes!();
+    border_attributes!();
+    font_attributes!();
+    attributes! {
+        align: "align" => value(Align),
+        background_color: "background-color" => string,
+        border_radius: "border-radius" => string,
+        container_background_color: "container-background-color" => string,
+        height: "height" => value(Size),
+        href: "href" => string,
+        inner_padding: "inner-padding" => value(Spacing),
+        name: "name" => string,
+        rel: "rel" => string,
+        target: "target" => string,
+        text_align: "text-align" => value(Align),
+        title: "title" => string,
+        vertical_align: "vertical-align" => value(VerticalAlign),
+        width: "width" => value(Size),
+    }
+}
+
+children!(MjButtonTag => MjBodyChild {
+    Comment(Comment),
+    Node(Node<MjBodyChild>),
+    Text(Text),
+});
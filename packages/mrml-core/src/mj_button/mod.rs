@@ -3,6 +3,8 @@ use std::marker::PhantomData;
 use crate::mj_body::MjBodyChild;
 use crate::prelude::{Component, StaticTag};
 
+#[cfg(feature = "builder")]
+mod builder;
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "parse")]
@@ -11,6 +13,10 @@ mod parse;
 mod print;
 #[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "render")]
+mod text;
+#[cfg(feature = "validate")]
+pub(crate) mod validate;
 
 pub const NAME: &str = "mj-button";
 
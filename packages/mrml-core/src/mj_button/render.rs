@@ -139,8 +139,12 @@ impl<'root> Render<'root> for Renderer<'root, MjButton, ()> {
             .maybe_add_attribute("bgcolor", self.attribute("background-color"))
             .add_attribute("role", "presentation")
             .maybe_add_attribute("valign", self.attribute("vertical-align"));
-        let link = Tag::new(self.attribute("href").map(|_| "a").unwrap_or("p"))
-            .maybe_add_attribute("href", self.attribute("href"))
+        let href = self.attribute("href").map(|href| {
+            self.context
+                .rewrite_link(super::NAME, &self.element.attributes, href)
+        });
+        let link = Tag::new(href.as_ref().map(|_| "a").unwrap_or("p"))
+            .maybe_add_attribute("href", href.clone())
             .maybe_add_attribute("rel", self.attribute("rel"))
             .maybe_add_attribute("name", self.attribute("name"))
             .maybe_add_attribute(
@@ -171,7 +175,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjButton {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Renderer::new(context, self, ()).boxed()
     }
 }
 
@@ -0,0 +1,16 @@
+use super::{MjButton, NAME};
+use crate::prelude::render::text::{get_attribute, TextBuffer, TextRender};
+
+impl TextRender for MjButton {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>) {
+        buffer.end_block();
+        self.children.render_text(buffer);
+        buffer.push_link(
+            0,
+            NAME,
+            &self.attributes,
+            get_attribute(&self.attributes, "href"),
+        );
+        buffer.end_block();
+    }
+}
@@ -0,0 +1,35 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, BORDER, FONT, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        BORDER,
+        FONT,
+        &[
+            ("align", AttributeType::Enum(&["left", "center", "right"])),
+            ("background-color", AttributeType::Color),
+            ("border-radius", AttributeType::String),
+            ("container-background-color", AttributeType::Color),
+            ("height", AttributeType::Size),
+            ("href", AttributeType::String),
+            ("inner-padding", AttributeType::Spacing),
+            ("name", AttributeType::String),
+            ("rel", AttributeType::String),
+            ("target", AttributeType::String),
+            (
+                "text-align",
+                AttributeType::Enum(&["left", "center", "right"]),
+            ),
+            ("title", AttributeType::String),
+            (
+                "vertical-align",
+                AttributeType::Enum(&["top", "bottom", "middle"]),
+            ),
+            ("width", AttributeType::Size),
+        ],
+    ]),
+    required: &[],
+    children: Children::Raw,
+};
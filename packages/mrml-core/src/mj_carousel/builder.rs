@@ -0,0 +1,35 @@
+use super::{MjCarousel, MjCarouselChild, MjCarouselTag};
+use crate::comment::Comment;
+use crate::mj_carousel_image::MjCarouselImage;
+use crate::prelude::builder::{
+    attributes, children, padding_attributes, Align, Builder, Size, Spacing, Thumbnails,
+};
+
+impl MjCarousel {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjCarousel> {
+    padding_attributes!();
+    attributes! {
+        align: "align" => value(Align),
+        border_radius: "border-radius" => value(Spacing),
+        container_background_color: "container-background-color" => string,
+        icon_width: "icon-width" => value(Size),
+        left_icon: "left-icon" => string,
+        right_icon: "right-icon" => string,
+        tb_border: "tb-border" => string,
+        tb_border_radius: "tb-border-radius" => value(Size),
+        tb_hover_border_color: "tb-hover-border-color" => string,
+        tb_selected_border_color: "tb-selected-border-color" => string,
+        tb_width: "tb-width" => value(Size),
+        thumbnails: "thumbnails" => value(Thumbnails),
+    }
+}
+
+children!(MjCarouselTag => MjCarouselChild {
+    Comment(Comment),
+    MjCarouselImage(MjCarouselImage),
+});
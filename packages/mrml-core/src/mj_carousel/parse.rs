@@ -14,7 +14,9 @@ impl ParseChildren<Vec<MjCarouselChild>> for MrmlParser<'_> {
         loop {
             match cursor.assert_next()? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjCarouselChild::Comment(Comment::from(inner.text.as_str())));
+                    result.push(MjCarouselChild::Comment(Comment::from_token(
+                        cursor, &inner,
+                    )));
                 }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_CAROUSEL_IMAGE {
@@ -56,7 +58,9 @@ impl AsyncParseChildren<Vec<MjCarouselChild>> for AsyncMrmlParser {
         loop {
             match cursor.assert_next()? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjCarouselChild::Comment(Comment::from(inner.text.as_str())));
+                    result.push(MjCarouselChild::Comment(Comment::from_token(
+                        cursor, &inner,
+                    )));
                 }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_CAROUSEL_IMAGE {
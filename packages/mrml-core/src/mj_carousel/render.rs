@@ -502,8 +502,8 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjCarousel {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        let id = context.generator.next_id();
-        Box::new(Renderer::new(context, self, MjCarouselExtra { id }))
+        let id = context.next_id(super::NAME, self);
+        Renderer::new(context, self, MjCarouselExtra { id }).boxed()
     }
 }
 
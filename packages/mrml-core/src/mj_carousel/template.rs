@@ -0,0 +1,12 @@
+use super::MjCarouselChild;
+use crate::prelude::render::Error;
+use crate::prelude::template::Template;
+
+impl Template for MjCarouselChild {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        match self {
+            Self::Comment(elt) => elt.resolve(context),
+            Self::MjCarouselImage(elt) => elt.resolve(context),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use super::{MjCarousel, MjCarouselChild};
+use crate::prelude::render::text::{render_image_text, TextBuffer, TextRender};
+
+impl TextRender for MjCarousel {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>) {
+        for child in self.children.iter() {
+            if let MjCarouselChild::MjCarouselImage(image) = child {
+                render_image_text(buffer, crate::mj_carousel_image::NAME, &image.attributes);
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        &[
+            ("align", AttributeType::Enum(&["left", "center", "right"])),
+            ("border-radius", AttributeType::Spacing),
+            ("container-background-color", AttributeType::Color),
+            ("icon-width", AttributeType::Size),
+            ("left-icon", AttributeType::String),
+            ("right-icon", AttributeType::String),
+            ("tb-border", AttributeType::String),
+            ("tb-border-radius", AttributeType::Size),
+            ("tb-hover-border-color", AttributeType::Color),
+            ("tb-selected-border-color", AttributeType::Color),
+            ("tb-width", AttributeType::Size),
+            ("thumbnails", AttributeType::Enum(&["visible", "hidden"])),
+        ],
+    ]),
+    required: &[],
+    children: Children::Only(&[crate::mj_carousel_image::NAME]),
+};
@@ -0,0 +1,22 @@
+use super::MjCarouselImage;
+use crate::prelude::builder::{attributes, Builder, Spacing};
+
+impl MjCarouselImage {
+    pub fn builder(src: impl Into<String>) -> Builder<Self> {
+        Builder::new(Self::default()).set("src", src.into())
+    }
+}
+
+impl Builder<MjCarouselImage> {
+    attributes! {
+        alt: "alt" => string,
+        border_radius: "border-radius" => value(Spacing),
+        href: "href" => string,
+        rel: "rel" => string,
+        target: "target" => string,
+        tb_border: "tb-border" => string,
+        tb_border_radius: "tb-border-radius" => value(Spacing),
+        thumbnails_src: "thumbnails-src" => string,
+        title: "title" => string,
+    }
+}
@@ -1,9 +1,13 @@
+#[cfg(feature = "builder")]
+mod builder;
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "print")]
 mod print;
 #[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "validate")]
+pub(crate) mod validate;
 
 use std::marker::PhantomData;
 
@@ -93,8 +93,8 @@ impl<'root> Renderer<'root, MjCarouselImage, MjCarouselImageExtra<'root>> {
             .set_style_thumbnails_img(Tag::new("img"))
             .maybe_add_attribute(
                 "src",
-                self.attribute("thumbnails-src")
-                    .or_else(|| self.attribute("src")),
+                self.attribute_as_asset("thumbnails-src")
+                    .or_else(|| self.attribute_as_asset("src")),
             )
             .maybe_add_attribute("alt", self.attribute("alt"))
             .maybe_add_attribute(
@@ -199,7 +199,7 @@ impl<'root> Render<'root> for Renderer<'root, MjCarouselImage, MjCarouselImageEx
             .set_style_images_img(Tag::new("img"))
             .add_attribute("border", "0")
             .maybe_add_attribute("alt", self.attribute("alt"))
-            .maybe_add_attribute("src", self.attribute("src"))
+            .maybe_add_attribute("src", self.attribute_as_asset("src"))
             .maybe_add_attribute("title", self.attribute("title"))
             .maybe_add_attribute(
                 "width",
@@ -221,6 +221,9 @@ impl<'root> Render<'root> for Renderer<'root, MjCarouselImage, MjCarouselImageEx
 
         div.render_open(&mut cursor.buffer)?;
         if let Some(href) = self.attribute("href") {
+            let href = self
+                .context
+                .rewrite_link(super::NAME, &self.element.attributes, href);
             let link = Tag::new("a")
                 .add_attribute("href", href)
                 .maybe_add_attribute("rel", self.attribute("rel"))
@@ -242,12 +245,13 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjCarouselImage {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(
+        Renderer::new(
             context,
             self,
             MjCarouselImageExtra {
                 attributes: Map::new(),
             },
-        ))
+        )
+        .boxed()
     }
 }
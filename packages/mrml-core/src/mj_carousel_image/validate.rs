@@ -0,0 +1,20 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[&[
+        ("alt", AttributeType::String),
+        ("border-radius", AttributeType::Spacing),
+        ("href", AttributeType::String),
+        ("rel", AttributeType::String),
+        ("src", AttributeType::String),
+        ("target", AttributeType::String),
+        ("tb-border", AttributeType::String),
+        ("tb-border-radius", AttributeType::Spacing),
+        ("thumbnails-src", AttributeType::String),
+        ("title", AttributeType::String),
+    ]]),
+    required: &["src"],
+    children: Children::Raw,
+};
@@ -0,0 +1,59 @@
+use super::{MjColumn, MjColumnTag};
+use crate::comment::Comment;
+use crate::mj_accordion::MjAccordion;
+use crate::mj_body::MjBodyChild;
+use crate::mj_button::MjButton;
+use crate::mj_carousel::MjCarousel;
+use crate::mj_divider::MjDivider;
+use crate::mj_image::MjImage;
+use crate::mj_navbar::MjNavbar;
+use crate::mj_raw::MjRaw;
+use crate::mj_social::MjSocial;
+use crate::mj_spacer::MjSpacer;
+use crate::mj_table::MjTable;
+use crate::mj_text::MjText;
+use crate::prelude::builder::{
+    attributes, border_attributes, children, padding_attributes, Builder, Direction, Size, Spacing,
+    VerticalAlign,
+};
+
+impl MjColumn {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjColumn> {
+    padding_attributes!();
+    border_attributes!();
+    attributes! {
+        background_color: "background-color" => string,
+        border_radius: "border-radius" => value(Spacing),
+        direction: "direction" => value(Direction),
+        inner_background_color: "inner-background-color" => string,
+        inner_border: "inner-border" => string,
+        inner_border_bottom: "inner-border-bottom" => string,
+        inner_border_left: "inner-border-left" => string,
+        inner_border_radius: "inner-border-radius" => value(Spacing),
+        inner_border_right: "inner-border-right" => string,
+        inner_border_top: "inner-border-top" => string,
+        mobile_width: "mobile-width" => value(Size),
+        vertical_align: "vertical-align" => value(VerticalAlign),
+        width: "width" => value(Size),
+    }
+}
+
+children!(MjColumnTag => MjBodyChild {
+    Comment(Comment),
+    MjAccordion(MjAccordion),
+    MjButton(MjButton),
+    MjCarousel(MjCarousel),
+    MjDivider(MjDivider),
+    MjImage(MjImage),
+    MjNavbar(MjNavbar),
+    MjRaw(MjRaw),
+    MjSocial(MjSocial),
+    MjSpacer(MjSpacer),
+    MjTable(MjTable),
+    MjText(MjText),
+});
@@ -344,13 +344,14 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjColumn {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(
+        Renderer::new(
             context,
             self,
             MjColumnExtra {
                 attributes: Map::new(),
             },
-        ))
+        )
+        .boxed()
     }
 }
 
@@ -0,0 +1,32 @@
+use super::NAME;
+use crate::prelude::validate::{
+    AttributeType, Attributes, Children, Rule, BODY_CONTENT, BORDER, PADDING,
+};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        BORDER,
+        &[
+            ("background-color", AttributeType::Color),
+            ("border-radius", AttributeType::Spacing),
+            ("direction", AttributeType::Enum(&["ltr", "rtl"])),
+            ("inner-background-color", AttributeType::Color),
+            ("inner-border", AttributeType::String),
+            ("inner-border-bottom", AttributeType::String),
+            ("inner-border-left", AttributeType::String),
+            ("inner-border-radius", AttributeType::Spacing),
+            ("mobile-width", AttributeType::Size),
+            ("inner-border-right", AttributeType::String),
+            ("inner-border-top", AttributeType::String),
+            (
+                "vertical-align",
+                AttributeType::Enum(&["top", "bottom", "middle"]),
+            ),
+            ("width", AttributeType::Size),
+        ],
+    ]),
+    required: &[],
+    children: Children::Only(BODY_CONTENT),
+};
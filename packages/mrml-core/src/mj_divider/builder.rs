@@ -0,0 +1,20 @@
+use super::MjDivider;
+use crate::prelude::builder::{attributes, padding_attributes, Align, Builder, Pixel, Size};
+
+impl MjDivider {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjDivider> {
+    padding_attributes!();
+    attributes! {
+        align: "align" => value(Align),
+        border_color: "border-color" => string,
+        border_style: "border-style" => string,
+        border_width: "border-width" => value(Pixel),
+        container_background_color: "container-background-color" => string,
+        width: "width" => value(Size),
+    }
+}
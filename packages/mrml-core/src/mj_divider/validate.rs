@@ -0,0 +1,19 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        &[
+            ("align", AttributeType::Enum(&["left", "center", "right"])),
+            ("border-color", AttributeType::Color),
+            ("border-style", AttributeType::String),
+            ("border-width", AttributeType::Pixel),
+            ("container-background-color", AttributeType::Color),
+            ("width", AttributeType::Size),
+        ],
+    ]),
+    required: &[],
+    children: Children::Raw,
+};
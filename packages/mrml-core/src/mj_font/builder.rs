@@ -0,0 +1,14 @@
+use super::{MjFont, MjFontAttributes};
+use crate::prelude::builder::Builder;
+
+impl MjFont {
+    pub fn builder(name: impl Into<String>, href: impl Into<String>) -> Builder<Self> {
+        Builder::new(Self::new(
+            MjFontAttributes {
+                name: name.into(),
+                href: href.into(),
+            },
+            (),
+        ))
+    }
+}
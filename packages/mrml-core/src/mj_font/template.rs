@@ -0,0 +1,10 @@
+use super::MjFontAttributes;
+use crate::prelude::render::Error;
+use crate::prelude::template::{resolve_attribute, Template};
+
+impl Template for MjFontAttributes {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        resolve_attribute("name", &mut self.name, context)?;
+        resolve_attribute("href", &mut self.href, context)
+    }
+}
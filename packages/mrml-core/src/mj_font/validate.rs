@@ -0,0 +1,22 @@
+use super::{MjFontAttributes, NAME};
+use crate::prelude::validate::{AttributeType, AttributeValues, Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[&[
+        ("href", AttributeType::String),
+        ("name", AttributeType::String),
+    ]]),
+    required: &["name", "href"],
+    children: Children::Raw,
+};
+
+impl AttributeValues for MjFontAttributes {
+    fn attribute_value(&self, name: &str) -> Option<&str> {
+        match name {
+            "href" => Some(self.href.as_str()),
+            "name" => Some(self.name.as_str()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use super::{MjGroup, MjGroupTag};
+use crate::comment::Comment;
+use crate::mj_body::MjBodyChild;
+use crate::mj_column::MjColumn;
+use crate::mj_raw::MjRaw;
+use crate::prelude::builder::{attributes, children, Builder, Direction, Size, VerticalAlign};
+
+impl MjGroup {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjGroup> {
+    attributes! {
+        background_color: "background-color" => string,
+        direction: "direction" => value(Direction),
+        vertical_align: "vertical-align" => value(VerticalAlign),
+        width: "width" => value(Size),
+    }
+}
+
+children!(MjGroupTag => MjBodyChild {
+    Comment(Comment),
+    MjColumn(MjColumn),
+    MjRaw(MjRaw),
+});
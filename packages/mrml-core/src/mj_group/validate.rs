@@ -0,0 +1,17 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[&[
+        ("background-color", AttributeType::Color),
+        ("direction", AttributeType::Enum(&["ltr", "rtl"])),
+        (
+            "vertical-align",
+            AttributeType::Enum(&["top", "bottom", "middle"]),
+        ),
+        ("width", AttributeType::Size),
+    ]]),
+    required: &[],
+    children: Children::Only(&[crate::mj_column::NAME, crate::mj_raw::NAME]),
+};
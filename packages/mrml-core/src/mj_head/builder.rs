@@ -0,0 +1,29 @@
+use super::{MjHead, MjHeadChild, MjHeadTag};
+use crate::comment::Comment;
+use crate::mj_attributes::MjAttributes;
+use crate::mj_breakpoint::MjBreakpoint;
+use crate::mj_font::MjFont;
+use crate::mj_html_attributes::MjHtmlAttributes;
+use crate::mj_preview::MjPreview;
+use crate::mj_raw::MjRaw;
+use crate::mj_style::MjStyle;
+use crate::mj_title::MjTitle;
+use crate::prelude::builder::{children, Builder};
+
+impl MjHead {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+children!(MjHeadTag => MjHeadChild {
+    Comment(Comment),
+    MjAttributes(MjAttributes),
+    MjBreakpoint(MjBreakpoint),
+    MjFont(MjFont),
+    MjHtmlAttributes(MjHtmlAttributes),
+    MjPreview(MjPreview),
+    MjRaw(MjRaw),
+    MjStyle(MjStyle),
+    MjTitle(MjTitle),
+});
@@ -5,6 +5,7 @@ use crate::comment::Comment;
 use crate::mj_attributes::NAME as MJ_ATTRIBUTES;
 use crate::mj_breakpoint::NAME as MJ_BREAKPOINT;
 use crate::mj_font::NAME as MJ_FONT;
+use crate::mj_html_attributes::NAME as MJ_HTML_ATTRIBUTES;
 use crate::mj_include::NAME as MJ_INCLUDE;
 use crate::mj_preview::NAME as MJ_PREVIEW;
 use crate::mj_raw::NAME as MJ_RAW;
@@ -22,7 +23,7 @@ impl ParseChildren<Vec<MjHeadChild>> for MrmlParser<'_> {
         loop {
             match cursor.assert_next()? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjHeadChild::Comment(Comment::from(inner.text.as_str())));
+                    result.push(MjHeadChild::Comment(Comment::from_token(cursor, &inner)));
                 }
                 MrmlToken::ElementStart(inner) => {
                     result.push(self.parse(cursor, inner.local)?);
@@ -56,7 +57,7 @@ impl AsyncParseChildren<Vec<MjHeadChild>> for AsyncMrmlParser {
         loop {
             match cursor.assert_next()? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjHeadChild::Comment(Comment::from(inner.text.as_str())));
+                    result.push(MjHeadChild::Comment(Comment::from_token(cursor, &inner)));
                 }
                 MrmlToken::ElementStart(inner) => {
                     result.push(self.async_parse(cursor, inner.local).await?);
@@ -86,6 +87,7 @@ impl ParseElement<MjHeadChild> for MrmlParser<'_> {
             MJ_ATTRIBUTES => self.parse(cursor, tag).map(MjHeadChild::MjAttributes),
             MJ_BREAKPOINT => self.parse(cursor, tag).map(MjHeadChild::MjBreakpoint),
             MJ_FONT => self.parse(cursor, tag).map(MjHeadChild::MjFont),
+            MJ_HTML_ATTRIBUTES => self.parse(cursor, tag).map(MjHeadChild::MjHtmlAttributes),
             MJ_INCLUDE => self.parse(cursor, tag).map(MjHeadChild::MjInclude),
             MJ_PREVIEW => self.parse(cursor, tag).map(MjHeadChild::MjPreview),
             MJ_RAW => self.parse(cursor, tag).map(MjHeadChild::MjRaw),
@@ -118,6 +120,10 @@ impl AsyncParseElement<MjHeadChild> for AsyncMrmlParser {
                 .await
                 .map(MjHeadChild::MjBreakpoint),
             MJ_FONT => self.async_parse(cursor, tag).await.map(MjHeadChild::MjFont),
+            MJ_HTML_ATTRIBUTES => self
+                .async_parse(cursor, tag)
+                .await
+                .map(MjHeadChild::MjHtmlAttributes),
             MJ_INCLUDE => self
                 .async_parse(cursor, tag)
                 .await
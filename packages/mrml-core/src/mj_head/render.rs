@@ -1,5 +1,6 @@
 use super::MjHead;
 use crate::helper::sort::sort_by_key;
+use crate::mj_html_attributes::MjSelector;
 use crate::mj_style::StyleInlineMode;
 use crate::prelude::hash::Map;
 use crate::prelude::render::*;
@@ -106,23 +107,28 @@ impl MjHead {
             .map(|font| (font.name(), font.href()))
             .collect()
     }
-}
-
-fn render_font_import(target: &mut String, href: &str) {
-    target.push_str("@import url(");
-    target.push_str(href);
-    target.push_str(");");
-}
 
-fn render_font_link(target: &mut String, href: &str) {
-    target.push_str("<link href=\"");
-    target.push_str(href);
-    target.push_str("\" rel=\"stylesheet\" type=\"text/css\">");
-}
+    pub(crate) fn mj_selector_iter(&self) -> impl Iterator<Item = &MjSelector> {
+        self.children.iter().flat_map(|item| {
+            item.as_mj_html_attributes()
+                .into_iter()
+                .chain(
+                    item.as_mj_include()
+                        .filter(|item| item.0.attributes.kind.is_mjml())
+                        .into_iter()
+                        .flat_map(|incl| {
+                            incl.0
+                                .children
+                                .iter()
+                                .filter_map(|child| child.as_mj_html_attributes())
+                        }),
+                )
+                .flat_map(|inner| inner.selectors_iter())
+        })
+    }
 
-impl Renderer<'_, MjHead, ()> {
-    fn mj_style_iter(&self) -> impl Iterator<Item = (&str, StyleInlineMode)> {
-        self.element.children.iter().flat_map(|item| {
+    pub(crate) fn mj_style_iter(&self) -> impl Iterator<Item = (&str, StyleInlineMode)> {
+        self.children.iter().flat_map(|item| {
             // Include styles from mj_include elements
             let included_styles = item.as_mj_include().into_iter().flat_map(|inner| {
                 let includes_css = inner
@@ -160,6 +166,49 @@ impl Renderer<'_, MjHead, ()> {
             included_styles.chain(direct_styles)
         })
     }
+}
+
+fn render_font_import(target: &mut String, href: &str) {
+    target.push_str("@import url(");
+    target.push_str(href);
+    target.push_str(");");
+}
+
+fn render_font_link(target: &mut String, href: &str) {
+    target.push_str("<link href=\"");
+    target.push_str(href);
+    target.push_str("\" rel=\"stylesheet\" type=\"text/css\">");
+}
+
+impl MjHead {
+    /// Writes the beginning of the head, up to the base styles, which depends
+    /// neither on the body nor on the render options.
+    pub(crate) fn render_start(&self, buffer: &mut RenderBuffer) {
+        buffer.push_str("<head>");
+        // we write the title even though there is no content
+        buffer.push_str("<title>");
+        if let Some(title) = self.title().map(|item| item.content()) {
+            buffer.push_str(title);
+        }
+        buffer.push_str("</title>");
+        buffer.start_mso_negation_conditional_tag();
+        buffer.push_str("<meta http-equiv=\"X-UA-Compatible\" content=\"IE=edge\">");
+        buffer.end_negation_conditional_tag();
+        buffer.push_str("<meta http-equiv=\"Content-Type\" content=\"text/html; charset=UTF-8\">");
+        buffer.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">");
+        buffer.push_str(STYLE_BASE);
+    }
+}
+
+impl Renderer<'_, MjHead, ()> {
+    /// Writes the end of the head, with what has been collected while
+    /// rendering the body and the `mj-raw` elements.
+    pub(crate) fn render_end(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.render_collected(cursor);
+        self.render_raw(cursor)?;
+        cursor.buffer.push_str("</head>");
+        Ok(())
+    }
 
     fn render_font_families(&self, cursor: &mut RenderCursor) {
         let used_font_families = cursor.header.used_font_families();
@@ -171,8 +220,13 @@ impl Renderer<'_, MjHead, ()> {
         let mut imports = String::default();
         for name in cursor.header.used_font_families().iter() {
             if let Some(href) = self.context.header.font_families().get(name.as_str()) {
-                render_font_link(&mut links, href);
-                render_font_import(&mut imports, href);
+                let href = self.context.options.resolve_asset(
+                    crate::mj_font::NAME,
+                    "href",
+                    std::borrow::Cow::Borrowed(href),
+                );
+                render_font_link(&mut links, &href);
+                render_font_import(&mut imports, &href);
             } else if let Some(href) = self.context.options.fonts.get(name) {
                 render_font_link(&mut links, href);
                 render_font_import(&mut imports, href);
@@ -247,8 +301,8 @@ impl Renderer<'_, MjHead, ()> {
         }
 
         // Process mj-style items
-        for (content, inline_mode) in self.mj_style_iter() {
-            if inline_mode == StyleInlineMode::Inline {
+        for (content, inline_mode) in self.context.header.mj_styles() {
+            if *inline_mode == StyleInlineMode::Inline {
                 // Add inline styles to inline_styles collection
                 cursor.header.add_inline_style(content.to_string());
             } else {
@@ -272,13 +326,21 @@ impl Renderer<'_, MjHead, ()> {
         }
 
         // Process mj-style items - output all styles to the buffer
-        for (content, _is_inline) in self.mj_style_iter() {
+        for (content, _is_inline) in self.context.header.mj_styles() {
             cursor.buffer.push_str("<style type=\"text/css\">");
             cursor.buffer.push_str(content);
             cursor.buffer.push_str("</style>");
         }
     }
 
+    /// Writes the fonts, media queries and styles collected while rendering
+    /// the body.
+    pub(crate) fn render_collected(&self, cursor: &mut RenderCursor) {
+        self.render_font_families(cursor);
+        self.render_media_queries(cursor);
+        self.render_styles(cursor);
+    }
+
     fn render_raw(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let mut index: usize = 0;
         let siblings = self.element.children.len();
@@ -311,31 +373,8 @@ impl<'root> Render<'root> for Renderer<'root, MjHead, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        cursor.buffer.push_str("<head>");
-        // we write the title even though there is no content
-        cursor.buffer.push_str("<title>");
-        if let Some(title) = self.element.title().map(|item| item.content()) {
-            cursor.buffer.push_str(title);
-        }
-        cursor.buffer.push_str("</title>");
-        cursor.buffer.start_mso_negation_conditional_tag();
-        cursor
-            .buffer
-            .push_str("<meta http-equiv=\"X-UA-Compatible\" content=\"IE=edge\">");
-        cursor.buffer.end_negation_conditional_tag();
-        cursor
-            .buffer
-            .push_str("<meta http-equiv=\"Content-Type\" content=\"text/html; charset=UTF-8\">");
-        cursor
-            .buffer
-            .push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">");
-        cursor.buffer.push_str(STYLE_BASE);
-        self.render_font_families(cursor);
-        self.render_media_queries(cursor);
-        self.render_styles(cursor);
-        self.render_raw(cursor)?;
-        cursor.buffer.push_str("</head>");
-        Ok(())
+        self.element.render_start(&mut cursor.buffer);
+        self.render_end(cursor)
     }
 }
 
@@ -344,7 +383,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjHead {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Renderer::new(context, self, ()).boxed()
     }
 }
 
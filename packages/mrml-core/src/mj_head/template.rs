@@ -0,0 +1,20 @@
+use super::MjHeadChild;
+use crate::prelude::render::Error;
+use crate::prelude::template::Template;
+
+impl Template for MjHeadChild {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        match self {
+            Self::Comment(elt) => elt.resolve(context),
+            Self::MjAttributes(elt) => elt.resolve(context),
+            Self::MjBreakpoint(elt) => elt.resolve(context),
+            Self::MjFont(elt) => elt.resolve(context),
+            Self::MjHtmlAttributes(elt) => elt.resolve(context),
+            Self::MjInclude(elt) => elt.resolve(context),
+            Self::MjPreview(elt) => elt.resolve(context),
+            Self::MjRaw(elt) => elt.resolve(context),
+            Self::MjStyle(elt) => elt.resolve(context),
+            Self::MjTitle(elt) => elt.resolve(context),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use super::NAME;
+use crate::prelude::validate::{Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[]),
+    required: &[],
+    children: Children::Only(&[
+        crate::mj_attributes::NAME,
+        crate::mj_breakpoint::NAME,
+        crate::mj_font::NAME,
+        crate::mj_html_attributes::NAME,
+        crate::mj_preview::NAME,
+        crate::mj_raw::NAME,
+        crate::mj_style::NAME,
+        crate::mj_title::NAME,
+    ]),
+};
@@ -0,0 +1,61 @@
+use super::{MjHero, MjHeroTag};
+use crate::comment::Comment;
+use crate::mj_accordion::MjAccordion;
+use crate::mj_body::MjBodyChild;
+use crate::mj_button::MjButton;
+use crate::mj_carousel::MjCarousel;
+use crate::mj_divider::MjDivider;
+use crate::mj_image::MjImage;
+use crate::mj_navbar::MjNavbar;
+use crate::mj_raw::MjRaw;
+use crate::mj_social::MjSocial;
+use crate::mj_spacer::MjSpacer;
+use crate::mj_table::MjTable;
+use crate::mj_text::MjText;
+use crate::prelude::builder::{
+    attributes, children, padding_attributes, Builder, HeroMode, Size, Spacing, VerticalAlign,
+};
+
+impl MjHero {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjHero> {
+    padding_attributes!();
+    attributes! {
+        background_color: "background-color" => string,
+        background_height: "background-height" => value(Size),
+        background_position: "background-position" => string,
+        background_url: "background-url" => string,
+        background_width: "background-width" => value(Size),
+        border_radius: "border-radius" => string,
+        container_background_color: "container-background-color" => string,
+        height: "height" => value(Size),
+        inner_background_color: "inner-background-color" => string,
+        inner_padding: "inner-padding" => value(Spacing),
+        inner_padding_bottom: "inner-padding-bottom" => value(Size),
+        inner_padding_left: "inner-padding-left" => value(Size),
+        inner_padding_right: "inner-padding-right" => value(Size),
+        inner_padding_top: "inner-padding-top" => value(Size),
+        mode: "mode" => value(HeroMode),
+        vertical_align: "vertical-align" => value(VerticalAlign),
+        width: "width" => value(Size),
+    }
+}
+
+children!(MjHeroTag => MjBodyChild {
+    Comment(Comment),
+    MjAccordion(MjAccordion),
+    MjButton(MjButton),
+    MjCarousel(MjCarousel),
+    MjDivider(MjDivider),
+    MjImage(MjImage),
+    MjNavbar(MjNavbar),
+    MjRaw(MjRaw),
+    MjSocial(MjSocial),
+    MjSpacer(MjSpacer),
+    MjTable(MjTable),
+    MjText(MjText),
+});
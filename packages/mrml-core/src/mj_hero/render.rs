@@ -106,7 +106,7 @@ impl<'root> Renderer<'root, MjHero, ()> {
         'root: 'a,
     {
         if let (Some(url), Some(color), Some(position)) = (
-            self.attribute("background-url"),
+            self.attribute_as_asset("background-url"),
             self.attribute("background-color"),
             self.attribute("background-position"),
         ) {
@@ -230,7 +230,7 @@ impl<'root> Renderer<'root, MjHero, ()> {
         let td_fluid = self.set_style_td_fluid(Tag::td());
         let td = self
             .set_style_hero(Tag::td())
-            .maybe_add_attribute("background", self.attribute("background-url"));
+            .maybe_add_attribute("background", self.attribute_as_asset("background-url"));
 
         td_fluid.render_closed(&mut cursor.buffer)?;
         td.render_open(&mut cursor.buffer)?;
@@ -252,7 +252,7 @@ impl<'root> Renderer<'root, MjHero, ()> {
         let td = self
             .set_style_hero(Tag::td())
             .add_style("height", format!("{height}px"))
-            .maybe_add_attribute("background", self.attribute("background-url"))
+            .maybe_add_attribute("background", self.attribute_as_asset("background-url"))
             .add_attribute("height", height.to_string());
 
         td.render_open(&mut cursor.buffer)?;
@@ -322,7 +322,7 @@ impl<'root> Render<'root> for Renderer<'root, MjHero, ()> {
         let outlook_td = self.set_style_outlook_td(Tag::td());
         let v_image = self
             .set_style_outlook_image(Tag::new("v:image"))
-            .maybe_add_attribute("src", self.attribute("background-url"))
+            .maybe_add_attribute("src", self.attribute_as_asset("background-url"))
             .add_attribute("xmlns:v", "urn:schemas-microsoft-com:vml");
         let div = self
             .set_style_div(Tag::div())
@@ -366,7 +366,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjHero {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Renderer::new(context, self, ()).boxed()
     }
 }
 
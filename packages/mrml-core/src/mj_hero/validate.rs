@@ -0,0 +1,36 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, BODY_CONTENT, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        &[
+            ("background-color", AttributeType::Color),
+            ("background-height", AttributeType::Size),
+            ("background-position", AttributeType::String),
+            ("background-url", AttributeType::String),
+            ("background-width", AttributeType::Size),
+            ("border-radius", AttributeType::String),
+            ("container-background-color", AttributeType::Color),
+            ("height", AttributeType::Size),
+            ("inner-background-color", AttributeType::Color),
+            ("inner-padding", AttributeType::Spacing),
+            ("inner-padding-bottom", AttributeType::Size),
+            ("inner-padding-left", AttributeType::Size),
+            ("inner-padding-right", AttributeType::Size),
+            ("inner-padding-top", AttributeType::Size),
+            (
+                "mode",
+                AttributeType::Enum(&["fluid-height", "fixed-height"]),
+            ),
+            (
+                "vertical-align",
+                AttributeType::Enum(&["top", "bottom", "middle"]),
+            ),
+            ("width", AttributeType::Size),
+        ],
+    ]),
+    required: &[],
+    children: Children::Only(BODY_CONTENT),
+};
@@ -0,0 +1,43 @@
+use super::{
+    MjHtmlAttribute, MjHtmlAttributeAttributes, MjHtmlAttributes, MjHtmlAttributesTag, MjSelector,
+    MjSelectorAttributes, MjSelectorTag,
+};
+use crate::prelude::builder::{Builder, ChildOf};
+
+impl MjHtmlAttributes {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl MjSelector {
+    /// Starts a builder of the attributes added to the html elements matching
+    /// the css selector `path`.
+    pub fn builder(path: impl Into<String>) -> Builder<Self> {
+        Builder::new(Self::new(
+            MjSelectorAttributes { path: path.into() },
+            Vec::new(),
+        ))
+    }
+}
+
+impl MjHtmlAttribute {
+    pub fn builder(name: impl Into<String>, value: impl Into<String>) -> Builder<Self> {
+        Builder::new(Self::new(
+            MjHtmlAttributeAttributes { name: name.into() },
+            value.into(),
+        ))
+    }
+}
+
+impl ChildOf<MjHtmlAttributesTag, MjSelector> for MjSelector {
+    fn into_child(self) -> MjSelector {
+        self
+    }
+}
+
+impl ChildOf<MjSelectorTag, MjHtmlAttribute> for MjHtmlAttribute {
+    fn into_child(self) -> MjHtmlAttribute {
+        self
+    }
+}
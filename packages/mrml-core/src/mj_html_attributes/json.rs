@@ -0,0 +1,64 @@
+use super::{MjHtmlAttributeAttributes, MjSelectorAttributes};
+use crate::prelude::json::JsonAttributes;
+
+impl JsonAttributes for MjSelectorAttributes {
+    fn has_attributes(&self) -> bool {
+        true
+    }
+
+    fn try_from_serde<Err: serde::de::Error>(this: Option<Self>) -> Result<Self, Err>
+    where
+        Self: Sized,
+    {
+        this.ok_or_else(|| serde::de::Error::missing_field("attributes"))
+    }
+}
+
+impl JsonAttributes for MjHtmlAttributeAttributes {
+    fn has_attributes(&self) -> bool {
+        true
+    }
+
+    fn try_from_serde<Err: serde::de::Error>(this: Option<Self>) -> Result<Self, Err>
+    where
+        Self: Sized,
+    {
+        this.ok_or_else(|| serde::de::Error::missing_field("attributes"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_html_attributes::{MjHtmlAttribute, MjHtmlAttributes, MjSelector};
+
+    #[test]
+    fn serialize() {
+        let elt = MjHtmlAttributes::new(
+            (),
+            vec![MjSelector::build(
+                ".custom div",
+                vec![MjHtmlAttribute::build("data-id", "42")],
+            )],
+        );
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-html-attributes","children":[{"type":"mj-selector","attributes":{"path":".custom div"},"children":[{"type":"mj-html-attribute","attributes":{"name":"data-id"},"children":"42"}]}]}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-html-attributes","children":[{"type":"mj-selector","attributes":{"path":".custom div"},"children":[{"type":"mj-html-attribute","attributes":{"name":"data-id"},"children":"42"}]}]}"#;
+        let res: MjHtmlAttributes = serde_json::from_str(json).unwrap();
+        assert_eq!(res.children.len(), 1);
+        assert_eq!(res.children[0].path(), ".custom div");
+        let next = serde_json::to_string(&res).unwrap();
+        assert_eq!(next, json);
+    }
+
+    #[test]
+    fn deserialize_missing_path() {
+        let json = r#"{"type":"mj-selector"}"#;
+        assert!(serde_json::from_str::<MjSelector>(json).is_err());
+    }
+}
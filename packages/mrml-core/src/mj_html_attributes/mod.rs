@@ -0,0 +1,116 @@
+//! Module containing the `mj-html-attributes` element as defined in [the
+//! documentation](https://documentation.mjml.io/#mj-html-attributes).
+//!
+//! ```xml
+//! <mj-html-attributes>
+//!   <mj-selector path=".custom div">
+//!     <mj-html-attribute name="data-id">42</mj-html-attribute>
+//!   </mj-selector>
+//! </mj-html-attributes>
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::prelude::{Component, StaticTag};
+
+#[cfg(feature = "builder")]
+mod builder;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+pub(crate) mod render;
+#[cfg(feature = "template")]
+mod template;
+#[cfg(feature = "validate")]
+pub(crate) mod validate;
+
+pub const NAME: &str = "mj-html-attributes";
+pub const SELECTOR_NAME: &str = "mj-selector";
+pub const ATTRIBUTE_NAME: &str = "mj-html-attribute";
+
+pub struct MjHtmlAttributesTag;
+
+impl StaticTag for MjHtmlAttributesTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjHtmlAttributes = Component<PhantomData<MjHtmlAttributesTag>, (), Vec<MjSelector>>;
+
+impl MjHtmlAttributes {
+    pub fn children(&self) -> &Vec<MjSelector> {
+        &self.children
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub struct MjSelectorAttributes {
+    pub path: String,
+}
+
+pub struct MjSelectorTag;
+
+impl StaticTag for MjSelectorTag {
+    fn static_tag() -> &'static str {
+        SELECTOR_NAME
+    }
+}
+
+pub type MjSelector =
+    Component<PhantomData<MjSelectorTag>, MjSelectorAttributes, Vec<MjHtmlAttribute>>;
+
+impl MjSelector {
+    pub fn path(&self) -> &str {
+        &self.attributes.path
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub struct MjHtmlAttributeAttributes {
+    pub name: String,
+}
+
+pub struct MjHtmlAttributeTag;
+
+impl StaticTag for MjHtmlAttributeTag {
+    fn static_tag() -> &'static str {
+        ATTRIBUTE_NAME
+    }
+}
+
+pub type MjHtmlAttribute =
+    Component<PhantomData<MjHtmlAttributeTag>, MjHtmlAttributeAttributes, String>;
+
+impl MjHtmlAttribute {
+    pub fn name(&self) -> &str {
+        &self.attributes.name
+    }
+
+    pub fn value(&self) -> &str {
+        self.children.trim()
+    }
+}
+
+#[cfg(test)]
+impl MjSelector {
+    pub(crate) fn build<P: Into<String>>(path: P, children: Vec<MjHtmlAttribute>) -> Self {
+        Self::new(MjSelectorAttributes { path: path.into() }, children)
+    }
+}
+
+#[cfg(test)]
+impl MjHtmlAttribute {
+    pub(crate) fn build<N: Into<String>, V: Into<String>>(name: N, value: V) -> Self {
+        Self::new(
+            MjHtmlAttributeAttributes { name: name.into() },
+            value.into(),
+        )
+    }
+}
@@ -0,0 +1,238 @@
+use htmlparser::StrSpan;
+
+use super::{
+    MjHtmlAttribute, MjHtmlAttributeAttributes, MjSelector, MjSelectorAttributes, ATTRIBUTE_NAME,
+    SELECTOR_NAME,
+};
+#[cfg(feature = "async")]
+use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
+use crate::prelude::parser::{
+    Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
+    WarningKind,
+};
+
+#[inline]
+fn parse_selector_attributes(
+    cursor: &mut MrmlCursor<'_>,
+    tag: &StrSpan<'_>,
+) -> Result<MjSelectorAttributes, Error> {
+    let mut path = None;
+    while let Some(attr) = cursor.next_attribute()? {
+        match (attr.local.as_str(), attr.value) {
+            ("path", Some(value)) => path = Some(value.to_string()),
+            _ => cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span),
+        }
+    }
+    Ok(MjSelectorAttributes {
+        path: path.ok_or_else(|| Error::MissingAttribute {
+            name: "path",
+            origin: cursor.origin(),
+            position: tag.into(),
+        })?,
+    })
+}
+
+#[inline]
+fn parse_attribute_attributes(
+    cursor: &mut MrmlCursor<'_>,
+    tag: &StrSpan<'_>,
+) -> Result<MjHtmlAttributeAttributes, Error> {
+    let mut name = None;
+    while let Some(attr) = cursor.next_attribute()? {
+        match (attr.local.as_str(), attr.value) {
+            ("name", Some(value)) => name = Some(value.to_string()),
+            _ => cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span),
+        }
+    }
+    Ok(MjHtmlAttributeAttributes {
+        name: name.ok_or_else(|| Error::MissingAttribute {
+            name: "name",
+            origin: cursor.origin(),
+            position: tag.into(),
+        })?,
+    })
+}
+
+/// Moves the cursor to the next child element, ignoring the comments, and
+/// returns its tag. Only elements named `expected` are accepted.
+fn next_child_named<'a>(
+    cursor: &mut MrmlCursor<'a>,
+    expected: &str,
+) -> Result<Option<StrSpan<'a>>, Error> {
+    loop {
+        match cursor.assert_next()? {
+            MrmlToken::Comment(_) => {}
+            MrmlToken::ElementStart(inner) if inner.local.as_str() == expected => {
+                return Ok(Some(inner.local));
+            }
+            MrmlToken::ElementStart(inner) => {
+                return Err(Error::UnexpectedElement {
+                    origin: cursor.origin(),
+                    position: inner.span.into(),
+                });
+            }
+            MrmlToken::ElementClose(inner) => {
+                cursor.rewind(MrmlToken::ElementClose(inner));
+                return Ok(None);
+            }
+            other => {
+                return Err(Error::UnexpectedToken {
+                    origin: cursor.origin(),
+                    position: other.span(),
+                });
+            }
+        }
+    }
+}
+
+impl ParseAttributes<MjSelectorAttributes> for MrmlParser<'_> {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        tag: &StrSpan<'_>,
+    ) -> Result<MjSelectorAttributes, Error> {
+        parse_selector_attributes(cursor, tag)
+    }
+}
+
+impl ParseAttributes<MjHtmlAttributeAttributes> for MrmlParser<'_> {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        tag: &StrSpan<'_>,
+    ) -> Result<MjHtmlAttributeAttributes, Error> {
+        parse_attribute_attributes(cursor, tag)
+    }
+}
+
+impl ParseChildren<Vec<MjSelector>> for MrmlParser<'_> {
+    fn parse_children(&self, cursor: &mut MrmlCursor<'_>) -> Result<Vec<MjSelector>, Error> {
+        let mut result = Vec::new();
+        while let Some(tag) = next_child_named(cursor, SELECTOR_NAME)? {
+            result.push(self.parse(cursor, tag)?);
+        }
+        Ok(result)
+    }
+}
+
+impl ParseChildren<Vec<MjHtmlAttribute>> for MrmlParser<'_> {
+    fn parse_children(&self, cursor: &mut MrmlCursor<'_>) -> Result<Vec<MjHtmlAttribute>, Error> {
+        let mut result = Vec::new();
+        while let Some(tag) = next_child_named(cursor, ATTRIBUTE_NAME)? {
+            result.push(self.parse(cursor, tag)?);
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "async")]
+impl ParseAttributes<MjSelectorAttributes> for AsyncMrmlParser {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        tag: &StrSpan<'_>,
+    ) -> Result<MjSelectorAttributes, Error> {
+        parse_selector_attributes(cursor, tag)
+    }
+}
+
+#[cfg(feature = "async")]
+impl ParseAttributes<MjHtmlAttributeAttributes> for AsyncMrmlParser {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        tag: &StrSpan<'_>,
+    ) -> Result<MjHtmlAttributeAttributes, Error> {
+        parse_attribute_attributes(cursor, tag)
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AsyncParseChildren<Vec<MjSelector>> for AsyncMrmlParser {
+    async fn async_parse_children<'a>(
+        &self,
+        cursor: &mut MrmlCursor<'a>,
+    ) -> Result<Vec<MjSelector>, Error> {
+        let mut result = Vec::new();
+        while let Some(tag) = next_child_named(cursor, SELECTOR_NAME)? {
+            result.push(self.async_parse(cursor, tag).await?);
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AsyncParseChildren<Vec<MjHtmlAttribute>> for AsyncMrmlParser {
+    async fn async_parse_children<'a>(
+        &self,
+        cursor: &mut MrmlCursor<'a>,
+    ) -> Result<Vec<MjHtmlAttribute>, Error> {
+        let mut result = Vec::new();
+        while let Some(tag) = next_child_named(cursor, ATTRIBUTE_NAME)? {
+            result.push(self.async_parse(cursor, tag).await?);
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_html_attributes::MjHtmlAttributes;
+
+    crate::should_parse!(
+        success,
+        MjHtmlAttributes,
+        r#"<mj-html-attributes>
+    <!-- comment -->
+    <mj-selector path=".custom div">
+        <mj-html-attribute name="data-id">42</mj-html-attribute>
+        <mj-html-attribute name="data-name">foo</mj-html-attribute>
+    </mj-selector>
+</mj-html-attributes>"#
+    );
+
+    crate::should_parse!(
+        unexpected_attribute,
+        MjHtmlAttributes,
+        r#"<mj-html-attributes><mj-selector path="div" foo="bar" /></mj-html-attributes>"#,
+        1
+    );
+
+    crate::should_not_parse!(
+        missing_path,
+        MjHtmlAttributes,
+        r#"<mj-html-attributes><mj-selector /></mj-html-attributes>"#,
+        "MissingAttribute { name: \"path\""
+    );
+
+    crate::should_not_parse!(
+        missing_name,
+        MjHtmlAttributes,
+        r#"<mj-html-attributes><mj-selector path="div"><mj-html-attribute>42</mj-html-attribute></mj-selector></mj-html-attributes>"#,
+        "MissingAttribute { name: \"name\""
+    );
+
+    crate::should_not_parse!(
+        unexpected_element,
+        MjHtmlAttributes,
+        r#"<mj-html-attributes><mj-text /></mj-html-attributes>"#
+    );
+
+    #[test]
+    fn should_keep_values() {
+        let opts = crate::prelude::parser::ParserOptions::default();
+        let parser = crate::prelude::parser::MrmlParser::new(&opts);
+        let mut cursor = crate::prelude::parser::MrmlCursor::new(
+            r#"<mj-html-attributes><mj-selector path=".a"><mj-html-attribute name="data-id">42</mj-html-attribute></mj-selector></mj-html-attributes>"#,
+        );
+        let element: MjHtmlAttributes = parser.parse_root(&mut cursor).unwrap();
+        assert_eq!(element.children.len(), 1);
+        assert_eq!(element.children[0].path(), ".a");
+        assert_eq!(element.children[0].children[0].name(), "data-id");
+        assert_eq!(element.children[0].children[0].value(), "42");
+    }
+}
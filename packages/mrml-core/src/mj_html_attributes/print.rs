@@ -0,0 +1,75 @@
+use super::{MjHtmlAttribute, MjHtmlAttributeAttributes, MjSelectorAttributes, ATTRIBUTE_NAME};
+use crate::prelude::print::{Printable, PrintableAttributes, Printer};
+
+impl PrintableAttributes for MjSelectorAttributes {
+    fn print<P: Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        printer.push_attribute("path", self.path.as_str())
+    }
+}
+
+impl PrintableAttributes for MjHtmlAttributeAttributes {
+    fn print<P: Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        printer.push_attribute("name", self.name.as_str())
+    }
+}
+
+impl Printable for MjHtmlAttribute {
+    fn print<P: Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        #[cfg(feature = "span")]
+        if printer.print_source(self.location.as_ref(), || self.print_dense())? {
+            return Ok(());
+        }
+        printer.push_indent();
+        printer.open_tag(ATTRIBUTE_NAME)?;
+        self.attributes.print(printer)?;
+        printer.close_tag();
+        printer.push_str(self.children.as_str());
+        printer.end_tag(ATTRIBUTE_NAME)?;
+        printer.push_new_line();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_html_attributes::{MjHtmlAttribute, MjHtmlAttributes, MjSelector};
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let item = MjHtmlAttributes::default();
+        assert_eq!("<mj-html-attributes />", item.print_dense().unwrap());
+    }
+
+    #[test]
+    fn with_selector() {
+        let item = MjHtmlAttributes::new(
+            (),
+            vec![MjSelector::build(
+                ".custom div",
+                vec![MjHtmlAttribute::build("data-id", "42")],
+            )],
+        );
+        assert_eq!(
+            r#"<mj-html-attributes><mj-selector path=".custom div"><mj-html-attribute name="data-id">42</mj-html-attribute></mj-selector></mj-html-attributes>"#,
+            item.print_dense().unwrap()
+        );
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn pretty_round_trip() {
+        let origin = r#"<mjml>
+  <mj-head>
+    <mj-html-attributes>
+      <mj-selector path=".custom div">
+        <mj-html-attribute name="data-id">42</mj-html-attribute>
+      </mj-selector>
+    </mj-html-attributes>
+  </mj-head>
+</mjml>
+"#;
+        let root = crate::mjml::Mjml::parse(origin).unwrap();
+        similar_asserts::assert_eq!(origin, root.element.print_pretty().unwrap());
+    }
+}
@@ -0,0 +1,226 @@
+use super::{MjHtmlAttributes, MjSelector};
+use crate::helper::html::HtmlDocument;
+use crate::helper::selector::Selector;
+use crate::prelude::hash::Map;
+use crate::prelude::render::text::decode_entities;
+use crate::prelude::render::{escape_attribute, Error};
+
+impl MjSelector {
+    pub(crate) fn html_attributes_iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.children
+            .iter()
+            .map(|child| (child.name(), child.value()))
+    }
+}
+
+impl MjHtmlAttributes {
+    pub(crate) fn selectors_iter(&self) -> impl Iterator<Item = &MjSelector> {
+        self.children.iter()
+    }
+}
+
+/// A `mj-selector` element with its parsed path.
+pub(crate) struct PreparedSelector<'a> {
+    selector: Selector,
+    element: &'a MjSelector,
+}
+
+pub(crate) fn prepare_selectors<'a, I>(selectors: I) -> Result<Vec<PreparedSelector<'a>>, Error>
+where
+    I: IntoIterator<Item = &'a MjSelector>,
+{
+    selectors
+        .into_iter()
+        .map(|element| {
+            Selector::try_from(element.path())
+                .map(|selector| PreparedSelector { selector, element })
+                .map_err(|err| Error::InvalidSelector(err.0))
+        })
+        .collect()
+}
+
+/// Applies the attributes defined by the `mj-selector` elements to the
+/// rendered document. The selectors are applied in order, so when several
+/// selectors set the same attribute on an element, the last one wins.
+pub(crate) fn apply_html_attributes(html: String, selectors: &[PreparedSelector<'_>]) -> String {
+    if selectors.is_empty() {
+        return html;
+    }
+    let doc = HtmlDocument::parse(&html);
+    // for each element, the attributes to set by lowercase name, the html
+    // attributes being case insensitive
+    let mut changes: Map<usize, Map<String, (&str, &str)>> = Map::new();
+    for prepared in selectors {
+        for index in prepared.selector.select(&doc) {
+            let entry = changes.entry(index).or_default();
+            for (name, value) in prepared.element.html_attributes_iter() {
+                entry.insert(name.to_ascii_lowercase(), (name, value));
+            }
+        }
+    }
+    if changes.is_empty() {
+        return html;
+    }
+
+    // list of the replacements to do, sorted by position
+    let mut edits: Vec<(std::ops::Range<usize>, String)> = Vec::new();
+    for (index, attributes) in changes.iter() {
+        let element = doc.element(*index);
+        let mut appended = String::new();
+        for (lowercase_name, (name, value)) in attributes.iter() {
+            // the values of the template are escaped already
+            let value = escape_attribute(&decode_entities(value));
+            let rendered = format!("{name}=\"{value}\"");
+            if let Some(existing) = element
+                .attributes
+                .iter()
+                .find(|attr| attr.name == *lowercase_name)
+            {
+                edits.push((existing.span.clone(), rendered));
+            } else {
+                appended.push(' ');
+                appended.push_str(&rendered);
+            }
+        }
+        if !appended.is_empty() {
+            edits.push((element.open_end..element.open_end, appended));
+        }
+    }
+    edits.sort_by_key(|(range, _)| range.start);
+
+    let mut result = String::with_capacity(html.len());
+    let mut position = 0;
+    for (range, value) in edits {
+        result.push_str(&html[position..range.start]);
+        result.push_str(&value);
+        position = range.end;
+    }
+    result.push_str(&html[position..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_html_attributes, prepare_selectors};
+    use crate::mj_html_attributes::{MjHtmlAttribute, MjSelector};
+
+    #[test]
+    fn should_add_and_replace_attributes() {
+        let selectors = [
+            MjSelector::build(
+                ".custom td",
+                vec![
+                    MjHtmlAttribute::build("data-id", "42"),
+                    MjHtmlAttribute::build("align", "left"),
+                ],
+            ),
+            MjSelector::build(
+                "td:first-child",
+                vec![MjHtmlAttribute::build("data-id", "\"first\"")],
+            ),
+        ];
+        let prepared = prepare_selectors(selectors.iter()).unwrap();
+        let result = apply_html_attributes(
+            r#"<table class="custom"><tr><td align="center">a</td><td/></tr></table>"#.into(),
+            &prepared,
+        );
+        assert_eq!(
+            result,
+            r#"<table class="custom"><tr><td align="left" data-id="&quot;first&quot;">a</td><td data-id="42" align="left"/></tr></table>"#
+        );
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_apply_on_rendered_template() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-html-attributes>
+      <mj-selector path=".custom div">
+        <mj-html-attribute name="data-id">42</mj-html-attribute>
+      </mj-selector>
+    </mj-html-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text css-class="custom">Hello World!</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let opts = crate::prelude::render::RenderOptions::default();
+        let result = root.element.render(&opts).unwrap();
+        assert_eq!(result.matches("data-id=\"42\"").count(), 1);
+        assert!(result.contains(r#"color:#000000;" data-id="42">Hello World!</div>"#));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_apply_from_included_head() {
+        use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+        use crate::prelude::parser::ParserOptions;
+
+        let loader = MemoryIncludeLoader::from(vec![(
+            "partial.mjml",
+            r#"<mj-html-attributes><mj-selector path="body"><mj-html-attribute name="data-included">yes</mj-html-attribute></mj-selector></mj-html-attributes>"#,
+        )]);
+        let opts = ParserOptions {
+            include_loader: Box::new(loader),
+        };
+        let root = crate::parse_with_options(
+            r#"<mjml><mj-head><mj-include path="partial.mjml" /></mj-head><mj-body /></mjml>"#,
+            &opts,
+        )
+        .unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains(r#"data-included="yes""#));
+    }
+
+    #[test]
+    fn should_set_attributes_once_whatever_their_case() {
+        let selectors = [MjSelector::build(
+            "td",
+            vec![
+                MjHtmlAttribute::build("ALIGN", "right"),
+                MjHtmlAttribute::build("align", "left"),
+            ],
+        )];
+        let prepared = prepare_selectors(selectors.iter()).unwrap();
+        let result = apply_html_attributes(
+            r#"<table><tr><td align="center">a</td></tr></table>"#.into(),
+            &prepared,
+        );
+        assert_eq!(result, r#"<table><tr><td align="left">a</td></tr></table>"#);
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_not_escape_template_values_twice() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-html-attributes>
+      <mj-selector path=".custom td">
+        <mj-html-attribute name="ALIGN">right</mj-html-attribute>
+        <mj-html-attribute name="align">left</mj-html-attribute>
+        <mj-html-attribute name="data-x">a &amp; "b"</mj-html-attribute>
+      </mj-selector>
+    </mj-html-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section css-class="custom"><mj-column><mj-text>Hello</mj-text></mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains(r#"data-x="a &amp; &quot;b&quot;""#));
+        assert!(!result.contains("ALIGN"));
+    }
+
+    #[test]
+    fn should_fail_with_invalid_selector() {
+        let selectors = [MjSelector::build("div >", vec![])];
+        assert!(prepare_selectors(selectors.iter()).is_err());
+    }
+}
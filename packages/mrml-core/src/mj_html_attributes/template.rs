@@ -0,0 +1,15 @@
+use super::{MjHtmlAttributeAttributes, MjSelectorAttributes};
+use crate::prelude::render::Error;
+use crate::prelude::template::{resolve_attribute, Template};
+
+impl Template for MjSelectorAttributes {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        resolve_attribute("path", &mut self.path, context)
+    }
+}
+
+impl Template for MjHtmlAttributeAttributes {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        resolve_attribute("name", &mut self.name, context)
+    }
+}
@@ -0,0 +1,35 @@
+use super::{MjHtmlAttributeAttributes, MjSelectorAttributes, NAME};
+use crate::prelude::validate::{AttributeType, AttributeValues, Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[]),
+    required: &[],
+    children: Children::Only(&[super::SELECTOR_NAME]),
+};
+
+pub(crate) const SELECTOR_RULE: Rule = Rule {
+    tag: super::SELECTOR_NAME,
+    attributes: Attributes::Only(&[&[("path", AttributeType::String)]]),
+    required: &["path"],
+    children: Children::Only(&[super::ATTRIBUTE_NAME]),
+};
+
+pub(crate) const ATTRIBUTE_RULE: Rule = Rule {
+    tag: super::ATTRIBUTE_NAME,
+    attributes: Attributes::Only(&[&[("name", AttributeType::String)]]),
+    required: &["name"],
+    children: Children::Raw,
+};
+
+impl AttributeValues for MjSelectorAttributes {
+    fn attribute_value(&self, name: &str) -> Option<&str> {
+        (name == "path").then_some(self.path.as_str())
+    }
+}
+
+impl AttributeValues for MjHtmlAttributeAttributes {
+    fn attribute_value(&self, name: &str) -> Option<&str> {
+        (name == "name").then_some(self.name.as_str())
+    }
+}
@@ -0,0 +1,34 @@
+use super::MjImage;
+use crate::prelude::builder::{
+    attributes, border_attributes, padding_attributes, Align, Builder, OrAuto, Pixel, Size, Spacing,
+};
+
+impl MjImage {
+    pub fn builder(src: impl Into<String>) -> Builder<Self> {
+        Builder::new(Self::default()).set("src", src.into())
+    }
+}
+
+impl Builder<MjImage> {
+    padding_attributes!();
+    border_attributes!();
+    attributes! {
+        align: "align" => value(Align),
+        alt: "alt" => string,
+        border_radius: "border-radius" => value(Spacing),
+        container_background_color: "container-background-color" => string,
+        fluid_on_mobile: "fluid-on-mobile" => value(bool),
+        font_size: "font-size" => value(Pixel),
+        height: "height" => into(OrAuto<Pixel>),
+        href: "href" => string,
+        max_height: "max-height" => value(Size),
+        name: "name" => string,
+        rel: "rel" => string,
+        sizes: "sizes" => string,
+        srcset: "srcset" => string,
+        target: "target" => string,
+        title: "title" => string,
+        usemap: "usemap" => string,
+        width: "width" => value(Pixel),
+    }
+}
@@ -93,7 +93,7 @@ impl<'root> Renderer<'root, MjImage, ()> {
                     .map(|size| size.value().to_string())
                     .unwrap_or_else(|| "auto".into()),
             )
-            .maybe_add_attribute("src", self.attribute("src"))
+            .maybe_add_attribute("src", self.attribute_as_asset("src"))
             .maybe_add_attribute("srcset", self.attribute("srcset"))
             .maybe_add_attribute("title", self.attribute("title"))
             .maybe_add_attribute(
@@ -107,8 +107,12 @@ impl<'root> Renderer<'root, MjImage, ()> {
     }
 
     fn render_link(&self, buf: &mut RenderBuffer) -> std::fmt::Result {
+        let href = self.attribute("href").map(|href| {
+            self.context
+                .rewrite_link(super::NAME, &self.element.attributes, href)
+        });
         Tag::new("a")
-            .maybe_add_attribute("href", self.attribute("href"))
+            .maybe_add_attribute("href", href)
             .maybe_add_attribute("name", self.attribute("name"))
             .maybe_add_attribute("rel", self.attribute("rel"))
             .maybe_add_attribute("target", self.attribute("target"))
@@ -199,7 +203,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjImage {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Renderer::new(context, self, ()).boxed()
     }
 }
 
@@ -0,0 +1,8 @@
+use super::{MjImage, NAME};
+use crate::prelude::render::text::{render_image_text, TextBuffer, TextRender};
+
+impl TextRender for MjImage {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>) {
+        render_image_text(buffer, NAME, &self.attributes);
+    }
+}
@@ -0,0 +1,32 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, BORDER, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        BORDER,
+        &[
+            ("align", AttributeType::Enum(&["left", "center", "right"])),
+            ("alt", AttributeType::String),
+            ("border-radius", AttributeType::Spacing),
+            ("container-background-color", AttributeType::Color),
+            ("fluid-on-mobile", AttributeType::Boolean),
+            ("font-size", AttributeType::Pixel),
+            ("height", AttributeType::PixelOrAuto),
+            ("href", AttributeType::String),
+            ("max-height", AttributeType::Size),
+            ("name", AttributeType::String),
+            ("rel", AttributeType::String),
+            ("sizes", AttributeType::String),
+            ("src", AttributeType::String),
+            ("srcset", AttributeType::String),
+            ("target", AttributeType::String),
+            ("title", AttributeType::String),
+            ("usemap", AttributeType::String),
+            ("width", AttributeType::Pixel),
+        ],
+    ]),
+    required: &["src"],
+    children: Children::Raw,
+};
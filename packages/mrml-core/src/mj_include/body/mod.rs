@@ -6,6 +6,10 @@ mod parse;
 mod print;
 #[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "template")]
+mod template;
+#[cfg(feature = "render")]
+mod text;
 
 use std::marker::PhantomData;
 
@@ -1,5 +1,7 @@
 use htmlparser::StrSpan;
 
+#[cfg(feature = "span")]
+use super::MjIncludeBodyInner;
 use super::{MjIncludeBody, MjIncludeBodyAttributes, MjIncludeBodyChild, MjIncludeBodyKind};
 use crate::comment::Comment;
 use crate::mj_accordion::NAME as MJ_ACCORDION;
@@ -19,11 +21,13 @@ use crate::mj_spacer::NAME as MJ_SPACER;
 use crate::mj_table::NAME as MJ_TABLE;
 use crate::mj_text::NAME as MJ_TEXT;
 use crate::mj_wrapper::{MjWrapper, NAME as MJ_WRAPPER};
+#[cfg(feature = "span")]
+use crate::prelude::parser::element_start;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
-    WarningKind,
+    Error, IncludeKind, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren,
+    ParseElement, WarningKind,
 };
 use crate::text::Text;
 
@@ -205,19 +209,22 @@ impl ParseChildren<Vec<MjIncludeBodyChild>> for MrmlParser<'_> {
         while let Some(token) = cursor.next_token() {
             match token? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjIncludeBodyChild::Comment(Comment::from(
-                        inner.text.as_str(),
+                    result.push(MjIncludeBodyChild::Comment(Comment::from_token(
+                        cursor, &inner,
                     )));
                 }
-                MrmlToken::ElementStart(inner) => {
-                    result.push(self.parse(cursor, inner.local)?);
-                }
+                MrmlToken::ElementStart(inner) => match self.components.get(inner.local.as_str()) {
+                    Some(component) => {
+                        result.extend(self.parse_component(cursor, inner.local, component)?);
+                    }
+                    None => result.push(self.parse(cursor, inner.local)?),
+                },
                 MrmlToken::ElementClose(inner) => {
                     cursor.rewind(MrmlToken::ElementClose(inner));
                     return Ok(result);
                 }
                 MrmlToken::Text(inner) => {
-                    result.push(MjIncludeBodyChild::Text(Text::from(inner.text.as_str())));
+                    result.push(MjIncludeBodyChild::Text(Text::from_token(cursor, &inner)));
                 }
                 other => {
                     return Err(Error::UnexpectedToken {
@@ -245,19 +252,25 @@ impl AsyncParseChildren<Vec<MjIncludeBodyChild>> for AsyncMrmlParser {
         while let Some(token) = cursor.next_token() {
             match token? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjIncludeBodyChild::Comment(Comment::from(
-                        inner.text.as_str(),
+                    result.push(MjIncludeBodyChild::Comment(Comment::from_token(
+                        cursor, &inner,
                     )));
                 }
-                MrmlToken::ElementStart(inner) => {
-                    result.push(self.async_parse(cursor, inner.local).await?);
-                }
+                MrmlToken::ElementStart(inner) => match self.components.get(inner.local.as_str()) {
+                    Some(component) => {
+                        let children = self
+                            .async_parse_component(cursor, inner.local, component)
+                            .await?;
+                        result.extend(children);
+                    }
+                    None => result.push(self.async_parse(cursor, inner.local).await?),
+                },
                 MrmlToken::ElementClose(inner) => {
                     cursor.rewind(MrmlToken::ElementClose(inner));
                     return Ok(result);
                 }
                 MrmlToken::Text(inner) => {
-                    result.push(MjIncludeBodyChild::Text(Text::from(inner.text.as_str())));
+                    result.push(MjIncludeBodyChild::Text(Text::from_token(cursor, &inner)));
                 }
                 other => {
                     return Err(Error::UnexpectedToken {
@@ -280,6 +293,8 @@ impl ParseElement<MjIncludeBody> for MrmlParser<'_> {
     ) -> Result<MjIncludeBody, Error> {
         let (attributes, children): (MjIncludeBodyAttributes, Vec<MjIncludeBodyChild>) =
             self.parse_attributes_and_children(cursor, &tag)?;
+        #[cfg(feature = "span")]
+        let location = cursor.location(element_start(&tag));
 
         // if a mj-include has some content, we don't load it
         let children: Vec<MjIncludeBodyChild> = if children.is_empty() {
@@ -292,6 +307,11 @@ impl ParseElement<MjIncludeBody> for MrmlParser<'_> {
                     position: tag.into(),
                     source,
                 })?;
+            cursor.add_include(
+                IncludeKind::Body(attributes.kind.clone()),
+                &attributes.path,
+                tag.into(),
+            );
             // Wrap the loaded content in a synthetic root element so the
             // XML tokenizer can handle content with multiple root elements.
             let wrapped = format!("{FRAGMENT_OPEN}{child}{FRAGMENT_CLOSE}");
@@ -306,7 +326,7 @@ impl ParseElement<MjIncludeBody> for MrmlParser<'_> {
                     let children: Vec<MjBodyChild> =
                         self.parse_children(&mut sub).map_err(&with_position)?;
                     sub.assert_element_close().map_err(&with_position)?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.with_child(sub);
                     vec![MjIncludeBodyChild::MjWrapper(MjWrapper::new(
                         Default::default(),
                         children,
@@ -319,7 +339,7 @@ impl ParseElement<MjIncludeBody> for MrmlParser<'_> {
                     sub.assert_element_end().map_err(&with_position)?;
                     let children = self.parse_children(&mut sub).map_err(&with_position)?;
                     sub.assert_element_close().map_err(&with_position)?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.with_child(sub);
                     children
                 }
             }
@@ -327,7 +347,13 @@ impl ParseElement<MjIncludeBody> for MrmlParser<'_> {
             children
         };
 
-        Ok(MjIncludeBody::new(attributes, children))
+        let element = MjIncludeBody::new(attributes, children);
+        #[cfg(feature = "span")]
+        let element = MjIncludeBody(MjIncludeBodyInner {
+            location: Some(location),
+            ..element.0
+        });
+        Ok(element)
     }
 }
 
@@ -344,6 +370,8 @@ impl crate::prelude::parser::AsyncParseElement<MjIncludeBody> for AsyncMrmlParse
 
         let (attributes, children): (MjIncludeBodyAttributes, Vec<MjIncludeBodyChild>) =
             self.parse_attributes_and_children(cursor, &tag).await?;
+        #[cfg(feature = "span")]
+        let location = cursor.location(element_start(&tag));
 
         // if a mj-include has some content, we don't load it
         let children: Vec<MjIncludeBodyChild> = if children.is_empty() {
@@ -357,6 +385,11 @@ impl crate::prelude::parser::AsyncParseElement<MjIncludeBody> for AsyncMrmlParse
                     position: tag.into(),
                     source,
                 })?;
+            cursor.add_include(
+                IncludeKind::Body(attributes.kind.clone()),
+                &attributes.path,
+                tag.into(),
+            );
             // Wrap the loaded content in a synthetic root element so the
             // XML tokenizer can handle content with multiple root elements.
             let wrapped = format!("{FRAGMENT_OPEN}{child}{FRAGMENT_CLOSE}");
@@ -373,7 +406,7 @@ impl crate::prelude::parser::AsyncParseElement<MjIncludeBody> for AsyncMrmlParse
                         .await
                         .map_err(&with_position)?;
                     sub.assert_element_close().map_err(&with_position)?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.with_child(sub);
                     vec![MjIncludeBodyChild::MjWrapper(MjWrapper::new(
                         Default::default(),
                         children,
@@ -389,7 +422,7 @@ impl crate::prelude::parser::AsyncParseElement<MjIncludeBody> for AsyncMrmlParse
                         .await
                         .map_err(&with_position)?;
                     sub.assert_element_close().map_err(&with_position)?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.with_child(sub);
                     children
                 }
             }
@@ -397,7 +430,13 @@ impl crate::prelude::parser::AsyncParseElement<MjIncludeBody> for AsyncMrmlParse
             children
         };
 
-        Ok(MjIncludeBody::new(attributes, children))
+        let element = MjIncludeBody::new(attributes, children);
+        #[cfg(feature = "span")]
+        let element = MjIncludeBody(MjIncludeBodyInner {
+            location: Some(location),
+            ..element.0
+        });
+        Ok(element)
     }
 }
 
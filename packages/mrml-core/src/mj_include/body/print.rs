@@ -25,6 +25,11 @@ impl PrintableElement for super::MjIncludeBody {
     fn children(&self) -> &Self::Children {
         &()
     }
+
+    #[cfg(feature = "span")]
+    fn location(&self) -> Option<&crate::prelude::Location> {
+        self.0.location.as_ref()
+    }
 }
 
 #[cfg(test)]
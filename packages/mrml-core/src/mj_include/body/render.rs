@@ -71,7 +71,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjIncludeBody {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Renderer::new(context, self, ()).boxed()
     }
 }
 
@@ -94,7 +94,7 @@ mod tests {
         let mj_head = Some(MjHead::default());
         let expected: String = {
             let header = Header::new(mj_head.as_ref(), "und", "auto");
-            let context = RenderContext::new(&opts, header);
+            let context = RenderContext::new(&opts, &header);
             let mut cursor = RenderCursor::default();
             let elt = MjText::default();
             let renderer = elt.renderer(&context);
@@ -103,7 +103,7 @@ mod tests {
         };
         let result: String = {
             let header = Header::new(mj_head.as_ref(), "und", "auto");
-            let context = RenderContext::new(&opts, header);
+            let context = RenderContext::new(&opts, &header);
             let mut cursor = RenderCursor::default();
             let elt = MjIncludeBody::new(
                 MjIncludeBodyAttributes::new("memory:foo.mjml"),
@@ -123,7 +123,7 @@ mod tests {
 
         let expected: String = {
             let header = Header::new(mj_head.as_ref(), "und", "auto");
-            let context = RenderContext::new(&opts, header);
+            let context = RenderContext::new(&opts, &header);
             let mut cursor = RenderCursor::default();
 
             let mut node = Node::from("span");
@@ -138,7 +138,7 @@ mod tests {
         };
         let result: String = {
             let header = Header::new(mj_head.as_ref(), "und", "auto");
-            let context = RenderContext::new(&opts, header);
+            let context = RenderContext::new(&opts, &header);
             let mut cursor = RenderCursor::default();
 
             let mut node = Node::from("span");
@@ -0,0 +1,43 @@
+use super::{MjIncludeBody, MjIncludeBodyAttributes, MjIncludeBodyChild};
+use crate::prelude::render::Error;
+use crate::prelude::template::Template;
+
+impl Template for MjIncludeBodyAttributes {
+    /// The included content has already been loaded when parsing, so the path
+    /// is left untouched.
+    fn resolve(&mut self, _context: &serde_json::Value) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl Template for MjIncludeBodyChild {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        match self {
+            Self::Comment(elt) => elt.resolve(context),
+            Self::MjAccordion(elt) => elt.resolve(context),
+            Self::MjButton(elt) => elt.resolve(context),
+            Self::MjCarousel(elt) => elt.resolve(context),
+            Self::MjColumn(elt) => elt.resolve(context),
+            Self::MjDivider(elt) => elt.resolve(context),
+            Self::MjGroup(elt) => elt.resolve(context),
+            Self::MjHero(elt) => elt.resolve(context),
+            Self::MjImage(elt) => elt.resolve(context),
+            Self::MjNavbar(elt) => elt.resolve(context),
+            Self::MjRaw(elt) => elt.resolve(context),
+            Self::MjSection(elt) => elt.resolve(context),
+            Self::MjSocial(elt) => elt.resolve(context),
+            Self::MjSpacer(elt) => elt.resolve(context),
+            Self::MjTable(elt) => elt.resolve(context),
+            Self::MjText(elt) => elt.resolve(context),
+            Self::MjWrapper(elt) => elt.resolve(context),
+            Self::Node(elt) => elt.resolve(context),
+            Self::Text(elt) => elt.resolve(context),
+        }
+    }
+}
+
+impl Template for MjIncludeBody {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        self.0.resolve(context)
+    }
+}
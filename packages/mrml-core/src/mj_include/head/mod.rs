@@ -6,6 +6,8 @@ mod parse;
 mod print;
 #[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "template")]
+mod template;
 
 use std::marker::PhantomData;
 
@@ -20,6 +22,7 @@ pub enum MjIncludeHeadChild {
     MjAttributes(crate::mj_attributes::MjAttributes),
     MjBreakpoint(crate::mj_breakpoint::MjBreakpoint),
     MjFont(crate::mj_font::MjFont),
+    MjHtmlAttributes(crate::mj_html_attributes::MjHtmlAttributes),
     MjPreview(crate::mj_preview::MjPreview),
     MjRaw(crate::mj_raw::MjRaw),
     MjStyle(crate::mj_style::MjStyle),
@@ -1,19 +1,24 @@
 use htmlparser::StrSpan;
 
+#[cfg(feature = "span")]
+use super::MjIncludeHeadInner;
 use super::{MjIncludeHead, MjIncludeHeadAttributes, MjIncludeHeadChild, MjIncludeHeadKind};
 use crate::comment::Comment;
 use crate::mj_attributes::NAME as MJ_ATTRIBUTES;
 use crate::mj_breakpoint::NAME as MJ_BREAKPOINT;
 use crate::mj_font::NAME as MJ_FONT;
+use crate::mj_html_attributes::NAME as MJ_HTML_ATTRIBUTES;
 use crate::mj_preview::NAME as MJ_PREVIEW;
 use crate::mj_raw::NAME as MJ_RAW;
 use crate::mj_style::NAME as MJ_STYLE;
 use crate::mj_title::NAME as MJ_TITLE;
+#[cfg(feature = "span")]
+use crate::prelude::parser::element_start;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
-    WarningKind,
+    Error, IncludeKind, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren,
+    ParseElement, WarningKind,
 };
 use crate::text::Text;
 
@@ -34,6 +39,9 @@ impl ParseElement<MjIncludeHeadChild> for MrmlParser<'_> {
                 .parse(cursor, tag)
                 .map(MjIncludeHeadChild::MjBreakpoint),
             MJ_FONT => self.parse(cursor, tag).map(MjIncludeHeadChild::MjFont),
+            MJ_HTML_ATTRIBUTES => self
+                .parse(cursor, tag)
+                .map(MjIncludeHeadChild::MjHtmlAttributes),
             MJ_PREVIEW => self.parse(cursor, tag).map(MjIncludeHeadChild::MjPreview),
             MJ_RAW => self.parse(cursor, tag).map(MjIncludeHeadChild::MjRaw),
             MJ_STYLE => self.parse(cursor, tag).map(MjIncludeHeadChild::MjStyle),
@@ -68,6 +76,10 @@ impl AsyncParseElement<MjIncludeHeadChild> for AsyncMrmlParser {
                 .async_parse(cursor, tag)
                 .await
                 .map(MjIncludeHeadChild::MjFont),
+            MJ_HTML_ATTRIBUTES => self
+                .async_parse(cursor, tag)
+                .await
+                .map(MjIncludeHeadChild::MjHtmlAttributes),
             MJ_PREVIEW => self
                 .async_parse(cursor, tag)
                 .await
@@ -152,12 +164,12 @@ impl ParseChildren<Vec<MjIncludeHeadChild>> for MrmlParser<'_> {
         while let Some(token) = cursor.next_token() {
             match token? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjIncludeHeadChild::Comment(Comment::from(
-                        inner.text.as_str(),
+                    result.push(MjIncludeHeadChild::Comment(Comment::from_token(
+                        cursor, &inner,
                     )));
                 }
                 MrmlToken::Text(inner) => {
-                    result.push(MjIncludeHeadChild::Text(Text::from(inner.text.as_str())));
+                    result.push(MjIncludeHeadChild::Text(Text::from_token(cursor, &inner)));
                 }
                 MrmlToken::ElementStart(inner) => {
                     result.push(self.parse(cursor, inner.local)?);
@@ -190,12 +202,12 @@ impl AsyncParseChildren<Vec<MjIncludeHeadChild>> for AsyncMrmlParser {
         while let Some(token) = cursor.next_token() {
             match token? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjIncludeHeadChild::Comment(Comment::from(
-                        inner.text.as_str(),
+                    result.push(MjIncludeHeadChild::Comment(Comment::from_token(
+                        cursor, &inner,
                     )));
                 }
                 MrmlToken::Text(inner) => {
-                    result.push(MjIncludeHeadChild::Text(Text::from(inner.text.as_str())));
+                    result.push(MjIncludeHeadChild::Text(Text::from_token(cursor, &inner)));
                 }
                 MrmlToken::ElementStart(inner) => {
                     result.push(self.async_parse(cursor, inner.local).await?);
@@ -224,6 +236,8 @@ impl ParseElement<MjIncludeHead> for MrmlParser<'_> {
     ) -> Result<MjIncludeHead, Error> {
         let (attributes, children): (MjIncludeHeadAttributes, Vec<MjIncludeHeadChild>) =
             self.parse_attributes_and_children(cursor, &tag)?;
+        #[cfg(feature = "span")]
+        let location = cursor.location(element_start(&tag));
 
         // if a mj-include has some content, we don't load it
         let children: Vec<MjIncludeHeadChild> = if children.is_empty() {
@@ -236,6 +250,11 @@ impl ParseElement<MjIncludeHead> for MrmlParser<'_> {
                     position: tag.into(),
                     source,
                 })?;
+            cursor.add_include(
+                IncludeKind::Head(attributes.kind.clone()),
+                &attributes.path,
+                tag.into(),
+            );
 
             // Wrap the loaded content in a synthetic root element so the
             // XML tokenizer can handle content with multiple root elements.
@@ -262,7 +281,7 @@ impl ParseElement<MjIncludeHead> for MrmlParser<'_> {
                     sub.assert_element_end().map_err(&with_position)?;
                     let children = self.parse_children(&mut sub).map_err(&with_position)?;
                     sub.assert_element_close().map_err(&with_position)?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.with_child(sub);
                     children
                 }
                 MjIncludeHeadKind::Html => {
@@ -277,7 +296,13 @@ impl ParseElement<MjIncludeHead> for MrmlParser<'_> {
             children
         };
 
-        Ok(MjIncludeHead::new(attributes, children))
+        let element = MjIncludeHead::new(attributes, children);
+        #[cfg(feature = "span")]
+        let element = MjIncludeHead(MjIncludeHeadInner {
+            location: Some(location),
+            ..element.0
+        });
+        Ok(element)
     }
 }
 
@@ -292,6 +317,8 @@ impl AsyncParseElement<MjIncludeHead> for AsyncMrmlParser {
     ) -> Result<MjIncludeHead, Error> {
         let (attributes, children): (MjIncludeHeadAttributes, Vec<MjIncludeHeadChild>) =
             self.parse_attributes_and_children(cursor, &tag).await?;
+        #[cfg(feature = "span")]
+        let location = cursor.location(element_start(&tag));
 
         // if a mj-include has some content, we don't load it
         let children: Vec<MjIncludeHeadChild> = if children.is_empty() {
@@ -305,6 +332,11 @@ impl AsyncParseElement<MjIncludeHead> for AsyncMrmlParser {
                     position: tag.into(),
                     source,
                 })?;
+            cursor.add_include(
+                IncludeKind::Head(attributes.kind.clone()),
+                &attributes.path,
+                tag.into(),
+            );
 
             // Wrap the loaded content in a synthetic root element so the
             // XML tokenizer can handle content with multiple root elements.
@@ -334,7 +366,7 @@ impl AsyncParseElement<MjIncludeHead> for AsyncMrmlParser {
                         .await
                         .map_err(&with_position)?;
                     sub.assert_element_close().map_err(&with_position)?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.with_child(sub);
                     children
                 }
                 MjIncludeHeadKind::Html => {
@@ -349,7 +381,13 @@ impl AsyncParseElement<MjIncludeHead> for AsyncMrmlParser {
             children
         };
 
-        Ok(MjIncludeHead::new(attributes, children))
+        let element = MjIncludeHead::new(attributes, children);
+        #[cfg(feature = "span")]
+        let element = MjIncludeHead(MjIncludeHeadInner {
+            location: Some(location),
+            ..element.0
+        });
+        Ok(element)
     }
 }
 
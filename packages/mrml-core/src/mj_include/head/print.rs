@@ -16,6 +16,11 @@ impl PrintableElement for super::MjIncludeHead {
     fn children(&self) -> &Self::Children {
         &()
     }
+
+    #[cfg(feature = "span")]
+    fn location(&self) -> Option<&crate::prelude::Location> {
+        self.0.location.as_ref()
+    }
 }
 
 impl PrintableAttributes for super::MjIncludeHeadAttributes {
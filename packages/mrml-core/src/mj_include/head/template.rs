@@ -0,0 +1,34 @@
+use super::{MjIncludeHead, MjIncludeHeadAttributes, MjIncludeHeadChild};
+use crate::prelude::render::Error;
+use crate::prelude::template::Template;
+
+impl Template for MjIncludeHeadAttributes {
+    /// The included content has already been loaded when parsing, so the path
+    /// is left untouched.
+    fn resolve(&mut self, _context: &serde_json::Value) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl Template for MjIncludeHeadChild {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        match self {
+            Self::Comment(elt) => elt.resolve(context),
+            Self::MjAttributes(elt) => elt.resolve(context),
+            Self::MjBreakpoint(elt) => elt.resolve(context),
+            Self::MjFont(elt) => elt.resolve(context),
+            Self::MjHtmlAttributes(elt) => elt.resolve(context),
+            Self::MjPreview(elt) => elt.resolve(context),
+            Self::MjRaw(elt) => elt.resolve(context),
+            Self::MjStyle(elt) => elt.resolve(context),
+            Self::MjTitle(elt) => elt.resolve(context),
+            Self::Text(elt) => elt.resolve(context),
+        }
+    }
+}
+
+impl Template for MjIncludeHead {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        self.0.resolve(context)
+    }
+}
@@ -1,5 +1,7 @@
 pub mod body;
 pub mod head;
+#[cfg(feature = "validate")]
+pub(crate) mod validate;
 
 pub const NAME: &str = "mj-include";
 
@@ -0,0 +1,39 @@
+use super::body::MjIncludeBodyAttributes;
+use super::head::{MjIncludeHeadAttributes, MjIncludeHeadKind};
+use super::NAME;
+use crate::prelude::validate::{AttributeType, AttributeValues, Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[&[
+        ("css-inline", AttributeType::Enum(&["inline"])),
+        ("path", AttributeType::String),
+        ("type", AttributeType::Enum(&["mjml", "html", "css"])),
+    ]]),
+    required: &["path"],
+    children: Children::Raw,
+};
+
+impl AttributeValues for MjIncludeBodyAttributes {
+    fn attribute_value(&self, name: &str) -> Option<&str> {
+        match name {
+            "path" => Some(self.path.as_str()),
+            "type" => Some(self.kind.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl AttributeValues for MjIncludeHeadAttributes {
+    fn attribute_value(&self, name: &str) -> Option<&str> {
+        match name {
+            "path" => Some(self.path.as_str()),
+            "type" => Some(self.kind.as_ref()),
+            "css-inline" => match self.kind {
+                MjIncludeHeadKind::Css { inline: true } => Some("inline"),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use super::{MjNavbar, MjNavbarChild, MjNavbarTag};
+use crate::comment::Comment;
+use crate::mj_navbar_link::MjNavbarLink;
+use crate::prelude::builder::{
+    attributes, children, padding_attributes, Align, Builder, Size, Spacing,
+};
+
+impl MjNavbar {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjNavbar> {
+    padding_attributes!();
+    attributes! {
+        align: "align" => value(Align),
+        base_url: "base-url" => string,
+        hamburger: "hamburger" => string,
+        ico_align: "ico-align" => value(Align),
+        ico_close: "ico-close" => string,
+        ico_color: "ico-color" => string,
+        ico_font_family: "ico-font-family" => string,
+        ico_font_size: "ico-font-size" => value(Size),
+        ico_line_height: "ico-line-height" => value(Size),
+        ico_open: "ico-open" => string,
+        ico_padding: "ico-padding" => value(Spacing),
+        ico_padding_bottom: "ico-padding-bottom" => value(Size),
+        ico_padding_left: "ico-padding-left" => value(Size),
+        ico_padding_right: "ico-padding-right" => value(Size),
+        ico_padding_top: "ico-padding-top" => value(Size),
+        ico_text_decoration: "ico-text-decoration" => string,
+        ico_text_transform: "ico-text-transform" => string,
+    }
+}
+
+children!(MjNavbarTag => MjNavbarChild {
+    Comment(Comment),
+    MjNavbarLink(MjNavbarLink),
+});
@@ -14,7 +14,7 @@ impl ParseChildren<Vec<MjNavbarChild>> for MrmlParser<'_> {
         loop {
             match cursor.assert_next()? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjNavbarChild::Comment(Comment::from(inner.text.as_str())));
+                    result.push(MjNavbarChild::Comment(Comment::from_token(cursor, &inner)));
                 }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_NAVBAR_LINK {
@@ -56,7 +56,7 @@ impl AsyncParseChildren<Vec<MjNavbarChild>> for AsyncMrmlParser {
         loop {
             match cursor.assert_next()? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjNavbarChild::Comment(Comment::from(inner.text.as_str())));
+                    result.push(MjNavbarChild::Comment(Comment::from_token(cursor, &inner)));
                 }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_NAVBAR_LINK {
@@ -228,8 +228,8 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjNavbar {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        let id = context.generator.next_id();
-        Box::new(Renderer::new(context, self, MjNavbarExtra { id }))
+        let id = context.next_id(super::NAME, self);
+        Renderer::new(context, self, MjNavbarExtra { id }).boxed()
     }
 }
 
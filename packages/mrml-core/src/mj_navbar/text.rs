@@ -0,0 +1,29 @@
+use super::{MjNavbar, MjNavbarChild};
+use crate::prelude::render::text::{get_attribute, TextBuffer, TextRender};
+
+impl TextRender for MjNavbar {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>) {
+        let base_url = get_attribute(&self.attributes, "base-url").unwrap_or_default();
+        buffer.end_block();
+        let links = self.children.iter().filter_map(|child| match child {
+            MjNavbarChild::MjNavbarLink(link) => Some(link),
+            MjNavbarChild::Comment(_) => None,
+        });
+        for (index, link) in links.enumerate() {
+            if index > 0 {
+                buffer.push_text(" | ");
+            }
+            let start = buffer.position();
+            link.children.render_text(buffer);
+            let href =
+                get_attribute(&link.attributes, "href").map(|href| format!("{base_url}{href}"));
+            buffer.push_link(
+                start,
+                crate::mj_navbar_link::NAME,
+                &link.attributes,
+                href.as_deref(),
+            );
+        }
+        buffer.end_block();
+    }
+}
@@ -0,0 +1,33 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        &[
+            ("align", AttributeType::Enum(&["left", "center", "right"])),
+            ("base-url", AttributeType::String),
+            ("hamburger", AttributeType::String),
+            (
+                "ico-align",
+                AttributeType::Enum(&["left", "center", "right"]),
+            ),
+            ("ico-close", AttributeType::String),
+            ("ico-color", AttributeType::Color),
+            ("ico-font-family", AttributeType::String),
+            ("ico-font-size", AttributeType::Size),
+            ("ico-line-height", AttributeType::LineHeight),
+            ("ico-open", AttributeType::String),
+            ("ico-padding", AttributeType::Spacing),
+            ("ico-padding-bottom", AttributeType::Size),
+            ("ico-padding-left", AttributeType::Size),
+            ("ico-padding-right", AttributeType::Size),
+            ("ico-padding-top", AttributeType::Size),
+            ("ico-text-decoration", AttributeType::String),
+            ("ico-text-transform", AttributeType::String),
+        ],
+    ]),
+    required: &[],
+    children: Children::Only(&[crate::mj_navbar_link::NAME, crate::mj_raw::NAME]),
+};
@@ -0,0 +1,31 @@
+use super::{MjNavbarLink, MjNavbarLinkTag};
+use crate::comment::Comment;
+use crate::conditional_comment::ConditionalComment;
+use crate::mj_raw::MjRawChild;
+use crate::node::Node;
+use crate::prelude::builder::{attributes, children, font_attributes, padding_attributes, Builder};
+use crate::text::Text;
+
+impl MjNavbarLink {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjNavbarLink> {
+    padding_attributes!();
+    font_attributes!();
+    attributes! {
+        href: "href" => string,
+        name: "name" => string,
+        rel: "rel" => string,
+        target: "target" => string,
+    }
+}
+
+children!(MjNavbarLinkTag => MjRawChild {
+    Comment(Comment),
+    ConditionalComment(ConditionalComment),
+    Node(Node<MjRawChild>),
+    Text(Text),
+});
@@ -51,10 +51,14 @@ impl<'root> Renderer<'root, MjNavbarLink, MjNavbarLinkExtra<'root>> {
     }
 
     fn get_link(&self) -> Option<String> {
-        self.attribute("href").as_ref().and_then(|href| {
-            self.attribute("navbar-base-url")
-                .map(move |base| format!("{base}{href}"))
-                .or_else(|| Some(href.to_string()))
+        self.attribute("href").map(|href| {
+            let link = self
+                .attribute("navbar-base-url")
+                .map(|base| format!("{base}{href}"))
+                .unwrap_or_else(|| href.to_string());
+            self.context
+                .rewrite_link(super::NAME, &self.element.attributes, link)
+                .into_owned()
         })
     }
 
@@ -147,6 +151,6 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjNavbarLink {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, MjNavbarLinkExtra::default()))
+        Renderer::new(context, self, MjNavbarLinkExtra::default()).boxed()
     }
 }
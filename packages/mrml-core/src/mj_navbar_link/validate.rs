@@ -0,0 +1,18 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, FONT, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        FONT,
+        &[
+            ("href", AttributeType::String),
+            ("name", AttributeType::String),
+            ("rel", AttributeType::String),
+            ("target", AttributeType::String),
+        ],
+    ]),
+    required: &[],
+    children: Children::Raw,
+};
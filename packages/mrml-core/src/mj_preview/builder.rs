@@ -0,0 +1,8 @@
+use super::MjPreview;
+use crate::prelude::builder::Builder;
+
+impl MjPreview {
+    pub fn builder(content: impl Into<String>) -> Builder<Self> {
+        Builder::new(Self::from(content.into()))
+    }
+}
@@ -1,7 +1,5 @@
 #[cfg(test)]
 mod tests {
-    use std::marker::PhantomData;
-
     use crate::comment::Comment;
     use crate::mj_preview::{MjPreview, MjPreviewChild};
     use crate::prelude::OneOrMany;
@@ -15,14 +13,13 @@ mod tests {
             r#"{"type":"mj-preview","children":"Hello World"}"#
         );
 
-        let elt = MjPreview {
-            tag: PhantomData,
-            attributes: (),
-            children: OneOrMany::Many(vec![
+        let elt = MjPreview::new(
+            (),
+            OneOrMany::Many(vec![
                 MjPreviewChild::Text(Text::from("Hello World")),
                 MjPreviewChild::Comment(Comment::from("this is a comment")),
             ]),
-        };
+        );
         assert_eq!(
             serde_json::to_string(&elt).unwrap(),
             r#"{"type":"mj-preview","children":["Hello World",{"type":"comment","children":"this is a comment"}]}"#
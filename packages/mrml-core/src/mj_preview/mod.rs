@@ -4,12 +4,18 @@ use crate::comment::Comment;
 use crate::prelude::{Component, OneOrMany, StaticTag};
 use crate::text::Text;
 
+#[cfg(feature = "builder")]
+mod builder;
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "parse")]
 mod parse;
 #[cfg(feature = "print")]
 mod print;
+#[cfg(feature = "template")]
+mod template;
+#[cfg(feature = "validate")]
+pub(crate) mod validate;
 
 pub const NAME: &str = "mj-preview";
 
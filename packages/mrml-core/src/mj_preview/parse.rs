@@ -16,10 +16,10 @@ impl ParseChildren<OneOrMany<MjPreviewChild>> for MrmlParser<'_> {
             let token = cursor.assert_next()?;
             match token {
                 MrmlToken::Comment(inner) => {
-                    children.push(MjPreviewChild::Comment(Comment::from(inner.text.as_str())));
+                    children.push(MjPreviewChild::Comment(Comment::from_token(cursor, &inner)));
                 }
                 MrmlToken::Text(inner) => {
-                    children.push(MjPreviewChild::Text(Text::from(inner.text.as_str())));
+                    children.push(MjPreviewChild::Text(Text::from_token(cursor, &inner)));
                 }
                 MrmlToken::ElementClose(inner) => {
                     cursor.rewind(MrmlToken::ElementClose(inner));
@@ -49,10 +49,10 @@ impl AsyncParseChildren<OneOrMany<MjPreviewChild>> for AsyncMrmlParser {
             let token = cursor.assert_next()?;
             match token {
                 MrmlToken::Comment(inner) => {
-                    children.push(MjPreviewChild::Comment(Comment::from(inner.text.as_str())));
+                    children.push(MjPreviewChild::Comment(Comment::from_token(cursor, &inner)));
                 }
                 MrmlToken::Text(inner) => {
-                    children.push(MjPreviewChild::Text(Text::from(inner.text.as_str())));
+                    children.push(MjPreviewChild::Text(Text::from_token(cursor, &inner)));
                 }
                 MrmlToken::ElementClose(inner) => {
                     cursor.rewind(MrmlToken::ElementClose(inner));
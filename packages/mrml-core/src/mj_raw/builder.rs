@@ -0,0 +1,19 @@
+use super::{MjRaw, MjRawChild, MjRawTag};
+use crate::comment::Comment;
+use crate::conditional_comment::ConditionalComment;
+use crate::node::Node;
+use crate::prelude::builder::{children, Builder};
+use crate::text::Text;
+
+impl MjRaw {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+children!(MjRawTag => MjRawChild {
+    Comment(Comment),
+    ConditionalComment(ConditionalComment),
+    Node(Node<MjRawChild>),
+    Text(Text),
+});
@@ -17,6 +17,11 @@ fn parse_raw_node<'a>(
     tag: StrSpan<'a>,
     qualified_name: String,
 ) -> Result<Node<MjRawChild>, Error> {
+    // the local name can be preceded by a prefix, like in `<o:p>`
+    #[cfg(feature = "span")]
+    let start = tag
+        .start()
+        .saturating_sub(qualified_name.len() - tag.len() + 1);
     let attributes = parse_attributes_map(cursor)?;
     let ending = cursor.assert_element_end()?;
     if ending.empty || is_void_element(tag.as_str()) {
@@ -24,6 +29,8 @@ fn parse_raw_node<'a>(
             tag: qualified_name,
             attributes,
             children: Vec::new(),
+            #[cfg(feature = "span")]
+            location: Some(cursor.location(start)),
         });
     }
 
@@ -34,6 +41,8 @@ fn parse_raw_node<'a>(
         tag: qualified_name,
         attributes,
         children,
+        #[cfg(feature = "span")]
+        location: Some(cursor.location(start)),
     })
 }
 
@@ -43,7 +52,7 @@ fn parse_raw_children(cursor: &mut MrmlCursor<'_>) -> Result<Vec<MjRawChild>, Er
         let token = cursor.assert_next()?;
         match token {
             MrmlToken::Comment(inner) => {
-                children.push(MjRawChild::Comment(Comment::from(inner.text.as_str())));
+                children.push(MjRawChild::Comment(Comment::from_token(cursor, &inner)));
             }
             MrmlToken::ElementStart(elt) => {
                 let qualified_name = elt.qualified_name();
@@ -54,21 +63,21 @@ fn parse_raw_children(cursor: &mut MrmlCursor<'_>) -> Result<Vec<MjRawChild>, Er
                 )?));
             }
             MrmlToken::Text(inner) => {
-                children.push(MjRawChild::Text(Text::from(inner.text.as_str())));
+                children.push(MjRawChild::Text(Text::from_token(cursor, &inner)));
             }
             MrmlToken::ElementClose(close) => {
                 cursor.rewind(MrmlToken::ElementClose(close));
                 return Ok(children);
             }
             MrmlToken::ConditionalCommentStart(start) => {
-                children.push(MjRawChild::ConditionalComment(ConditionalComment::from(
-                    start.span.as_str(),
-                )));
+                children.push(MjRawChild::ConditionalComment(
+                    ConditionalComment::from_token(cursor, &start.span),
+                ));
             }
             MrmlToken::ConditionalCommentEnd(end) => {
-                children.push(MjRawChild::ConditionalComment(ConditionalComment::from(
-                    end.span.as_str(),
-                )));
+                children.push(MjRawChild::ConditionalComment(
+                    ConditionalComment::from_token(cursor, &end.span),
+                ));
             }
             other => {
                 return Err(Error::UnexpectedToken {
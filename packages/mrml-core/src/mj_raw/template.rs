@@ -0,0 +1,14 @@
+use super::MjRawChild;
+use crate::prelude::render::Error;
+use crate::prelude::template::Template;
+
+impl Template for MjRawChild {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        match self {
+            Self::ConditionalComment(elt) => elt.resolve(context),
+            Self::Comment(elt) => elt.resolve(context),
+            Self::Node(elt) => elt.resolve(context),
+            Self::Text(elt) => elt.resolve(context),
+        }
+    }
+}
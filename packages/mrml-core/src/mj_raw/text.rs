@@ -0,0 +1,13 @@
+use super::MjRawChild;
+use crate::prelude::render::text::{TextBuffer, TextRender};
+
+impl TextRender for MjRawChild {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>) {
+        match self {
+            Self::Comment(elt) => elt.render_text(buffer),
+            Self::ConditionalComment(elt) => elt.render_text(buffer),
+            Self::Node(elt) => elt.render_text(buffer),
+            Self::Text(elt) => elt.render_text(buffer),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[&[("position", AttributeType::Enum(&["file-start"]))]]),
+    required: &[],
+    children: Children::Raw,
+};
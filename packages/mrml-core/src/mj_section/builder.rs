@@ -0,0 +1,51 @@
+use super::{MjSection, MjSectionTag};
+use crate::comment::Comment;
+use crate::mj_body::MjBodyChild;
+use crate::mj_column::MjColumn;
+use crate::mj_group::MjGroup;
+use crate::mj_raw::MjRaw;
+use crate::prelude::builder::{
+    attributes, border_attributes, children, padding_attributes, Align, BackgroundRepeat, Builder,
+    Direction, Spacing,
+};
+
+impl MjSection {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjSection> {
+    padding_attributes!();
+    border_attributes!();
+    attributes! {
+        background_color: "background-color" => string,
+        background_position: "background-position" => string,
+        background_position_x: "background-position-x" => string,
+        background_position_y: "background-position-y" => string,
+        background_repeat: "background-repeat" => value(BackgroundRepeat),
+        background_size: "background-size" => string,
+        background_url: "background-url" => string,
+        border_radius: "border-radius" => string,
+        direction: "direction" => value(Direction),
+        text_align: "text-align" => value(Align),
+        text_padding: "text-padding" => value(Spacing),
+    }
+
+    /// Makes the background of the section take the whole width of the
+    /// email.
+    pub fn full_width(self, value: bool) -> Self {
+        if value {
+            self.set("full-width", "full-width".to_string())
+        } else {
+            self.unset("full-width")
+        }
+    }
+}
+
+children!(MjSectionTag => MjBodyChild {
+    Comment(Comment),
+    MjColumn(MjColumn),
+    MjGroup(MjGroup),
+    MjRaw(MjRaw),
+});
@@ -3,12 +3,16 @@ use std::marker::PhantomData;
 use crate::mj_body::MjBodyChild;
 use crate::prelude::{Component, StaticTag};
 
+#[cfg(feature = "builder")]
+mod builder;
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "print")]
 mod print;
 #[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "validate")]
+pub(crate) mod validate;
 
 #[cfg(feature = "render")]
 pub(crate) use render::{SectionLikeRender, WithMjSectionBackground};
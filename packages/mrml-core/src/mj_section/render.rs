@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::convert::TryFrom;
 
 use super::{MjSection, NAME};
+use crate::helper::color::vml_color;
 use crate::helper::size::{Percent, Pixel};
 use crate::prelude::render::*;
 
@@ -64,7 +65,7 @@ pub(crate) trait WithMjSectionBackground<'root>: Render<'root> {
         if let Some(color) = self.attribute("background-color") {
             res.push(color.into());
         }
-        if let Some(url) = self.attribute("background-url") {
+        if let Some(url) = self.attribute_as_asset("background-url") {
             res.push(format!("url('{url}')").into());
             // has default value
             res.push(
@@ -204,8 +205,8 @@ pub(crate) trait WithMjSectionBackground<'root>: Render<'root> {
         Tag::new("v:fill")
             .add_attribute("position", vfill_position)
             .add_attribute("origin", vfill_origin)
-            .maybe_add_attribute("src", self.attribute("background-url"))
-            .maybe_add_attribute("color", self.attribute("background-color"))
+            .maybe_add_attribute("src", self.attribute_as_asset("background-url"))
+            .maybe_add_attribute("color", self.attribute("background-color").map(vml_color))
             .maybe_add_attribute("size", vsize)
             .add_attribute("type", vml_type)
             .maybe_add_attribute("aspect", aspect)
@@ -427,7 +428,7 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
                     if is_full_width {
                         None
                     } else {
-                        self.attribute("background-url")
+                        self.attribute_as_asset("background-url")
                     },
                 ),
         );
@@ -483,7 +484,7 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
         self.set_style_table_full_width(Tag::table_presentation())
             .add_attribute("align", "center")
             .maybe_add_class(self.attribute("css-class"))
-            .maybe_add_attribute("background", self.attribute("background-url"))
+            .maybe_add_attribute("background", self.attribute_as_asset("background-url"))
     }
 
     fn render_full_width(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
@@ -601,7 +602,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjSection {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Renderer::new(context, self, ()).boxed()
     }
 }
 
@@ -626,4 +627,14 @@ mod tests {
     );
     crate::should_render!(padding, "mj-section-padding");
     crate::should_render!(text_align, "mj-section-text-align");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_expand_short_hex_color_for_vml() {
+        let template = r##"<mjml><mj-body><mj-section background-color="#abc" background-url="https://example.com/bg.png"></mj-section></mj-body></mjml>"##;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert!(html.contains(r##"color="#aabbcc""##));
+        assert!(html.contains("background:#abc"));
+    }
 }
@@ -0,0 +1,35 @@
+use super::{MjSocial, MjSocialChild, MjSocialTag};
+use crate::comment::Comment;
+use crate::mj_social_element::MjSocialElement;
+use crate::prelude::builder::{
+    attributes, children, font_attributes, padding_attributes, Align, Builder, Size, SocialMode,
+    Spacing, TableLayout,
+};
+
+impl MjSocial {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjSocial> {
+    padding_attributes!();
+    font_attributes!();
+    attributes! {
+        align: "align" => value(Align),
+        border_radius: "border-radius" => value(Size),
+        container_background_color: "container-background-color" => string,
+        icon_height: "icon-height" => value(Size),
+        icon_padding: "icon-padding" => value(Spacing),
+        icon_size: "icon-size" => value(Size),
+        inner_padding: "inner-padding" => value(Spacing),
+        mode: "mode" => value(SocialMode),
+        table_layout: "table-layout" => value(TableLayout),
+        text_padding: "text-padding" => value(Spacing),
+    }
+}
+
+children!(MjSocialTag => MjSocialChild {
+    Comment(Comment),
+    MjSocialElement(MjSocialElement),
+});
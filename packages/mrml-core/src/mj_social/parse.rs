@@ -14,7 +14,7 @@ impl ParseChildren<Vec<MjSocialChild>> for MrmlParser<'_> {
         loop {
             match cursor.assert_next()? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjSocialChild::Comment(Comment::from(inner.text.as_str())));
+                    result.push(MjSocialChild::Comment(Comment::from_token(cursor, &inner)));
                 }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_SOCIAL_ELEMENT {
@@ -56,7 +56,7 @@ impl AsyncParseChildren<Vec<MjSocialChild>> for AsyncMrmlParser {
         loop {
             match cursor.assert_next()? {
                 MrmlToken::Comment(inner) => {
-                    result.push(MjSocialChild::Comment(Comment::from(inner.text.as_str())));
+                    result.push(MjSocialChild::Comment(Comment::from_token(cursor, &inner)));
                 }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_SOCIAL_ELEMENT {
@@ -0,0 +1,12 @@
+use super::MjSocialChild;
+use crate::prelude::render::Error;
+use crate::prelude::template::Template;
+
+impl Template for MjSocialChild {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        match self {
+            Self::Comment(elt) => elt.resolve(context),
+            Self::MjSocialElement(elt) => elt.resolve(context),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use super::{MjSocial, MjSocialChild};
+use crate::prelude::render::text::{TextBuffer, TextRender};
+
+impl TextRender for MjSocial {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>) {
+        buffer.end_block();
+        let elements = self.children.iter().filter_map(|child| match child {
+            MjSocialChild::MjSocialElement(element) => Some(element),
+            MjSocialChild::Comment(_) => None,
+        });
+        for (index, element) in elements.enumerate() {
+            if index > 0 {
+                buffer.push_text(" | ");
+            }
+            element.render_text(buffer);
+        }
+        buffer.end_block();
+    }
+}
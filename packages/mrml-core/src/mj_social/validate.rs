@@ -0,0 +1,24 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, FONT, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        FONT,
+        &[
+            ("align", AttributeType::Enum(&["left", "center", "right"])),
+            ("border-radius", AttributeType::Size),
+            ("container-background-color", AttributeType::Color),
+            ("icon-height", AttributeType::Size),
+            ("icon-padding", AttributeType::Spacing),
+            ("icon-size", AttributeType::Size),
+            ("inner-padding", AttributeType::Spacing),
+            ("mode", AttributeType::Enum(&["horizontal", "vertical"])),
+            ("table-layout", AttributeType::Enum(&["auto", "fixed"])),
+            ("text-padding", AttributeType::Spacing),
+        ],
+    ]),
+    required: &[],
+    children: Children::Only(&[crate::mj_raw::NAME, crate::mj_social_element::NAME]),
+};
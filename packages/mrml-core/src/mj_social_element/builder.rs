@@ -0,0 +1,48 @@
+use super::{MjSocialElement, MjSocialElementTag};
+use crate::comment::Comment;
+use crate::conditional_comment::ConditionalComment;
+use crate::mj_raw::MjRawChild;
+use crate::node::Node;
+use crate::prelude::builder::{
+    attributes, children, font_attributes, padding_attributes, Align, Builder, IconPosition, Pixel,
+    Size, Spacing, VerticalAlign,
+};
+use crate::text::Text;
+
+impl MjSocialElement {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjSocialElement> {
+    padding_attributes!();
+    font_attributes!();
+    attributes! {
+        align: "align" => value(Align),
+        alt: "alt" => string,
+        background_color: "background-color" => string,
+        border_radius: "border-radius" => value(Pixel),
+        href: "href" => string,
+        icon_height: "icon-height" => value(Size),
+        icon_padding: "icon-padding" => value(Spacing),
+        icon_position: "icon-position" => value(IconPosition),
+        icon_size: "icon-size" => value(Size),
+        name: "name" => string,
+        rel: "rel" => string,
+        sizes: "sizes" => string,
+        src: "src" => string,
+        srcset: "srcset" => string,
+        target: "target" => string,
+        text_padding: "text-padding" => value(Spacing),
+        title: "title" => string,
+        vertical_align: "vertical-align" => value(VerticalAlign),
+    }
+}
+
+children!(MjSocialElementTag => MjRawChild {
+    Comment(Comment),
+    ConditionalComment(ConditionalComment),
+    Node(Node<MjRawChild>),
+    Text(Text),
+});
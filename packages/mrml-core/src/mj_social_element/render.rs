@@ -46,7 +46,7 @@ impl<'root> Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
     where
         'root: 'a,
     {
-        self.attribute("src").map(Cow::Borrowed).or_else(|| {
+        self.attribute_as_asset("src").or_else(|| {
             self.extra
                 .network
                 .as_ref()
@@ -145,6 +145,10 @@ impl<'root> Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
                 .and_then(|net| net.share_url(href))
                 .map(Cow::Owned)
                 .or(Some(Cow::Borrowed(href)))
+                .map(|link| {
+                    self.context
+                        .rewrite_link(super::NAME, &self.element.attributes, link)
+                })
         })
     }
 
@@ -290,7 +294,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjSocialElement {
                 .and_then(|v| v.as_deref())
                 .and_then(SocialNetwork::find),
         );
-        Box::new(Renderer::new(context, self, extra))
+        Renderer::new(context, self, extra).boxed()
     }
 }
 
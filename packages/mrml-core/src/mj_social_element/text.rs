@@ -0,0 +1,20 @@
+use super::{MjSocialElement, NAME};
+use crate::prelude::render::text::{get_attribute, TextBuffer, TextRender};
+
+impl TextRender for MjSocialElement {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>) {
+        let start = buffer.position();
+        self.children.render_text(buffer);
+        if buffer.written_since(start).trim().is_empty() {
+            if let Some(name) = get_attribute(&self.attributes, "name") {
+                buffer.push_text(name.trim_end_matches("-noshare"));
+            }
+        }
+        buffer.push_link(
+            start,
+            NAME,
+            &self.attributes,
+            get_attribute(&self.attributes, "href"),
+        );
+    }
+}
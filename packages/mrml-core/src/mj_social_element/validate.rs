@@ -0,0 +1,35 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, FONT, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        FONT,
+        &[
+            ("align", AttributeType::Enum(&["left", "center", "right"])),
+            ("alt", AttributeType::String),
+            ("background-color", AttributeType::Color),
+            ("border-radius", AttributeType::Pixel),
+            ("href", AttributeType::String),
+            ("icon-height", AttributeType::Size),
+            ("icon-padding", AttributeType::Spacing),
+            ("icon-position", AttributeType::Enum(&["left", "right"])),
+            ("icon-size", AttributeType::Size),
+            ("name", AttributeType::String),
+            ("rel", AttributeType::String),
+            ("sizes", AttributeType::String),
+            ("src", AttributeType::String),
+            ("srcset", AttributeType::String),
+            ("target", AttributeType::String),
+            ("text-padding", AttributeType::Spacing),
+            ("title", AttributeType::String),
+            (
+                "vertical-align",
+                AttributeType::Enum(&["top", "bottom", "middle"]),
+            ),
+        ],
+    ]),
+    required: &[],
+    children: Children::Raw,
+};
@@ -0,0 +1,17 @@
+use super::MjSpacer;
+use crate::prelude::builder::{attributes, border_attributes, padding_attributes, Builder, Size};
+
+impl MjSpacer {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjSpacer> {
+    padding_attributes!();
+    border_attributes!();
+    attributes! {
+        container_background_color: "container-background-color" => string,
+        height: "height" => value(Size),
+    }
+}
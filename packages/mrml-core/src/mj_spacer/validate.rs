@@ -0,0 +1,16 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, BORDER, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        BORDER,
+        &[
+            ("container-background-color", AttributeType::Color),
+            ("height", AttributeType::Size),
+        ],
+    ]),
+    required: &[],
+    children: Children::Raw,
+};
@@ -0,0 +1,17 @@
+use super::MjStyle;
+use crate::prelude::builder::Builder;
+
+impl MjStyle {
+    pub fn builder(content: impl Into<String>) -> Builder<Self> {
+        Builder::new(Self::from(content.into()))
+    }
+}
+
+impl Builder<MjStyle> {
+    /// Inlines the styles in the `style` attributes of the html, instead of
+    /// writing them in the head.
+    pub fn inline(mut self, value: bool) -> Self {
+        self.element.attributes.inline = value.then(|| "inline".to_string());
+        self
+    }
+}
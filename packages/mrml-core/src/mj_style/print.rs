@@ -2,6 +2,10 @@ use crate::prelude::print::Printable;
 
 impl Printable for super::MjStyle {
     fn print<P: crate::prelude::print::Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        #[cfg(feature = "span")]
+        if printer.print_source(self.location.as_ref(), || self.print_dense())? {
+            return Ok(());
+        }
         printer.push_indent();
         printer.open_tag(super::NAME)?;
         printer.close_tag();
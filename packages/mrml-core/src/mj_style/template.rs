@@ -0,0 +1,9 @@
+use super::MjStyleAttributes;
+use crate::prelude::render::Error;
+use crate::prelude::template::{resolve_attribute, Template};
+
+impl Template for MjStyleAttributes {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        resolve_attribute("inline", &mut self.inline, context)
+    }
+}
@@ -0,0 +1,18 @@
+use super::{MjStyleAttributes, NAME};
+use crate::prelude::validate::{AttributeType, AttributeValues, Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[&[("inline", AttributeType::Enum(&["inline"]))]]),
+    required: &[],
+    children: Children::Raw,
+};
+
+impl AttributeValues for MjStyleAttributes {
+    fn attribute_value(&self, name: &str) -> Option<&str> {
+        match name {
+            "inline" => self.inline.as_deref(),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use super::{MjTable, MjTableTag};
+use crate::comment::Comment;
+use crate::mj_body::MjBodyChild;
+use crate::node::Node;
+use crate::prelude::builder::{
+    attributes, children, font_attributes, padding_attributes, Align, Builder, OrAuto, Size,
+    TableLayout, TableRole,
+};
+use crate::text::Text;
+
+impl MjTable {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjTable> {
+    padding_attributes!();
+    font_attributes!();
+    attributes! {
+        align: "align" => value(Align),
+        border: "border" => string,
+        cellpadding: "cellpadding" => value(u32),
+        cellspacing: "cellspacing" => value(u32),
+        container_background_color: "container-background-color" => string,
+        role: "role" => value(TableRole),
+        table_layout: "table-layout" => value(TableLayout),
+        width: "width" => into(OrAuto<Size>),
+    }
+}
+
+children!(MjTableTag => MjBodyChild {
+    Comment(Comment),
+    Node(Node<MjBodyChild>),
+    Text(Text),
+});
@@ -90,7 +90,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjTable {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Renderer::new(context, self, ()).boxed()
     }
 }
 
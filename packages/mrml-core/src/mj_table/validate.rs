@@ -0,0 +1,25 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, FONT, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        FONT,
+        &[
+            ("align", AttributeType::Enum(&["left", "center", "right"])),
+            ("border", AttributeType::String),
+            ("cellpadding", AttributeType::Integer),
+            ("cellspacing", AttributeType::Integer),
+            ("container-background-color", AttributeType::Color),
+            ("role", AttributeType::Enum(&["none", "presentation"])),
+            (
+                "table-layout",
+                AttributeType::Enum(&["auto", "fixed", "initial", "inherit"]),
+            ),
+            ("width", AttributeType::SizeOrAuto),
+        ],
+    ]),
+    required: &[],
+    children: Children::Raw,
+};
@@ -0,0 +1,34 @@
+use super::{MjText, MjTextTag};
+use crate::comment::Comment;
+use crate::conditional_comment::ConditionalComment;
+use crate::mj_raw::MjRawChild;
+use crate::node::Node;
+use crate::prelude::builder::{
+    attributes, children, font_attributes, padding_attributes, Builder, Size, TextAlign,
+    VerticalAlign,
+};
+use crate::text::Text;
+
+impl MjText {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<MjText> {
+    padding_attributes!();
+    font_attributes!();
+    attributes! {
+        align: "align" => value(TextAlign),
+        container_background_color: "container-background-color" => string,
+        height: "height" => value(Size),
+        vertical_align: "vertical-align" => value(VerticalAlign),
+    }
+}
+
+children!(MjTextTag => MjRawChild {
+    Comment(Comment),
+    ConditionalComment(ConditionalComment),
+    Node(Node<MjRawChild>),
+    Text(Text),
+});
@@ -0,0 +1,24 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, FONT, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        FONT,
+        &[
+            (
+                "align",
+                AttributeType::Enum(&["left", "right", "center", "justify"]),
+            ),
+            ("container-background-color", AttributeType::Color),
+            ("height", AttributeType::Size),
+            (
+                "vertical-align",
+                AttributeType::Enum(&["top", "bottom", "middle"]),
+            ),
+        ],
+    ]),
+    required: &[],
+    children: Children::Raw,
+};
@@ -0,0 +1,8 @@
+use super::MjTitle;
+use crate::prelude::builder::Builder;
+
+impl MjTitle {
+    pub fn builder(content: impl Into<String>) -> Builder<Self> {
+        Builder::new(Self::from(content.into()))
+    }
+}
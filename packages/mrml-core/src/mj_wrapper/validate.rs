@@ -0,0 +1,40 @@
+use super::NAME;
+use crate::prelude::validate::{AttributeType, Attributes, Children, Rule, BORDER, PADDING};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[
+        PADDING,
+        BORDER,
+        &[
+            ("background-color", AttributeType::Color),
+            ("background-position", AttributeType::String),
+            ("background-position-x", AttributeType::String),
+            ("background-position-y", AttributeType::String),
+            (
+                "background-repeat",
+                AttributeType::Enum(&["repeat", "no-repeat"]),
+            ),
+            ("background-size", AttributeType::String),
+            ("background-url", AttributeType::String),
+            ("border-radius", AttributeType::String),
+            ("direction", AttributeType::Enum(&["ltr", "rtl"])),
+            (
+                "full-width",
+                AttributeType::Enum(&["full-width", "false", ""]),
+            ),
+            ("gap", AttributeType::Pixel),
+            (
+                "text-align",
+                AttributeType::Enum(&["left", "center", "right"]),
+            ),
+            ("text-padding", AttributeType::Spacing),
+        ],
+    ]),
+    required: &[],
+    children: Children::Only(&[
+        crate::mj_hero::NAME,
+        crate::mj_raw::NAME,
+        crate::mj_section::NAME,
+    ]),
+};
@@ -0,0 +1,49 @@
+use super::{Mjml, MjmlTag};
+use crate::mj_body::MjBody;
+use crate::mj_head::MjHead;
+use crate::prelude::builder::{Builder, ChildOf, Direction};
+
+impl Mjml {
+    pub fn builder() -> Builder<Self> {
+        Builder::new(Self::default())
+    }
+}
+
+impl Builder<Mjml> {
+    pub fn head(mut self, head: impl ChildOf<MjmlTag, MjHead>) -> Self {
+        self.element.children.head = Some(head.into_child());
+        self
+    }
+
+    pub fn body(mut self, body: impl ChildOf<MjmlTag, MjBody>) -> Self {
+        self.element.children.body = Some(body.into_child());
+        self
+    }
+
+    pub fn lang(mut self, value: impl Into<String>) -> Self {
+        self.element.attributes.lang = Some(value.into());
+        self
+    }
+
+    pub fn dir(mut self, value: Direction) -> Self {
+        self.element.attributes.dir = Some(value.to_string());
+        self
+    }
+
+    pub fn owa(mut self, value: impl Into<String>) -> Self {
+        self.element.attributes.owa = Some(value.into());
+        self
+    }
+}
+
+impl ChildOf<MjmlTag, MjHead> for MjHead {
+    fn into_child(self) -> MjHead {
+        self
+    }
+}
+
+impl ChildOf<MjmlTag, MjBody> for MjBody {
+    fn into_child(self) -> MjBody {
+        self
+    }
+}
@@ -6,6 +6,8 @@ use crate::mj_body::MjBody;
 use crate::mj_head::MjHead;
 use crate::prelude::{Component, StaticTag};
 
+#[cfg(feature = "builder")]
+mod builder;
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "parse")]
@@ -14,6 +16,15 @@ pub mod parse;
 mod print;
 #[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "template")]
+mod template;
+#[cfg(feature = "render")]
+mod text;
+#[cfg(feature = "validate")]
+pub(crate) mod validate;
+
+#[cfg(feature = "render")]
+pub use render::{PreparedTemplate, RenderedFragment};
 
 pub const NAME: &str = "mjml";
 
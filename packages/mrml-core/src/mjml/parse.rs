@@ -7,8 +7,8 @@ use crate::mj_include::NAME as MJ_INCLUDE;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
-    ParseOutput, ParserOptions, WarningKind,
+    Error, IncludeKind, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren,
+    ParseElement, ParseOutput, ParserOptions, WarningKind,
 };
 
 const WRAPPER_OPEN: &str = "<mjml>";
@@ -103,6 +103,7 @@ impl ParseChildren<MjmlChildren> for MrmlParser<'_> {
                                     position: start.span.into(),
                                     source,
                                 })?;
+                        cursor.add_include(IncludeKind::Mjml, &path, start.local.into());
                         let wrapped = format!("{WRAPPER_OPEN}{content}{WRAPPER_CLOSE}");
                         let offset = WRAPPER_OPEN.len();
                         let with_position = |err: Error| err.adjust_positions(offset);
@@ -113,7 +114,7 @@ impl ParseChildren<MjmlChildren> for MrmlParser<'_> {
                         let included: MjmlChildren =
                             self.parse_children(&mut sub).map_err(&with_position)?;
                         sub.assert_element_close().map_err(&with_position)?;
-                        cursor.with_warnings(sub.warnings());
+                        cursor.with_child(sub);
                         merge_include_children(&mut children, included);
                     }
                     _ => {
@@ -190,6 +191,7 @@ impl AsyncParseChildren<MjmlChildren> for AsyncMrmlParser {
                                 position: start.span.into(),
                                 source,
                             })?;
+                        cursor.add_include(IncludeKind::Mjml, &path, start.local.into());
                         let wrapped = format!("{WRAPPER_OPEN}{content}{WRAPPER_CLOSE}");
                         let offset = WRAPPER_OPEN.len();
                         let with_position = |err: Error| err.adjust_positions(offset);
@@ -202,7 +204,7 @@ impl AsyncParseChildren<MjmlChildren> for AsyncMrmlParser {
                             .await
                             .map_err(&with_position)?;
                         sub.assert_element_close().map_err(&with_position)?;
-                        cursor.with_warnings(sub.warnings());
+                        cursor.with_child(sub);
                         merge_include_children(&mut children, included);
                     }
                     _ => {
@@ -253,10 +255,7 @@ impl Mjml {
         let parser = MrmlParser::new(opts);
         let mut cursor = MrmlCursor::new(value.as_ref());
         let element = parser.parse_root(&mut cursor)?;
-        Ok(ParseOutput {
-            element,
-            warnings: cursor.warnings(),
-        })
+        Ok(cursor.output(element))
     }
 
     #[cfg(feature = "async")]
@@ -267,10 +266,7 @@ impl Mjml {
         let parser = AsyncMrmlParser::new(opts);
         let mut cursor = MrmlCursor::new(value.as_ref());
         let element = parser.parse_root(&mut cursor).await?;
-        Ok(ParseOutput {
-            element,
-            warnings: cursor.warnings(),
-        })
+        Ok(cursor.output(element))
     }
 
     /// Function to parse a raw mjml template using the default parsing
@@ -280,10 +276,7 @@ impl Mjml {
         let parser = MrmlParser::new(&opts);
         let mut cursor = MrmlCursor::new(value.as_ref());
         let element = parser.parse_root(&mut cursor)?;
-        Ok(ParseOutput {
-            element,
-            warnings: cursor.warnings(),
-        })
+        Ok(cursor.output(element))
     }
 
     #[cfg(feature = "async")]
@@ -293,10 +286,7 @@ impl Mjml {
         let parser = AsyncMrmlParser::default();
         let mut cursor = MrmlCursor::new(value.as_ref());
         let element = parser.parse_root(&mut cursor).await?;
-        Ok(ParseOutput {
-            element,
-            warnings: cursor.warnings(),
-        })
+        Ok(cursor.output(element))
     }
 }
 
@@ -357,6 +347,69 @@ mod tests {
         assert!(output.element.children.head.is_none());
     }
 
+    #[test]
+    fn should_list_includes() {
+        use crate::mj_include::body::MjIncludeBodyKind;
+        use crate::mj_include::head::MjIncludeHeadKind;
+        use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+        use crate::prelude::parser::Origin;
+
+        let loader = MemoryIncludeLoader::from(vec![
+            (
+                "layout.mjml",
+                r#"<mj-head><mj-include path="style.css" type="css" /></mj-head><mj-body><mj-include path="logo.html" type="html" /></mj-body>"#,
+            ),
+            ("style.css", ".red { color: red; }"),
+            ("logo.html", "<img src=\"logo.png\" />"),
+            ("header.mjml", "<mj-text>Hello</mj-text>"),
+        ]);
+        let opts = ParserOptions {
+            include_loader: Box::new(loader),
+        };
+        let template = r#"<mjml><mj-include path="layout.mjml" /><mj-body><mj-include path="header.mjml" /></mj-body></mjml>"#;
+        let output = Mjml::parse_with_options(template, &opts).unwrap();
+        let includes = output
+            .includes
+            .iter()
+            .map(|item| {
+                (
+                    item.path.as_str(),
+                    item.kind.clone(),
+                    item.origin.clone(),
+                    item.span.start,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            includes,
+            [
+                ("layout.mjml", IncludeKind::Mjml, Origin::Root, 7),
+                (
+                    "style.css",
+                    IncludeKind::Head(MjIncludeHeadKind::Css { inline: false }),
+                    Origin::Include {
+                        path: "layout.mjml".into()
+                    },
+                    10
+                ),
+                (
+                    "logo.html",
+                    IncludeKind::Body(MjIncludeBodyKind::Html),
+                    Origin::Include {
+                        path: "layout.mjml".into()
+                    },
+                    71
+                ),
+                (
+                    "header.mjml",
+                    IncludeKind::Body(MjIncludeBodyKind::Mjml),
+                    Origin::Root,
+                    49
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn should_parse_with_lang_sync() {
         let template = "<mjml lang=\"fr\"></mjml>";
@@ -2,45 +2,76 @@
 use std::borrow::Cow;
 
 use super::Mjml;
+use crate::mj_body::{MjBody, MjBodyChild};
 use crate::mj_head::MjHead;
+use crate::mj_html_attributes::render::{
+    apply_html_attributes, prepare_selectors, PreparedSelector,
+};
 use crate::prelude::render::*;
 
+impl Mjml {
+    /// Writes the document up to the `<head>` element.
+    fn render_html_start(&self, buffer: &mut RenderBuffer) -> Result<(), Error> {
+        buffer.push_str("<!doctype html>");
+        buffer.open_tag("html");
+        buffer.push_attribute("lang", self.attributes.lang())?;
+        buffer.push_attribute("dir", self.attributes.dir())?;
+        buffer.push_attribute("xmlns", "http://www.w3.org/1999/xhtml")?;
+        buffer.push_attribute("xmlns:v", "urn:schemas-microsoft-com:vml")?;
+        buffer.push_attribute("xmlns:o", "urn:schemas-microsoft-com:office:office")?;
+        buffer.close_tag();
+        Ok(())
+    }
+}
+
+impl<'root> Renderer<'root, Mjml, ()> {
+    fn render_body(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        render_body(self.context, self.element.body(), cursor)
+    }
+
+    /// Writes the document up to the body, once the body has been rendered
+    /// in the cursor to know the fonts and styles it needs.
+    fn render_head(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.element.render_html_start(&mut cursor.buffer)?;
+        if let Some(head) = self.element.head() {
+            head.renderer(self.context).render(cursor)
+        } else {
+            MjHead::default().renderer(self.context).render(cursor)
+        }
+    }
+}
+
+fn render_body<'root>(
+    context: &'root RenderContext<'root>,
+    body: Option<&'root MjBody>,
+    cursor: &mut RenderCursor,
+) -> Result<(), Error> {
+    if let Some(body) = body {
+        body.renderer(context).render(cursor)
+    } else {
+        cursor.buffer.push_str("<body></body>");
+        Ok(())
+    }
+}
+
 impl<'root> Render<'root> for Renderer<'root, Mjml, ()> {
     fn context(&self) -> &'root RenderContext<'root> {
         self.context
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        if let Some(body) = self.element.body() {
-            body.renderer(self.context).render(cursor)?;
-        } else {
-            cursor.buffer.push_str("<body></body>");
-        }
+        #[cfg(feature = "source-map")]
+        let mappings_start = cursor.mappings_count();
+        self.render_body(cursor)?;
         let mut body = RenderBuffer::default();
         std::mem::swap(&mut body, &mut cursor.buffer);
-        cursor.buffer.push_str("<!doctype html>");
-        cursor.buffer.open_tag("html");
-        cursor
-            .buffer
-            .push_attribute("lang", self.element.attributes.lang())?;
-        cursor
-            .buffer
-            .push_attribute("dir", self.element.attributes.dir())?;
-        cursor
-            .buffer
-            .push_attribute("xmlns", "http://www.w3.org/1999/xhtml")?;
-        cursor
-            .buffer
-            .push_attribute("xmlns:v", "urn:schemas-microsoft-com:vml")?;
-        cursor
-            .buffer
-            .push_attribute("xmlns:o", "urn:schemas-microsoft-com:office:office")?;
-        cursor.buffer.close_tag();
-        if let Some(head) = self.element.head() {
-            head.renderer(self.context).render(cursor)?;
-        } else {
-            MjHead::default().renderer(self.context).render(cursor)?;
-        }
+        // the body is rendered before the head, so its mappings are set aside
+        // until it's written at its final position
+        #[cfg(feature = "source-map")]
+        let body_mappings = cursor.take_mappings(mappings_start);
+        self.render_head(cursor)?;
+        #[cfg(feature = "source-map")]
+        cursor.restore_mappings(body_mappings, cursor.buffer.len());
         cursor.buffer.push_str(body.as_ref());
         cursor.buffer.end_tag("html");
         Ok(())
@@ -52,24 +83,407 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for Mjml {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Renderer::new(context, self, ()).boxed()
     }
 }
 
-impl Mjml {
+/// A component of the body rendered without the html document around it, see
+/// [`PreparedTemplate::render_fragment`].
+#[derive(Clone, Debug, Default)]
+pub struct RenderedFragment {
+    /// The html of the component.
+    pub html: String,
+    /// The elements to add to the `<head>` of the document including the
+    /// fragment, written like [`Mjml::render`] does: the links to the fonts,
+    /// the media queries and the styles.
+    pub head: String,
+    /// The font families used by the fragment.
+    pub fonts: Vec<String>,
+    /// The classes of the columns with their width, applied by a media query
+    /// above the breakpoint.
+    pub media_queries: Vec<(String, String)>,
+    /// The css rules used by the fragment, like the ones of `mj-navbar`.
+    pub styles: Vec<String>,
+}
+
+/// A template with the state derived from its `mj-head` computed once, to be
+/// rendered several times.
+///
+/// ```rust
+/// use mrml::prelude::render::RenderOptions;
+///
+/// let root = mrml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").unwrap();
+/// let prepared = root.element.prepare().unwrap();
+/// let opts = RenderOptions::default();
+/// for _ in 0..3 {
+///     assert!(prepared.render(&opts).unwrap().contains("Hello"));
+/// }
+/// ```
+pub struct PreparedTemplate<'a> {
+    pub(super) element: &'a Mjml,
+    header: Header<'a>,
+    selectors: Vec<PreparedSelector<'a>>,
+    /// The document up to the base styles of the head, written once.
+    head_start: String,
+    /// Whether the `mj-head` or the `mjml` element contain placeholders.
+    #[cfg(feature = "template")]
+    pub(super) head_has_placeholders: bool,
+}
+
+impl PreparedTemplate<'_> {
+    /// Renders the template, only the body and what depends on it being
+    /// rendered on each call.
     pub fn render(&self, opts: &RenderOptions) -> Result<String, Error> {
-        let header = Header::new(
-            self.children.head.as_ref(),
-            self.attributes.lang(),
-            self.attributes.dir(),
-        );
-        let context = RenderContext::new(opts, header);
+        self.render_body_with(opts, self.element.body())
+    }
+
+    /// Renders the template with another body than its own, the state of its
+    /// head being kept.
+    pub(super) fn render_body_with(
+        &self,
+        opts: &RenderOptions,
+        body: Option<&MjBody>,
+    ) -> Result<String, Error> {
+        let context = RenderContext::new(opts, &self.header);
         let mut cursor = RenderCursor::default();
-        self.renderer(&context).render(&mut cursor)?;
+        render_body(&context, body, &mut cursor)?;
+        let body = std::mem::take(&mut cursor.buffer);
+        self.render_document(opts, &context, cursor, body.as_ref())
+    }
+
+    /// Writes the whole document around the rendered body, in the cursor in
+    /// which the body has been rendered.
+    fn render_document(
+        &self,
+        opts: &RenderOptions,
+        context: &RenderContext,
+        mut cursor: RenderCursor,
+        body: &str,
+    ) -> Result<String, Error> {
+        cursor.buffer.push_str(&self.head_start);
+        self.render_head_end(context, &mut cursor)?;
+        cursor.buffer.push_str(body);
+        cursor.buffer.end_tag("html");
+        self.finish(opts, cursor)
+    }
+
+    /// Writes what follows the base styles in the head, with the fonts and
+    /// styles collected while rendering the body.
+    fn render_head_end(
+        &self,
+        context: &RenderContext,
+        cursor: &mut RenderCursor,
+    ) -> Result<(), Error> {
+        match self.element.head() {
+            Some(head) => Renderer::new(context, head, ()).render_end(cursor),
+            None => Renderer::new(context, &MjHead::default(), ()).render_end(cursor),
+        }
+    }
 
+    /// Renders the template like [`PreparedTemplate::render`], along with the
+    /// mappings from the rendered html back to the elements of the template.
+    ///
+    /// Only the elements parsed with the `span` feature have a location, so
+    /// the other ones are not in the source map. When the html is rewritten
+    /// once rendered, to inline css, apply `mj-html-attributes` or follow the
+    /// [`OutputMode`], the positions can't be tracked anymore and the source
+    /// map is empty.
+    #[cfg(feature = "source-map")]
+    pub fn render_with_source_map(
+        &self,
+        opts: &RenderOptions,
+    ) -> Result<(String, SourceMap), Error> {
+        let context = RenderContext::new(opts, &self.header);
+        let mut cursor = RenderCursor {
+            source_map: Some(SourceMap::default()),
+            ..Default::default()
+        };
+        self.element.renderer(&context).render(&mut cursor)?;
+        let source_map = match cursor.source_map.take() {
+            Some(source_map) if !self.is_rewritten(opts, &cursor) => source_map.finish(),
+            _ => SourceMap::default(),
+        };
+        let output = self.finish(opts, cursor)?;
+        Ok((output, source_map))
+    }
+
+    /// Renders a component of the body without the html document around it,
+    /// to compose it with other fragments. The default attributes, fonts and
+    /// styles of the template are applied to the component, which doesn't
+    /// have to be part of the template.
+    ///
+    /// ```rust
+    /// let template = r#"<mjml>
+    ///   <mj-head><mj-attributes><mj-text color="red" /></mj-attributes></mj-head>
+    ///   <mj-body>
+    ///     <mj-section><mj-column><mj-text>Hello</mj-text></mj-column></mj-section>
+    ///   </mj-body>
+    /// </mjml>"#;
+    /// let root = mrml::parse(template).unwrap();
+    /// let prepared = root.element.prepare().unwrap();
+    /// let section = &root.element.body().unwrap().children[0];
+    /// let fragment = prepared
+    ///     .render_fragment(section, &Default::default())
+    ///     .unwrap();
+    /// assert!(!fragment.html.contains("<body"));
+    /// assert!(fragment.html.contains("color:red"));
+    /// assert_eq!(fragment.media_queries[0].0, "mj-column-per-100");
+    /// assert!(fragment.head.contains(".mj-column-per-100"));
+    /// ```
+    pub fn render_fragment(
+        &self,
+        element: &MjBodyChild,
+        opts: &RenderOptions,
+    ) -> Result<RenderedFragment, Error> {
+        let context = RenderContext::new(opts, &self.header);
+        let mut cursor = RenderCursor::default();
+        let default_body = MjBody::default();
+        let body = self.element.body().unwrap_or(&default_body);
+        let mut renderer = element.renderer(&context);
+        renderer.set_container_width(Renderer::new(&context, body, ()).get_width());
+        renderer.render(&mut cursor)?;
+        let html: String = std::mem::take(&mut cursor.buffer).into();
+
+        let default_head = MjHead::default();
+        let head = self.element.head().unwrap_or(&default_head);
+        Renderer::new(&context, head, ()).render_collected(&mut cursor);
+
+        #[cfg(feature = "css-inline")]
+        let html = if cursor.header.inline_styles().is_empty() {
+            html
+        } else {
+            let inline_styles = cursor
+                .header
+                .inline_styles()
+                .iter()
+                .map(|s| s.as_ref())
+                .collect::<String>();
+            css_inline::CSSInliner::options()
+                .load_remote_stylesheets(false)
+                .build()
+                .inline_fragment(&html, &inline_styles)
+                .map_err(Error::InlineCSS)?
+        };
+
+        Ok(RenderedFragment {
+            html: opts
+                .output_mode
+                .apply(apply_html_attributes(html, &self.selectors)),
+            head: cursor.buffer.into(),
+            fonts: cursor.header.used_font_families().iter().cloned().collect(),
+            media_queries: cursor
+                .header
+                .media_queries()
+                .iter()
+                .map(|(classname, size)| (classname.clone(), size.to_string()))
+                .collect(),
+            styles: cursor
+                .header
+                .styles()
+                .iter()
+                .map(|style| style.to_string())
+                .collect(),
+        })
+    }
+
+    /// Renders the template like [`PreparedTemplate::render`], writing the
+    /// html to the writer instead of returning it.
+    ///
+    /// The head depends on the fonts and styles used in the body, so the body
+    /// is rendered twice: a first time to collect them, without keeping the
+    /// html, and a second time once the head has been written, the html being
+    /// written to the writer by chunks. The link rewriter, asset resolver and
+    /// id generator of the options are called on both passes. When the html
+    /// has to be rewritten once rendered, to inline css, apply
+    /// `mj-html-attributes` or follow the [`OutputMode`], the whole document
+    /// is built in memory and then written at once.
+    ///
+    /// ```rust
+    /// let root = mrml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").unwrap();
+    /// let prepared = root.element.prepare().unwrap();
+    /// let mut output = Vec::new();
+    /// prepared
+    ///     .render_to_writer(&Default::default(), &mut output)
+    ///     .unwrap();
+    /// assert!(String::from_utf8(output).unwrap().contains("Hello"));
+    /// ```
+    pub fn render_to_writer<W: std::io::Write>(
+        &self,
+        opts: &RenderOptions,
+        writer: W,
+    ) -> Result<(), Error> {
+        let mut writer = IoWriter {
+            inner: writer,
+            error: None,
+        };
+        let result = self.render_to_fmt_writer(opts, &mut writer);
+        if let Some(err) = writer.error {
+            return Err(Error::Write(err));
+        }
+        result?;
+        writer.inner.flush().map_err(Error::Write)
+    }
+
+    /// Renders the template to a [`std::fmt::Write`], see
+    /// [`PreparedTemplate::render_to_writer`].
+    pub fn render_to_fmt_writer<W: std::fmt::Write>(
+        &self,
+        opts: &RenderOptions,
+        mut writer: W,
+    ) -> Result<(), Error> {
+        if self.needs_rewrite(opts, &VariableHeader::default()) {
+            let output = self.render(opts)?;
+            return writer.write_str(&output).map_err(Error::Format);
+        }
+
+        let context = RenderContext::new(opts, &self.header);
+        let mut discarded = Discard;
+        let mut cursor = RenderCursor {
+            buffer: RenderBuffer::streaming(&mut discarded),
+            ..Default::default()
+        };
+        render_body(&context, self.element.body(), &mut cursor)?;
+        let header = cursor.header;
+        if self.needs_rewrite(opts, &header) {
+            let output = self.render(opts)?;
+            return writer.write_str(&output).map_err(Error::Format);
+        }
+
+        let mut head = RenderCursor {
+            buffer: RenderBuffer::streaming(&mut writer),
+            header,
+            ..Default::default()
+        };
+        head.buffer.push_str(&self.head_start);
+        self.render_head_end(&context, &mut head)?;
+        head.buffer.flush()?;
+        drop(head);
+
+        // a new context, for the ids to be generated again from the start
+        let context = RenderContext::new(opts, &self.header);
+        let mut body = RenderCursor {
+            buffer: RenderBuffer::streaming(&mut writer),
+            ..Default::default()
+        };
+        render_body(&context, self.element.body(), &mut body)?;
+        body.buffer.end_tag("html");
+        body.buffer.flush()?;
+        Ok(())
+    }
+
+    /// Renders the template like [`PreparedTemplate::render`], along with a
+    /// report of the size of the html, warning when it exceeds the limit,
+    /// like [`GMAIL_CLIP_LIMIT`]. The size of each child of `mj-body` is
+    /// measured while the template is rendered.
+    ///
+    /// ```rust
+    /// use mrml::prelude::render::{GMAIL_CLIP_LIMIT, RenderOptions};
+    ///
+    /// let template = "<mjml><mj-body><mj-section><mj-column><mj-text>Hello</mj-text></mj-column></mj-section></mj-body></mjml>";
+    /// let root = mrml::parse(template).unwrap();
+    /// let prepared = root.element.prepare().unwrap();
+    /// let (html, report) = prepared
+    ///     .render_with_report(&RenderOptions::default(), GMAIL_CLIP_LIMIT)
+    ///     .unwrap();
+    /// assert_eq!(report.size, html.len());
+    /// assert_eq!(report.sections[0].tag.as_deref(), Some("mj-section"));
+    /// assert!(report.warnings.is_empty());
+    /// ```
+    pub fn render_with_report(
+        &self,
+        opts: &RenderOptions,
+        limit: usize,
+    ) -> Result<(String, RenderReport), Error> {
+        let context = RenderContext::new(opts, &self.header);
+        let mut cursor = RenderCursor {
+            sections: Some(Vec::new()),
+            ..Default::default()
+        };
+        render_body(&context, self.element.body(), &mut cursor)?;
+        let ranges = cursor.sections.take().unwrap_or_default();
+        let rewritten = self.needs_rewrite(opts, &cursor.header);
+        let body = std::mem::take(&mut cursor.buffer);
+        let html = self.render_document(opts, &context, cursor, body.as_ref())?;
+        // when not rewritten, the body is only followed by the end of the html
+        // element
+        let offset = (!rewritten).then(|| html.len() - body.len() - "</html>".len());
+        let sections = match self.element.body() {
+            Some(body) => body
+                .children
+                .iter()
+                .zip(ranges)
+                .enumerate()
+                .map(|(index, (child, range))| SectionReport {
+                    index,
+                    tag: child.renderer(&context).tag().map(String::from),
+                    size: range.len(),
+                    range: offset.map(|offset| range.start + offset..range.end + offset),
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        let report = RenderReport::new(&html, sections, limit);
+        Ok((html, report))
+    }
+
+    /// Checks the css of the rendered template against its support by the
+    /// main email clients, see [`crate::prelude::lint`].
+    ///
+    /// The css is checked before being inlined, so the issues of the
+    /// `mj-style` elements are reported once for the element instead of
+    /// once for each `style` attribute it ends up in.
+    #[cfg(feature = "lint")]
+    pub fn lint(
+        &self,
+        opts: &RenderOptions,
+    ) -> Result<Vec<crate::prelude::lint::LintIssue>, Error> {
+        let context = RenderContext::new(opts, &self.header);
+        let mut cursor = RenderCursor {
+            source_map: Some(SourceMap::default()),
+            ..Default::default()
+        };
+        self.element.renderer(&context).render(&mut cursor)?;
+        let source_map = cursor.source_map.take().unwrap_or_default().finish();
+
+        let mut linter = crate::prelude::lint::Linter::default();
+        linter.check_html(cursor.buffer.as_ref(), &source_map);
+        for (index, css) in cursor.header.styles().iter().enumerate() {
+            let origin = cursor
+                .style_origins
+                .iter()
+                .find(|(style, _)| *style == index)
+                .map(|(_, mapping)| mapping);
+            linter.check_component_style(css, origin);
+        }
+        if let Some(head) = self.element.head() {
+            linter.check_head(head);
+        }
+        Ok(linter.finish())
+    }
+
+    /// Whether the html is rewritten once rendered.
+    #[cfg(feature = "source-map")]
+    fn is_rewritten(&self, opts: &RenderOptions, cursor: &RenderCursor) -> bool {
+        self.needs_rewrite(opts, &cursor.header)
+    }
+
+    fn needs_rewrite(&self, opts: &RenderOptions, header: &VariableHeader) -> bool {
+        if opts.output_mode != OutputMode::Raw {
+            return true;
+        }
+        #[cfg(feature = "css-inline")]
+        if !header.inline_styles().is_empty() {
+            return true;
+        }
+        #[cfg(not(feature = "css-inline"))]
+        let _ = header;
+        !self.selectors.is_empty()
+    }
+
+    fn finish(&self, opts: &RenderOptions, cursor: RenderCursor) -> Result<String, Error> {
         // Only inline CSS if there are inline styles
         #[cfg(feature = "css-inline")]
-        if !cursor.header.inline_styles().is_empty() {
+        let output = if !cursor.header.inline_styles().is_empty() {
             // Collect inline styles from the header into a single string
             let inline_styles = cursor
                 .header
@@ -86,12 +500,155 @@ impl Mjml {
                 .extra_css(Some(Cow::Owned(inline_styles)))
                 .build();
 
-            return inliner
+            inliner
                 .inline(cursor.buffer.as_ref())
-                .map_err(Error::InlineCSS);
+                .map_err(Error::InlineCSS)?
+        } else {
+            cursor.buffer.into()
+        };
+        #[cfg(not(feature = "css-inline"))]
+        let output: String = cursor.buffer.into();
+
+        // Like mjml, the html attributes are applied once the css has been
+        // inlined
+        let output = apply_html_attributes(output, &self.selectors);
+        Ok(opts.output_mode.apply(output))
+    }
+}
+
+/// Drops what is written to it, to render the body without keeping its html.
+struct Discard;
+
+impl std::fmt::Write for Discard {
+    fn write_str(&mut self, _: &str) -> std::fmt::Result {
+        Ok(())
+    }
+}
+
+/// Adapts a [`std::io::Write`] to the [`std::fmt::Write`] used to render,
+/// keeping the error to return it.
+struct IoWriter<W> {
+    inner: W,
+    error: Option<std::io::Error>,
+}
+
+impl<W: std::io::Write> std::fmt::Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            std::fmt::Error
+        })
+    }
+}
+
+impl Mjml {
+    /// Computes the state derived from the `mj-head` element (default
+    /// attributes, fonts, breakpoint, styles...) and writes the beginning of
+    /// the head, so that the template can be rendered several times without
+    /// doing it again.
+    pub fn prepare(&self) -> Result<PreparedTemplate<'_>, Error> {
+        let header = Header::new(
+            self.children.head.as_ref(),
+            self.attributes.lang(),
+            self.attributes.dir(),
+        );
+        let selectors = match self.head() {
+            Some(head) => prepare_selectors(head.mj_selector_iter())?,
+            None => Vec::new(),
+        };
+        let mut head_start = RenderBuffer::default();
+        self.render_html_start(&mut head_start)?;
+        match self.head() {
+            Some(head) => head.render_start(&mut head_start),
+            None => MjHead::default().render_start(&mut head_start),
         }
+        Ok(PreparedTemplate {
+            element: self,
+            header,
+            selectors,
+            head_start: head_start.into(),
+            #[cfg(feature = "template")]
+            head_has_placeholders: self.head_has_placeholders(),
+        })
+    }
+
+    pub fn render(&self, opts: &RenderOptions) -> Result<String, Error> {
+        self.prepare()?.render(opts)
+    }
+
+    /// Renders the template to a writer, see
+    /// [`PreparedTemplate::render_to_writer`].
+    pub fn render_to_writer<W: std::io::Write>(
+        &self,
+        opts: &RenderOptions,
+        writer: W,
+    ) -> Result<(), Error> {
+        self.prepare()?.render_to_writer(opts, writer)
+    }
+
+    /// Renders the template to a [`std::fmt::Write`], see
+    /// [`PreparedTemplate::render_to_writer`].
+    pub fn render_to_fmt_writer<W: std::fmt::Write>(
+        &self,
+        opts: &RenderOptions,
+        writer: W,
+    ) -> Result<(), Error> {
+        self.prepare()?.render_to_fmt_writer(opts, writer)
+    }
+
+    /// Renders the template along with a report of the size of the html, see
+    /// [`PreparedTemplate::render_with_report`].
+    pub fn render_with_report(
+        &self,
+        opts: &RenderOptions,
+        limit: usize,
+    ) -> Result<(String, RenderReport), Error> {
+        self.prepare()?.render_with_report(opts, limit)
+    }
 
-        Ok(cursor.buffer.into())
+    /// Renders a component of the body without the html document around it,
+    /// see [`PreparedTemplate::render_fragment`].
+    pub fn render_fragment(
+        &self,
+        element: &MjBodyChild,
+        opts: &RenderOptions,
+    ) -> Result<RenderedFragment, Error> {
+        self.prepare()?.render_fragment(element, opts)
+    }
+
+    /// Renders the template along with the mappings from the rendered html
+    /// back to its elements, see [`PreparedTemplate::render_with_source_map`].
+    ///
+    /// ```rust
+    /// use mrml::prelude::render::RenderOptions;
+    ///
+    /// let template = "<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>";
+    /// let root = mrml::parse(template).unwrap();
+    /// let (html, source_map) = root
+    ///     .element
+    ///     .render_with_source_map(&RenderOptions::default())
+    ///     .unwrap();
+    /// let position = html.find("Hello").unwrap();
+    /// let text = source_map.lookup(position).last().unwrap();
+    /// assert_eq!(text.tag, "mj-text");
+    /// assert_eq!(&template[text.source.start..text.source.end], "<mj-text>Hello</mj-text>");
+    /// ```
+    #[cfg(feature = "source-map")]
+    pub fn render_with_source_map(
+        &self,
+        opts: &RenderOptions,
+    ) -> Result<(String, SourceMap), Error> {
+        self.prepare()?.render_with_source_map(opts)
+    }
+
+    /// Checks the css of the template against its support by the main email
+    /// clients, see [`PreparedTemplate::lint`].
+    #[cfg(feature = "lint")]
+    pub fn lint(
+        &self,
+        opts: &RenderOptions,
+    ) -> Result<Vec<crate::prelude::lint::LintIssue>, Error> {
+        self.prepare()?.lint(opts)
     }
 
     pub fn get_title(&self) -> Option<String> {
@@ -114,6 +671,375 @@ mod tests {
 
     crate::should_render!(empty, "mjml");
 
+    #[test]
+    fn prepared_should_render_like_render() {
+        let opts = RenderOptions::default();
+        let template = include_str!("../../resources/template/air-astana.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let expected = root.element.render(&opts).unwrap();
+        let prepared = root.element.prepare().unwrap();
+        assert_eq!(prepared.render(&opts).unwrap(), expected);
+        assert_eq!(prepared.render(&opts).unwrap(), expected);
+    }
+
+    #[test]
+    fn should_minify_output() {
+        use crate::helper::html::HtmlDocument;
+        use crate::prelude::render::OutputMode;
+
+        let template = include_str!("../../resources/template/amario.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let raw = root.element.render(&RenderOptions::default()).unwrap();
+        let minified = root
+            .element
+            .render(&RenderOptions {
+                output_mode: OutputMode::Minify,
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(minified.len() < raw.len());
+        assert_eq!(
+            minified.matches("<!--[if mso | IE]>").count(),
+            raw.matches("<!--[if mso | IE]>").count()
+        );
+        assert_eq!(
+            minified.matches("<![endif]-->").count(),
+            raw.matches("<![endif]-->").count()
+        );
+        let tags = |html: &str| {
+            HtmlDocument::parse(html)
+                .elements()
+                .iter()
+                .map(|element| (element.tag.clone(), element.parent))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(tags(&minified), tags(&raw));
+    }
+
+    #[test]
+    fn should_prettify_output() {
+        use crate::prelude::render::OutputMode;
+
+        let root = Mjml::parse(
+            "<mjml><mj-body><mj-section><mj-column><mj-text>Hello</mj-text></mj-column></mj-section></mj-body></mjml>",
+        )
+        .unwrap();
+        let opts = RenderOptions {
+            output_mode: OutputMode::Pretty,
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains("\n  <head>\n"));
+        assert!(html
+            .lines()
+            .any(|line| line.trim_start().starts_with("<div") && line.ends_with(">Hello</div>")));
+        // the output can't be streamed, it's formatted once rendered
+        let mut streamed = String::new();
+        root.element
+            .render_to_fmt_writer(&opts, &mut streamed)
+            .unwrap();
+        assert_eq!(streamed, html);
+    }
+
+    #[test]
+    fn should_report_size() {
+        use crate::prelude::render::{ReportWarning, GMAIL_CLIP_LIMIT};
+
+        let template = include_str!("../../resources/template/amario.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let opts = RenderOptions::default();
+        let (html, report) = root
+            .element
+            .render_with_report(&opts, GMAIL_CLIP_LIMIT)
+            .unwrap();
+        assert_eq!(html, root.element.render(&opts).unwrap());
+        assert_eq!(report.size, html.len());
+        assert!(report.head_size > report.style_size);
+        assert!(report.style_size > 0);
+        let children = &root.element.body().unwrap().children;
+        assert_eq!(report.sections.len(), children.len());
+        assert!(report
+            .sections
+            .iter()
+            .filter(|section| section.tag.as_deref() == Some("mj-section"))
+            .all(|section| section.size > 0));
+        assert!(report.sections.iter().map(|s| s.size).sum::<usize>() < report.size);
+        let body_start = html.find("<body").unwrap();
+        let body_end = html.rfind("</body>").unwrap();
+        let mut previous_end = body_start;
+        for section in report.sections.iter() {
+            let range = section.range.clone().unwrap();
+            assert_eq!(range.len(), section.size);
+            assert!(range.start >= previous_end && range.end < body_end);
+            previous_end = range.end;
+        }
+        let first = report.sections[0].range.clone().unwrap();
+        assert!(html[first].starts_with("<!--"));
+        assert!(report.warnings.is_empty());
+
+        let (_, report) = root.element.render_with_report(&opts, 1024).unwrap();
+        assert_eq!(
+            report.warnings,
+            vec![ReportWarning::SizeLimitExceeded {
+                size: html.len(),
+                limit: 1024
+            }]
+        );
+        let biggest = report.biggest_sections();
+        assert!(biggest[0].size >= biggest[1].size);
+    }
+
+    #[cfg(feature = "lint")]
+    #[test]
+    fn should_lint_styles() {
+        use crate::prelude::lint::{Client, CssSource};
+
+        let template = r#"<mjml>
+  <mj-head><mj-style>.banner { position: absolute; color: red; }</mj-style></mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-button border-radius="8px">Click</mj-button>
+        <mj-button border-radius="8px">Again</mj-button>
+        <mj-text><div style="display: grid">Hello</div></mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let issues = root.element.lint(&RenderOptions::default()).unwrap();
+        let found = issues
+            .iter()
+            .map(|issue| {
+                let element = issue.element.as_ref().unwrap();
+                (
+                    issue.property.as_str(),
+                    issue.source,
+                    &template[element.span.start..element.span.end],
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "border-radius",
+                    CssSource::InlineStyle,
+                    r#"<mj-button border-radius="8px">Click</mj-button>"#
+                ),
+                (
+                    "border-radius",
+                    CssSource::InlineStyle,
+                    r#"<mj-button border-radius="8px">Again</mj-button>"#
+                ),
+                (
+                    "display",
+                    CssSource::InlineStyle,
+                    r#"<div style="display: grid">Hello</div>"#
+                ),
+                (
+                    "position",
+                    CssSource::MjStyle,
+                    "<mj-style>.banner { position: absolute; color: red; }</mj-style>"
+                ),
+            ]
+        );
+        assert!(issues[2].affects(Client::Gmail));
+        assert!(!issues[0].affects(Client::Gmail));
+        assert_eq!(
+            issues[3].to_string(),
+            "`position: absolute` is not supported by Outlook, Gmail (stylesheet of mj-style at 18..82)"
+        );
+    }
+
+    #[cfg(feature = "lint")]
+    #[test]
+    fn should_lint_included_styles() {
+        use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+        use crate::prelude::parser::{Origin, ParserOptions};
+
+        let opts = ParserOptions {
+            include_loader: Box::new(MemoryIncludeLoader::from(vec![(
+                "style.css",
+                ".card { box-shadow: 0 0 2px #000; }",
+            )])),
+        };
+        let template = r#"<mjml><mj-head><mj-include path="style.css" type="css" /></mj-head><mj-body /></mjml>"#;
+        let root = Mjml::parse_with_options(template, &opts).unwrap();
+        let issues = root.element.lint(&RenderOptions::default()).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].property, "box-shadow");
+        let element = issues[0].element.as_ref().unwrap();
+        assert_eq!(element.tag, "mj-include");
+        assert_eq!(element.origin, Origin::Root);
+    }
+
+    #[derive(Default)]
+    struct Chunks(Vec<String>);
+
+    impl std::fmt::Write for Chunks {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            self.0.push(s.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn should_stream_like_render() {
+        let opts = RenderOptions::default();
+        for template in [
+            include_str!("../../resources/template/air-astana.mjml"),
+            include_str!("../../resources/template/amario.mjml"),
+            include_str!("../../resources/compare/success/mj-navbar.mjml"),
+        ] {
+            let root = Mjml::parse(template).unwrap();
+            let expected = root.element.render(&opts).unwrap();
+            let mut chunks = Chunks::default();
+            root.element
+                .render_to_fmt_writer(&opts, &mut chunks)
+                .unwrap();
+            assert_eq!(chunks.0.concat(), expected);
+
+            let mut output = Vec::new();
+            root.element.render_to_writer(&opts, &mut output).unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn should_stream_by_chunks() {
+        let template = include_str!("../../resources/template/amario.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let mut chunks = Chunks::default();
+        root.element
+            .render_to_fmt_writer(&RenderOptions::default(), &mut chunks)
+            .unwrap();
+        assert!(chunks.0.len() > 1);
+        // the head is written before the body
+        assert!(chunks.0[0].starts_with("<!doctype html>"));
+        assert!(chunks.0[0].contains("</head>"));
+        // the body is written by chunks too, not kept in memory
+        assert!(chunks.0.len() > 2);
+        assert!(chunks.0.iter().all(|chunk| chunk.len() < 16 * 1024));
+    }
+
+    #[test]
+    fn should_stream_rewritten_template() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-html-attributes>
+      <mj-selector path=".custom div">
+        <mj-html-attribute name="data-id">42</mj-html-attribute>
+      </mj-selector>
+    </mj-html-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section><mj-column><mj-text css-class="custom">Hello</mj-text></mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let opts = RenderOptions::default();
+        let mut output = String::new();
+        root.element
+            .render_to_fmt_writer(&opts, &mut output)
+            .unwrap();
+        assert!(output.contains(r#"data-id="42""#));
+        assert_eq!(output, root.element.render(&opts).unwrap());
+    }
+
+    #[test]
+    fn should_fail_streaming_with_failing_writer() {
+        struct Failing;
+
+        impl std::io::Write for Failing {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("closed"))
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let root = Mjml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").unwrap();
+        let err = root
+            .element
+            .render_to_writer(&RenderOptions::default(), Failing)
+            .unwrap_err();
+        assert!(matches!(err, crate::prelude::render::Error::Write(_)));
+    }
+
+    #[test]
+    fn should_render_fragment_of_another_template() {
+        let opts = RenderOptions::default();
+        let layout = Mjml::parse(
+            r#"<mjml>
+  <mj-head>
+    <mj-attributes><mj-class name="title" color="blue" font-family="Roboto" /></mj-attributes>
+    <mj-style>.custom { color: green; }</mj-style>
+  </mj-head>
+  <mj-body width="500px" />
+</mjml>"#,
+        )
+        .unwrap();
+        let content = Mjml::parse(
+            r#"<mjml><mj-body>
+  <mj-section><mj-column><mj-text mj-class="title">Title</mj-text></mj-column><mj-column /></mj-section>
+  <mj-navbar hamburger="hamburger"><mj-navbar-link href="/">Home</mj-navbar-link></mj-navbar>
+</mj-body></mjml>"#,
+        )
+        .unwrap();
+        let prepared = layout.element.prepare().unwrap();
+        let children = &content.element.body().unwrap().children;
+
+        let section = prepared.render_fragment(&children[0], &opts).unwrap();
+        assert!(section.html.starts_with("<!--[if mso | IE]>"));
+        assert!(!section.html.contains("<body"));
+        assert!(section.html.contains("color:blue"));
+        // the width of the body of the layout is used
+        assert!(section.html.contains("max-width:500px"));
+        assert_eq!(section.fonts, ["Roboto"]);
+        assert_eq!(
+            section.media_queries,
+            [("mj-column-per-50".to_string(), "50%".to_string())]
+        );
+        assert!(section.styles.is_empty());
+        assert!(section
+            .head
+            .contains("fonts.googleapis.com/css?family=Roboto"));
+        assert!(section
+            .head
+            .contains(".mj-column-per-50 { width:50% !important;"));
+        assert!(section.head.contains(".custom { color: green; }"));
+        assert!(!section.head.contains("<head"));
+
+        let navbar = prepared.render_fragment(&children[1], &opts).unwrap();
+        assert!(navbar.media_queries.is_empty());
+        assert!(!navbar.styles.is_empty());
+        assert!(navbar.head.contains(".mj-menu-checkbox"));
+    }
+
+    #[cfg(feature = "css-inline")]
+    #[test]
+    fn should_inline_styles_in_fragment() {
+        let template = r#"<mjml>
+  <mj-head><mj-style inline="inline">.red { color: red; }</mj-style></mj-head>
+  <mj-body>
+    <mj-section><mj-column><mj-text css-class="red">Hello</mj-text></mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let section = &root.element.body().unwrap().children[0];
+        let fragment = root
+            .element
+            .render_fragment(section, &RenderOptions::default())
+            .unwrap();
+        assert!(!fragment.html.contains("<html"));
+        assert!(fragment
+            .html
+            .contains(r#"<td class="red" align="left" style="color: red;"#));
+    }
+
     #[test]
     fn template_amario() {
         let opts = RenderOptions::default();
@@ -0,0 +1,249 @@
+use super::{Mjml, MjmlAttributes, MjmlChildren, PreparedTemplate};
+use crate::prelude::render::{Error, RenderOptions};
+use crate::prelude::template::{resolve_attribute, Template};
+
+impl Template for MjmlAttributes {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        resolve_attribute("owa", &mut self.owa, context)?;
+        resolve_attribute("lang", &mut self.lang, context)?;
+        resolve_attribute("dir", &mut self.dir, context)
+    }
+}
+
+impl Template for MjmlChildren {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        self.head.resolve(context)?;
+        self.body.resolve(context)
+    }
+}
+
+impl Mjml {
+    /// Renders the template after resolving its `{{ variable }}` placeholders
+    /// with the given context. The parsed template is left untouched, so it
+    /// can be rendered again with another context.
+    ///
+    /// See [the template module](crate::prelude::template) for the placeholder
+    /// syntax.
+    pub fn render_with_context(
+        &self,
+        opts: &RenderOptions,
+        context: &serde_json::Value,
+    ) -> Result<String, Error> {
+        self.prepare()?.render_with_context(opts, context)
+    }
+
+    /// Whether the elements the prepared state is computed from contain
+    /// placeholders, which no context can resolve.
+    pub(super) fn head_has_placeholders(&self) -> bool {
+        let mut attributes = self.attributes.clone();
+        let mut head = self.children.head.clone();
+        attributes.resolve(&serde_json::Value::Null).is_err()
+            || head.resolve(&serde_json::Value::Null).is_err()
+    }
+}
+
+impl PreparedTemplate<'_> {
+    /// Renders the template after resolving its `{{ variable }}` placeholders
+    /// with the given context, see [`Mjml::render_with_context`].
+    ///
+    /// Only a copy of the body is resolved, the state derived from the head
+    /// being reused. When the head contains placeholders, the whole template
+    /// is resolved and prepared again.
+    ///
+    /// ```rust
+    /// use mrml::prelude::render::RenderOptions;
+    ///
+    /// let root = mrml::parse("<mjml><mj-body><mj-text>Hello {{ name }}</mj-text></mj-body></mjml>").unwrap();
+    /// let prepared = root.element.prepare().unwrap();
+    /// let opts = RenderOptions::default();
+    /// for name in ["Alice", "Bob"] {
+    ///     let context = serde_json::json!({ "name": name });
+    ///     let html = prepared.render_with_context(&opts, &context).unwrap();
+    ///     assert!(html.contains(&format!("Hello {name}")));
+    /// }
+    /// ```
+    pub fn render_with_context(
+        &self,
+        opts: &RenderOptions,
+        context: &serde_json::Value,
+    ) -> Result<String, Error> {
+        if self.head_has_placeholders {
+            let mut resolved = self.element.clone();
+            resolved.resolve(context)?;
+            return resolved.prepare()?.render(opts);
+        }
+        let mut body = self.element.body().cloned();
+        body.resolve(context)?;
+        self.render_body_with(opts, body.as_ref())
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::mjml::Mjml;
+    use crate::prelude::render::{Error, RenderOptions};
+
+    const TEMPLATE: &str = r#"<mjml lang="{{ lang }}">
+  <mj-head>
+    <mj-title>{{ title }}</mj-title>
+    <mj-attributes>
+      <mj-all font-family="{{ font }}" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text color="{{ colors.0 }}">Hello {{ user.name }}!</mj-text>
+        <mj-button href="{{ link }}">Click</mj-button>
+        <mj-raw><p data-id="{{ user.id }}">{{{ footer }}}</p></mj-raw>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+
+    #[test]
+    fn should_resolve_variables() {
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let context = json!({
+            "lang": "fr",
+            "title": "Welcome",
+            "font": "Arial",
+            "colors": ["#ff0000"],
+            "user": { "id": 42, "name": "Tom & Jerry" },
+            "link": "https://example.com/?a=1",
+            "footer": "<b>bye</b>",
+        });
+        let result = root
+            .element
+            .render_with_context(&RenderOptions::default(), &context)
+            .unwrap();
+        assert!(result.contains(r#"<html lang="fr""#));
+        assert!(result.contains("<title>Welcome</title>"));
+        assert!(result.contains("font-family:Arial;"));
+        assert!(result.contains("color:#ff0000;"));
+        assert!(result.contains("Hello Tom &amp; Jerry!"));
+        assert!(result.contains(r#"href="https://example.com/?a=1""#));
+        assert!(result.contains(r#"<p data-id="42"><b>bye</b></p>"#));
+        // the parsed template is not modified
+        assert_eq!(root.element.get_title().as_deref(), Some("{{ title }}"));
+    }
+
+    #[test]
+    fn prepared_should_resolve_variables_on_each_render() {
+        let opts = RenderOptions::default();
+        let template = r#"<mjml><mj-head><mj-title>Title</mj-title></mj-head><mj-body><mj-text>Hello {{ name }}</mj-text></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let prepared = root.element.prepare().unwrap();
+        assert!(!prepared.head_has_placeholders);
+        for name in ["Alice", "Bob"] {
+            let context = json!({ "name": name });
+            assert_eq!(
+                prepared.render_with_context(&opts, &context).unwrap(),
+                Mjml::parse(template.replace("{{ name }}", name))
+                    .unwrap()
+                    .element
+                    .render(&opts)
+                    .unwrap()
+            );
+        }
+
+        // the placeholders of the head are resolved too
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let prepared = root.element.prepare().unwrap();
+        assert!(prepared.head_has_placeholders);
+        let context = json!({
+            "lang": "fr",
+            "title": "Welcome",
+            "font": "Arial",
+            "colors": ["red"],
+            "user": { "id": 42, "name": "Alice" },
+            "link": "https://example.com",
+            "footer": "bye",
+        });
+        let result = prepared.render_with_context(&opts, &context).unwrap();
+        assert!(result.contains("<title>Welcome</title>"));
+        assert!(result.contains("Hello Alice!"));
+    }
+
+    #[test]
+    fn should_fail_with_missing_variable() {
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let err = root
+            .element
+            .render_with_context(&RenderOptions::default(), &json!({ "lang": "fr" }))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::MissingVariable { name, element, attribute: None, .. }
+                if name == "title" && element.as_deref() == Some("mj-title")
+        ));
+    }
+
+    #[test]
+    fn should_tell_where_variable_is_missing() {
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let context = json!({
+            "lang": "fr",
+            "title": "Title",
+            "font": "Arial",
+            "colors": ["red"],
+            "user": { "name": "Alice" },
+        });
+        let err = root
+            .element
+            .render_with_context(&RenderOptions::default(), &context)
+            .unwrap_err();
+        match err {
+            Error::MissingVariable {
+                name,
+                value_span,
+                element,
+                attribute,
+                ..
+            } => {
+                assert_eq!(name, "link");
+                // relative to the value of the attribute
+                assert_eq!((value_span.start, value_span.end), (0, 10));
+                assert_eq!(element.as_deref(), Some("mj-button"));
+                assert_eq!(attribute.as_deref(), Some("href"));
+            }
+            other => panic!("unexpected error {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "span")]
+    #[test]
+    fn should_locate_missing_variable() {
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let err = root
+            .element
+            .render_with_context(&RenderOptions::default(), &json!({ "lang": "fr" }))
+            .unwrap_err();
+        let Error::MissingVariable { location, .. } = err else {
+            panic!("unexpected error {err:?}");
+        };
+        let span = location.unwrap().span;
+        assert!(TEMPLATE[span.start..span.end].starts_with("<mj-title>"));
+
+        let context = json!({
+            "lang": "fr",
+            "title": "Title",
+            "font": "Arial",
+            "colors": ["red"],
+            "user": { "name": "Alice" },
+        });
+        let err = root
+            .element
+            .render_with_context(&RenderOptions::default(), &context)
+            .unwrap_err();
+        let Error::MissingVariable { location, .. } = err else {
+            panic!("unexpected error {err:?}");
+        };
+        let location = location.unwrap();
+        assert!(TEMPLATE[location.span.start..location.span.end].starts_with("<mj-button"));
+        let span = location.attributes.get("href").unwrap();
+        assert_eq!(&TEMPLATE[span.start..span.end], r#"href="{{ link }}""#);
+    }
+}
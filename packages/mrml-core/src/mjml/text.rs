@@ -0,0 +1,122 @@
+use super::Mjml;
+use crate::prelude::render::text::{decode_entities, TextBuffer, TextRender};
+use crate::prelude::render::TextRenderOptions;
+
+impl Mjml {
+    /// Renders the template as plain text, to be used as the `text/plain`
+    /// alternative of the html email.
+    ///
+    /// ```rust
+    /// use mrml::prelude::render::TextRenderOptions;
+    ///
+    /// let root = mrml::parse(r#"<mjml><mj-body>
+    ///   <mj-text>Hello <b>World</b>!</mj-text>
+    ///   <mj-button href="https://example.com">Click</mj-button>
+    /// </mj-body></mjml>"#).unwrap();
+    /// let text = root.element.render_text(&TextRenderOptions::default());
+    /// assert_eq!(text, "Hello World!\n\nClick (https://example.com)\n");
+    /// ```
+    pub fn render_text(&self, opts: &TextRenderOptions) -> String {
+        let mut buffer = TextBuffer::new(opts);
+        if opts.include_preview {
+            if let Some(preview) = self.get_preview() {
+                buffer.push_text(&decode_entities(&preview));
+                buffer.end_block();
+            }
+        }
+        if let Some(body) = self.body() {
+            body.children.render_text(&mut buffer);
+        }
+        buffer.into_string()
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::prelude::render::TextRenderOptions;
+
+    #[test]
+    fn should_render_components() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-preview>Our latest news</mj-preview>
+  </mj-head>
+  <mj-body>
+    <mj-navbar base-url="https://example.com">
+      <mj-navbar-link href="/home">Home</mj-navbar-link>
+      <mj-navbar-link href="/blog">Blog</mj-navbar-link>
+    </mj-navbar>
+    <mj-section>
+      <mj-column>
+        <mj-image src="logo.png" alt="Our logo" href="https://example.com" />
+        <mj-image src="spacer.png" />
+        <mj-text>
+          <h1>Hello&nbsp;World</h1>
+          <p>Read <a href="https://example.com/post">the article</a>.<br/>See you soon!</p>
+          <ul><li>first</li><li>second</li></ul>
+        </mj-text>
+        <mj-divider />
+        <mj-table>
+          <tr><th>Item</th><th>Price</th></tr>
+          <tr><td>Book</td><td>12 &#8364;</td></tr>
+        </mj-table>
+        <mj-button href="https://example.com/shop">Shop now</mj-button>
+        <mj-social>
+          <mj-social-element name="facebook" href="https://facebook.com/example" />
+          <mj-social-element name="twitter" href="https://twitter.com/example">Twitter</mj-social-element>
+        </mj-social>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let text = root.element.render_text(&TextRenderOptions::default());
+        similar_asserts::assert_eq!(
+            text,
+            r#"Our latest news
+
+Home (https://example.com/home) | Blog (https://example.com/blog)
+
+Our logo (https://example.com)
+
+Hello World
+
+Read the article (https://example.com/post).
+See you soon!
+
+- first
+- second
+
+---
+
+Item | Price
+Book | 12 €
+
+Shop now (https://example.com/shop)
+
+facebook (https://facebook.com/example) | Twitter
+(https://twitter.com/example)
+"#
+        );
+    }
+
+    #[test]
+    fn should_apply_options() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-preview>Preview</mj-preview>
+  </mj-head>
+  <mj-body>
+    <mj-text>The quick brown fox jumps over <a href="https://example.com">the lazy dog</a></mj-text>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let text = root.element.render_text(&TextRenderOptions {
+            line_width: Some(20),
+            include_links: false,
+            include_preview: false,
+            ..Default::default()
+        });
+        assert_eq!(text, "The quick brown fox\njumps over the lazy\ndog\n");
+    }
+}
@@ -0,0 +1,24 @@
+use super::{MjmlAttributes, NAME};
+use crate::prelude::validate::{AttributeType, AttributeValues, Attributes, Children, Rule};
+
+pub(crate) const RULE: Rule = Rule {
+    tag: NAME,
+    attributes: Attributes::Only(&[&[
+        ("dir", AttributeType::Enum(&["ltr", "rtl", "auto"])),
+        ("lang", AttributeType::String),
+        ("owa", AttributeType::String),
+    ]]),
+    required: &[],
+    children: Children::Only(&[crate::mj_head::NAME, crate::mj_body::NAME]),
+};
+
+impl AttributeValues for MjmlAttributes {
+    fn attribute_value(&self, name: &str) -> Option<&str> {
+        match name {
+            "dir" => self.dir.as_deref(),
+            "lang" => self.lang.as_deref(),
+            "owa" => self.owa.as_deref(),
+            _ => None,
+        }
+    }
+}
@@ -4,6 +4,8 @@ mod json;
 mod print;
 #[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "render")]
+mod text;
 
 pub type Node<T> = crate::prelude::Component<String, crate::prelude::AttributeMap, Vec<T>>;
 
@@ -13,6 +15,8 @@ impl<N: Into<String>, T> From<N> for Node<T> {
             tag: tag.into(),
             attributes: crate::prelude::AttributeMap::new(),
             children: Vec::new(),
+            #[cfg(feature = "span")]
+            location: None,
         }
     }
 }
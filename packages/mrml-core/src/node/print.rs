@@ -4,6 +4,10 @@ use crate::prelude::print::{Printable, PrintableAttributes, PrintableChildren, P
 
 impl<T: Printable> Printable for Node<T> {
     fn print<P: Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        #[cfg(feature = "span")]
+        if printer.print_source(self.location.as_ref(), || self.print_dense())? {
+            return Ok(());
+        }
         printer.push_indent();
         let tag = self.tag.as_str();
         printer.open_tag(tag)?;
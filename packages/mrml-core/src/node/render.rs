@@ -16,10 +16,21 @@ where
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         cursor.buffer.open_tag(&self.element.tag);
+        let is_link = self.element.tag.eq_ignore_ascii_case("a");
         for (key, value) in self.element.attributes.iter() {
-            cursor
-                .buffer
-                .push_attribute(key.as_str(), value.as_deref())?;
+            match value.as_deref() {
+                Some(href) if is_link && key == "href" => {
+                    let href = self.context.rewrite_link(
+                        &self.element.tag,
+                        &self.element.attributes,
+                        href,
+                    );
+                    cursor
+                        .buffer
+                        .push_attribute(key.as_str(), Some(href.as_ref()))?;
+                }
+                value => cursor.buffer.push_attribute(key.as_str(), value)?,
+            }
         }
         if self.element.children.is_empty() {
             if is_void_element(self.element.tag.as_str()) || self.element.tag.contains(':') {
@@ -49,7 +60,7 @@ impl<'render, 'root: 'render, T: Renderable<'render, 'root>> Renderable<'render,
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Renderer::new(context, self, ()).boxed()
     }
 }
 
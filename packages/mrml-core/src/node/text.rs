@@ -0,0 +1,74 @@
+use super::Node;
+use crate::prelude::render::text::{decode_entities, get_attribute, TextBuffer, TextRender};
+
+fn is_block_element(tag: &str) -> bool {
+    matches!(
+        tag,
+        "address"
+            | "article"
+            | "blockquote"
+            | "center"
+            | "div"
+            | "footer"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "header"
+            | "ol"
+            | "p"
+            | "pre"
+            | "section"
+            | "table"
+            | "ul"
+    )
+}
+
+impl<T: TextRender> TextRender for Node<T> {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>) {
+        let tag = self.tag.to_ascii_lowercase();
+        match tag.as_str() {
+            "head" | "script" | "style" | "title" => {}
+            "br" => buffer.line_break(),
+            "hr" => {
+                buffer.end_block();
+                buffer.push_text("---");
+                buffer.end_block();
+            }
+            "img" => {
+                if let Some(alt) = get_attribute(&self.attributes, "alt") {
+                    buffer.push_text(&decode_entities(alt));
+                }
+            }
+            "a" => {
+                let start = buffer.position();
+                self.children.render_text(buffer);
+                let href = get_attribute(&self.attributes, "href");
+                buffer.push_link(start, &self.tag, &self.attributes, href);
+            }
+            "li" => {
+                buffer.start_line();
+                buffer.push_text("- ");
+                self.children.render_text(buffer);
+                buffer.start_line();
+            }
+            "tr" => {
+                buffer.start_row();
+                self.children.render_text(buffer);
+                buffer.start_line();
+            }
+            "td" | "th" => {
+                buffer.start_cell();
+                self.children.render_text(buffer);
+            }
+            other if is_block_element(other) => {
+                buffer.end_block();
+                self.children.render_text(buffer);
+                buffer.end_block();
+            }
+            _ => self.children.render_text(buffer),
+        }
+    }
+}
@@ -0,0 +1,551 @@
+//! Typed builders of the elements, to write templates in rust with the type of
+//! the attributes and the children allowed in each element checked by the
+//! compiler.
+//!
+//! Each element has a `builder` function, taking no argument except for the
+//! elements requiring an attribute or a content, like the `src` of `mj-image`
+//! or the css of `mj-style`. The builders can be given as children without
+//! being built.
+//!
+//! ```rust
+//! use mrml::mj_body::MjBody;
+//! use mrml::mj_column::MjColumn;
+//! use mrml::mj_section::MjSection;
+//! use mrml::mj_text::MjText;
+//! use mrml::mjml::Mjml;
+//! use mrml::prelude::builder::{Pixel, Size, Spacing, TextAlign};
+//!
+//! let root = Mjml::builder()
+//!     .body(
+//!         MjBody::builder().child(
+//!             MjSection::builder()
+//!                 .padding(Spacing::Two(Pixel::new(10.0), Pixel::new(0.0)))
+//!                 .background_color("#f0f0f0")
+//!                 .child(
+//!                     MjColumn::builder()
+//!                         .width(Size::percent(50.0))
+//!                         .child(MjText::builder().align(TextAlign::Center).text("Hello")),
+//!                 ),
+//!         ),
+//!     )
+//!     .build();
+//! assert_eq!(root.body().unwrap().children.len(), 1);
+//! ```
+
+use std::marker::PhantomData;
+
+pub use crate::helper::size::{Percent, Pixel, Size};
+pub use crate::helper::spacing::Spacing;
+use crate::prelude::{AttributeMap, Component};
+use crate::text::Text;
+
+/// Builder of an element, created with the `builder` function of the element.
+#[derive(Clone, Debug)]
+pub struct Builder<E> {
+    pub(crate) element: E,
+}
+
+impl<E> Builder<E> {
+    pub(crate) fn new(element: E) -> Self {
+        Self { element }
+    }
+
+    pub fn build(self) -> E {
+        self.element
+    }
+}
+
+/// Elements, and their builders, allowed as children of the element with the
+/// `Tag`, stored as `Child`.
+pub trait ChildOf<Tag, Child> {
+    fn into_child(self) -> Child;
+}
+
+impl<T, C, E: ChildOf<T, C>> ChildOf<T, C> for Builder<E> {
+    fn into_child(self) -> C {
+        self.element.into_child()
+    }
+}
+
+impl<T, A, C> Builder<Component<PhantomData<T>, A, Vec<C>>> {
+    pub fn child(mut self, child: impl ChildOf<T, C>) -> Self {
+        self.element.children.push(child.into_child());
+        self
+    }
+
+    /// Adds a text to the content of the element.
+    pub fn text(self, value: impl Into<String>) -> Self
+    where
+        Text: ChildOf<T, C>,
+    {
+        self.child(Text::from(value.into()))
+    }
+}
+
+impl<T, C> Builder<Component<PhantomData<T>, AttributeMap, C>> {
+    pub(crate) fn set(mut self, name: &str, value: String) -> Self {
+        self.element
+            .attributes
+            .insert(name.to_string(), Some(value));
+        self
+    }
+
+    pub(crate) fn unset(mut self, name: &str) -> Self {
+        self.element.attributes.remove(name);
+        self
+    }
+
+    /// Sets an attribute without any check on its value.
+    pub fn attribute(self, name: &str, value: impl Into<String>) -> Self {
+        self.set(name, value.into())
+    }
+
+    pub fn css_class(self, value: impl Into<String>) -> Self {
+        self.set("css-class", value.into())
+    }
+
+    pub fn mj_class(self, value: impl Into<String>) -> Self {
+        self.set("mj-class", value.into())
+    }
+}
+
+/// Value of an attribute that can also be `auto`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrAuto<T> {
+    Auto,
+    Value(T),
+}
+
+impl<T> From<T> for OrAuto<T> {
+    fn from(value: T) -> Self {
+        Self::Value(value)
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for OrAuto<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => f.write_str("auto"),
+            Self::Value(inner) => inner.fmt(f),
+        }
+    }
+}
+
+macro_rules! attribute_value {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $value:literal),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum $name {
+            $($variant),*
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value),*
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+attribute_value!(
+    /// Horizontal alignment of an element, or of its content.
+    Align {
+        Left => "left",
+        Center => "center",
+        Right => "right",
+    }
+);
+
+attribute_value!(
+    /// Alignment of a text, which can also be justified.
+    TextAlign {
+        Left => "left",
+        Center => "center",
+        Right => "right",
+        Justify => "justify",
+    }
+);
+
+attribute_value!(VerticalAlign {
+    Top => "top",
+    Middle => "middle",
+    Bottom => "bottom",
+});
+
+attribute_value!(Direction {
+    Ltr => "ltr",
+    Rtl => "rtl",
+});
+
+attribute_value!(BackgroundRepeat {
+    Repeat => "repeat",
+    NoRepeat => "no-repeat",
+});
+
+attribute_value!(IconPosition {
+    Left => "left",
+    Right => "right",
+});
+
+attribute_value!(TableLayout {
+    Auto => "auto",
+    Fixed => "fixed",
+});
+
+attribute_value!(TableRole {
+    None => "none",
+    Presentation => "presentation",
+});
+
+attribute_value!(
+    /// How the height of a `mj-hero` is computed.
+    HeroMode {
+        FluidHeight => "fluid-height",
+        FixedHeight => "fixed-height",
+    }
+);
+
+attribute_value!(SocialMode {
+    Horizontal => "horizontal",
+    Vertical => "vertical",
+});
+
+attribute_value!(Thumbnails {
+    Visible => "visible",
+    Hidden => "hidden",
+});
+
+/// Writes the setters of the attributes of an element, each one with the
+/// type of its value: `string` for any text, `value(T)` for a value written
+/// with its `Display` implementation and `into(T)` for anything converted into
+/// such a value.
+macro_rules! attributes {
+    ($($method:ident: $name:literal => $kind:ident $(($ty:ty))?),* $(,)?) => {
+        $($crate::prelude::builder::attributes!(@setter $method, $name, $kind $(, $ty)?);)*
+    };
+    (@setter $method:ident, $name:literal, string) => {
+        #[doc = concat!("Sets the `", $name, "` attribute.")]
+        pub fn $method(self, value: impl Into<String>) -> Self {
+            self.set($name, value.into())
+        }
+    };
+    (@setter $method:ident, $name:literal, value, $ty:ty) => {
+        #[doc = concat!("Sets the `", $name, "` attribute.")]
+        pub fn $method(self, value: $ty) -> Self {
+            self.set($name, value.to_string())
+        }
+    };
+    (@setter $method:ident, $name:literal, into, $ty:ty) => {
+        #[doc = concat!("Sets the `", $name, "` attribute.")]
+        pub fn $method(self, value: impl Into<$ty>) -> Self {
+            self.set($name, value.into().to_string())
+        }
+    };
+}
+
+pub(crate) use attributes;
+
+macro_rules! padding_attributes {
+    () => {
+        $crate::prelude::builder::attributes! {
+            padding: "padding" => value($crate::prelude::builder::Spacing),
+            padding_top: "padding-top" => value($crate::prelude::builder::Size),
+            padding_right: "padding-right" => value($crate::prelude::builder::Size),
+            padding_bottom: "padding-bottom" => value($crate::prelude::builder::Size),
+            padding_left: "padding-left" => value($crate::prelude::builder::Size),
+        }
+    };
+}
+
+pub(crate) use padding_attributes;
+
+macro_rules! border_attributes {
+    () => {
+        $crate::prelude::builder::attributes! {
+            border: "border" => string,
+            border_top: "border-top" => string,
+            border_right: "border-right" => string,
+            border_bottom: "border-bottom" => string,
+            border_left: "border-left" => string,
+        }
+    };
+}
+
+pub(crate) use border_attributes;
+
+macro_rules! font_attributes {
+    () => {
+        $crate::prelude::builder::attributes! {
+            color: "color" => string,
+            font_family: "font-family" => string,
+            font_size: "font-size" => value($crate::prelude::builder::Pixel),
+            font_style: "font-style" => string,
+            font_weight: "font-weight" => string,
+            letter_spacing: "letter-spacing" => string,
+            line_height: "line-height" => value($crate::prelude::builder::Size),
+            text_decoration: "text-decoration" => string,
+            text_transform: "text-transform" => string,
+        }
+    };
+}
+
+pub(crate) use font_attributes;
+
+/// Declares the elements allowed as children of the element with the tag, with
+/// the variant of the children type storing them.
+macro_rules! children {
+    ($tag:ty => $child:ident { $($variant:ident($element:ty)),* $(,)? }) => {
+        $(
+            impl $crate::prelude::builder::ChildOf<$tag, $child> for $element {
+                fn into_child(self) -> $child {
+                    $child::$variant(self)
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use children;
+
+#[cfg(all(test, feature = "parse", feature = "render"))]
+mod tests {
+    use super::{Align, OrAuto, Pixel, Size, Spacing, TextAlign};
+    use crate::mj_accordion::MjAccordion;
+    use crate::mj_accordion_element::MjAccordionElement;
+    use crate::mj_accordion_text::MjAccordionText;
+    use crate::mj_accordion_title::MjAccordionTitle;
+    use crate::mj_attributes::MjAttributes;
+    use crate::mj_attributes_all::MjAttributesAll;
+    use crate::mj_attributes_class::MjAttributesClass;
+    use crate::mj_attributes_element::MjAttributesElement;
+    use crate::mj_body::MjBody;
+    use crate::mj_breakpoint::MjBreakpoint;
+    use crate::mj_button::MjButton;
+    use crate::mj_carousel::MjCarousel;
+    use crate::mj_carousel_image::MjCarouselImage;
+    use crate::mj_column::MjColumn;
+    use crate::mj_divider::MjDivider;
+    use crate::mj_font::MjFont;
+    use crate::mj_group::MjGroup;
+    use crate::mj_head::MjHead;
+    use crate::mj_hero::MjHero;
+    use crate::mj_html_attributes::{MjHtmlAttribute, MjHtmlAttributes, MjSelector};
+    use crate::mj_image::MjImage;
+    use crate::mj_navbar::MjNavbar;
+    use crate::mj_navbar_link::MjNavbarLink;
+    use crate::mj_preview::MjPreview;
+    use crate::mj_section::MjSection;
+    use crate::mj_social::MjSocial;
+    use crate::mj_social_element::MjSocialElement;
+    use crate::mj_spacer::MjSpacer;
+    use crate::mj_style::MjStyle;
+    use crate::mj_table::MjTable;
+    use crate::mj_text::MjText;
+    use crate::mj_title::MjTitle;
+    use crate::mj_wrapper::MjWrapper;
+    use crate::mjml::Mjml;
+    use crate::prelude::render::RenderOptions;
+
+    #[test]
+    fn should_render_like_parsed_template() {
+        let template = r##"<mjml lang="fr">
+  <mj-head>
+    <mj-title>Title</mj-title>
+    <mj-preview>Preview</mj-preview>
+    <mj-breakpoint width="480px" />
+    <mj-font name="Raleway" href="https://fonts.googleapis.com/css?family=Raleway" />
+    <mj-attributes>
+      <mj-all font-family="Raleway" />
+      <mj-class name="blue" color="blue" />
+      <mj-text font-size="14px" />
+    </mj-attributes>
+    <mj-style inline="inline">.title { font-weight: bold; }</mj-style>
+    <mj-html-attributes>
+      <mj-selector path=".title div">
+        <mj-html-attribute name="data-id">42</mj-html-attribute>
+      </mj-selector>
+    </mj-html-attributes>
+  </mj-head>
+  <mj-body width="600px">
+    <mj-wrapper padding="10px 0px" full-width="full-width">
+      <mj-section background-color="#f0f0f0" text-align="left">
+        <mj-column width="50%">
+          <mj-text css-class="title" align="center" mj-class="blue">Hello</mj-text>
+          <mj-button href="https://example.com" padding-top="5px">Click</mj-button>
+          <mj-image src="https://example.com/logo.png" width="100px" height="auto" />
+        </mj-column>
+        <mj-column>
+          <mj-divider border-width="2px" />
+          <mj-spacer height="20px" />
+          <mj-table width="auto"><tr><td>Cell</td></tr></mj-table>
+        </mj-column>
+      </mj-section>
+    </mj-wrapper>
+    <mj-hero background-color="#000" mode="fixed-height">
+      <mj-social align="left">
+        <mj-social-element name="facebook" href="https://facebook.com">Facebook</mj-social-element>
+      </mj-social>
+      <mj-navbar>
+        <mj-navbar-link href="/about">About</mj-navbar-link>
+      </mj-navbar>
+    </mj-hero>
+    <mj-section>
+      <mj-group>
+        <mj-column>
+          <mj-accordion>
+            <mj-accordion-element>
+              <mj-accordion-title>Question</mj-accordion-title>
+              <mj-accordion-text>Answer</mj-accordion-text>
+            </mj-accordion-element>
+          </mj-accordion>
+          <mj-carousel>
+            <mj-carousel-image src="https://example.com/a.png" />
+          </mj-carousel>
+        </mj-column>
+      </mj-group>
+    </mj-section>
+  </mj-body>
+</mjml>"##;
+        let built = Mjml::builder()
+            .lang("fr")
+            .head(
+                MjHead::builder()
+                    .child(MjTitle::builder("Title"))
+                    .child(MjPreview::builder("Preview"))
+                    .child(MjBreakpoint::builder(Pixel::new(480.0)))
+                    .child(MjFont::builder(
+                        "Raleway",
+                        "https://fonts.googleapis.com/css?family=Raleway",
+                    ))
+                    .child(
+                        MjAttributes::builder()
+                            .child(MjAttributesAll::builder().attribute("font-family", "Raleway"))
+                            .child(MjAttributesClass::builder("blue").attribute("color", "blue"))
+                            .child(
+                                MjAttributesElement::builder("mj-text")
+                                    .attribute("font-size", "14px"),
+                            ),
+                    )
+                    .child(MjStyle::builder(".title { font-weight: bold; }").inline(true))
+                    .child(
+                        MjHtmlAttributes::builder().child(
+                            MjSelector::builder(".title div")
+                                .child(MjHtmlAttribute::builder("data-id", "42")),
+                        ),
+                    ),
+            )
+            .body(
+                MjBody::builder()
+                    .width(Pixel::new(600.0))
+                    .child(
+                        MjWrapper::builder()
+                            .padding(Spacing::Two(Pixel::new(10.0), Pixel::new(0.0)))
+                            .full_width(true)
+                            .child(
+                                MjSection::builder()
+                                    .background_color("#f0f0f0")
+                                    .text_align(Align::Left)
+                                    .child(
+                                        MjColumn::builder()
+                                            .width(Size::percent(50.0))
+                                            .child(
+                                                MjText::builder()
+                                                    .css_class("title")
+                                                    .align(TextAlign::Center)
+                                                    .mj_class("blue")
+                                                    .text("Hello"),
+                                            )
+                                            .child(
+                                                MjButton::builder()
+                                                    .href("https://example.com")
+                                                    .padding_top(Size::pixel(5.0))
+                                                    .text("Click"),
+                                            )
+                                            .child(
+                                                MjImage::builder("https://example.com/logo.png")
+                                                    .width(Pixel::new(100.0))
+                                                    .height(OrAuto::Auto),
+                                            ),
+                                    )
+                                    .child(
+                                        MjColumn::builder()
+                                            .child(
+                                                MjDivider::builder().border_width(Pixel::new(2.0)),
+                                            )
+                                            .child(MjSpacer::builder().height(Size::pixel(20.0)))
+                                            .child(
+                                                MjTable::builder()
+                                                    .width(OrAuto::Auto)
+                                                    .text("<tr><td>Cell</td></tr>"),
+                                            ),
+                                    ),
+                            ),
+                    )
+                    .child(
+                        MjHero::builder()
+                            .background_color("#000")
+                            .mode(super::HeroMode::FixedHeight)
+                            .child(
+                                MjSocial::builder().align(Align::Left).child(
+                                    MjSocialElement::builder()
+                                        .name("facebook")
+                                        .href("https://facebook.com")
+                                        .text("Facebook"),
+                                ),
+                            )
+                            .child(
+                                MjNavbar::builder()
+                                    .child(MjNavbarLink::builder().href("/about").text("About")),
+                            ),
+                    )
+                    .child(
+                        MjSection::builder().child(
+                            MjGroup::builder().child(
+                                MjColumn::builder()
+                                    .child(
+                                        MjAccordion::builder().child(
+                                            MjAccordionElement::builder()
+                                                .title(MjAccordionTitle::builder().text("Question"))
+                                                .text(MjAccordionText::builder().text("Answer")),
+                                        ),
+                                    )
+                                    .child(MjCarousel::builder().child(MjCarouselImage::builder(
+                                        "https://example.com/a.png",
+                                    ))),
+                            ),
+                        ),
+                    ),
+            )
+            .build();
+        let parsed = Mjml::parse(template).unwrap();
+        let opts = RenderOptions::default();
+        assert_eq!(
+            built.render(&opts).unwrap(),
+            parsed.element.render(&opts).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_remove_full_width() {
+        let section = MjSection::builder().full_width(true).build();
+        assert_eq!(
+            section.attributes.get("full-width"),
+            Some(&Some("full-width".to_string()))
+        );
+        let section = MjSection::builder()
+            .full_width(true)
+            .full_width(false)
+            .build();
+        assert!(section.attributes.get("full-width").is_none());
+        let wrapper = MjWrapper::builder().full_width(false).build();
+        assert!(wrapper.attributes.is_empty());
+    }
+}
@@ -11,7 +11,7 @@ type HashImpl = std::hash::BuildHasherDefault<FxHasher>;
 
 pub type MapImpl<K, V> = IndexMap<K, V, HashImpl>;
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "json", serde(transparent))]
 pub struct Map<K, V>(MapImpl<K, V>)
@@ -1,3 +1,6 @@
+#[cfg(feature = "json-schema")]
+pub mod schema;
+
 use std::hash::Hash;
 use std::marker::PhantomData;
 
@@ -250,6 +253,8 @@ where
             tag: rtype,
             attributes: Attributes::try_from_serde(attributes)?,
             children: Children::try_from_serde(children)?,
+            #[cfg(feature = "span")]
+            location: None,
         })
     }
 }
@@ -0,0 +1,446 @@
+use serde_json::{Map, Value};
+
+use super::{element, is_known_tag, Attributes, Children, Def, Element, RAW};
+use crate::mjml::Mjml;
+use crate::prelude::validate::DiagnosticKind;
+
+/// Value of a json template that doesn't match the schema.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("{message} at {pointer:?}")]
+pub struct Error {
+    /// [JSON pointer](https://www.rfc-editor.org/rfc/rfc6901) of the value,
+    /// empty for the root of the template.
+    pub pointer: String,
+    pub message: String,
+}
+
+impl Error {
+    fn new(pointer: &str, message: impl ToString) -> Self {
+        Self {
+            pointer: pointer.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+fn child(pointer: &str, segment: &str) -> String {
+    format!(
+        "{pointer}/{}",
+        segment.replace('~', "~0").replace('/', "~1")
+    )
+}
+
+fn as_object<'v>(value: &'v Value, pointer: &str) -> Result<&'v Map<String, Value>, Error> {
+    value
+        .as_object()
+        .ok_or_else(|| Error::new(pointer, "expected an object"))
+}
+
+fn as_string<'v>(value: &'v Value, pointer: &str) -> Result<&'v str, Error> {
+    value
+        .as_str()
+        .ok_or_else(|| Error::new(pointer, "expected a string"))
+}
+
+fn as_array<'v>(value: &'v Value, pointer: &str) -> Result<&'v Vec<Value>, Error> {
+    value
+        .as_array()
+        .ok_or_else(|| Error::new(pointer, "expected an array"))
+}
+
+/// Checks that the object only has the given properties.
+fn check_properties(
+    object: &Map<String, Value>,
+    pointer: &str,
+    allowed: &[&str],
+) -> Result<(), Error> {
+    match object.keys().find(|key| !allowed.contains(&key.as_str())) {
+        Some(key) => Err(Error::new(
+            &child(pointer, key),
+            format!("unexpected property {key:?}"),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn check_tag<'v>(object: &'v Map<String, Value>, pointer: &str) -> Result<&'v str, Error> {
+    let value = object
+        .get("type")
+        .ok_or_else(|| Error::new(pointer, "missing property \"type\""))?;
+    as_string(value, &child(pointer, "type"))
+}
+
+/// Checks attributes that can have any name, like the ones of html elements.
+fn check_any_attributes(value: &Value, pointer: &str) -> Result<(), Error> {
+    for (name, value) in as_object(value, pointer)? {
+        if !value.is_null() {
+            as_string(value, &child(pointer, name))?;
+        }
+    }
+    Ok(())
+}
+
+fn check_include_attributes(value: &Value, pointer: &str, css: bool) -> Result<(), Error> {
+    let object = as_object(value, pointer)?;
+    check_properties(object, pointer, &["path", "type"])?;
+    match object.get("path") {
+        Some(path) => as_string(path, &child(pointer, "path")).map(|_| ())?,
+        None => {
+            return Err(Error::new(
+                pointer,
+                DiagnosticKind::MissingAttribute {
+                    tag: crate::mj_include::NAME.to_string(),
+                    name: "path",
+                },
+            ))
+        }
+    };
+    let Some(kind) = object.get("type") else {
+        return Ok(());
+    };
+    let is_css = kind
+        .as_object()
+        .filter(|inner| inner.len() == 1)
+        .and_then(|inner| inner.get("css"))
+        .and_then(Value::as_object)
+        .filter(|inner| inner.len() == 1)
+        .and_then(|inner| inner.get("inline"))
+        .is_some_and(Value::is_boolean);
+    match kind.as_str() {
+        Some("mjml" | "html") => Ok(()),
+        None if css && is_css => Ok(()),
+        _ => Err(Error::new(
+            &child(pointer, "type"),
+            DiagnosticKind::InvalidAttributeValue {
+                tag: crate::mj_include::NAME.to_string(),
+                name: "type".to_string(),
+                expected: if css {
+                    "mjml, html or an object with the css inline mode".to_string()
+                } else {
+                    "mjml or html".to_string()
+                },
+            },
+        )),
+    }
+}
+
+fn check_attributes(element: &Element, value: Option<&Value>, pointer: &str) -> Result<(), Error> {
+    let (types, required) = element.attribute_types();
+    let Some(value) = value else {
+        return match required.first() {
+            Some(name) => Err(Error::new(
+                pointer,
+                DiagnosticKind::MissingAttribute {
+                    tag: element.tag.to_string(),
+                    name,
+                },
+            )),
+            None => Ok(()),
+        };
+    };
+    let pointer = child(pointer, "attributes");
+    let object = as_object(value, &pointer)?;
+    let nullable = matches!(element.attributes, Attributes::Map);
+    for (name, value) in object {
+        let attribute_pointer = child(&pointer, name);
+        let kind = types
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, kind)| kind);
+        let accepted = match (kind, value) {
+            (None, _) if !element.accepts_any_attribute() => {
+                return Err(Error::new(
+                    &attribute_pointer,
+                    DiagnosticKind::UnknownAttribute {
+                        tag: element.tag.to_string(),
+                        name: name.clone(),
+                    },
+                ))
+            }
+            (_, Value::Null) if !nullable || required.contains(&name.as_str()) => false,
+            (None, Value::Null) => true,
+            (Some(kind), Value::Null) => kind.accepts(None),
+            (None, Value::String(_)) => true,
+            (Some(kind), Value::String(inner)) => kind.accepts(Some(inner)),
+            _ => return Err(Error::new(&attribute_pointer, "expected a string")),
+        };
+        if !accepted {
+            return Err(Error::new(
+                &attribute_pointer,
+                DiagnosticKind::InvalidAttributeValue {
+                    tag: element.tag.to_string(),
+                    name: name.clone(),
+                    expected: kind
+                        .map(|kind| kind.expected())
+                        .unwrap_or_else(|| "a string".to_string()),
+                },
+            ));
+        }
+    }
+    match required.iter().find(|name| !object.contains_key(**name)) {
+        Some(name) => Err(Error::new(
+            &pointer,
+            DiagnosticKind::MissingAttribute {
+                tag: element.tag.to_string(),
+                name,
+            },
+        )),
+        None => Ok(()),
+    }
+}
+
+fn check_children(parent: &str, defs: &[Def], value: &Value, pointer: &str) -> Result<(), Error> {
+    for (index, item) in as_array(value, pointer)?.iter().enumerate() {
+        check_child(parent, defs, item, &child(pointer, &index.to_string()))?;
+    }
+    Ok(())
+}
+
+/// Checks a child of the `parent` element, that can be any of the `defs`.
+fn check_child(parent: &str, defs: &[Def], value: &Value, pointer: &str) -> Result<(), Error> {
+    match value {
+        Value::String(_) if defs.contains(&Def::Text) => Ok(()),
+        Value::String(_) => Err(Error::new(
+            pointer,
+            format!("text is not allowed in {parent:?}"),
+        )),
+        Value::Object(object) => {
+            let tag = check_tag(object, pointer)?;
+            if let Some(def) = defs.iter().find(|def| def.tag() == Some(tag)) {
+                check_def(*def, value, pointer)
+            } else if is_known_tag(tag) {
+                Err(Error::new(
+                    pointer,
+                    DiagnosticKind::UnexpectedChild {
+                        parent: parent.to_string(),
+                        tag: tag.to_string(),
+                    },
+                ))
+            } else if defs.contains(&Def::Node) {
+                check_def(Def::Node, value, pointer)
+            } else {
+                Err(Error::new(
+                    pointer,
+                    DiagnosticKind::UnknownElement {
+                        tag: tag.to_string(),
+                    },
+                ))
+            }
+        }
+        _ => Err(Error::new(pointer, "expected a string or an object")),
+    }
+}
+
+fn check_element(element: &Element, value: &Value, pointer: &str) -> Result<(), Error> {
+    let object = as_object(value, pointer)?;
+    let tag = check_tag(object, pointer)?;
+    if tag != element.tag {
+        return Err(Error::new(
+            &child(pointer, "type"),
+            format!("expected {:?}", element.tag),
+        ));
+    }
+    let mut allowed = vec!["type"];
+    if !matches!(element.attributes, Attributes::None) {
+        allowed.push("attributes");
+    }
+    if !matches!(element.children, Children::None) {
+        allowed.push("children");
+    }
+    check_properties(object, pointer, &allowed)?;
+
+    let attributes = object.get("attributes");
+    match (element.attributes, attributes) {
+        (Attributes::Include { css }, Some(value)) => {
+            check_include_attributes(value, &child(pointer, "attributes"), css)?
+        }
+        (Attributes::Include { .. }, None) => {
+            return Err(Error::new(pointer, "missing property \"attributes\""))
+        }
+        (Attributes::None, _) => {}
+        (Attributes::Map | Attributes::Fields(_), _) => {
+            check_attributes(element, attributes, pointer)?
+        }
+    }
+
+    let Some(children) = object.get("children") else {
+        return Ok(());
+    };
+    let pointer = child(pointer, "children");
+    match element.children {
+        Children::None => Ok(()),
+        Children::String => as_string(children, &pointer).map(|_| ()),
+        Children::Texts => check_children(element.tag, &[Def::Text], children, &pointer),
+        Children::OneOrMany if !children.is_array() => {
+            check_child(element.tag, &element.children_defs(), children, &pointer)
+        }
+        Children::OneOrMany | Children::Raw | Children::Only(_) | Children::Rule => {
+            check_children(element.tag, &element.children_defs(), children, &pointer)
+        }
+    }
+}
+
+fn check_def(def: Def, value: &Value, pointer: &str) -> Result<(), Error> {
+    match def {
+        Def::Text => as_string(value, pointer).map(|_| ()),
+        Def::Comment => {
+            let object = as_object(value, pointer)?;
+            check_properties(object, pointer, &["type", "children"])?;
+            match object.get("children") {
+                Some(children) => as_string(children, &child(pointer, "children")).map(|_| ()),
+                None => Ok(()),
+            }
+        }
+        Def::Node => {
+            let object = as_object(value, pointer)?;
+            check_properties(object, pointer, &["type", "attributes", "children"])?;
+            let tag = check_tag(object, pointer)?;
+            if let Some(attributes) = object.get("attributes") {
+                check_any_attributes(attributes, &child(pointer, "attributes"))?;
+            }
+            match object.get("children") {
+                Some(children) => check_children(tag, RAW, children, &child(pointer, "children")),
+                None => Ok(()),
+            }
+        }
+        Def::MjAttributesElement => {
+            let object = as_object(value, pointer)?;
+            check_properties(object, pointer, &["type", "name", "attributes"])?;
+            let name = object
+                .get("name")
+                .ok_or_else(|| Error::new(pointer, "missing property \"name\""))?;
+            as_string(name, &child(pointer, "name"))?;
+            match object.get("attributes") {
+                Some(attributes) => check_any_attributes(attributes, &child(pointer, "attributes")),
+                None => Ok(()),
+            }
+        }
+        Def::Element(name) => check_element(element(name), value, pointer),
+    }
+}
+
+/// Checks a json template against the schema, returning the first value that
+/// doesn't match it.
+pub fn check(value: &Value) -> Result<(), Error> {
+    check_def(Def::Element("Mjml"), value, "")
+}
+
+/// Deserializes a json template, after checking it against the schema so that
+/// the error points to the value that doesn't match.
+pub fn from_str(input: &str) -> Result<Mjml, Error> {
+    let value: Value = serde_json::from_str(input).map_err(|err| Error::new("", err))?;
+    check(&value)?;
+    // deserialized from the input to keep the order of the attributes
+    serde_json::from_str(input).map_err(|err| Error::new("", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{check, from_str};
+
+    fn template(body: serde_json::Value) -> serde_json::Value {
+        json!({ "type": "mjml", "children": [{ "type": "mj-body", "children": body }] })
+    }
+
+    #[test]
+    fn should_accept_formatted_template() {
+        let template = include_str!("../../../../resources/compare/success/mj-accordion.mjml");
+        let root = crate::parse(template).unwrap();
+        let json = serde_json::to_value(&root.element).unwrap();
+        check(&json).unwrap();
+    }
+
+    #[test]
+    fn should_point_to_unexpected_child() {
+        let value =
+            template(json!([{ "type": "mj-section", "children": [{ "type": "mj-text" }] }]));
+        let error = check(&value).unwrap_err();
+        assert_eq!(error.pointer, "/children/0/children/0/children/0");
+        assert_eq!(
+            error.message,
+            "element \"mj-text\" is not allowed in \"mj-section\""
+        );
+    }
+
+    #[test]
+    fn should_point_to_invalid_attribute() {
+        let value = template(json!([{
+            "type": "mj-section",
+            "attributes": { "padding": "nope" },
+        }]));
+        let error = check(&value).unwrap_err();
+        assert_eq!(error.pointer, "/children/0/children/0/attributes/padding");
+
+        let value = template(json!([{
+            "type": "mj-section",
+            "attributes": { "foo/bar": "nope" },
+        }]));
+        let error = check(&value).unwrap_err();
+        assert_eq!(error.pointer, "/children/0/children/0/attributes/foo~1bar");
+    }
+
+    #[test]
+    fn should_point_to_missing_attribute() {
+        let value = template(json!([{
+            "type": "mj-section",
+            "children": [{ "type": "mj-column", "children": [{ "type": "mj-image" }] }],
+        }]));
+        let error = check(&value).unwrap_err();
+        assert_eq!(
+            error.pointer,
+            "/children/0/children/0/children/0/children/0"
+        );
+        assert_eq!(
+            error.message,
+            "missing attribute \"src\" in element \"mj-image\""
+        );
+    }
+
+    #[test]
+    fn should_check_html_content() {
+        let value = template(json!([{
+            "type": "mj-section",
+            "children": [{ "type": "mj-column", "children": [{
+                "type": "mj-text",
+                "children": ["Hello", { "type": "b", "children": [{ "type": "mj-button" }] }],
+            }] }],
+        }]));
+        let error = check(&value).unwrap_err();
+        assert_eq!(
+            error.pointer,
+            "/children/0/children/0/children/0/children/0/children/1/children/0"
+        );
+    }
+
+    #[test]
+    fn should_check_include_type() {
+        let head = |kind: serde_json::Value| {
+            json!({ "type": "mjml", "children": [{ "type": "mj-head", "children": [{
+                "type": "mj-include",
+                "attributes": { "path": "style.css", "type": kind },
+            }] }] })
+        };
+        check(&head(json!({ "css": { "inline": false } }))).unwrap();
+        check(&head(json!("html"))).unwrap();
+        let error = check(&head(json!("css"))).unwrap_err();
+        assert_eq!(error.pointer, "/children/0/children/0/attributes/type");
+        let error = check(&template(json!([{
+            "type": "mj-include",
+            "attributes": { "path": "style.css", "type": { "css": { "inline": false } } },
+        }])))
+        .unwrap_err();
+        assert_eq!(error.pointer, "/children/0/children/0/attributes/type");
+    }
+
+    #[test]
+    fn should_deserialize_checked_template() {
+        let error = from_str("{").unwrap_err();
+        assert_eq!(error.pointer, "");
+        let error = from_str(r#"{"type":"mj-body"}"#).unwrap_err();
+        assert_eq!(error.pointer, "/type");
+        let root = from_str(r#"{"type":"mjml","attributes":{"lang":"fr"}}"#).unwrap();
+        assert_eq!(root.attributes.lang.as_deref(), Some("fr"));
+    }
+}
@@ -0,0 +1,737 @@
+//! Description of the json format of the templates, as a
+//! [JSON Schema](https://json-schema.org/) or as typescript definitions, with
+//! the attributes and the children allowed in each element.
+//!
+//! The attributes and the children are the ones known by the validation of
+//! the templates, limited to what the json format can hold.
+//!
+//! ```rust
+//! use mrml::prelude::json::schema;
+//!
+//! let json = r#"{"type":"mjml","children":[{"type":"mj-body","children":[{"type":"mj-image"}]}]}"#;
+//! let error = schema::from_str(json).unwrap_err();
+//! // mj-image is not allowed in mj-body
+//! assert_eq!(error.pointer, "/children/0/children/0");
+//!
+//! let json = r#"{"type":"mjml","children":[{"type":"mj-body","children":[{"type":"mj-section"}]}]}"#;
+//! let root = schema::from_str(json).unwrap();
+//! assert!(root.body().is_some());
+//! ```
+
+mod check;
+mod typescript;
+
+use serde_json::{json, Map, Value};
+
+pub use self::check::{check, from_str, Error};
+pub use self::typescript::typescript;
+use crate::prelude::validate::{
+    find_defaults_rule, find_rule, AttributeType, Rule, GLOBAL_ATTRIBUTES,
+};
+
+/// How the attributes of an element are stored.
+#[derive(Clone, Copy)]
+enum Attributes {
+    /// The element has no attribute.
+    None,
+    /// A map of the attributes, that can have no value, checked against the
+    /// validation rule of the element.
+    Map,
+    /// A struct of the attributes checked against the validation rule of the
+    /// element, where the given fields are required.
+    Fields(&'static [&'static str]),
+    /// The path and type of `mj-include`, where the `css` type is only
+    /// allowed in `mj-head`.
+    Include { css: bool },
+}
+
+/// What can be found in the children of an element.
+#[derive(Clone, Copy)]
+enum Children {
+    None,
+    /// A single string.
+    String,
+    /// A string, a comment, or a list of strings and comments.
+    OneOrMany,
+    /// A list of strings.
+    Texts,
+    /// A list of strings, comments and html elements.
+    Raw,
+    /// A list of the given definitions.
+    Only(&'static [Def]),
+    /// A list of the components allowed by the validation rule of the
+    /// element, comments and `mj-include`.
+    Rule,
+}
+
+/// A definition of the schema, named after its rust type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Def {
+    Text,
+    Comment,
+    /// Any html element.
+    Node,
+    /// The default attributes of an element, in `mj-attributes`.
+    MjAttributesElement,
+    Element(&'static str),
+}
+
+struct Element {
+    name: &'static str,
+    tag: &'static str,
+    attributes: Attributes,
+    children: Children,
+}
+
+const RAW: &[Def] = &[Def::Text, Def::Comment, Def::Node];
+
+const ELEMENTS: &[Element] = &[
+    Element {
+        name: "Mjml",
+        tag: crate::mjml::NAME,
+        attributes: Attributes::Fields(&[]),
+        children: Children::Only(&[Def::Element("MjHead"), Def::Element("MjBody")]),
+    },
+    Element {
+        name: "MjHead",
+        tag: crate::mj_head::NAME,
+        attributes: Attributes::None,
+        children: Children::Only(&[
+            Def::Comment,
+            Def::Element("MjAttributes"),
+            Def::Element("MjBreakpoint"),
+            Def::Element("MjFont"),
+            Def::Element("MjHtmlAttributes"),
+            Def::Element("MjIncludeHead"),
+            Def::Element("MjPreview"),
+            Def::Element("MjRaw"),
+            Def::Element("MjStyle"),
+            Def::Element("MjTitle"),
+        ]),
+    },
+    Element {
+        name: "MjAttributes",
+        tag: crate::mj_attributes::NAME,
+        attributes: Attributes::None,
+        children: Children::Only(&[
+            Def::Element("MjAttributesAll"),
+            Def::Element("MjAttributesClass"),
+            Def::MjAttributesElement,
+        ]),
+    },
+    Element {
+        name: "MjAttributesAll",
+        tag: crate::mj_attributes_all::NAME,
+        attributes: Attributes::Map,
+        children: Children::None,
+    },
+    Element {
+        name: "MjAttributesClass",
+        tag: crate::mj_attributes_class::NAME,
+        attributes: Attributes::Map,
+        children: Children::None,
+    },
+    Element {
+        name: "MjBreakpoint",
+        tag: crate::mj_breakpoint::NAME,
+        attributes: Attributes::Fields(&["width"]),
+        children: Children::None,
+    },
+    Element {
+        name: "MjFont",
+        tag: crate::mj_font::NAME,
+        attributes: Attributes::Fields(&["name", "href"]),
+        children: Children::None,
+    },
+    Element {
+        name: "MjHtmlAttributes",
+        tag: crate::mj_html_attributes::NAME,
+        attributes: Attributes::None,
+        children: Children::Only(&[Def::Element("MjSelector")]),
+    },
+    Element {
+        name: "MjSelector",
+        tag: crate::mj_html_attributes::SELECTOR_NAME,
+        attributes: Attributes::Fields(&["path"]),
+        children: Children::Only(&[Def::Element("MjHtmlAttribute")]),
+    },
+    Element {
+        name: "MjHtmlAttribute",
+        tag: crate::mj_html_attributes::ATTRIBUTE_NAME,
+        attributes: Attributes::Fields(&["name"]),
+        children: Children::String,
+    },
+    Element {
+        name: "MjIncludeHead",
+        tag: crate::mj_include::NAME,
+        attributes: Attributes::Include { css: true },
+        children: Children::Only(&[
+            Def::Comment,
+            Def::Element("MjAttributes"),
+            Def::Element("MjBreakpoint"),
+            Def::Element("MjFont"),
+            Def::Element("MjHtmlAttributes"),
+            Def::Element("MjPreview"),
+            Def::Element("MjRaw"),
+            Def::Element("MjStyle"),
+            Def::Element("MjTitle"),
+            Def::Text,
+        ]),
+    },
+    Element {
+        name: "MjPreview",
+        tag: crate::mj_preview::NAME,
+        attributes: Attributes::None,
+        children: Children::OneOrMany,
+    },
+    Element {
+        name: "MjRaw",
+        tag: crate::mj_raw::NAME,
+        attributes: Attributes::None,
+        children: Children::Raw,
+    },
+    Element {
+        name: "MjStyle",
+        tag: crate::mj_style::NAME,
+        attributes: Attributes::Fields(&[]),
+        children: Children::String,
+    },
+    Element {
+        name: "MjTitle",
+        tag: crate::mj_title::NAME,
+        attributes: Attributes::None,
+        children: Children::String,
+    },
+    Element {
+        name: "MjBody",
+        tag: crate::mj_body::NAME,
+        attributes: Attributes::Map,
+        children: Children::Rule,
+    },
+    Element {
+        name: "MjIncludeBody",
+        tag: crate::mj_include::NAME,
+        attributes: Attributes::Include { css: false },
+        children: Children::Only(&[
+            Def::Comment,
+            Def::Element("MjAccordion"),
+            Def::Element("MjButton"),
+            Def::Element("MjCarousel"),
+            Def::Element("MjColumn"),
+            Def::Element("MjDivider"),
+            Def::Element("MjGroup"),
+            Def::Element("MjHero"),
+            Def::Element("MjImage"),
+            Def::Element("MjNavbar"),
+            Def::Element("MjRaw"),
+            Def::Element("MjSection"),
+            Def::Element("MjSocial"),
+            Def::Element("MjSpacer"),
+            Def::Element("MjTable"),
+            Def::Element("MjText"),
+            Def::Element("MjWrapper"),
+            Def::Node,
+            Def::Text,
+        ]),
+    },
+    Element {
+        name: "MjAccordion",
+        tag: crate::mj_accordion::NAME,
+        attributes: Attributes::Map,
+        children: Children::Only(&[Def::Comment, Def::Element("MjAccordionElement")]),
+    },
+    Element {
+        name: "MjAccordionElement",
+        tag: crate::mj_accordion_element::NAME,
+        attributes: Attributes::Map,
+        children: Children::Only(&[
+            Def::Comment,
+            Def::Element("MjAccordionTitle"),
+            Def::Element("MjAccordionText"),
+        ]),
+    },
+    Element {
+        name: "MjAccordionTitle",
+        tag: crate::mj_accordion_title::NAME,
+        attributes: Attributes::Map,
+        children: Children::Texts,
+    },
+    Element {
+        name: "MjAccordionText",
+        tag: crate::mj_accordion_text::NAME,
+        attributes: Attributes::Map,
+        children: Children::Raw,
+    },
+    Element {
+        name: "MjButton",
+        tag: crate::mj_button::NAME,
+        attributes: Attributes::Map,
+        children: Children::Raw,
+    },
+    Element {
+        name: "MjCarousel",
+        tag: crate::mj_carousel::NAME,
+        attributes: Attributes::Map,
+        children: Children::Only(&[Def::Comment, Def::Element("MjCarouselImage")]),
+    },
+    Element {
+        name: "MjCarouselImage",
+        tag: crate::mj_carousel_image::NAME,
+        attributes: Attributes::Map,
+        children: Children::None,
+    },
+    Element {
+        name: "MjColumn",
+        tag: crate::mj_column::NAME,
+        attributes: Attributes::Map,
+        children: Children::Rule,
+    },
+    Element {
+        name: "MjDivider",
+        tag: crate::mj_divider::NAME,
+        attributes: Attributes::Map,
+        children: Children::None,
+    },
+    Element {
+        name: "MjGroup",
+        tag: crate::mj_group::NAME,
+        attributes: Attributes::Map,
+        children: Children::Rule,
+    },
+    Element {
+        name: "MjHero",
+        tag: crate::mj_hero::NAME,
+        attributes: Attributes::Map,
+        children: Children::Rule,
+    },
+    Element {
+        name: "MjImage",
+        tag: crate::mj_image::NAME,
+        attributes: Attributes::Map,
+        children: Children::None,
+    },
+    Element {
+        name: "MjNavbar",
+        tag: crate::mj_navbar::NAME,
+        attributes: Attributes::Map,
+        children: Children::Only(&[Def::Comment, Def::Element("MjNavbarLink")]),
+    },
+    Element {
+        name: "MjNavbarLink",
+        tag: crate::mj_navbar_link::NAME,
+        attributes: Attributes::Map,
+        children: Children::Raw,
+    },
+    Element {
+        name: "MjSection",
+        tag: crate::mj_section::NAME,
+        attributes: Attributes::Map,
+        children: Children::Rule,
+    },
+    Element {
+        name: "MjSocial",
+        tag: crate::mj_social::NAME,
+        attributes: Attributes::Map,
+        children: Children::Only(&[Def::Comment, Def::Element("MjSocialElement")]),
+    },
+    Element {
+        name: "MjSocialElement",
+        tag: crate::mj_social_element::NAME,
+        attributes: Attributes::Map,
+        children: Children::Raw,
+    },
+    Element {
+        name: "MjSpacer",
+        tag: crate::mj_spacer::NAME,
+        attributes: Attributes::Map,
+        children: Children::None,
+    },
+    Element {
+        name: "MjTable",
+        tag: crate::mj_table::NAME,
+        attributes: Attributes::Map,
+        children: Children::Raw,
+    },
+    Element {
+        name: "MjText",
+        tag: crate::mj_text::NAME,
+        attributes: Attributes::Map,
+        children: Children::Raw,
+    },
+    Element {
+        name: "MjWrapper",
+        tag: crate::mj_wrapper::NAME,
+        attributes: Attributes::Map,
+        children: Children::Rule,
+    },
+];
+
+const COMMENT_TAG: &str = "comment";
+const ATTRIBUTES_ELEMENT_TAG: &str = "mj-element";
+
+fn element(name: &str) -> &'static Element {
+    ELEMENTS
+        .iter()
+        .find(|item| item.name == name)
+        .expect("the definitions should only reference known elements")
+}
+
+/// Whether the tag is the one of a mjml element, and not of an html one.
+fn is_known_tag(tag: &str) -> bool {
+    tag == COMMENT_TAG
+        || tag == ATTRIBUTES_ELEMENT_TAG
+        || ELEMENTS.iter().any(|item| item.tag == tag)
+}
+
+impl Def {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Text => "Text",
+            Self::Comment => "Comment",
+            Self::Node => "Node",
+            Self::MjAttributesElement => "MjAttributesElement",
+            Self::Element(name) => name,
+        }
+    }
+
+    /// The `type` of the json objects of the definition, `None` for the
+    /// strings and the html elements.
+    fn tag(&self) -> Option<&'static str> {
+        match self {
+            Self::Text | Self::Node => None,
+            Self::Comment => Some(COMMENT_TAG),
+            Self::MjAttributesElement => Some(ATTRIBUTES_ELEMENT_TAG),
+            Self::Element(name) => Some(element(name).tag),
+        }
+    }
+
+    fn all() -> impl Iterator<Item = Def> {
+        [
+            Self::Text,
+            Self::Comment,
+            Self::Node,
+            Self::MjAttributesElement,
+        ]
+        .into_iter()
+        .chain(ELEMENTS.iter().map(|item| Self::Element(item.name)))
+    }
+}
+
+impl Element {
+    fn rule(&self) -> Option<&'static Rule> {
+        find_rule(self.tag).or_else(|| find_defaults_rule(self.tag))
+    }
+
+    /// The definitions allowed in a list of children.
+    fn children_defs(&self) -> Vec<Def> {
+        match self.children {
+            Children::None | Children::String | Children::Texts => Vec::new(),
+            Children::OneOrMany => vec![Def::Text, Def::Comment],
+            Children::Raw => RAW.to_vec(),
+            Children::Only(defs) => defs.to_vec(),
+            Children::Rule => {
+                let allowed = self
+                    .rule()
+                    .and_then(|rule| match rule.children {
+                        crate::prelude::validate::Children::Only(list) => Some(list),
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+                [Def::Comment, Def::Element("MjIncludeBody")]
+                    .into_iter()
+                    .chain(allowed.iter().filter_map(|tag| {
+                        ELEMENTS
+                            .iter()
+                            .find(|item| item.tag == *tag)
+                            .map(|item| Def::Element(item.name))
+                    }))
+                    .collect()
+            }
+        }
+    }
+
+    /// The known attributes, with their type, and the required ones.
+    fn attribute_types(
+        &self,
+    ) -> (
+        Vec<(&'static str, &'static AttributeType)>,
+        Vec<&'static str>,
+    ) {
+        let Some(rule) = self.rule() else {
+            return (Vec::new(), Vec::new());
+        };
+        let mut types = Vec::new();
+        if matches!(self.attributes, Attributes::Map) {
+            types.extend(
+                GLOBAL_ATTRIBUTES
+                    .iter()
+                    .map(|name| (*name, &AttributeType::String)),
+            );
+        }
+        types.extend(rule.attribute_types().map(|(name, kind)| (*name, kind)));
+        let mut required = rule.required.to_vec();
+        if let Attributes::Fields(fields) = self.attributes {
+            required.extend(fields.iter().filter(|name| !rule.required.contains(name)));
+        }
+        // the required attributes of the rules accepting any attribute
+        for name in required.iter() {
+            if !types.iter().any(|(key, _)| key == name) {
+                types.push((name, &AttributeType::String));
+            }
+        }
+        (types, required)
+    }
+
+    /// Whether other attributes than the known ones are accepted.
+    fn accepts_any_attribute(&self) -> bool {
+        self.rule().is_some_and(|rule| {
+            matches!(rule.attributes, crate::prelude::validate::Attributes::Any)
+        })
+    }
+
+    /// Whether the json object requires the `attributes` property.
+    fn requires_attributes(&self) -> bool {
+        match self.attributes {
+            Attributes::None => false,
+            Attributes::Include { .. } => true,
+            Attributes::Map | Attributes::Fields(_) => !self.attribute_types().1.is_empty(),
+        }
+    }
+}
+
+fn reference(def: Def) -> Value {
+    json!({ "$ref": format!("#/$defs/{}", def.name()) })
+}
+
+fn list_of(defs: &[Def]) -> Value {
+    json!({
+        "type": "array",
+        "items": { "anyOf": defs.iter().copied().map(reference).collect::<Vec<_>>() },
+    })
+}
+
+fn attribute_value(kind: &AttributeType, nullable: bool) -> Value {
+    match kind {
+        AttributeType::String if nullable => json!({ "type": ["string", "null"] }),
+        AttributeType::String => json!({ "type": "string" }),
+        AttributeType::Enum(values) => json!({ "enum": values }),
+        AttributeType::Boolean => json!({ "enum": ["true", "false"] }),
+        other => json!({ "type": "string", "description": other.expected() }),
+    }
+}
+
+fn include_attributes(css: bool) -> Value {
+    let mut kinds = vec![json!({ "enum": ["mjml", "html"] })];
+    if css {
+        kinds.push(json!({
+            "type": "object",
+            "properties": {
+                "css": {
+                    "type": "object",
+                    "properties": { "inline": { "type": "boolean" } },
+                    "required": ["inline"],
+                    "additionalProperties": false,
+                },
+            },
+            "required": ["css"],
+            "additionalProperties": false,
+        }));
+    }
+    json!({
+        "type": "object",
+        "properties": {
+            "path": { "type": "string" },
+            "type": { "anyOf": kinds },
+        },
+        "required": ["path"],
+        "additionalProperties": false,
+    })
+}
+
+fn element_attributes(element: &Element) -> Option<Value> {
+    let nullable = match element.attributes {
+        Attributes::None => return None,
+        Attributes::Include { css } => return Some(include_attributes(css)),
+        Attributes::Map => true,
+        Attributes::Fields(_) => false,
+    };
+    let (types, required) = element.attribute_types();
+    let properties = types
+        .into_iter()
+        .map(|(name, kind)| {
+            let nullable = nullable && !required.contains(&name);
+            (name.to_string(), attribute_value(kind, nullable))
+        })
+        .collect::<Map<_, _>>();
+    let additional = if element.accepts_any_attribute() {
+        json!({ "type": ["string", "null"] })
+    } else {
+        json!(false)
+    };
+    Some(json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": additional,
+    }))
+}
+
+fn element_children(element: &Element) -> Option<Value> {
+    match element.children {
+        Children::None => None,
+        Children::String => Some(json!({ "type": "string" })),
+        Children::Texts => Some(list_of(&[Def::Text])),
+        Children::OneOrMany => Some(json!({
+            "anyOf": [reference(Def::Text), reference(Def::Comment), list_of(&element.children_defs())],
+        })),
+        Children::Raw | Children::Only(_) | Children::Rule => {
+            Some(list_of(&element.children_defs()))
+        }
+    }
+}
+
+fn definition(def: Def) -> Value {
+    match def {
+        Def::Text => json!({ "type": "string" }),
+        Def::Comment => json!({
+            "type": "object",
+            "properties": {
+                "type": { "const": COMMENT_TAG },
+                "children": { "type": "string" },
+            },
+            "required": ["type"],
+            "additionalProperties": false,
+        }),
+        Def::Node => {
+            let known = std::iter::once(COMMENT_TAG)
+                .chain(std::iter::once(ATTRIBUTES_ELEMENT_TAG))
+                .chain(ELEMENTS.iter().map(|item| item.tag))
+                .collect::<std::collections::BTreeSet<_>>();
+            json!({
+                "description": "An html element.",
+                "type": "object",
+                "properties": {
+                    "type": { "type": "string", "not": { "enum": known } },
+                    "attributes": {
+                        "type": "object",
+                        "additionalProperties": { "type": ["string", "null"] },
+                    },
+                    "children": list_of(RAW),
+                },
+                "required": ["type"],
+                "additionalProperties": false,
+            })
+        }
+        Def::MjAttributesElement => json!({
+            "description": "The default attributes of an element.",
+            "type": "object",
+            "properties": {
+                "type": { "const": ATTRIBUTES_ELEMENT_TAG },
+                "name": { "type": "string" },
+                "attributes": {
+                    "type": "object",
+                    "additionalProperties": { "type": ["string", "null"] },
+                },
+            },
+            "required": ["type", "name"],
+            "additionalProperties": false,
+        }),
+        Def::Element(name) => {
+            let element = element(name);
+            let mut properties = Map::new();
+            properties.insert("type".into(), json!({ "const": element.tag }));
+            let mut required = vec!["type"];
+            if let Some(attributes) = element_attributes(element) {
+                properties.insert("attributes".into(), attributes);
+                if element.requires_attributes() {
+                    required.push("attributes");
+                }
+            }
+            if let Some(children) = element_children(element) {
+                properties.insert("children".into(), children);
+            }
+            json!({
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false,
+            })
+        }
+    }
+}
+
+/// Generates the JSON Schema of the templates, for the json format of the
+/// `json` feature.
+///
+/// ```rust
+/// let schema = mrml::prelude::json::schema::schema();
+/// let button = &schema["$defs"]["MjButton"];
+/// assert_eq!(button["properties"]["type"]["const"], "mj-button");
+/// assert_eq!(
+///     button["properties"]["attributes"]["properties"]["align"]["enum"],
+///     serde_json::json!(["left", "center", "right"])
+/// );
+/// ```
+pub fn schema() -> Value {
+    let definitions = Def::all()
+        .map(|def| (def.name().to_string(), definition(def)))
+        .collect::<Map<_, _>>();
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "Mjml",
+        "$ref": "#/$defs/Mjml",
+        "$defs": definitions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{element, Def, ELEMENTS};
+
+    #[test]
+    fn should_reference_known_definitions() {
+        let names = Def::all().map(|def| def.name()).collect::<Vec<_>>();
+        for item in ELEMENTS {
+            assert!(item.rule().is_some(), "no rule for {}", item.tag);
+            for child in item.children_defs() {
+                assert!(names.contains(&child.name()), "unknown {child:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn should_list_children_of_rule() {
+        let children = element("MjSection").children_defs();
+        assert_eq!(
+            children,
+            vec![
+                Def::Comment,
+                Def::Element("MjIncludeBody"),
+                Def::Element("MjColumn"),
+                Def::Element("MjGroup"),
+                Def::Element("MjRaw"),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_describe_required_attributes() {
+        let schema = super::schema();
+        let image = &schema["$defs"]["MjImage"];
+        assert_eq!(image["required"], serde_json::json!(["type", "attributes"]));
+        assert_eq!(
+            image["properties"]["attributes"]["required"],
+            serde_json::json!(["src"])
+        );
+        assert_eq!(
+            image["properties"]["attributes"]["properties"]["src"]["type"],
+            "string"
+        );
+        let breakpoint = &schema["$defs"]["MjBreakpoint"];
+        assert_eq!(
+            breakpoint["properties"]["attributes"]["required"],
+            serde_json::json!(["width"])
+        );
+        assert!(schema["$defs"]["MjSpacer"]["properties"]["children"].is_null());
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt::Write;
+
+use super::{
+    element, Attributes, Children, Def, Element, ATTRIBUTES_ELEMENT_TAG, COMMENT_TAG, RAW,
+};
+use crate::prelude::validate::AttributeType;
+
+fn union(defs: &[Def]) -> String {
+    defs.iter()
+        .map(|def| def.name())
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+fn list(defs: &[Def]) -> String {
+    if defs.len() == 1 {
+        format!("{}[]", union(defs))
+    } else {
+        format!("({})[]", union(defs))
+    }
+}
+
+fn attribute_value(kind: &AttributeType, nullable: bool) -> String {
+    match kind {
+        AttributeType::String if nullable => "string | null".into(),
+        AttributeType::Enum(values) => values
+            .iter()
+            .map(|value| format!("{value:?}"))
+            .collect::<Vec<_>>()
+            .join(" | "),
+        AttributeType::Boolean => "\"true\" | \"false\"".into(),
+        _ => "string".into(),
+    }
+}
+
+fn write_attributes(buffer: &mut String, element: &Element) {
+    let nullable = match element.attributes {
+        Attributes::None => return,
+        Attributes::Include { css } => {
+            let kind = if css {
+                "\"mjml\" | \"html\" | { css: { inline: boolean } }"
+            } else {
+                "\"mjml\" | \"html\""
+            };
+            let _ = writeln!(
+                buffer,
+                "  attributes: {{\n    path: string;\n    type?: {kind};\n  }};"
+            );
+            return;
+        }
+        Attributes::Map => true,
+        Attributes::Fields(_) => false,
+    };
+    let (types, required) = element.attribute_types();
+    let optional = if required.is_empty() { "?" } else { "" };
+    let _ = writeln!(buffer, "  attributes{optional}: {{");
+    for (name, kind) in types {
+        if !matches!(
+            kind,
+            AttributeType::String | AttributeType::Enum(_) | AttributeType::Boolean
+        ) {
+            let _ = writeln!(buffer, "    /** {} */", kind.expected());
+        }
+        let is_required = required.contains(&name);
+        let _ = writeln!(
+            buffer,
+            "    {name:?}{}: {};",
+            if is_required { "" } else { "?" },
+            attribute_value(kind, nullable && !is_required)
+        );
+    }
+    if element.accepts_any_attribute() {
+        let _ = writeln!(buffer, "    [name: string]: string | null;");
+    }
+    let _ = writeln!(buffer, "  }};");
+}
+
+fn write_element(buffer: &mut String, name: &str) {
+    let element = element(name);
+    let _ = writeln!(buffer, "export interface {name} {{");
+    let _ = writeln!(buffer, "  type: {:?};", element.tag);
+    write_attributes(buffer, element);
+    let children = match element.children {
+        Children::None => None,
+        Children::String => Some("string".to_string()),
+        Children::Texts => Some(list(&[Def::Text])),
+        Children::OneOrMany => {
+            let defs = element.children_defs();
+            Some(format!("{} | {}", union(&defs), list(&defs)))
+        }
+        Children::Raw | Children::Only(_) | Children::Rule => Some(list(&element.children_defs())),
+    };
+    if let Some(children) = children {
+        let _ = writeln!(buffer, "  children?: {children};");
+    }
+    let _ = writeln!(buffer, "}}");
+}
+
+fn write_def(buffer: &mut String, def: Def) {
+    match def {
+        Def::Text => {
+            let _ = writeln!(buffer, "export type Text = string;");
+        }
+        Def::Comment => {
+            let _ = writeln!(
+                buffer,
+                "export interface Comment {{\n  type: {COMMENT_TAG:?};\n  children?: string;\n}}"
+            );
+        }
+        Def::Node => {
+            let _ = writeln!(
+                buffer,
+                "/** An html element. */\nexport interface Node {{\n  type: string;\n  attributes?: Record<string, string | null>;\n  children?: {};\n}}",
+                list(RAW)
+            );
+        }
+        Def::MjAttributesElement => {
+            let _ = writeln!(
+                buffer,
+                "/** The default attributes of an element. */\nexport interface MjAttributesElement {{\n  type: {ATTRIBUTES_ELEMENT_TAG:?};\n  name: string;\n  attributes?: Record<string, string | null>;\n}}"
+            );
+        }
+        Def::Element(name) => write_element(buffer, name),
+    }
+}
+
+/// Generates the typescript definitions of the templates, for the json format
+/// of the `json` feature.
+///
+/// ```rust
+/// let definitions = mrml::prelude::json::schema::typescript();
+/// assert!(definitions.contains("export interface MjButton {"));
+/// ```
+pub fn typescript() -> String {
+    let mut buffer =
+        String::from("// Generated by mrml, describes the json format of the templates.\n");
+    for def in Def::all() {
+        buffer.push('\n');
+        write_def(&mut buffer, def);
+    }
+    buffer
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn should_describe_elements() {
+        let definitions = super::typescript();
+        assert!(definitions
+            .contains("export interface MjImage {\n  type: \"mj-image\";\n  attributes: {\n"));
+        assert!(definitions.contains("    \"src\": string;\n"));
+        assert!(definitions.contains("    /** a size in px or auto */\n    \"height\"?: string;\n"));
+        assert!(definitions
+            .contains("  children?: (Comment | MjIncludeBody | MjColumn | MjGroup | MjRaw)[];\n"));
+        assert!(definitions.contains("    \"name\": string;\n    [name: string]: string | null;\n"));
+    }
+}
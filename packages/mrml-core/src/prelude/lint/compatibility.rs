@@ -0,0 +1,203 @@
+//! Support of the css properties by the main email clients, based on the data
+//! of [caniemail](https://www.caniemail.com/). Only the properties that some of
+//! those clients ignore are listed.
+
+/// The email clients the css is checked against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Client {
+    /// Outlook on Windows, rendering with the Word engine.
+    Outlook,
+    Gmail,
+    AppleMail,
+}
+
+impl Client {
+    pub const ALL: [Client; 3] = [Self::Outlook, Self::Gmail, Self::AppleMail];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Outlook => "Outlook",
+            Self::Gmail => "Gmail",
+            Self::AppleMail => "Apple Mail",
+        }
+    }
+}
+
+impl std::fmt::Display for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The values of a property a rule applies to.
+enum Values {
+    Any,
+    /// The value is one of these keywords.
+    Keywords(&'static [&'static str]),
+    /// The value uses one of these functions.
+    Functions(&'static [&'static str]),
+}
+
+struct Rule {
+    property: &'static str,
+    values: Values,
+    unsupported: &'static [Client],
+}
+
+const OUTLOOK: &[Client] = &[Client::Outlook];
+const OUTLOOK_GMAIL: &[Client] = &[Client::Outlook, Client::Gmail];
+
+const RULES: &[Rule] = &[
+    Rule {
+        property: "animation",
+        values: Values::Any,
+        unsupported: OUTLOOK_GMAIL,
+    },
+    Rule {
+        property: "background",
+        values: Values::Functions(&["linear-gradient", "radial-gradient"]),
+        unsupported: OUTLOOK,
+    },
+    Rule {
+        property: "background-image",
+        values: Values::Functions(&["linear-gradient", "radial-gradient"]),
+        unsupported: OUTLOOK,
+    },
+    Rule {
+        property: "border-radius",
+        values: Values::Any,
+        unsupported: OUTLOOK,
+    },
+    Rule {
+        property: "box-shadow",
+        values: Values::Any,
+        unsupported: OUTLOOK,
+    },
+    Rule {
+        property: "clip-path",
+        values: Values::Any,
+        unsupported: OUTLOOK_GMAIL,
+    },
+    Rule {
+        property: "display",
+        values: Values::Keywords(&["grid", "inline-grid"]),
+        unsupported: OUTLOOK_GMAIL,
+    },
+    Rule {
+        property: "display",
+        values: Values::Keywords(&["flex", "inline-flex"]),
+        unsupported: OUTLOOK,
+    },
+    Rule {
+        property: "filter",
+        values: Values::Any,
+        unsupported: OUTLOOK_GMAIL,
+    },
+    Rule {
+        property: "object-fit",
+        values: Values::Any,
+        unsupported: OUTLOOK_GMAIL,
+    },
+    Rule {
+        property: "opacity",
+        values: Values::Any,
+        unsupported: OUTLOOK,
+    },
+    Rule {
+        property: "position",
+        values: Values::Keywords(&["absolute", "fixed", "relative", "sticky"]),
+        unsupported: OUTLOOK_GMAIL,
+    },
+    Rule {
+        property: "text-shadow",
+        values: Values::Any,
+        unsupported: OUTLOOK,
+    },
+    Rule {
+        property: "transform",
+        values: Values::Any,
+        unsupported: OUTLOOK_GMAIL,
+    },
+    Rule {
+        property: "transition",
+        values: Values::Any,
+        unsupported: OUTLOOK_GMAIL,
+    },
+    Rule {
+        property: "z-index",
+        values: Values::Any,
+        unsupported: OUTLOOK,
+    },
+];
+
+/// Functions that are not supported whatever the property.
+const FUNCTIONS: &[(&str, &[Client])] = &[("calc", OUTLOOK), ("var", OUTLOOK_GMAIL)];
+
+fn uses_function(value: &str, name: &str) -> bool {
+    value.match_indices(name).any(|(index, _)| {
+        let before = value[..index].chars().next_back();
+        !before.is_some_and(|c| c.is_ascii_alphanumeric() || c == '-')
+            && value[index + name.len()..].starts_with('(')
+    })
+}
+
+/// The clients that don't support a css declaration, empty when all of them
+/// support it.
+///
+/// ```rust
+/// use mrml::prelude::lint::{unsupported_clients, Client};
+///
+/// assert_eq!(unsupported_clients("border-radius", "4px"), &[Client::Outlook]);
+/// assert_eq!(unsupported_clients("display", "flex"), &[Client::Outlook]);
+/// assert!(unsupported_clients("display", "block").is_empty());
+/// ```
+pub fn unsupported_clients(property: &str, value: &str) -> &'static [Client] {
+    let property = property.trim().to_ascii_lowercase();
+    let value = value.trim().to_ascii_lowercase();
+    let value = value.trim_end_matches("!important").trim_end();
+    let found = RULES.iter().find(|rule| {
+        rule.property == property
+            && match rule.values {
+                Values::Any => true,
+                Values::Keywords(keywords) => keywords.contains(&value),
+                Values::Functions(functions) => {
+                    functions.iter().any(|name| uses_function(value, name))
+                }
+            }
+    });
+    if let Some(rule) = found {
+        return rule.unsupported;
+    }
+    FUNCTIONS
+        .iter()
+        .find(|(name, _)| uses_function(value, name))
+        .map_or(&[], |(_, clients)| clients)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{unsupported_clients, Client};
+
+    #[test]
+    fn should_find_unsupported_values() {
+        assert_eq!(
+            unsupported_clients("Position", "absolute !important"),
+            &[Client::Outlook, Client::Gmail]
+        );
+        assert!(unsupported_clients("position", "static").is_empty());
+        assert_eq!(
+            unsupported_clients("background", "#fff linear-gradient(#fff, #000)"),
+            &[Client::Outlook]
+        );
+        assert!(unsupported_clients("background", "#fff url(gradient.png)").is_empty());
+        assert_eq!(
+            unsupported_clients("width", "calc(100% - 10px)"),
+            &[Client::Outlook]
+        );
+        assert_eq!(
+            unsupported_clients("color", "var(--primary)"),
+            &[Client::Outlook, Client::Gmail]
+        );
+        assert!(unsupported_clients("font-family", "Bariol, sans-serif").is_empty());
+    }
+}
@@ -0,0 +1,291 @@
+//! Check of the css of a rendered template against its support by the main
+//! email clients: the styles added to the head by the components, the content
+//! of the `mj-style` elements and the `style` attributes of the html.
+//!
+//! Each issue points to the element of the template that generated the css,
+//! when the template has been parsed with the `span` feature.
+//!
+//! ```rust
+//! use mrml::prelude::lint::Client;
+//! use mrml::prelude::render::RenderOptions;
+//!
+//! let template = r#"<mjml><mj-body><mj-section><mj-column><mj-text css-class="shadow">Hello</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+//! let root = mrml::parse(template).unwrap();
+//! let issues = root.element.lint(&RenderOptions::default()).unwrap();
+//! assert!(issues.is_empty());
+//!
+//! let template = r#"<mjml><mj-head><mj-style>.shadow { box-shadow: 0 0 4px #000; }</mj-style></mj-head><mj-body></mj-body></mjml>"#;
+//! let root = mrml::parse(template).unwrap();
+//! let issues = root.element.lint(&RenderOptions::default()).unwrap();
+//! assert_eq!(issues[0].property, "box-shadow");
+//! assert_eq!(issues[0].clients, &[Client::Outlook]);
+//! ```
+
+mod compatibility;
+
+pub use self::compatibility::{unsupported_clients, Client};
+use super::parser::Origin;
+use super::render::{Mapping, SourceMap};
+use super::Span;
+use crate::helper::html::HtmlDocument;
+use crate::mj_head::MjHead;
+use crate::prelude::Location;
+
+/// Where the css of an issue has been found.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CssSource {
+    /// A `style` attribute of the rendered html.
+    InlineStyle,
+    /// A style added to the head by a component, like `mj-navbar`.
+    ComponentStyle,
+    /// The content of a `mj-style` element, or of an included css file.
+    MjStyle,
+}
+
+impl std::fmt::Display for CssSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InlineStyle => f.write_str("inline style"),
+            Self::ComponentStyle => f.write_str("component style"),
+            Self::MjStyle => f.write_str("stylesheet"),
+        }
+    }
+}
+
+/// Element of the template that generated the css of an issue.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintElement {
+    /// Name of the element, like `mj-button`.
+    pub tag: String,
+    /// Template the element comes from.
+    pub origin: Origin,
+    /// Position of the element in the template it comes from.
+    pub span: Span,
+}
+
+impl LintElement {
+    fn new(tag: &str, location: &Location) -> Self {
+        Self {
+            tag: tag.to_string(),
+            origin: location.origin.clone(),
+            span: location.span,
+        }
+    }
+}
+
+impl From<&Mapping> for LintElement {
+    fn from(value: &Mapping) -> Self {
+        Self {
+            tag: value.tag.clone(),
+            origin: value.origin.clone(),
+            span: value.source,
+        }
+    }
+}
+
+/// A css declaration that some email clients don't support.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintIssue {
+    pub property: String,
+    pub value: String,
+    /// The clients ignoring the declaration.
+    pub clients: &'static [Client],
+    pub source: CssSource,
+    /// The element that generated the declaration, `None` when it has no
+    /// location.
+    pub element: Option<LintElement>,
+}
+
+impl LintIssue {
+    pub fn affects(&self, client: Client) -> bool {
+        self.clients.contains(&client)
+    }
+}
+
+impl std::fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}: {}` is not supported by ",
+            self.property, self.value
+        )?;
+        for (index, client) in self.clients.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            client.fmt(f)?;
+        }
+        write!(f, " ({}", self.source)?;
+        if let Some(element) = self.element.as_ref() {
+            write!(
+                f,
+                " of {} at {}..{}",
+                element.tag, element.span.start, element.span.end
+            )?;
+            if let Origin::Include { path } = &element.origin {
+                write!(f, " in {path}")?;
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+/// Splits css in its declarations, ignoring the selectors and at-rules.
+fn declarations(css: &str) -> impl Iterator<Item = (&str, &str)> {
+    css.split('}')
+        .map(|block| block.rsplit('{').next().unwrap_or(block))
+        .flat_map(|block| block.split(';'))
+        .filter_map(|declaration| declaration.split_once(':'))
+        .map(|(property, value)| (property.trim(), value.trim()))
+        .filter(|(property, value)| !property.is_empty() && !value.is_empty())
+}
+
+fn strip_comments(css: &str) -> std::borrow::Cow<'_, str> {
+    if !css.contains("/*") {
+        return std::borrow::Cow::Borrowed(css);
+    }
+    let mut result = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        result.push_str(&rest[..start]);
+        rest = rest[start + 2..]
+            .find("*/")
+            .map_or("", |end| &rest[start + 2 + end + 2..]);
+    }
+    result.push_str(rest);
+    std::borrow::Cow::Owned(result)
+}
+
+#[derive(Default)]
+pub(crate) struct Linter {
+    issues: Vec<LintIssue>,
+}
+
+impl Linter {
+    fn check(&mut self, css: &str, source: CssSource, element: Option<LintElement>) {
+        let css = strip_comments(css);
+        for (property, value) in declarations(&css) {
+            let clients = unsupported_clients(property, value);
+            if clients.is_empty() {
+                continue;
+            }
+            let issue = LintIssue {
+                property: property.to_ascii_lowercase(),
+                value: value.to_string(),
+                clients,
+                source,
+                element: element.clone(),
+            };
+            if !self.issues.contains(&issue) {
+                self.issues.push(issue);
+            }
+        }
+    }
+
+    /// Checks the `style` attributes of the rendered html, attributed to the
+    /// innermost element that rendered them.
+    pub(crate) fn check_html(&mut self, html: &str, source_map: &SourceMap) {
+        let document = HtmlDocument::parse(html);
+        for element in document.elements() {
+            for attribute in element.attributes.iter() {
+                let Some(value) = attribute
+                    .value
+                    .as_ref()
+                    .filter(|_| attribute.name == "style")
+                else {
+                    continue;
+                };
+                let origin = source_map.lookup(attribute.span.start).last();
+                self.check(
+                    &html[value.clone()],
+                    CssSource::InlineStyle,
+                    origin.map(LintElement::from),
+                );
+            }
+        }
+    }
+
+    /// Checks a style added to the head by the component that rendered
+    /// `origin`.
+    pub(crate) fn check_component_style(&mut self, css: &str, origin: Option<&Mapping>) {
+        self.check(
+            css,
+            CssSource::ComponentStyle,
+            origin.map(LintElement::from),
+        );
+    }
+
+    /// Checks the `mj-style` elements of the head, including the ones of the
+    /// included templates and the included css files.
+    pub(crate) fn check_head(&mut self, head: &MjHead) {
+        for child in head.children.iter() {
+            if let Some(style) = child.as_mj_style() {
+                let element = style
+                    .location
+                    .as_ref()
+                    .map(|location| LintElement::new(crate::mj_style::NAME, location));
+                self.check(&style.children, CssSource::MjStyle, element);
+            } else if let Some(include) = child.as_mj_include() {
+                let is_css = matches!(
+                    include.0.attributes.kind,
+                    crate::mj_include::head::MjIncludeHeadKind::Css { .. }
+                );
+                let include_element = include
+                    .0
+                    .location
+                    .as_ref()
+                    .map(|location| LintElement::new(crate::mj_include::NAME, location));
+                for item in include.0.children.iter() {
+                    if let Some(style) = item.as_mj_style() {
+                        let element = style
+                            .location
+                            .as_ref()
+                            .map(|location| LintElement::new(crate::mj_style::NAME, location))
+                            .or_else(|| include_element.clone());
+                        self.check(&style.children, CssSource::MjStyle, element);
+                    } else if let Some(text) = item.as_text().filter(|_| is_css) {
+                        self.check(
+                            text.inner_str(),
+                            CssSource::MjStyle,
+                            include_element.clone(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn finish(self) -> Vec<LintIssue> {
+        self.issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{declarations, strip_comments};
+
+    #[test]
+    fn should_split_declarations() {
+        let css = "@media only screen { .foo { display: flex; color:red } } p{margin:0}";
+        assert_eq!(
+            declarations(css).collect::<Vec<_>>(),
+            vec![("display", "flex"), ("color", "red"), ("margin", "0")]
+        );
+        assert_eq!(
+            declarations("background: url(https://example.com/a.png)").collect::<Vec<_>>(),
+            vec![("background", "url(https://example.com/a.png)")]
+        );
+    }
+
+    #[test]
+    fn should_strip_comments() {
+        assert_eq!(
+            strip_comments("a { /* float: left; */ color: red }"),
+            "a {  color: red }"
+        );
+        assert_eq!(
+            strip_comments("a { color: red } /* end"),
+            "a { color: red } "
+        );
+    }
+}
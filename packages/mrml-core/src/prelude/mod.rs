@@ -1,13 +1,22 @@
 use std::marker::PhantomData;
 
+#[cfg(feature = "builder")]
+pub mod builder;
 #[cfg(feature = "json")]
 pub mod json;
+#[cfg(feature = "lint")]
+pub mod lint;
 #[cfg(feature = "parse")]
 pub mod parser;
 #[cfg(feature = "print")]
 pub mod print;
 #[cfg(feature = "render")]
 pub mod render;
+#[cfg(feature = "template")]
+pub mod template;
+#[cfg(feature = "validate")]
+pub mod validate;
+pub mod visit;
 
 pub mod hash;
 
@@ -15,11 +24,34 @@ pub trait StaticTag {
     fn static_tag() -> &'static str;
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 pub struct Component<Tag, Attributes, Children> {
     pub tag: Tag,
     pub attributes: Attributes,
     pub children: Children,
+    #[cfg(feature = "span")]
+    pub(crate) location: Option<Location>,
+}
+
+// The location is left out, for a parsed element to equal the same element
+// built another way.
+impl<T: PartialEq, A: PartialEq, C: PartialEq> PartialEq for Component<T, A, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.tag == other.tag
+            && self.attributes == other.attributes
+            && self.children == other.children
+    }
+}
+
+impl<T: Eq, A: Eq, C: Eq> Eq for Component<T, A, C> {}
+
+#[cfg(feature = "span")]
+impl<T, A, C> Component<T, A, C> {
+    /// Where the element has been parsed from, `None` when the element has
+    /// been built another way.
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
 }
 
 impl<T: Default, A: Default, C: Default> Default for Component<T, A, C> {
@@ -28,6 +60,8 @@ impl<T: Default, A: Default, C: Default> Default for Component<T, A, C> {
             tag: T::default(),
             attributes: A::default(),
             children: C::default(),
+            #[cfg(feature = "span")]
+            location: None,
         }
     }
 }
@@ -39,6 +73,8 @@ impl<T, A, C> Component<PhantomData<T>, A, C> {
             tag: PhantomData::<T>,
             attributes,
             children,
+            #[cfg(feature = "span")]
+            location: None,
         }
     }
 }
@@ -65,6 +101,32 @@ pub(crate) fn is_void_element(tag: &str) -> bool {
     )
 }
 
+/// Position of a piece of content, as byte offsets in its source.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "source-map", derive(serde::Serialize))]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl std::fmt::Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.start, self.end)
+    }
+}
+
+/// Position of an element, a text or a comment in the template it has been
+/// parsed from.
+#[cfg(feature = "span")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub origin: parser::Origin,
+    pub span: Span,
+    /// Position of each attribute of the element, by name. Empty for texts
+    /// and comments.
+    pub attributes: hash::Map<String, Span>,
+}
+
 pub type AttributeMap = hash::Map<String, Option<String>>;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
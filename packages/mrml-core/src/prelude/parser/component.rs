@@ -0,0 +1,471 @@
+//! Module containing the [`ComponentRegistry`], to use custom components in
+//! the body of a template.
+//!
+//! A custom component is expanded to mjml while parsing: the element is
+//! replaced by the elements of its expansion, like an `mj-include` with its
+//! content. The registry is given to the parser with
+//! [`MrmlParser::with_components`](super::MrmlParser::with_components).
+//!
+//! ```rust
+//! use mrml::mj_body::MjBodyChild;
+//! use mrml::prelude::parser::component::{ComponentElement, ComponentError, ComponentRegistry};
+//! use mrml::prelude::parser::{MrmlParser, ParserOptions};
+//!
+//! fn card(element: &ComponentElement<'_>) -> Result<String, ComponentError> {
+//!     let color = element.attribute("color").unwrap_or("#ffffff");
+//!     Ok(format!(
+//!         r#"<mj-section background-color="{color}"><mj-column>{}</mj-column></mj-section>"#,
+//!         element.content
+//!     ))
+//! }
+//!
+//! let opts = ParserOptions::default();
+//! let parser = MrmlParser::new(&opts)
+//!     .with_components(ComponentRegistry::default().with_component("mx-card", card));
+//! let template = r#"<mjml><mj-body><mx-card color="red"><mj-text>Hello</mj-text></mx-card></mj-body></mjml>"#;
+//! let output = parser.parse_template(template).expect("parse template");
+//! let body = output.element.children.body.unwrap();
+//! assert!(matches!(body.children[0], MjBodyChild::MjSection(_)));
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use htmlparser::StrSpan;
+
+use super::{parse_attributes_map, Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, Span};
+#[cfg(feature = "async")]
+use super::{AsyncMrmlParser, AsyncParseChildren};
+use crate::prelude::{is_void_element, AttributeMap};
+
+const FRAGMENT_OPEN: &str = "<mrml-fragment>";
+const FRAGMENT_CLOSE: &str = "</mrml-fragment>";
+/// Maximum number of nested expansions, to stop a component expanding to
+/// itself, directly or through other components.
+const MAX_EXPANSION_DEPTH: usize = 32;
+
+#[derive(Clone, Debug)]
+pub struct ComponentError {
+    pub message: String,
+}
+
+impl ComponentError {
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ComponentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ComponentError {}
+
+/// A custom element found in a template.
+#[derive(Debug)]
+pub struct ComponentElement<'a> {
+    pub tag: &'a str,
+    pub attributes: &'a AttributeMap,
+    /// The mjml between the opening and the closing tags of the element.
+    pub content: &'a str,
+}
+
+impl ComponentElement<'_> {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).and_then(|value| value.as_deref())
+    }
+}
+
+pub trait CustomComponent: Send + Sync {
+    /// Returns the mjml replacing the element. The expansion is parsed like
+    /// the children of the element containing it and can contain other
+    /// custom components.
+    ///
+    /// The warnings and the errors found in the expansion are reported at the
+    /// position of the element.
+    fn expand(&self, element: &ComponentElement<'_>) -> Result<String, ComponentError>;
+}
+
+impl<F> CustomComponent for F
+where
+    F: Fn(&ComponentElement<'_>) -> Result<String, ComponentError> + Send + Sync,
+{
+    fn expand(&self, element: &ComponentElement<'_>) -> Result<String, ComponentError> {
+        self(element)
+    }
+}
+
+/// The custom components that can be used in the body of a template, by tag.
+#[derive(Clone, Default)]
+pub struct ComponentRegistry {
+    components: HashMap<String, Arc<dyn CustomComponent>>,
+}
+
+impl std::fmt::Debug for ComponentRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut tags = self.components.keys().collect::<Vec<_>>();
+        tags.sort();
+        f.debug_struct("ComponentRegistry")
+            .field("components", &tags)
+            .finish()
+    }
+}
+
+impl ComponentRegistry {
+    pub fn with_component<T, C>(mut self, tag: T, component: C) -> Self
+    where
+        T: Into<String>,
+        C: CustomComponent + 'static,
+    {
+        self.add_component(tag, component);
+        self
+    }
+
+    pub fn add_component<T, C>(&mut self, tag: T, component: C)
+    where
+        T: Into<String>,
+        C: CustomComponent + 'static,
+    {
+        self.components.insert(tag.into(), Arc::new(component));
+    }
+
+    pub fn get(&self, tag: &str) -> Option<&dyn CustomComponent> {
+        self.components.get(tag).map(AsRef::as_ref)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+impl<'a> MrmlCursor<'a> {
+    /// Reads the tokens until the element being parsed is closed, returning
+    /// its content, starting at `start`.
+    fn read_element_content(&mut self, start: usize) -> Result<&'a str, Error> {
+        let mut opened: Vec<StrSpan<'a>> = Vec::new();
+        loop {
+            match self.assert_next()? {
+                MrmlToken::ElementStart(inner) => opened.push(inner.local),
+                MrmlToken::ElementEnd(inner)
+                    if inner.empty
+                        || opened
+                            .last()
+                            .is_some_and(|tag| is_void_element(tag.as_str())) =>
+                {
+                    opened.pop();
+                }
+                MrmlToken::ElementClose(inner) if opened.is_empty() => {
+                    #[cfg(feature = "span")]
+                    {
+                        self.last_end = inner.span.end();
+                    }
+                    return Ok(&self.source[start..inner.span.start()]);
+                }
+                MrmlToken::ElementClose(_) => {
+                    opened.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn component_error(&self, tag: &StrSpan<'_>, source: ComponentError) -> Error {
+        Error::ComponentError {
+            tag: tag.to_string(),
+            origin: self.origin(),
+            position: tag.into(),
+            source,
+        }
+    }
+
+    /// Reads the attributes and the content of a custom element, returning its
+    /// expansion.
+    fn expand_component(
+        &mut self,
+        tag: &StrSpan<'_>,
+        component: &dyn CustomComponent,
+    ) -> Result<String, Error> {
+        if self.expansion_depth >= MAX_EXPANSION_DEPTH {
+            return Err(self.component_error(
+                tag,
+                ComponentError::new(format!(
+                    "more than {MAX_EXPANSION_DEPTH} nested components, the component probably expands to itself"
+                )),
+            ));
+        }
+        let attributes = parse_attributes_map(self)?;
+        let ending = self.assert_element_end()?;
+        let content = if ending.empty {
+            ""
+        } else {
+            self.read_element_content(ending.span.end())?
+        };
+        let element = ComponentElement {
+            tag: tag.as_str(),
+            attributes: &attributes,
+            content,
+        };
+        component
+            .expand(&element)
+            .map_err(|source| self.component_error(tag, source))
+    }
+
+    /// Reports an error found in an expansion at the position of the custom
+    /// element, keeping the message of the errors of the nested components.
+    fn expansion_error(&self, tag: &StrSpan<'_>, error: Error) -> Error {
+        match error {
+            Error::ComponentError { source, .. } => self.component_error(tag, source),
+            other => self.component_error(tag, ComponentError::new(other.to_string())),
+        }
+    }
+
+    /// Keeps the warnings and the includes of an expansion, at the position
+    /// of the custom element.
+    fn with_expansion(&mut self, child: MrmlCursor<'_>, span: Span) {
+        let output = child.output(());
+        for mut warning in output.warnings {
+            warning.span = span;
+            self.warnings.push(warning);
+        }
+        for include in output.includes {
+            self.add_include(include.kind, &include.path, span);
+        }
+    }
+}
+
+impl MrmlParser<'_> {
+    /// Parses a custom element and returns the elements of its expansion.
+    pub(crate) fn parse_component<C>(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        tag: StrSpan<'_>,
+        component: &dyn CustomComponent,
+    ) -> Result<Vec<C>, Error>
+    where
+        Self: ParseChildren<Vec<C>>,
+    {
+        let expanded = cursor.expand_component(&tag, component)?;
+        let wrapped = format!("{FRAGMENT_OPEN}{expanded}{FRAGMENT_CLOSE}");
+        let mut sub = cursor.new_expansion(wrapped.as_str(), &tag);
+        sub.set_source_offset(FRAGMENT_OPEN.len());
+        let children = (|| {
+            sub.assert_element_start()?;
+            sub.assert_element_end()?;
+            let children = self.parse_children(&mut sub)?;
+            sub.assert_element_close()?;
+            Ok(children)
+        })()
+        .map_err(|err: Error| cursor.expansion_error(&tag, err))?;
+        cursor.with_expansion(sub, tag.into());
+        Ok(children)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncMrmlParser {
+    pub(crate) async fn async_parse_component<C>(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        tag: StrSpan<'_>,
+        component: &dyn CustomComponent,
+    ) -> Result<Vec<C>, Error>
+    where
+        Self: AsyncParseChildren<Vec<C>>,
+    {
+        let expanded = cursor.expand_component(&tag, component)?;
+        let wrapped = format!("{FRAGMENT_OPEN}{expanded}{FRAGMENT_CLOSE}");
+        let mut sub = cursor.new_expansion(wrapped.as_str(), &tag);
+        sub.set_source_offset(FRAGMENT_OPEN.len());
+        let children = async {
+            sub.assert_element_start()?;
+            sub.assert_element_end()?;
+            let children = self.async_parse_children(&mut sub).await?;
+            sub.assert_element_close()?;
+            Ok(children)
+        }
+        .await
+        .map_err(|err: Error| cursor.expansion_error(&tag, err))?;
+        cursor.with_expansion(sub, tag.into());
+        Ok(children)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentElement, ComponentError, ComponentRegistry};
+    use crate::mj_body::MjBodyChild;
+    use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+    use crate::prelude::parser::{Error, MrmlParser, ParserOptions, WarningKind};
+
+    fn card(element: &ComponentElement<'_>) -> Result<String, ComponentError> {
+        let color = element
+            .attribute("color")
+            .ok_or_else(|| ComponentError::new("missing color"))?;
+        Ok(format!(
+            r#"<mj-section background-color="{color}"><mj-column>{}</mj-column></mj-section>"#,
+            element.content
+        ))
+    }
+
+    fn footer(_: &ComponentElement<'_>) -> Result<String, ComponentError> {
+        Ok(r#"<mj-text>Bye</mj-text><mj-divider />"#.into())
+    }
+
+    fn components() -> ComponentRegistry {
+        ComponentRegistry::default()
+            .with_component("mx-card", card)
+            .with_component("mx-footer", footer)
+    }
+
+    #[test]
+    fn should_expand_components() {
+        let template = r#"<mjml><mj-body><mx-card color="red"><mj-text>Hello<br>World</mj-text><mx-footer /></mx-card><mj-text>Next</mj-text></mj-body></mjml>"#;
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts).with_components(components());
+        let output = parser.parse_template(template).unwrap();
+        let body = output.element.children.body.unwrap();
+        assert_eq!(body.children.len(), 2);
+        let MjBodyChild::MjSection(ref section) = body.children[0] else {
+            panic!("expected a section");
+        };
+        assert_eq!(
+            section.attributes.get("background-color"),
+            Some(&Some("red".to_string()))
+        );
+        let MjBodyChild::MjColumn(ref column) = section.children[0] else {
+            panic!("expected a column");
+        };
+        // the expansion of the nested component takes the place of the element
+        assert_eq!(column.children.len(), 3);
+        assert!(matches!(column.children[1], MjBodyChild::MjText(_)));
+        assert!(matches!(column.children[2], MjBodyChild::MjDivider(_)));
+        assert!(matches!(body.children[1], MjBodyChild::MjText(_)));
+    }
+
+    #[test]
+    fn should_keep_unknown_elements() {
+        let template = r#"<mjml><mj-body><mx-other /></mj-body></mjml>"#;
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts).with_components(components());
+        let output = parser.parse_template(template).unwrap();
+        let body = output.element.children.body.unwrap();
+        assert!(matches!(body.children[0], MjBodyChild::Node(_)));
+    }
+
+    #[test]
+    fn should_expand_components_in_included_templates() {
+        let opts = ParserOptions {
+            include_loader: Box::new(MemoryIncludeLoader::from(vec![(
+                "footer.mjml",
+                r#"<mj-section><mj-column><mx-footer /></mj-column></mj-section><mx-card color="blue" />"#,
+            )])),
+        };
+        let template = r#"<mjml><mj-body><mj-include path="footer.mjml" /></mj-body></mjml>"#;
+        let parser = MrmlParser::new(&opts).with_components(components());
+        let output = parser.parse_template(template).unwrap();
+        let body = output.element.children.body.unwrap();
+        let MjBodyChild::MjInclude(ref include) = body.children[0] else {
+            panic!("expected an include");
+        };
+        assert_eq!(include.0.children.len(), 2);
+    }
+
+    #[test]
+    fn should_report_component_errors() {
+        let template = r#"<mjml><mj-body><mx-card /></mj-body></mjml>"#;
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts).with_components(components());
+        let error = parser.parse_template(template).err().unwrap();
+        assert!(matches!(error, Error::ComponentError { ref tag, .. } if tag == "mx-card"));
+        assert_eq!(
+            error.to_string(),
+            "unable to expand component \"mx-card\" in root template at position 16:23"
+        );
+    }
+
+    #[test]
+    fn should_report_invalid_expansion() {
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts).with_components(
+            ComponentRegistry::default().with_component("mx-broken", |_: &ComponentElement<'_>| {
+                Ok(String::from("<mj-text>"))
+            }),
+        );
+        let template = r#"<mjml><mj-body><mj-text /><mx-broken /></mj-body></mjml>"#;
+        let error = parser.parse_template(template).err().unwrap();
+        assert!(matches!(error, Error::ComponentError { ref tag, .. } if tag == "mx-broken"));
+    }
+
+    #[test]
+    fn should_report_expansion_warnings_on_element() {
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts).with_components(
+            ComponentRegistry::default().with_component("mx-title", |_: &ComponentElement<'_>| {
+                Ok(String::from(r#"<mj-text>Hi</mj-text><mj-raw foo="bar" />"#))
+            }),
+        );
+        let template = r#"<mjml><mj-body><mx-title /></mj-body></mjml>"#;
+        let output = parser.parse_template(template).unwrap();
+        assert_eq!(output.warnings.len(), 1);
+        assert_eq!(output.warnings[0].kind, WarningKind::UnexpectedAttribute);
+        assert_eq!(output.warnings[0].span.start, 16);
+        assert_eq!(output.warnings[0].span.end, 24);
+    }
+
+    #[test]
+    fn should_stop_recursive_expansion() {
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts).with_components(
+            ComponentRegistry::default()
+                .with_component("mx-ping", |_: &ComponentElement<'_>| {
+                    Ok(String::from("<mx-pong />"))
+                })
+                .with_component("mx-pong", |_: &ComponentElement<'_>| {
+                    Ok(String::from("<mj-text>Pong</mj-text><mx-ping />"))
+                }),
+        );
+        let template = r#"<mjml><mj-body><mx-ping /></mj-body></mjml>"#;
+        let error = parser.parse_template(template).err().unwrap();
+        let Error::ComponentError {
+            ref tag,
+            ref position,
+            ref source,
+            ..
+        } = error
+        else {
+            panic!("unexpected error {error:?}");
+        };
+        assert_eq!(tag, "mx-ping");
+        assert_eq!(position.start, 16);
+        assert!(source.message.starts_with("more than 32 nested components"));
+    }
+
+    #[test]
+    fn should_expand_nested_components() {
+        let template = r#"<mjml><mj-body><mx-card color="red"><mx-card color="blue" /></mx-card></mj-body></mjml>"#;
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts).with_components(components());
+        assert!(parser.parse_template(template).is_ok());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_expand_components_async() {
+        let parser =
+            crate::prelude::parser::AsyncMrmlParser::default().with_components(components());
+        let template =
+            r#"<mjml><mj-body><mx-card color="red"><mx-footer /></mx-card></mj-body></mjml>"#;
+        let output = parser.parse_template(template).await.unwrap();
+        let body = output.element.children.body.unwrap();
+        let MjBodyChild::MjSection(ref section) = body.children[0] else {
+            panic!("expected a section");
+        };
+        assert!(
+            matches!(section.children[0], MjBodyChild::MjColumn(ref column) if column.children.len() == 2)
+        );
+    }
+}
@@ -3,9 +3,11 @@ use std::marker::PhantomData;
 use htmlparser::{StrSpan, Tokenizer};
 use indexmap::map::Entry;
 
+use self::component::ComponentError;
 use self::loader::IncludeLoaderError;
 use super::hash::Map;
 
+pub mod component;
 #[cfg(feature = "http-loader-base")]
 pub mod http_loader;
 pub mod loader;
@@ -21,7 +23,9 @@ mod token;
 pub use output::*;
 pub use token::*;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "source-map", derive(serde::Serialize))]
+#[cfg_attr(feature = "source-map", serde(tag = "type", rename_all = "kebab-case"))]
 pub enum Origin {
     Root,
     Include { path: String },
@@ -82,6 +86,14 @@ pub enum Error {
         origin: Origin,
         position: Span,
     },
+    #[error("unable to expand component {tag:?} in {origin} at position {position}")]
+    ComponentError {
+        tag: String,
+        origin: Origin,
+        position: Span,
+        #[source]
+        source: ComponentError,
+    },
 }
 
 impl Error {
@@ -139,6 +151,17 @@ impl Error {
                 origin,
                 position: adj(position),
             },
+            Self::ComponentError {
+                tag,
+                origin,
+                position,
+                source,
+            } => Self::ComponentError {
+                tag,
+                origin,
+                position: adj(position),
+                source,
+            },
             // Variants without byte positions are returned unchanged.
             other @ (Self::EndOfStream { .. }
             | Self::SizeLimit { .. }
@@ -209,23 +232,53 @@ pub(crate) trait AsyncParseChildren<C> {
 }
 
 pub struct MrmlCursor<'a> {
+    source: &'a str,
     tokenizer: Tokenizer<'a>,
     buffer: Vec<MrmlToken<'a>>,
     origin: Origin,
     warnings: Vec<Warning>,
+    includes: Vec<Include>,
     /// Byte offset to subtract from token positions when reporting warnings.
     /// Used when content is wrapped in a synthetic root element for parsing.
     source_offset: usize,
+    /// Number of custom components being expanded, see
+    /// [`MrmlCursor::new_expansion`].
+    expansion_depth: usize,
+    /// End of the last element end or close token, used to compute the span
+    /// of the element being parsed.
+    #[cfg(feature = "span")]
+    last_end: usize,
+    /// Start of the last element start token read.
+    #[cfg(feature = "span")]
+    element_start: usize,
+    /// Position of the attributes read, along with the start of their element.
+    #[cfg(feature = "span")]
+    attributes: Vec<(usize, String, Span)>,
+    /// Position of the custom element being expanded, in the template
+    /// containing it, see [`MrmlCursor::new_expansion`].
+    #[cfg(feature = "span")]
+    expansion: Option<Span>,
 }
 
 impl<'a> MrmlCursor<'a> {
     pub fn new(source: &'a str) -> Self {
         Self {
+            source,
             tokenizer: Tokenizer::from(source),
             buffer: Default::default(),
             origin: Origin::Root,
             warnings: Default::default(),
+            includes: Default::default(),
             source_offset: 0,
+            expansion_depth: 0,
+            #[cfg(feature = "span")]
+            last_end: 0,
+            #[cfg(feature = "span")]
+            element_start: 0,
+            #[cfg(feature = "span")]
+            attributes: Vec::new(),
+            #[cfg(feature = "span")]
+            expansion: None,
         }
     }
 
@@ -234,15 +287,34 @@ impl<'a> MrmlCursor<'a> {
         origin: O,
         source: &'b str,
     ) -> MrmlCursor<'b> {
-        MrmlCursor {
-            tokenizer: Tokenizer::from(source),
-            buffer: Default::default(),
-            origin: Origin::Include {
-                path: origin.into(),
-            },
-            warnings: Default::default(),
-            source_offset: 0,
+        let mut child = MrmlCursor::new(source);
+        child.origin = Origin::Include {
+            path: origin.into(),
+        };
+        child.expansion_depth = self.expansion_depth;
+        child
+    }
+
+    /// The expansion of a custom component is parsed like a part of the
+    /// template containing it. The elements of the expansion are located at
+    /// the custom element `tag`, that has just been read, their own positions
+    /// being in a content that is not in any template.
+    pub(crate) fn new_expansion<'b>(&self, source: &'b str, tag: &StrSpan<'_>) -> MrmlCursor<'b> {
+        let mut child = MrmlCursor::new(source);
+        child.origin = self.origin.clone();
+        child.expansion_depth = self.expansion_depth + 1;
+        #[cfg(feature = "span")]
+        {
+            child.expansion = Some(self.expansion.unwrap_or_else(|| {
+                self.source_span(Span {
+                    start: element_start(tag),
+                    end: self.last_end,
+                })
+            }));
         }
+        #[cfg(not(feature = "span"))]
+        let _ = tag;
+        child
     }
 
     pub(crate) fn set_source_offset(&mut self, offset: usize) {
@@ -252,15 +324,95 @@ impl<'a> MrmlCursor<'a> {
     pub(crate) fn origin(&self) -> Origin {
         self.origin.clone()
     }
+
+    /// Position of a token in its template, the one of the custom element
+    /// for the content of an expansion.
+    #[cfg(feature = "span")]
+    fn source_span(&self, span: Span) -> Span {
+        if let Some(expansion) = self.expansion {
+            return expansion;
+        }
+        Span {
+            start: span.start.saturating_sub(self.source_offset),
+            end: span.end.saturating_sub(self.source_offset),
+        }
+    }
+
+    /// Location of the element starting at `start` and ending with the last
+    /// element end or close token.
+    #[cfg(feature = "span")]
+    pub(crate) fn location(&self, start: usize) -> super::Location {
+        let from = self
+            .attributes
+            .partition_point(|(element, _, _)| *element < start);
+        let mut attributes = Map::new();
+        for (_, name, span) in self.attributes[from..]
+            .iter()
+            .take_while(|(element, _, _)| *element == start)
+        {
+            // like for the values, the first occurrence is kept
+            attributes
+                .entry(name.clone())
+                .or_insert_with(|| self.source_span(*span));
+        }
+        super::Location {
+            origin: self.origin(),
+            span: self.source_span(Span {
+                start,
+                end: self.last_end,
+            }),
+            attributes,
+        }
+    }
+
+    /// Location of a token that isn't an element, like a text or a comment.
+    #[cfg(feature = "span")]
+    pub(crate) fn token_location(&self, span: Span) -> super::Location {
+        super::Location {
+            origin: self.origin(),
+            span: self.source_span(span),
+            attributes: Map::new(),
+        }
+    }
+}
+
+/// Position of the `<` opening an element, from the span of its local name.
+#[cfg(feature = "span")]
+pub(crate) fn element_start(tag: &StrSpan<'_>) -> usize {
+    tag.start().saturating_sub(1)
 }
 
 pub struct MrmlParser<'opts> {
     pub(crate) options: &'opts ParserOptions,
+    pub(crate) components: component::ComponentRegistry,
+    #[cfg(feature = "validate")]
+    pub(crate) typed_attributes: bool,
 }
 
 impl<'opts> MrmlParser<'opts> {
     pub fn new(options: &'opts ParserOptions) -> Self {
-        Self { options }
+        Self {
+            options,
+            components: Default::default(),
+            #[cfg(feature = "validate")]
+            typed_attributes: false,
+        }
+    }
+
+    /// Checks the values of the known attributes of the components against
+    /// their type, with a [`WarningKind::InvalidAttributeValue`] warning for
+    /// the invalid ones.
+    #[cfg(feature = "validate")]
+    pub fn with_typed_attributes(mut self, enabled: bool) -> Self {
+        self.typed_attributes = enabled;
+        self
+    }
+
+    /// Expands the elements of the body matching the given
+    /// [custom components](component).
+    pub fn with_components(mut self, components: component::ComponentRegistry) -> Self {
+        self.components = components;
+        self
     }
 }
 
@@ -301,8 +453,12 @@ impl ParseAttributes<Map<String, Option<String>>> for MrmlParser<'_> {
     fn parse_attributes(
         &self,
         cursor: &mut MrmlCursor<'_>,
-        _tag: &StrSpan<'_>,
+        #[cfg_attr(not(feature = "validate"), allow(unused_variables))] tag: &StrSpan<'_>,
     ) -> Result<Map<String, Option<String>>, Error> {
+        #[cfg(feature = "validate")]
+        if self.typed_attributes {
+            return parse_typed_attributes_map(cursor, tag.as_str());
+        }
         parse_attributes_map(cursor)
     }
 }
@@ -330,12 +486,33 @@ impl ParseChildren<String> for MrmlParser<'_> {
 #[derive(Default)]
 pub struct AsyncMrmlParser {
     pub(crate) options: std::sync::Arc<AsyncParserOptions>,
+    pub(crate) components: component::ComponentRegistry,
+    #[cfg(feature = "validate")]
+    pub(crate) typed_attributes: bool,
 }
 
 #[cfg(feature = "async")]
 impl AsyncMrmlParser {
     pub fn new(options: std::sync::Arc<AsyncParserOptions>) -> Self {
-        Self { options }
+        Self {
+            options,
+            components: Default::default(),
+            #[cfg(feature = "validate")]
+            typed_attributes: false,
+        }
+    }
+
+    /// See [`MrmlParser::with_typed_attributes`].
+    #[cfg(feature = "validate")]
+    pub fn with_typed_attributes(mut self, enabled: bool) -> Self {
+        self.typed_attributes = enabled;
+        self
+    }
+
+    /// See [`MrmlParser::with_components`].
+    pub fn with_components(mut self, components: component::ComponentRegistry) -> Self {
+        self.components = components;
+        self
     }
 }
 
@@ -378,8 +555,12 @@ impl ParseAttributes<Map<String, Option<String>>> for AsyncMrmlParser {
     fn parse_attributes(
         &self,
         cursor: &mut MrmlCursor<'_>,
-        _tag: &StrSpan<'_>,
+        #[cfg_attr(not(feature = "validate"), allow(unused_variables))] tag: &StrSpan<'_>,
     ) -> Result<Map<String, Option<String>>, Error> {
+        #[cfg(feature = "validate")]
+        if self.typed_attributes {
+            return parse_typed_attributes_map(cursor, tag.as_str());
+        }
         parse_attributes_map(cursor)
     }
 }
@@ -424,6 +605,36 @@ pub(crate) fn parse_attributes_map(
     Ok(result)
 }
 
+/// Like [`parse_attributes_map`], with a warning for each known attribute of
+/// the component that doesn't have a value of the expected type.
+#[cfg(feature = "validate")]
+pub(crate) fn parse_typed_attributes_map(
+    cursor: &mut MrmlCursor<'_>,
+    tag: &str,
+) -> Result<Map<String, Option<String>>, Error> {
+    let Some(rule) = super::validate::find_rule(tag) else {
+        return parse_attributes_map(cursor);
+    };
+    let mut result = Map::new();
+    while let Some(attr) = cursor.next_attribute()? {
+        match result.entry(attr.qualified_name()) {
+            Entry::Vacant(slot) => {
+                let value = attr.value.map(|inner| inner.to_string());
+                if let Some(Some(kind)) = rule.attribute_type(slot.key()) {
+                    if !kind.accepts(value.as_deref()) {
+                        cursor.add_warning(WarningKind::InvalidAttributeValue, attr.span);
+                    }
+                }
+                slot.insert(value);
+            }
+            Entry::Occupied(_) => {
+                cursor.add_warning(WarningKind::DuplicateAttribute, attr.span);
+            }
+        }
+    }
+    Ok(result)
+}
+
 pub(crate) fn parse_attributes_empty(cursor: &mut MrmlCursor<'_>) -> Result<(), Error> {
     if let Some(attr) = cursor.next_attribute()? {
         cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span);
@@ -448,6 +659,8 @@ where
             tag: PhantomData::<Tag>,
             attributes,
             children,
+            #[cfg(feature = "span")]
+            location: Some(cursor.location(element_start(&tag))),
         })
     }
 }
@@ -472,6 +685,8 @@ where
             tag: PhantomData::<Tag>,
             attributes,
             children: (),
+            #[cfg(feature = "span")]
+            location: Some(cursor.location(element_start(&tag))),
         })
     }
 }
@@ -497,6 +712,8 @@ where
             tag: PhantomData::<Tag>,
             attributes,
             children,
+            #[cfg(feature = "span")]
+            location: Some(cursor.location(element_start(&tag))),
         })
     }
 }
@@ -525,6 +742,8 @@ where
             tag: PhantomData::<Tag>,
             attributes,
             children: (),
+            #[cfg(feature = "span")]
+            location: Some(cursor.location(element_start(&tag))),
         })
     }
 }
@@ -680,4 +899,184 @@ mod tests {
         // The span should point at the second occurrence, not the first.
         assert_eq!(warnings[0].span.start, raw.rfind("font-size").unwrap());
     }
+
+    #[cfg(feature = "validate")]
+    #[test]
+    fn should_warn_on_invalid_attribute_value() {
+        use crate::mjml::Mjml;
+
+        let raw = r#"<mjml><mj-body><mj-section padding="abc"><mj-column width="12em" css-class="a"><mj-text>hi</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        assert!(Mjml::parse(raw).unwrap().warnings.is_empty());
+
+        let opts = ParserOptions::default();
+        let output = MrmlParser::new(&opts)
+            .with_typed_attributes(true)
+            .parse_template(raw)
+            .unwrap();
+        let spans = output
+            .warnings
+            .iter()
+            .map(|warning| {
+                assert_eq!(warning.kind, WarningKind::InvalidAttributeValue);
+                &raw[warning.span.start..warning.span.end]
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(spans, vec![r#"padding="abc""#, r#"width="12em""#]);
+    }
+
+    #[cfg(feature = "span")]
+    #[test]
+    fn should_keep_element_location() {
+        use super::memory_loader::MemoryIncludeLoader;
+        use super::Origin;
+        use crate::mj_body::MjBodyChild;
+        use crate::mj_include::body::MjIncludeBodyChild;
+        use crate::mj_raw::MjRawChild;
+        use crate::mjml::Mjml;
+
+        let partial = "<mj-text>included</mj-text>";
+        let opts = ParserOptions {
+            include_loader: Box::new(MemoryIncludeLoader::from(vec![("partial.mjml", partial)])),
+        };
+        let raw = r#"<mjml><mj-body><mj-raw><o:p>hi</o:p><br></mj-raw><mj-include path="partial.mjml" /></mj-body></mjml>"#;
+        let root = Mjml::parse_with_options(raw, &opts).unwrap().element;
+        let span = |location: &Option<crate::prelude::Location>| {
+            let location = location.as_ref().unwrap();
+            &raw[location.span.start..location.span.end]
+        };
+        assert_eq!(span(&root.location), raw);
+        let body = root.body().unwrap();
+        assert_eq!(span(&body.location), &raw[6..raw.len() - 7]);
+        let MjBodyChild::MjRaw(mj_raw) = &body.children[0] else {
+            panic!("expected mj-raw");
+        };
+        assert_eq!(span(&mj_raw.location), "<mj-raw><o:p>hi</o:p><br></mj-raw>");
+        let nodes = mj_raw
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                MjRawChild::Node(node) => Some(span(&node.location)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(nodes, ["<o:p>hi</o:p>", "<br>"]);
+        let MjBodyChild::MjInclude(include) = &body.children[1] else {
+            panic!("expected mj-include");
+        };
+        assert_eq!(
+            span(&include.0.location),
+            r#"<mj-include path="partial.mjml" />"#
+        );
+        let MjIncludeBodyChild::MjText(included) = &include.0.children[0] else {
+            panic!("expected mj-text");
+        };
+        let location = included.location.as_ref().unwrap();
+        assert_eq!(
+            location.origin,
+            Origin::Include {
+                path: "partial.mjml".into()
+            }
+        );
+        assert_eq!(&partial[location.span.start..location.span.end], partial);
+    }
+
+    #[cfg(feature = "span")]
+    #[test]
+    fn should_keep_attribute_and_text_locations() {
+        use crate::mj_raw::MjRawChild;
+        use crate::prelude::Location;
+
+        let raw = r#"<mj-text align="left" color="red" align="right"><!-- note -->Hello <b class="x">World</b><!--[if mso]>mso<![endif]--></mj-text>"#;
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts);
+        let mut cursor = MrmlCursor::new(raw);
+        let element: MjText = parser.parse_root(&mut cursor).unwrap();
+        let source = |location: Option<&Location>| {
+            let span = location.unwrap().span;
+            &raw[span.start..span.end]
+        };
+
+        let location = element.location.as_ref().unwrap();
+        let attributes = location
+            .attributes
+            .iter()
+            .map(|(name, span)| (name.as_str(), &raw[span.start..span.end]))
+            .collect::<Vec<_>>();
+        // like for the values, the first occurrence of an attribute is kept
+        assert_eq!(
+            attributes,
+            [("align", r#"align="left""#), ("color", r#"color="red""#)]
+        );
+
+        let children = element
+            .children
+            .iter()
+            .map(|child| match child {
+                MjRawChild::Comment(inner) => source(inner.location.as_ref()),
+                MjRawChild::ConditionalComment(inner) => source(inner.location()),
+                MjRawChild::Node(inner) => source(inner.location.as_ref()),
+                MjRawChild::Text(inner) => source(inner.location()),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            children,
+            [
+                "<!-- note -->",
+                "Hello ",
+                r#"<b class="x">World</b>"#,
+                "<!--[if mso]>",
+                "mso",
+                "<![endif]-->"
+            ]
+        );
+        let MjRawChild::Node(node) = &element.children[2] else {
+            panic!("expected a node");
+        };
+        let class = node.location.as_ref().unwrap().attributes["class"];
+        assert_eq!(&raw[class.start..class.end], r#"class="x""#);
+    }
+
+    #[cfg(feature = "span")]
+    #[test]
+    fn should_equal_built_element_whatever_the_location() {
+        use crate::mj_image::MjImage;
+        use crate::mj_raw::MjRawChild;
+        use crate::prelude::AttributeMap;
+        use crate::text::Text;
+
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts);
+        let mut cursor = MrmlCursor::new(r#"<mj-image src="logo.png" />"#);
+        let parsed: MjImage = parser.parse_root(&mut cursor).unwrap();
+        assert!(parsed.location().is_some());
+
+        let mut attributes = AttributeMap::new();
+        attributes.insert("src".into(), Some("logo.png".into()));
+        let built = MjImage::new(attributes, ());
+        assert!(built.location().is_none());
+        assert_eq!(parsed, built);
+
+        let mut cursor = MrmlCursor::new("<mj-text>Hello</mj-text>");
+        let element: MjText = parser.parse_root(&mut cursor).unwrap();
+        let MjRawChild::Text(parsed) = &element.children[0] else {
+            panic!("expected a text");
+        };
+        assert!(parsed.location().is_some());
+        assert_eq!(parsed, &Text::from("Hello"));
+    }
+
+    #[cfg(not(feature = "span"))]
+    #[test]
+    fn should_not_keep_locations_by_default() {
+        use std::mem::size_of;
+
+        use crate::prelude::AttributeMap;
+        use crate::text::Text;
+
+        assert_eq!(size_of::<Text>(), size_of::<String>());
+        assert_eq!(
+            size_of::<MjText>(),
+            size_of::<(AttributeMap, Vec<crate::mj_raw::MjRawChild>)>()
+        );
+    }
 }
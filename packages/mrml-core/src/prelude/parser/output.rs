@@ -1,6 +1,22 @@
+#[non_exhaustive]
 pub struct ParseOutput<E> {
     pub element: E,
     pub warnings: Vec<Warning>,
+    /// The templates loaded with `mj-include`, directly or not, in the order
+    /// they have been loaded.
+    pub includes: Vec<Include>,
+}
+
+impl<E> ParseOutput<E> {
+    /// Output of an element that hasn't been parsed from a template, like a
+    /// deserialized one, without warnings nor includes.
+    pub fn new(element: E) -> Self {
+        Self {
+            element,
+            warnings: Vec::new(),
+            includes: Vec::new(),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -8,6 +24,10 @@ pub enum WarningKind {
     UnexpectedAttribute,
     DuplicateAttribute,
     InlineStyleUnsupported,
+    /// The value of a known attribute doesn't have the type expected by its
+    /// component, only reported by a parser
+    /// [with typed attributes](super::MrmlParser::with_typed_attributes).
+    InvalidAttributeValue,
 }
 
 impl WarningKind {
@@ -16,6 +36,7 @@ impl WarningKind {
             Self::UnexpectedAttribute => "unexpected-attribute",
             Self::DuplicateAttribute => "duplicate-attribute",
             Self::InlineStyleUnsupported => "inline-style-unsupported",
+            Self::InvalidAttributeValue => "invalid-attribute-value",
         }
     }
 }
@@ -28,6 +49,7 @@ impl std::fmt::Display for WarningKind {
             Self::InlineStyleUnsupported => {
                 f.write_str("inlining styles is not supported in this build")
             }
+            Self::InvalidAttributeValue => f.write_str("invalid attribute value"),
         }
     }
 }
@@ -39,7 +61,84 @@ pub struct Warning {
     pub span: super::Span,
 }
 
+/// Where an `mj-include` element is, defining how the included template is
+/// parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IncludeKind {
+    /// In the `mjml` element, the template can contain a `mj-head` and a
+    /// `mj-body`.
+    Mjml,
+    Body(crate::mj_include::body::MjIncludeBodyKind),
+    Head(crate::mj_include::head::MjIncludeHeadKind),
+}
+
+/// A template loaded with `mj-include`. Along with the origin of the
+/// `mj-include` element, the includes of a [`ParseOutput`] build the graph of
+/// the templates depending on each other.
+///
+/// ```rust
+/// use mrml::prelude::parser::memory_loader::MemoryIncludeLoader;
+/// use mrml::prelude::parser::{Origin, ParserOptions};
+///
+/// let loader = MemoryIncludeLoader::from(vec![
+///     ("layout.mjml", r#"<mj-body><mj-include path="header.mjml" /></mj-body>"#),
+///     ("header.mjml", r#"<mj-image src="logo.png" />"#),
+/// ]);
+/// let opts = ParserOptions {
+///     include_loader: Box::new(loader),
+/// };
+/// let template = r#"<mjml><mj-include path="layout.mjml" /></mjml>"#;
+/// let output = mrml::parse_with_options(template, &opts).unwrap();
+/// assert_eq!(output.includes[0].path, "layout.mjml");
+/// assert_eq!(output.includes[0].origin, Origin::Root);
+/// assert_eq!(output.includes[1].path, "header.mjml");
+/// assert_eq!(
+///     output.includes[1].origin,
+///     Origin::Include {
+///         path: "layout.mjml".into()
+///     }
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Include {
+    /// The path given to the include loader.
+    pub path: String,
+    pub kind: IncludeKind,
+    /// The template containing the `mj-include` element.
+    pub origin: super::Origin,
+    /// Position of the name of the `mj-include` element in its template.
+    pub span: super::Span,
+}
+
 impl super::MrmlCursor<'_> {
+    pub(crate) fn add_include(&mut self, kind: IncludeKind, path: &str, span: super::Span) {
+        self.includes.push(Include {
+            path: path.to_string(),
+            kind,
+            origin: self.origin.clone(),
+            span: super::Span {
+                start: span.start.saturating_sub(self.source_offset),
+                end: span.end.saturating_sub(self.source_offset),
+            },
+        });
+    }
+
+    pub(crate) fn output<E>(mut self, element: E) -> ParseOutput<E> {
+        let includes = std::mem::take(&mut self.includes);
+        ParseOutput {
+            element,
+            warnings: self.warnings(),
+            includes,
+        }
+    }
+
+    /// Keeps the warnings and the includes of an included template.
+    pub(crate) fn with_child(&mut self, child: super::MrmlCursor<'_>) {
+        let output = child.output(());
+        self.warnings.extend(output.warnings);
+        self.includes.extend(output.includes);
+    }
+
     pub(crate) fn add_warning<S: Into<super::Span>>(&mut self, kind: WarningKind, span: S) {
         self.warnings.push(Warning {
             kind,
@@ -63,10 +162,6 @@ impl super::MrmlCursor<'_> {
             self.warnings
         }
     }
-
-    pub(crate) fn with_warnings(&mut self, others: Vec<Warning>) {
-        self.warnings.extend(others);
-    }
 }
 
 impl std::fmt::Display for Warning {
@@ -1,20 +1,7 @@
-use std::fmt::Display;
-
 use htmlparser::{StrSpan, Token};
 
 use super::MrmlCursor;
-
-#[derive(Clone, Copy, Debug)]
-pub struct Span {
-    pub start: usize,
-    pub end: usize,
-}
-
-impl Display for Span {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}:{}", self.start, self.end)
-    }
-}
+pub use crate::prelude::Span;
 
 impl<'a> From<&StrSpan<'a>> for Span {
     fn from(value: &StrSpan<'a>) -> Self {
@@ -214,6 +201,25 @@ pub(crate) struct Text<'a> {
 }
 
 impl<'a> super::MrmlCursor<'a> {
+    /// Keeps the position of the attributes, attached to the start of their
+    /// element, to build the location of the element once parsed.
+    #[cfg(feature = "span")]
+    fn record_position(&mut self, token: &MrmlToken<'a>) {
+        match token {
+            MrmlToken::ElementStart(inner) => {
+                self.element_start = inner.span.start();
+            }
+            MrmlToken::Attribute(inner) => {
+                self.attributes.push((
+                    self.element_start,
+                    inner.qualified_name(),
+                    inner.span.into(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
     fn read_next_token(&mut self) -> Option<Result<MrmlToken<'a>, super::Error>> {
         self.tokenizer
             .next()
@@ -223,6 +229,10 @@ impl<'a> super::MrmlCursor<'a> {
                     source,
                 })
                 .and_then(|token| MrmlToken::parse(self, token))
+                .inspect(|_token| {
+                    #[cfg(feature = "span")]
+                    self.record_position(_token);
+                })
             })
             .and_then(|token| match token {
                 Ok(MrmlToken::Text(inner))
@@ -284,7 +294,13 @@ impl<'a> super::MrmlCursor<'a> {
 
     pub(crate) fn assert_element_end(&mut self) -> Result<ElementEnd<'a>, super::Error> {
         match self.next_token() {
-            Some(Ok(MrmlToken::ElementEnd(inner))) => Ok(inner),
+            Some(Ok(MrmlToken::ElementEnd(inner))) => {
+                #[cfg(feature = "span")]
+                {
+                    self.last_end = inner.span.end();
+                }
+                Ok(inner)
+            }
             Some(Ok(other)) => Err(super::Error::UnexpectedToken {
                 origin: self.origin(),
                 position: other.span(),
@@ -298,7 +314,13 @@ impl<'a> super::MrmlCursor<'a> {
 
     pub(crate) fn assert_element_close(&mut self) -> Result<ElementClose<'a>, super::Error> {
         match self.next_token() {
-            Some(Ok(MrmlToken::ElementClose(inner))) => Ok(inner),
+            Some(Ok(MrmlToken::ElementClose(inner))) => {
+                #[cfg(feature = "span")]
+                {
+                    self.last_end = inner.span.end();
+                }
+                Ok(inner)
+            }
             Some(Ok(MrmlToken::Text(inner))) if inner.text.trim().is_empty() => {
                 self.assert_element_close()
             }
@@ -0,0 +1,471 @@
+//! Printing of a template after some changes, keeping the source of the
+//! elements that didn't change, with its whitespaces, quotes and entities, so
+//! that the changes are the only differences with the source.
+
+use std::fmt::{Display, Write};
+
+use super::{DensePrinter, PrintAttribute, Printable, Printer};
+use crate::prelude::hash::Map;
+use crate::prelude::parser::Origin;
+use crate::prelude::{Location, Span};
+
+/// What an element looked like when it was parsed.
+#[derive(Debug, Default)]
+struct Original {
+    dense: String,
+    start_tag: Option<String>,
+}
+
+/// The span of the elements parsed from the template, not from an included
+/// one.
+fn root_span(location: Option<&Location>) -> Option<Span> {
+    location
+        .filter(|item| item.origin == Origin::Root)
+        .map(|item| item.span)
+}
+
+/// Position after the `>` ending the start tag of the element starting at
+/// `start`.
+fn tag_end(source: &str, start: usize) -> Option<usize> {
+    let mut quote = None;
+    for (index, c) in source.get(start..)?.char_indices() {
+        match (quote, c) {
+            (Some(expected), c) if c == expected => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '>') => return Some(start + index + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Prints the original element densely, keeping what each of its elements
+/// looked like, by position in the template.
+#[derive(Debug, Default)]
+struct Recorder {
+    inner: DensePrinter,
+    elements: Map<usize, Original>,
+    pending: Option<usize>,
+    /// Position of the element and start of its start tag in the buffer.
+    start_tag: Option<(usize, usize)>,
+}
+
+impl Recorder {
+    fn end_start_tag(&mut self) {
+        if let Some((start, from)) = self.start_tag.take() {
+            if let Some(original) = self.elements.get_mut(&start) {
+                original.start_tag = Some(self.inner.buffer[from..].to_string());
+            }
+        }
+    }
+}
+
+impl Printer for Recorder {
+    fn push_new_line(&mut self) {}
+    fn push_indent(&mut self) {}
+    fn increase_indent(&mut self) {}
+    fn decrease_indent(&mut self) {}
+
+    fn push(&mut self, value: char) {
+        self.inner.push(value);
+    }
+
+    fn push_str(&mut self, value: &str) {
+        self.inner.push_str(value);
+    }
+
+    fn open_tag<N: Display + ?Sized>(&mut self, name: &N) -> std::fmt::Result {
+        self.start_tag = self
+            .pending
+            .take()
+            .map(|start| (start, self.inner.buffer.len()));
+        self.inner.open_tag(name)
+    }
+
+    fn close_tag(&mut self) {
+        self.inner.close_tag();
+        self.end_start_tag();
+    }
+
+    fn closed_tag(&mut self) {
+        self.inner.closed_tag();
+        self.end_start_tag();
+    }
+
+    fn end_tag<N: Display + ?Sized>(&mut self, name: &N) -> std::fmt::Result {
+        self.inner.end_tag(name)
+    }
+
+    fn push_attribute<N, V>(&mut self, name: N, value: V) -> std::fmt::Result
+    where
+        PrintAttribute<N, V>: Display,
+    {
+        self.inner.push_attribute(name, value)
+    }
+
+    fn print_source<F>(
+        &mut self,
+        location: Option<&Location>,
+        dense: F,
+    ) -> Result<bool, std::fmt::Error>
+    where
+        F: FnOnce() -> Result<String, std::fmt::Error>,
+    {
+        self.pending = root_span(location).map(|span| span.start);
+        if let Some(start) = self.pending {
+            self.elements.insert(
+                start,
+                Original {
+                    dense: dense()?,
+                    start_tag: None,
+                },
+            );
+        }
+        Ok(false)
+    }
+
+    fn inner(self) -> String {
+        self.inner.inner()
+    }
+}
+
+/// An element being printed again.
+#[derive(Debug)]
+struct Frame {
+    span: Option<Span>,
+    /// Start of the start tag in the buffer, until it ends.
+    start_tag: Option<usize>,
+    /// Whitespaces before the last child parsed from the template, used for
+    /// the new children.
+    indent: String,
+}
+
+#[derive(Debug)]
+struct LosslessPrinter<'a> {
+    source: &'a str,
+    originals: Map<usize, Original>,
+    buffer: String,
+    frames: Vec<Frame>,
+    pending: Option<Span>,
+    /// End of the last part of the source that has been copied.
+    last_end: usize,
+}
+
+impl<'a> LosslessPrinter<'a> {
+    fn new(source: &'a str, originals: Map<usize, Original>) -> Self {
+        Self {
+            source,
+            originals,
+            buffer: String::new(),
+            frames: Vec::new(),
+            pending: None,
+            last_end: 0,
+        }
+    }
+
+    fn copy(&mut self, start: usize, end: usize) {
+        self.buffer.push_str(&self.source[start..end]);
+        self.last_end = end;
+    }
+
+    /// Copies the whitespaces before `position` that haven't been copied yet.
+    fn copy_whitespaces_before(&mut self, position: usize) -> &'a str {
+        let source = self.source;
+        let mut start = source[..position].trim_end().len();
+        if self.last_end <= position {
+            start = start.max(self.last_end);
+            self.last_end = position;
+        }
+        self.buffer.push_str(&source[start..position]);
+        &source[start..position]
+    }
+
+    fn end_start_tag(&mut self) {
+        let Some(frame) = self.frames.last_mut() else {
+            return;
+        };
+        let (Some(span), Some(from)) = (frame.span, frame.start_tag.take()) else {
+            return;
+        };
+        let Some(end) = tag_end(self.source, span.start) else {
+            return;
+        };
+        let unchanged = self
+            .originals
+            .get(&span.start)
+            .and_then(|original| original.start_tag.as_deref())
+            .is_some_and(|original| original == &self.buffer[from..]);
+        if unchanged {
+            self.buffer.truncate(from);
+            self.buffer.push_str(&self.source[span.start..end]);
+        }
+        self.last_end = end;
+    }
+}
+
+impl Printer for LosslessPrinter<'_> {
+    fn push_new_line(&mut self) {}
+    fn push_indent(&mut self) {}
+    fn increase_indent(&mut self) {}
+    fn decrease_indent(&mut self) {}
+
+    fn push(&mut self, value: char) {
+        self.buffer.push(value);
+    }
+
+    fn push_str(&mut self, value: &str) {
+        self.buffer.push_str(value);
+    }
+
+    fn open_tag<N: Display + ?Sized>(&mut self, name: &N) -> std::fmt::Result {
+        self.frames.push(Frame {
+            span: self.pending.take(),
+            start_tag: Some(self.buffer.len()),
+            indent: String::new(),
+        });
+        write!(&mut self.buffer, "<{name}")
+    }
+
+    fn close_tag(&mut self) {
+        self.buffer.push('>');
+        self.end_start_tag();
+    }
+
+    fn closed_tag(&mut self) {
+        self.buffer.push_str(" />");
+        self.end_start_tag();
+        if let Some(span) = self.frames.pop().and_then(|frame| frame.span) {
+            self.last_end = span.end;
+        }
+    }
+
+    fn end_tag<N: Display + ?Sized>(&mut self, name: &N) -> std::fmt::Result {
+        let end_tag = format!("</{name}>");
+        if let Some(span) = self.frames.pop().and_then(|frame| frame.span) {
+            if self.source[span.start..span.end].ends_with(&end_tag) {
+                self.copy_whitespaces_before(span.end - end_tag.len());
+            }
+            self.buffer.push_str(&end_tag);
+            self.last_end = span.end;
+        } else {
+            self.buffer.push_str(&end_tag);
+        }
+        Ok(())
+    }
+
+    fn push_attribute<N, V>(&mut self, name: N, value: V) -> std::fmt::Result
+    where
+        PrintAttribute<N, V>: Display,
+    {
+        write!(&mut self.buffer, " {}", PrintAttribute::from((name, value)))
+    }
+
+    fn print_source<F>(
+        &mut self,
+        location: Option<&Location>,
+        dense: F,
+    ) -> Result<bool, std::fmt::Error>
+    where
+        F: FnOnce() -> Result<String, std::fmt::Error>,
+    {
+        let span = root_span(location);
+        // the children of an element printed again are placed like in the
+        // source, the new ones like their previous sibling
+        if self.frames.last().is_some_and(|frame| frame.span.is_some()) {
+            let indent = match span {
+                Some(span) => self.copy_whitespaces_before(span.start).to_string(),
+                None => {
+                    let indent = self.frames.last().map(|frame| frame.indent.clone());
+                    let indent = indent.unwrap_or_default();
+                    self.buffer.push_str(&indent);
+                    indent
+                }
+            };
+            if let Some(frame) = self.frames.last_mut() {
+                frame.indent = indent;
+            }
+        }
+        self.pending = span;
+        let Some(span) = span else {
+            return Ok(false);
+        };
+        let unchanged = match self.originals.get(&span.start) {
+            Some(original) => original.dense == dense()?,
+            None => false,
+        };
+        if unchanged {
+            self.pending = None;
+            self.copy(span.start, span.end);
+        }
+        Ok(unchanged)
+    }
+
+    fn inner(self) -> String {
+        self.buffer
+    }
+}
+
+/// Prints an element parsed from `source` into `original`, after some
+/// changes, keeping the source of the elements that didn't change. The
+/// elements that changed are printed again, with the start tag and the
+/// whitespaces around their children kept from the source when possible, so
+/// that the changes are the only differences with the source.
+///
+/// The template must have been parsed with the `span` feature, and the
+/// elements loaded with `mj-include` are printed as `mj-include` elements.
+///
+/// ```rust
+/// use mrml::mj_body::MjBodyChild;
+/// use mrml::prelude::print::print_lossless;
+///
+/// let source = r#"<mjml>
+///   <mj-body>
+///     <mj-text   color='red'>Hello &amp; welcome</mj-text>
+///     <mj-button href="https://example.com">Go</mj-button>
+///   </mj-body>
+/// </mjml>"#;
+/// let original = mrml::parse(source).unwrap().element;
+/// let mut root = original.clone();
+/// if let Some(MjBodyChild::MjButton(button)) = root
+///     .children
+///     .body
+///     .as_mut()
+///     .and_then(|body| body.children.get_mut(1))
+/// {
+///     button
+///         .attributes
+///         .insert("href".into(), Some("https://example.org".into()));
+/// }
+/// assert_eq!(
+///     print_lossless(&root, &original, source).unwrap(),
+///     source.replace("example.com", "example.org")
+/// );
+/// ```
+pub fn print_lossless<E: Printable>(
+    element: &E,
+    original: &E,
+    source: &str,
+) -> Result<String, std::fmt::Error> {
+    let mut recorder = Recorder::default();
+    original.print(&mut recorder)?;
+    let mut printer = LosslessPrinter::new(source, recorder.elements);
+    element.print(&mut printer)?;
+    Ok(printer.inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::print_lossless;
+    use crate::mj_body::MjBodyChild;
+    use crate::mj_column::MjColumn;
+    use crate::mj_section::MjSection;
+    use crate::mj_text::MjText;
+    use crate::mjml::Mjml;
+    use crate::text::Text;
+
+    const SOURCE: &str = r#"<mjml>
+  <mj-head>
+    <mj-title>Hello &amp; welcome</mj-title>
+  </mj-head>
+  <mj-body>
+    <!-- the header -->
+    <mj-section padding='0'  css-class="header">
+      <mj-column>
+        <mj-text font-size="20px">
+          Hello <b>World</b>&nbsp;!
+        </mj-text>
+      </mj-column>
+    </mj-section>
+    <mj-section><mj-column><mj-image src="logo.png"/></mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+
+    fn sections(root: &mut Mjml) -> impl Iterator<Item = &mut MjSection> {
+        root.children
+            .body
+            .as_mut()
+            .unwrap()
+            .children
+            .iter_mut()
+            .filter_map(|child| match child {
+                MjBodyChild::MjSection(inner) => Some(inner),
+                _ => None,
+            })
+    }
+
+    fn column(section: &mut MjSection) -> &mut MjColumn {
+        match section.children.first_mut() {
+            Some(MjBodyChild::MjColumn(inner)) => inner,
+            _ => panic!("expected a column"),
+        }
+    }
+
+    #[test]
+    fn should_print_source_without_change() {
+        let original = Mjml::parse(SOURCE).unwrap().element;
+        let printed = print_lossless(&original, &original, SOURCE).unwrap();
+        similar_asserts::assert_eq!(printed, SOURCE);
+    }
+
+    #[test]
+    fn should_only_print_changed_attribute() {
+        let original = Mjml::parse(SOURCE).unwrap().element;
+        let mut root = original.clone();
+        let section = sections(&mut root).nth(1).unwrap();
+        section
+            .attributes
+            .insert("background-color".into(), Some("#fff".into()));
+        let printed = print_lossless(&root, &original, SOURCE).unwrap();
+        similar_asserts::assert_eq!(
+            printed,
+            SOURCE.replace(
+                "<mj-section><mj-column>",
+                "<mj-section background-color=\"#fff\"><mj-column>"
+            )
+        );
+    }
+
+    #[test]
+    fn should_keep_source_around_nested_change() {
+        let original = Mjml::parse(SOURCE).unwrap().element;
+        let mut root = original.clone();
+        let section = sections(&mut root).next().unwrap();
+        let Some(MjBodyChild::MjText(text)) = column(section).children.first_mut() else {
+            panic!("expected a text");
+        };
+        text.attributes
+            .insert("font-size".into(), Some("24px".into()));
+        let printed = print_lossless(&root, &original, SOURCE).unwrap();
+        similar_asserts::assert_eq!(printed, SOURCE.replace("20px", "24px"));
+    }
+
+    #[test]
+    fn should_place_new_child_like_its_sibling() {
+        let original = Mjml::parse(SOURCE).unwrap().element;
+        let mut root = original.clone();
+        let section = sections(&mut root).next().unwrap();
+        let column = column(section);
+        column.children.push(MjBodyChild::MjText(MjText::new(
+            Default::default(),
+            vec![Text::from("Bye").into()],
+        )));
+        let printed = print_lossless(&root, &original, SOURCE).unwrap();
+        similar_asserts::assert_eq!(
+            printed,
+            SOURCE.replace(
+                "        </mj-text>\n",
+                "        </mj-text>\n        <mj-text>Bye</mj-text>\n"
+            )
+        );
+    }
+
+    #[test]
+    fn should_remove_child() {
+        let original = Mjml::parse(SOURCE).unwrap().element;
+        let mut root = original.clone();
+        root.children.body.as_mut().unwrap().children.remove(0);
+        let printed = print_lossless(&root, &original, SOURCE).unwrap();
+        similar_asserts::assert_eq!(printed, SOURCE.replace("\n    <!-- the header -->", ""));
+    }
+}
@@ -3,6 +3,12 @@ use std::marker::PhantomData;
 
 use crate::prelude::hash::Map;
 
+#[cfg(feature = "span")]
+mod lossless;
+
+#[cfg(feature = "span")]
+pub use self::lossless::print_lossless;
+
 pub(crate) trait PrintableAttributes {
     fn print<P: Printer>(&self, printer: &mut P) -> std::fmt::Result;
 }
@@ -67,6 +73,11 @@ impl<T: StaticTag, A: PrintableAttributes, C: PrintableChildren> PrintableElemen
     fn children(&self) -> &Self::Children {
         &self.children
     }
+
+    #[cfg(feature = "span")]
+    fn location(&self) -> Option<&super::Location> {
+        self.location.as_ref()
+    }
 }
 
 use super::StaticTag;
@@ -89,6 +100,7 @@ use crate::mj_font::MjFont;
 use crate::mj_group::MjGroup;
 use crate::mj_head::MjHeadChild;
 use crate::mj_hero::MjHero;
+use crate::mj_html_attributes::MjHtmlAttributes;
 use crate::mj_image::MjImage;
 use crate::mj_include::body::MjIncludeBody;
 use crate::mj_include::head::MjIncludeHead;
@@ -142,10 +154,20 @@ pub(crate) trait PrintableElement {
     fn tag(&self) -> &str;
     fn attributes(&self) -> &Self::Attrs;
     fn children(&self) -> &Self::Children;
+
+    /// Where the element has been parsed from.
+    #[cfg(feature = "span")]
+    fn location(&self) -> Option<&super::Location> {
+        None
+    }
 }
 
 impl<E: PrintableElement> Printable for E {
     fn print<P: Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        #[cfg(feature = "span")]
+        if printer.print_source(self.location(), || self.print_dense())? {
+            return Ok(());
+        }
         let tag = self.tag();
         let attrs = self.attributes();
         let children = self.children();
@@ -204,6 +226,13 @@ pub trait Printer {
     fn push(&mut self, value: char);
     fn push_str(&mut self, value: &str);
 
+    /// Pushes the content of a text node, on its own line.
+    fn push_text(&mut self, value: &str) {
+        self.push_indent();
+        self.push_str(value);
+        self.push_new_line();
+    }
+
     fn open_tag<N: Display + ?Sized>(&mut self, name: &N) -> std::fmt::Result;
     fn close_tag(&mut self) {
         self.push('>');
@@ -217,6 +246,21 @@ pub trait Printer {
     where
         PrintAttribute<N, V>: Display;
 
+    /// Called before printing an element, a text or a comment, with the
+    /// location it has been parsed from. Returns `true` when the printer
+    /// printed it from the source of the template already.
+    #[cfg(feature = "span")]
+    fn print_source<F>(
+        &mut self,
+        _location: Option<&super::Location>,
+        _dense: F,
+    ) -> Result<bool, std::fmt::Error>
+    where
+        F: FnOnce() -> Result<String, std::fmt::Error>,
+    {
+        Ok(false)
+    }
+
     fn inner(self) -> String;
 }
 
@@ -2,25 +2,92 @@ use std::fmt::Write;
 
 use super::{Classes, Styles};
 
-#[derive(Debug, Default)]
-pub(crate) struct RenderBuffer {
+/// Size of the output kept in memory before being written to the sink.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+#[derive(Default)]
+pub(crate) struct RenderBuffer<'w> {
     inner: String,
+    /// Where the output is written once it reaches [`CHUNK_SIZE`], when
+    /// streaming.
+    sink: Option<&'w mut dyn std::fmt::Write>,
+    /// Length of the output already written to the sink.
+    flushed: usize,
+    failed: bool,
+}
+
+impl std::fmt::Debug for RenderBuffer<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RenderBuffer")
+            .field("inner", &self.inner)
+            .field("streaming", &self.sink.is_some())
+            .field("flushed", &self.flushed)
+            .finish()
+    }
 }
 
-impl std::fmt::Write for RenderBuffer {
+impl std::fmt::Write for RenderBuffer<'_> {
     #[inline]
     fn write_fmt(&mut self, args: std::fmt::Arguments<'_>) -> std::fmt::Result {
-        self.inner.write_fmt(args)
+        self.inner.write_fmt(args)?;
+        self.written();
+        Ok(())
     }
 
     #[inline]
     fn write_str(&mut self, s: &str) -> std::fmt::Result {
-        self.inner.write_str(s)
+        self.inner.write_str(s)?;
+        self.written();
+        Ok(())
     }
 
     #[inline]
     fn write_char(&mut self, c: char) -> std::fmt::Result {
-        self.inner.write_char(c)
+        self.inner.write_char(c)?;
+        self.written();
+        Ok(())
+    }
+}
+
+impl<'w> RenderBuffer<'w> {
+    /// Creates a buffer writing its output to the sink by chunks instead of
+    /// keeping it in memory.
+    pub fn streaming(sink: &'w mut dyn std::fmt::Write) -> Self {
+        Self {
+            inner: String::with_capacity(CHUNK_SIZE),
+            sink: Some(sink),
+            flushed: 0,
+            failed: false,
+        }
+    }
+
+    #[inline]
+    fn written(&mut self) {
+        if self.sink.is_some() && self.inner.len() >= CHUNK_SIZE {
+            self.flush_chunk();
+        }
+    }
+
+    #[cold]
+    fn flush_chunk(&mut self) {
+        if let Some(sink) = self.sink.as_mut() {
+            if !self.failed && sink.write_str(&self.inner).is_err() {
+                self.failed = true;
+            }
+            self.flushed += self.inner.len();
+            self.inner.clear();
+        }
+    }
+
+    /// Writes what remains of the output to the sink, failing if the sink
+    /// failed at any point.
+    pub fn flush(&mut self) -> std::fmt::Result {
+        self.flush_chunk();
+        if self.failed {
+            Err(std::fmt::Error)
+        } else {
+            Ok(())
+        }
     }
 }
 
@@ -53,15 +120,22 @@ impl<'a> std::fmt::Display for RenderAttribute<&'a str, Option<&'a str>> {
     }
 }
 
-impl RenderBuffer {
+impl RenderBuffer<'_> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.flushed + self.inner.len()
+    }
+
     #[inline]
     pub fn push_str(&mut self, value: &str) {
         self.inner.push_str(value);
+        self.written();
     }
 
     #[inline]
     pub fn push(&mut self, value: char) {
         self.inner.push(value);
+        self.written();
     }
 
     #[inline]
@@ -69,23 +143,28 @@ impl RenderBuffer {
     where
         RenderAttribute<N, V>: std::fmt::Display,
     {
-        write!(&mut self.inner, " {}", RenderAttribute(key, value))
+        write!(&mut self.inner, " {}", RenderAttribute(key, value))?;
+        self.written();
+        Ok(())
     }
 
     #[inline]
     pub fn open_tag(&mut self, tag: &str) {
         self.inner.push('<');
         self.inner.push_str(tag);
+        self.written();
     }
 
     #[inline]
     pub fn closed_tag(&mut self) {
         self.inner.push_str(" />");
+        self.written();
     }
 
     #[inline]
     pub fn close_tag(&mut self) {
         self.inner.push('>');
+        self.written();
     }
 
     #[inline]
@@ -93,6 +172,7 @@ impl RenderBuffer {
         self.inner.push_str("</");
         self.inner.push_str(tag);
         self.inner.push('>');
+        self.written();
     }
 }
 
@@ -103,46 +183,52 @@ const START_NEGATION_CONDITIONAL_TAG: &str = "<!--[if !mso | IE]><!-->";
 const START_MSO_NEGATION_CONDITIONAL_TAG: &str = "<!--[if !mso]><!-->";
 const END_NEGATION_CONDITIONAL_TAG: &str = "<!--<![endif]-->";
 
-impl RenderBuffer {
+impl RenderBuffer<'_> {
     #[inline]
     pub fn start_conditional_tag(&mut self) {
         self.inner.push_str(START_CONDITIONAL_TAG);
+        self.written();
     }
 
     #[inline]
     pub fn start_negation_conditional_tag(&mut self) {
         self.inner.push_str(START_NEGATION_CONDITIONAL_TAG);
+        self.written();
     }
 
     #[inline]
     pub fn start_mso_conditional_tag(&mut self) {
         self.inner.push_str(START_MSO_CONDITIONAL_TAG);
+        self.written();
     }
 
     #[inline]
     pub fn start_mso_negation_conditional_tag(&mut self) {
         self.inner.push_str(START_MSO_NEGATION_CONDITIONAL_TAG);
+        self.written();
     }
 
     #[inline]
     pub fn end_conditional_tag(&mut self) {
         self.inner.push_str(END_CONDITIONAL_TAG);
+        self.written();
     }
 
     #[inline]
     pub fn end_negation_conditional_tag(&mut self) {
         self.inner.push_str(END_NEGATION_CONDITIONAL_TAG);
+        self.written();
     }
 }
 
-impl AsRef<str> for RenderBuffer {
+impl AsRef<str> for RenderBuffer<'_> {
     fn as_ref(&self) -> &str {
         self.inner.as_str()
     }
 }
 
-impl From<RenderBuffer> for String {
-    fn from(value: RenderBuffer) -> Self {
+impl From<RenderBuffer<'_>> for String {
+    fn from(value: RenderBuffer<'_>) -> Self {
         value.inner
     }
 }
@@ -3,6 +3,7 @@ use std::convert::TryFrom;
 
 use crate::helper::size::{Pixel, Size};
 use crate::mj_head::MjHead;
+use crate::mj_style::StyleInlineMode;
 use crate::prelude::hash::{Map, Set};
 
 pub(crate) fn parse_font_families(value: &str) -> impl Iterator<Item = &str> + '_ {
@@ -170,6 +171,7 @@ pub(crate) struct Header<'h> {
     attributes_element: Map<&'h str, Map<&'h str, &'h str>>,
     breakpoint: Pixel,
     font_families: Map<&'h str, &'h str>,
+    mj_styles: Vec<(&'h str, StyleInlineMode)>,
     preview: Option<String>,
     title: Option<String>,
     lang: &'h str,
@@ -200,6 +202,9 @@ impl<'h> Header<'h> {
                 .as_ref()
                 .map(|h| h.build_font_families())
                 .unwrap_or_default(),
+            mj_styles: head
+                .map(|h| h.mj_style_iter().collect())
+                .unwrap_or_default(),
             preview: head.and_then(|h| h.preview().map(|t| t.content())),
             title: head
                 .and_then(|h| h.title())
@@ -235,6 +240,11 @@ impl<'h> Header<'h> {
         &self.font_families
     }
 
+    /// Content of the `mj-style` elements, including the included ones.
+    pub fn mj_styles(&self) -> &[(&'h str, StyleInlineMode)] {
+        &self.mj_styles
+    }
+
     pub fn lang(&self) -> &str {
         self.lang
     }
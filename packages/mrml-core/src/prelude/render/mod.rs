@@ -1,25 +1,55 @@
+use std::borrow::Cow;
 use std::convert::TryFrom;
 use std::sync::atomic::{AtomicU16, Ordering};
 
 use crate::helper::size::{Pixel, Size};
 use crate::helper::spacing::Spacing;
+use crate::prelude::print::Printable;
 
 mod buffer;
 mod header;
 mod options;
+mod report;
+#[cfg(feature = "source-map")]
+mod source_map;
 mod tag;
+pub(crate) mod text;
 
 pub(crate) use buffer::*;
 pub(crate) use header::*;
 pub use options::*;
+pub use report::*;
+#[cfg(feature = "source-map")]
+pub use source_map::{Mapping, SourceMap};
 pub(crate) use tag::*;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("unknown fragment {0}")]
     UnknownFragment(String),
+    #[error("invalid selector {0:?} in mj-html-attributes")]
+    InvalidSelector(String),
+    #[error("missing variable {name:?} at position {value_span} of its value")]
+    #[cfg(feature = "template")]
+    MissingVariable {
+        name: String,
+        /// Position of the placeholder in the value being resolved, the text
+        /// or the value of `attribute`, not in the whole template.
+        value_span: crate::prelude::Span,
+        /// Tag of the innermost element containing the value.
+        element: Option<String>,
+        /// Name of the attribute, when the value is an attribute value.
+        attribute: Option<String>,
+        /// Where the innermost text or element containing the value has been
+        /// parsed from, with the position of `attribute` in its attributes.
+        /// `None` when it has been built another way.
+        #[cfg(feature = "span")]
+        location: Option<Box<crate::prelude::Location>>,
+    },
     #[error("unable to format {0}")]
     Format(#[from] std::fmt::Error),
+    #[error("unable to write the output {0}")]
+    Write(#[from] std::io::Error),
     #[error("unable to inline css {0}")]
     #[cfg(feature = "css-inline")]
     InlineCSS(#[from] css_inline::InlineError),
@@ -29,32 +59,121 @@ pub enum Error {
 pub(crate) struct Generator(AtomicU16);
 
 impl Generator {
+    pub fn next_index(&self) -> u16 {
+        self.0.fetch_add(1, Ordering::SeqCst)
+    }
+
     pub fn next_id(&self) -> String {
-        let id = self.0.fetch_add(1, Ordering::SeqCst);
+        let id = self.next_index();
         format!("{id:0>8}")
     }
 }
 
+/// FNV-1a hash, giving the same ids on every platform.
+struct IdHasher(u64);
+
+impl Default for IdHasher {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl std::fmt::Write for IdHasher {
+    fn write_str(&mut self, value: &str) -> std::fmt::Result {
+        for byte in value.bytes() {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+        Ok(())
+    }
+}
+
+fn content_hash<E: Printable>(seed: &str, element: &E, index: u16) -> String {
+    use std::fmt::Write;
+
+    // printing in a string never fails
+    let dense = element.print_dense().unwrap_or_default();
+    let mut hasher = IdHasher::default();
+    // writing in the hasher never fails
+    let _ = write!(hasher, "{seed}\0{dense}\0{index}");
+    format!("{:016x}", hasher.0)
+}
+
+/// Escapes a value written in an attribute. The values of the template are
+/// escaped already, so they have to be decoded first.
+pub(crate) fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
+
 pub(crate) struct RenderContext<'h> {
     pub options: &'h RenderOptions,
-    pub header: Header<'h>,
+    pub header: &'h Header<'h>,
     pub generator: Generator,
 }
 
 impl<'h> RenderContext<'h> {
-    pub fn new(options: &'h RenderOptions, header: Header<'h>) -> Self {
+    pub fn new(options: &'h RenderOptions, header: &'h Header<'h>) -> Self {
         Self {
             options,
             header,
             generator: Generator::default(),
         }
     }
+
+    /// Generates the id of an element, following the [`IdStrategy`] of the
+    /// options.
+    pub fn next_id<E: Printable>(&self, tag: &str, element: &E) -> String {
+        match self.options.id_strategy {
+            IdStrategy::Counter => self.generator.next_id(),
+            IdStrategy::Prefixed(ref prefix) => format!("{prefix}{}", self.generator.next_id()),
+            IdStrategy::ContentHash(ref seed) => {
+                content_hash(seed, element, self.generator.next_index())
+            }
+            IdStrategy::Custom(ref generator) => {
+                generator.generate(tag, self.generator.next_index())
+            }
+        }
+    }
+
+    /// Applies the [`LinkRewriter`] of the options to a link of the element,
+    /// escaping the new url to be written in the attribute.
+    pub fn rewrite_link<'a, U: Into<Cow<'a, str>>>(
+        &self,
+        tag: &str,
+        attributes: &crate::prelude::AttributeMap,
+        url: U,
+    ) -> Cow<'a, str> {
+        let url = url.into();
+        let decoded = text::decode_entities(&url);
+        match rewrite_link(
+            self.options.link_rewriter.as_deref(),
+            tag,
+            attributes,
+            &decoded,
+        ) {
+            Some(value) => Cow::Owned(escape_attribute(&value)),
+            None => url,
+        }
+    }
 }
 
 #[derive(Debug, Default)]
-pub(crate) struct RenderCursor {
-    pub buffer: RenderBuffer,
+pub(crate) struct RenderCursor<'w> {
+    pub buffer: RenderBuffer<'w>,
     pub header: VariableHeader,
+    /// Mappings recorded while rendering, only when a source map is requested.
+    #[cfg(feature = "source-map")]
+    pub source_map: Option<SourceMap>,
+    /// Element having added each style of the header, by index of the style,
+    /// recorded along with the source map.
+    #[cfg(feature = "lint")]
+    pub style_origins: Vec<(usize, Mapping)>,
+    /// Position of the html of each child of `mj-body` in the buffer, only
+    /// when a report of the sizes is requested.
+    pub sections: Option<Vec<std::ops::Range<usize>>>,
 }
 
 pub(crate) struct Renderer<'root, Element, Extra> {
@@ -84,6 +203,15 @@ impl<'root, Element, Extra> Renderer<'root, Element, Extra> {
             extra,
         }
     }
+
+    #[cfg(not(feature = "source-map"))]
+    #[inline]
+    pub fn boxed<'render>(self) -> Box<dyn Render<'root> + 'render>
+    where
+        Self: Render<'root> + 'render,
+    {
+        Box::new(self)
+    }
 }
 
 pub(crate) trait Render<'root> {
@@ -116,6 +244,20 @@ pub(crate) trait Render<'root> {
             .and_then(|value| Spacing::try_from(value).ok())
     }
 
+    /// Reads an attribute holding the url of an asset, resolved with the
+    /// [`AssetResolver`] and the base url of the options.
+    fn attribute_as_asset<'a>(&'a self, name: &str) -> Option<Cow<'a, str>>
+    where
+        'root: 'a,
+    {
+        let url = self.attribute(name)?;
+        Some(self.context().options.resolve_asset(
+            self.tag().unwrap_or_default(),
+            name,
+            Cow::Borrowed(url),
+        ))
+    }
+
     fn attribute_equals(&self, key: &str, value: &str) -> bool {
         self.attribute(key).map(|res| res == value).unwrap_or(false)
     }
@@ -300,6 +442,12 @@ macro_rules! should_render {
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn should_escape_attribute() {
+        assert_eq!(super::escape_attribute(r#"a&b"c"#), "a&amp;b&quot;c");
+        assert_eq!(super::escape_attribute("&quot;<"), "&amp;quot;&lt;");
+    }
+
     #[test]
     fn header_should_increase() {
         let gen = super::Generator::default();
@@ -307,4 +455,78 @@ mod tests {
         assert_eq!(gen.next_id(), "00000001");
         assert_eq!(gen.next_id(), "00000002");
     }
+
+    #[cfg(feature = "parse")]
+    fn navbar_ids(template: &str, opts: &super::RenderOptions) -> Vec<String> {
+        let root = crate::parse(template).unwrap();
+        let html = root.element.render(opts).unwrap();
+        html.match_indices("<input id=\"")
+            .map(|(index, found)| {
+                let rest = &html[index + found.len()..];
+                rest[..rest.find('"').unwrap()].to_string()
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "parse")]
+    const NAVBARS: &str = r#"<mjml><mj-body>
+  <mj-navbar hamburger="hamburger"><mj-navbar-link href="/a">A</mj-navbar-link></mj-navbar>
+  <mj-navbar hamburger="hamburger"><mj-navbar-link href="/a">A</mj-navbar-link></mj-navbar>
+</mj-body></mjml>"#;
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_generate_ids_with_strategy() {
+        use std::sync::Arc;
+
+        use super::{IdStrategy, RenderOptions};
+
+        let opts = RenderOptions::default();
+        assert_eq!(navbar_ids(NAVBARS, &opts), ["00000000", "00000001"]);
+
+        let opts = RenderOptions {
+            id_strategy: IdStrategy::Prefixed("first-".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            navbar_ids(NAVBARS, &opts),
+            ["first-00000000", "first-00000001"]
+        );
+
+        let opts = RenderOptions {
+            id_strategy: IdStrategy::Custom(Arc::new(|tag: &str, index: u16| {
+                format!("{tag}-{index}")
+            })),
+            ..Default::default()
+        };
+        assert_eq!(navbar_ids(NAVBARS, &opts), ["mj-navbar-0", "mj-navbar-1"]);
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_generate_ids_from_content() {
+        use super::{IdStrategy, RenderOptions};
+
+        let opts = RenderOptions {
+            id_strategy: IdStrategy::ContentHash("newsletter".into()),
+            ..Default::default()
+        };
+        let ids = navbar_ids(NAVBARS, &opts);
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+        assert!(ids[0].chars().all(|c| c.is_ascii_hexdigit()));
+        // the same template gives the same ids
+        assert_eq!(navbar_ids(NAVBARS, &opts), ids);
+        // another template gives other ids
+        let other = NAVBARS.replace("/a", "/b");
+        let other_ids = navbar_ids(&other, &opts);
+        assert!(other_ids.iter().all(|id| !ids.contains(id)));
+        // and so does another seed
+        let opts = RenderOptions {
+            id_strategy: IdStrategy::ContentHash("footer".into()),
+            ..Default::default()
+        };
+        let other_ids = navbar_ids(NAVBARS, &opts);
+        assert!(other_ids.iter().all(|id| !ids.contains(id)));
+    }
 }
@@ -1,5 +1,8 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::prelude::AttributeMap;
 
 pub fn default_fonts() -> HashMap<String, Cow<'static, str>> {
     HashMap::from([
@@ -26,11 +29,286 @@ pub fn default_fonts() -> HashMap<String, Cow<'static, str>> {
     ])
 }
 
+/// A link of the template, given to the [`LinkRewriter`].
+#[derive(Clone, Copy, Debug)]
+pub struct Link<'a> {
+    /// Name of the element holding the link, like `mj-button` or `a`.
+    pub tag: &'a str,
+    /// Attributes of the element, as written in the template.
+    pub attributes: &'a AttributeMap,
+    /// The url, as it would be rendered without rewriting, with its html
+    /// entities decoded.
+    pub url: &'a str,
+}
+
+/// Rewrites the links of a template while rendering it, to add tracking
+/// parameters or redirections for example.
+///
+/// It's applied to the `href` of `mj-button`, `mj-image`, `mj-navbar-link`,
+/// `mj-social-element`, `mj-carousel-image` and of the `a` elements, in the
+/// html and in the text rendering. The new url is escaped when written in an
+/// attribute of the html.
+///
+/// ```rust
+/// # #[cfg(feature = "parse")]
+/// # {
+/// use std::sync::Arc;
+///
+/// use mrml::prelude::render::{Link, RenderOptions};
+///
+/// let opts = RenderOptions::default().with_link_rewriter(Arc::new(|link: &Link<'_>| {
+///     Some(format!("{}?utm_source=newsletter&utm_content={}", link.url, link.tag))
+/// }));
+/// let root = mrml::parse(r#"<mjml><mj-body><mj-button href="https://example.com">Go</mj-button></mj-body></mjml>"#).unwrap();
+/// let html = root.element.render(&opts).unwrap();
+/// assert!(html.contains("https://example.com?utm_source=newsletter&amp;utm_content=mj-button"));
+/// # }
+/// ```
+pub trait LinkRewriter: Send + Sync {
+    /// Returns the url replacing the link, `None` to keep it.
+    fn rewrite(&self, link: &Link<'_>) -> Option<String>;
+}
+
+impl<F> LinkRewriter for F
+where
+    F: Fn(&Link<'_>) -> Option<String> + Send + Sync,
+{
+    fn rewrite(&self, link: &Link<'_>) -> Option<String> {
+        self(link)
+    }
+}
+
+impl std::fmt::Debug for dyn LinkRewriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LinkRewriter")
+    }
+}
+
+/// Returns the url replacing the link, `None` to keep it.
+pub(crate) fn rewrite_link(
+    rewriter: Option<&dyn LinkRewriter>,
+    tag: &str,
+    attributes: &AttributeMap,
+    url: &str,
+) -> Option<String> {
+    rewriter?.rewrite(&Link {
+        tag,
+        attributes,
+        url,
+    })
+}
+
+/// An asset of the template, like an image, given to the [`AssetResolver`].
+#[derive(Clone, Copy, Debug)]
+pub struct Asset<'a> {
+    /// Name of the element using the asset, like `mj-image`.
+    pub tag: &'a str,
+    /// Name of the attribute holding the url, like `src` or `background-url`.
+    pub attribute: &'a str,
+    /// The url, as written in the template.
+    pub url: &'a str,
+}
+
+/// Resolves the url of the assets of a template while rendering it, to serve
+/// them from a CDN for example.
+///
+/// It's applied to the `src` of `mj-image`, `mj-carousel-image` and
+/// `mj-social-element`, the `thumbnails-src` of `mj-carousel-image`, the
+/// `background-url` of `mj-hero`, `mj-section` and `mj-wrapper` and the
+/// `href` of `mj-font`.
+///
+/// When the resolver keeps the url, the relative ones are prefixed with the
+/// [`base_url`](RenderOptions::base_url) of the options.
+///
+/// ```rust
+/// # #[cfg(feature = "parse")]
+/// # {
+/// use std::sync::Arc;
+///
+/// use mrml::prelude::render::{Asset, RenderOptions};
+///
+/// let opts = RenderOptions::default()
+///     .with_base_url("https://cdn.example.com/")
+///     .with_asset_resolver(Arc::new(|asset: &Asset<'_>| {
+///         asset.url.strip_prefix("logo:").map(|name| format!("https://logos.example.com/{name}.png"))
+///     }));
+/// let root = mrml::parse(r#"<mjml><mj-body><mj-image src="logo:acme" /><mj-image src="images/banner.png" /></mj-body></mjml>"#).unwrap();
+/// let html = root.element.render(&opts).unwrap();
+/// assert!(html.contains("src=\"https://logos.example.com/acme.png\""));
+/// assert!(html.contains("src=\"https://cdn.example.com/images/banner.png\""));
+/// # }
+/// ```
+pub trait AssetResolver: Send + Sync {
+    /// Returns the url replacing the one of the asset, `None` to keep it.
+    fn resolve(&self, asset: &Asset<'_>) -> Option<String>;
+}
+
+impl<F> AssetResolver for F
+where
+    F: Fn(&Asset<'_>) -> Option<String> + Send + Sync,
+{
+    fn resolve(&self, asset: &Asset<'_>) -> Option<String> {
+        self(asset)
+    }
+}
+
+impl std::fmt::Debug for dyn AssetResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AssetResolver")
+    }
+}
+
+/// Checks that the url has no scheme, like `https:` or `data:`, and is not
+/// relative to the protocol, like `//example.com/image.png`.
+fn is_relative_url(url: &str) -> bool {
+    if url.is_empty() || url.starts_with("//") || url.starts_with('#') {
+        return false;
+    }
+    let scheme_end =
+        url.find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')));
+    !matches!(scheme_end, Some(index) if index > 0 && url[index..].starts_with(':'))
+}
+
+fn join_url(base: &str, url: &str) -> String {
+    let url = url.trim_start_matches("./").trim_start_matches('/');
+    format!("{}/{url}", base.trim_end_matches('/'))
+}
+
+impl RenderOptions {
+    pub(crate) fn resolve_asset<'a>(
+        &self,
+        tag: &str,
+        attribute: &str,
+        url: Cow<'a, str>,
+    ) -> Cow<'a, str> {
+        let asset = Asset {
+            tag,
+            attribute,
+            url: url.as_ref(),
+        };
+        if let Some(value) = self
+            .asset_resolver
+            .as_ref()
+            .and_then(|resolver| resolver.resolve(&asset))
+        {
+            return Cow::Owned(value);
+        }
+        match self.base_url {
+            Some(ref base) if is_relative_url(&url) => Cow::Owned(join_url(base, &url)),
+            _ => url,
+        }
+    }
+}
+
+/// Generates the ids of the elements, see [`IdStrategy::Custom`].
+pub trait IdGenerator: Send + Sync {
+    /// Returns the id of an element, `index` being the number of ids
+    /// generated before it in the render. The id is used in css selectors and
+    /// html attributes, so it should only contain letters, digits, `-` and
+    /// `_`.
+    fn generate(&self, tag: &str, index: u16) -> String;
+}
+
+impl<F> IdGenerator for F
+where
+    F: Fn(&str, u16) -> String + Send + Sync,
+{
+    fn generate(&self, tag: &str, index: u16) -> String {
+        self(tag, index)
+    }
+}
+
+impl std::fmt::Debug for dyn IdGenerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("IdGenerator")
+    }
+}
+
+/// How the ids of the `mj-carousel` and `mj-navbar` elements, binding their
+/// inputs to their styles, are generated.
+///
+/// ```rust
+/// # #[cfg(feature = "parse")]
+/// # {
+/// use mrml::prelude::render::{IdStrategy, RenderOptions};
+///
+/// let template = r#"<mjml><mj-body><mj-navbar hamburger="hamburger"><mj-navbar-link href="/">Home</mj-navbar-link></mj-navbar></mj-body></mjml>"#;
+/// let root = mrml::parse(template).unwrap();
+/// let opts = RenderOptions::default().with_id_strategy(IdStrategy::Prefixed("digest-1-".into()));
+/// let html = root.element.render(&opts).unwrap();
+/// assert!(html.contains("id=\"digest-1-00000000\""));
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub enum IdStrategy {
+    /// A counter starting from `00000000` at each render, so the ids of two
+    /// rendered templates collide.
+    #[default]
+    Counter,
+    /// The counter, after the given prefix.
+    Prefixed(Cow<'static, str>),
+    /// A hash of the given seed, of the element and of the counter, which
+    /// doesn't change from one render of the template to another, but changes
+    /// with its content. Two templates rendered in the same page need
+    /// different seeds when they can contain the same elements.
+    ContentHash(Cow<'static, str>),
+    /// The ids returned by the [`IdGenerator`], from the tag of the element
+    /// and the counter.
+    Custom(Arc<dyn IdGenerator>),
+}
+
+/// How the rendered html is written.
+///
+/// ```rust
+/// # #[cfg(feature = "parse")]
+/// # {
+/// use mrml::prelude::render::{OutputMode, RenderOptions};
+///
+/// let root = mrml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").unwrap();
+/// let opts = RenderOptions::default().with_output_mode(OutputMode::Pretty);
+/// let html = root.element.render(&opts).unwrap();
+/// assert!(html.starts_with("<!doctype html>\n<html"));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputMode {
+    /// The html as it's rendered.
+    #[default]
+    Raw,
+    /// Collapses the whitespaces, removes the quotes of the attributes that
+    /// don't need them and minifies the css. The conditional comments
+    /// targeting Outlook are kept.
+    Minify,
+    /// Writes one element per line, indented, to read the html when
+    /// debugging. Changing the whitespaces, it can slightly change how the
+    /// html is displayed.
+    Pretty,
+}
+
+impl OutputMode {
+    pub(crate) fn apply(self, html: String) -> String {
+        match self {
+            Self::Raw => html,
+            Self::Minify => crate::helper::format::minify(&html),
+            Self::Pretty => crate::helper::format::prettify(&html),
+        }
+    }
+}
+
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct RenderOptions {
     pub disable_comments: bool,
     pub social_icon_origin: Option<Cow<'static, str>>,
     pub fonts: HashMap<String, Cow<'static, str>>,
+    /// Rewrites the links of the template, see [`LinkRewriter`].
+    pub link_rewriter: Option<Arc<dyn LinkRewriter>>,
+    /// Prefix of the relative urls of the assets, see [`AssetResolver`].
+    pub base_url: Option<Cow<'static, str>>,
+    /// Resolves the urls of the assets, see [`AssetResolver`].
+    pub asset_resolver: Option<Arc<dyn AssetResolver>>,
+    pub id_strategy: IdStrategy,
+    pub output_mode: OutputMode,
 }
 
 impl Default for RenderOptions {
@@ -39,6 +317,276 @@ impl Default for RenderOptions {
             disable_comments: false,
             social_icon_origin: None,
             fonts: default_fonts(),
+            link_rewriter: None,
+            base_url: None,
+            asset_resolver: None,
+            id_strategy: IdStrategy::Counter,
+            output_mode: OutputMode::Raw,
+        }
+    }
+}
+
+impl RenderOptions {
+    pub fn with_disable_comments(mut self, value: bool) -> Self {
+        self.disable_comments = value;
+        self
+    }
+
+    pub fn with_social_icon_origin<V: Into<Cow<'static, str>>>(mut self, value: V) -> Self {
+        self.social_icon_origin = Some(value.into());
+        self
+    }
+
+    pub fn with_fonts(mut self, value: HashMap<String, Cow<'static, str>>) -> Self {
+        self.fonts = value;
+        self
+    }
+
+    pub fn with_link_rewriter(mut self, value: Arc<dyn LinkRewriter>) -> Self {
+        self.link_rewriter = Some(value);
+        self
+    }
+
+    pub fn with_base_url<V: Into<Cow<'static, str>>>(mut self, value: V) -> Self {
+        self.base_url = Some(value.into());
+        self
+    }
+
+    pub fn with_asset_resolver(mut self, value: Arc<dyn AssetResolver>) -> Self {
+        self.asset_resolver = Some(value);
+        self
+    }
+
+    pub fn with_id_strategy(mut self, value: IdStrategy) -> Self {
+        self.id_strategy = value;
+        self
+    }
+
+    pub fn with_output_mode(mut self, value: OutputMode) -> Self {
+        self.output_mode = value;
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct TextRenderOptions {
+    /// Maximum length of a line, `None` to disable the wrapping.
+    pub line_width: Option<usize>,
+    /// Writes the url of the links after their content.
+    pub include_links: bool,
+    /// Starts the text with the content of `mj-preview`.
+    pub include_preview: bool,
+    /// Rewrites the links written after their content, see [`LinkRewriter`].
+    pub link_rewriter: Option<Arc<dyn LinkRewriter>>,
+}
+
+impl Default for TextRenderOptions {
+    fn default() -> Self {
+        Self {
+            line_width: Some(78),
+            include_links: true,
+            include_preview: true,
+            link_rewriter: None,
+        }
+    }
+}
+
+impl TextRenderOptions {
+    pub fn with_line_width(mut self, value: Option<usize>) -> Self {
+        self.line_width = value;
+        self
+    }
+
+    pub fn with_include_links(mut self, value: bool) -> Self {
+        self.include_links = value;
+        self
+    }
+
+    pub fn with_include_preview(mut self, value: bool) -> Self {
+        self.include_preview = value;
+        self
+    }
+
+    pub fn with_link_rewriter(mut self, value: Arc<dyn LinkRewriter>) -> Self {
+        self.link_rewriter = Some(value);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use std::sync::Arc;
+
+    use super::{Asset, Link, RenderOptions, TextRenderOptions};
+
+    const TEMPLATE: &str = r##"<mjml>
+  <mj-body>
+    <mj-button href="https://example.com/button">Button</mj-button>
+    <mj-image src="https://example.com/image.png" alt="Image" href="https://example.com/image" />
+    <mj-navbar base-url="https://example.com">
+      <mj-navbar-link href="/navbar">Navbar</mj-navbar-link>
+    </mj-navbar>
+    <mj-social>
+      <mj-social-element name="github-noshare" href="https://example.com/social">Social</mj-social-element>
+    </mj-social>
+    <mj-carousel>
+      <mj-carousel-image src="https://example.com/carousel.png" alt="Carousel" href="https://example.com/carousel" />
+    </mj-carousel>
+    <mj-text>Some <a href="https://example.com/text">link</a> and <a href="#anchor">anchor</a></mj-text>
+  </mj-body>
+</mjml>"##;
+
+    fn track(link: &Link<'_>) -> Option<String> {
+        if link.url.starts_with('#') {
+            return None;
+        }
+        Some(format!(
+            "https://track.example.com/{}?url={}",
+            link.tag, link.url
+        ))
+    }
+
+    #[test]
+    fn should_rewrite_links() {
+        let root = crate::parse(TEMPLATE).unwrap();
+        let opts = RenderOptions {
+            link_rewriter: Some(Arc::new(track)),
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        for expected in [
+            "https://track.example.com/mj-button?url=https://example.com/button",
+            "https://track.example.com/mj-image?url=https://example.com/image",
+            "https://track.example.com/mj-navbar-link?url=https://example.com/navbar",
+            "https://track.example.com/mj-social-element?url=https://example.com/social",
+            "https://track.example.com/mj-carousel-image?url=https://example.com/carousel",
+            "https://track.example.com/a?url=https://example.com/text",
+        ] {
+            assert!(html.contains(expected), "{expected} not found in {html}");
+        }
+        assert!(html.contains("href=\"#anchor\""));
+        assert!(!html.contains("href=\"https://example.com"));
+    }
+
+    #[test]
+    fn should_escape_rewritten_links() {
+        let template = r#"<mjml><mj-body><mj-button href="https://example.com?a=1&amp;b=2">Go</mj-button></mj-body></mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let rewriter = |link: &Link<'_>| Some(format!("{}&c=\"3\"", link.url));
+        let opts = RenderOptions {
+            link_rewriter: Some(Arc::new(rewriter)),
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains(r#"href="https://example.com?a=1&amp;b=2&amp;c=&quot;3&quot;""#));
+        let opts = TextRenderOptions {
+            link_rewriter: Some(Arc::new(rewriter)),
+            ..Default::default()
+        };
+        assert_eq!(
+            root.element.render_text(&opts),
+            "Go (https://example.com?a=1&b=2&c=\"3\")\n"
+        );
+    }
+
+    #[test]
+    fn should_rewrite_links_in_text() {
+        let root = crate::parse(TEMPLATE).unwrap();
+        let opts = TextRenderOptions {
+            line_width: None,
+            link_rewriter: Some(Arc::new(track)),
+            ..Default::default()
+        };
+        similar_asserts::assert_eq!(
+            root.element.render_text(&opts),
+            "Button (https://track.example.com/mj-button?url=https://example.com/button)
+
+Image (https://track.example.com/mj-image?url=https://example.com/image)
+
+Navbar (https://track.example.com/mj-navbar-link?url=https://example.com/navbar)
+
+Social (https://track.example.com/mj-social-element?url=https://example.com/social)
+
+Carousel (https://track.example.com/mj-carousel-image?url=https://example.com/carousel)
+
+Some link (https://track.example.com/a?url=https://example.com/text) and anchor
+"
+        );
+    }
+
+    #[test]
+    fn should_detect_relative_urls() {
+        for url in ["image.png", "./image.png", "/image.png", "images/a:b.png"] {
+            assert!(super::is_relative_url(url), "{url} should be relative");
+        }
+        for url in [
+            "",
+            "https://example.com/image.png",
+            "//example.com/image.png",
+            "data:image/png;base64,AAAA",
+            "cid:image",
+            "#anchor",
+        ] {
+            assert!(!super::is_relative_url(url), "{url} should not be relative");
+        }
+        assert_eq!(
+            super::join_url("https://cdn.example.com/", "./image.png"),
+            "https://cdn.example.com/image.png"
+        );
+        assert_eq!(
+            super::join_url("https://cdn.example.com/assets", "/image.png"),
+            "https://cdn.example.com/assets/image.png"
+        );
+    }
+
+    #[test]
+    fn should_resolve_assets() {
+        let template = r##"<mjml>
+  <mj-head>
+    <mj-font name="Custom" href="fonts/custom.css" />
+  </mj-head>
+  <mj-body>
+    <mj-hero background-url="hero.png" background-color="#fff"><mj-text font-family="Custom">Hero</mj-text></mj-hero>
+    <mj-wrapper background-url="wrapper.png">
+      <mj-section background-url="section.png">
+        <mj-column>
+          <mj-image src="image.png" />
+          <mj-image src="https://example.com/absolute.png" />
+          <mj-social><mj-social-element name="github" src="social.png">GitHub</mj-social-element></mj-social>
+        </mj-column>
+      </mj-section>
+    </mj-wrapper>
+    <mj-carousel>
+      <mj-carousel-image src="carousel.png" thumbnails-src="thumbnail.png" />
+    </mj-carousel>
+  </mj-body>
+</mjml>"##;
+        let root = crate::parse(template).unwrap();
+        let opts = RenderOptions {
+            base_url: Some("https://cdn.example.com".into()),
+            asset_resolver: Some(Arc::new(|asset: &Asset<'_>| {
+                (asset.tag == "mj-social-element")
+                    .then(|| format!("https://icons.example.com/{}", asset.url))
+            })),
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        for expected in [
+            "<link href=\"https://cdn.example.com/fonts/custom.css\"",
+            "background=\"https://cdn.example.com/hero.png\"",
+            "background=\"https://cdn.example.com/wrapper.png\"",
+            "url('https://cdn.example.com/section.png')",
+            "src=\"https://cdn.example.com/image.png\"",
+            "src=\"https://example.com/absolute.png\"",
+            "src=\"https://icons.example.com/social.png\"",
+            "src=\"https://cdn.example.com/carousel.png\"",
+            "src=\"https://cdn.example.com/thumbnail.png\"",
+        ] {
+            assert!(html.contains(expected), "{expected} not found in {html}");
         }
+        assert!(!html.contains("\"hero.png\""));
+        assert!(!html.contains("'hero.png'"));
+        assert!(!html.contains("\"section.png\""));
     }
 }
@@ -0,0 +1,153 @@
+/// Size from which Gmail clips the messages, hiding the end of the html behind
+/// a "View entire message" link.
+pub const GMAIL_CLIP_LIMIT: usize = 102 * 1024;
+
+/// Size of the html of a child of `mj-body`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SectionReport {
+    /// Position of the element amongst the children of `mj-body`.
+    pub index: usize,
+    /// Tag of the element, `None` for the comments and texts.
+    pub tag: Option<String>,
+    /// Size of the html of the element, in bytes, before the html gets
+    /// rewritten to inline css, apply `mj-html-attributes` or follow the
+    /// output mode.
+    pub size: usize,
+    /// Position of the html of the element in the rendered html, `None` when
+    /// the html is rewritten once rendered.
+    pub range: Option<std::ops::Range<usize>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReportWarning {
+    /// The html is bigger than the limit, so the end of it will be clipped.
+    SizeLimitExceeded { size: usize, limit: usize },
+}
+
+impl std::fmt::Display for ReportWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SizeLimitExceeded { size, limit } => write!(
+                f,
+                "the html size ({size} bytes) exceeds the limit of {limit} bytes"
+            ),
+        }
+    }
+}
+
+/// Sizes of the rendered html, to keep it under the size at which email
+/// clients clip it, see [`GMAIL_CLIP_LIMIT`].
+///
+/// The size of each child of `mj-body` is the one of its html in the body, so
+/// the sizes of the children and of the `<head>` don't add up to the size of
+/// the document, which also contains the elements around the children.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RenderReport {
+    /// Size of the whole html, in bytes.
+    pub size: usize,
+    /// Size of the `<head>` element, in bytes.
+    pub head_size: usize,
+    /// Size of the content of the `<style>` elements, in bytes.
+    pub style_size: usize,
+    pub sections: Vec<SectionReport>,
+    pub warnings: Vec<ReportWarning>,
+}
+
+impl RenderReport {
+    pub(crate) fn new(html: &str, sections: Vec<SectionReport>, limit: usize) -> Self {
+        let mut warnings = Vec::new();
+        if html.len() > limit {
+            warnings.push(ReportWarning::SizeLimitExceeded {
+                size: html.len(),
+                limit,
+            });
+        }
+        Self {
+            size: html.len(),
+            head_size: head_size(html),
+            style_size: style_size(html),
+            sections,
+            warnings,
+        }
+    }
+
+    /// The children of `mj-body` sorted from the biggest to the smallest.
+    pub fn biggest_sections(&self) -> Vec<&SectionReport> {
+        let mut sections = self.sections.iter().collect::<Vec<_>>();
+        sections.sort_by_key(|section| std::cmp::Reverse(section.size));
+        sections
+    }
+}
+
+/// Finds the position of an opening tag, skipping the tags starting with the
+/// same name, like `<header>` when looking for `<head>`.
+fn find_tag(html: &str, from: usize, tag: &str) -> Option<usize> {
+    let pattern = format!("<{tag}");
+    let mut pos = from;
+    while let Some(found) = html.get(pos..).and_then(|rest| rest.find(&pattern)) {
+        let start = pos + found;
+        let next = start + pattern.len();
+        match html.as_bytes().get(next) {
+            Some(c) if *c == b'>' || *c == b'/' || c.is_ascii_whitespace() => return Some(start),
+            _ => pos = next,
+        }
+    }
+    None
+}
+
+fn head_size(html: &str) -> usize {
+    let Some(start) = find_tag(html, 0, "head") else {
+        return 0;
+    };
+    html[start..]
+        .find("</head>")
+        .map(|end| end + "</head>".len())
+        .unwrap_or(html.len() - start)
+}
+
+fn style_size(html: &str) -> usize {
+    let mut size = 0;
+    let mut pos = 0;
+    while let Some(start) = find_tag(html, pos, "style") {
+        let Some(content_start) = html[start..].find('>').map(|end| start + end + 1) else {
+            break;
+        };
+        let content_end = html[content_start..]
+            .find("</style>")
+            .map(|end| content_start + end)
+            .unwrap_or(html.len());
+        size += content_end - content_start;
+        pos = content_end;
+    }
+    size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RenderReport, ReportWarning};
+
+    #[test]
+    fn should_measure_head_and_styles() {
+        let html = "<html><head><style>p{}</style><style type=\"text/css\">a{}</style></head><body><header>Hi</header></body></html>";
+        let report = RenderReport::new(html, Vec::new(), 1000);
+        assert_eq!(report.size, html.len());
+        assert_eq!(
+            report.head_size,
+            "<head><style>p{}</style><style type=\"text/css\">a{}</style></head>".len()
+        );
+        assert_eq!(report.style_size, 6);
+        assert!(report.warnings.is_empty());
+    }
+
+    #[test]
+    fn should_warn_over_the_limit() {
+        let report = RenderReport::new("<html></html>", Vec::new(), 10);
+        assert_eq!(
+            report.warnings,
+            vec![ReportWarning::SizeLimitExceeded {
+                size: 13,
+                limit: 10
+            }]
+        );
+    }
+}
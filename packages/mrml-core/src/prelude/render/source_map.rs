@@ -0,0 +1,545 @@
+use std::cmp::Reverse;
+use std::marker::PhantomData;
+
+use super::{Error, Render, RenderContext, RenderCursor, Renderer, Tag};
+use crate::helper::size::{Pixel, Size};
+use crate::helper::spacing::Spacing;
+use crate::mj_include::body::MjIncludeBody;
+use crate::node::Node;
+use crate::prelude::parser::Origin;
+use crate::prelude::{Component, Location, Span, StaticTag};
+
+/// Link between a range of the rendered html and the element of the template
+/// that rendered it.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct Mapping {
+    /// Name of the element in the template, like `mj-text`.
+    pub tag: String,
+    /// Byte range written in the rendered html.
+    pub output: Span,
+    /// Template the element comes from.
+    pub origin: Origin,
+    /// Position of the element in the template it comes from.
+    pub source: Span,
+}
+
+/// Mappings from the rendered html back to the elements of the template,
+/// ordered by position in the html. When elements are nested, the parent
+/// comes before its children.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
+pub struct SourceMap {
+    pub mappings: Vec<Mapping>,
+}
+
+impl SourceMap {
+    /// Mappings of the elements that rendered the byte at `position` of the
+    /// html, from the outermost to the innermost.
+    pub fn lookup(&self, position: usize) -> impl Iterator<Item = &Mapping> {
+        self.mappings
+            .iter()
+            .filter(move |item| item.output.start <= position && position < item.output.end)
+    }
+
+    pub(crate) fn finish(mut self) -> Self {
+        // the mappings are recorded once the element is rendered, so the
+        // children come before their parent
+        self.mappings.reverse();
+        self.mappings
+            .sort_by_key(|item| (item.output.start, Reverse(item.output.end)));
+        self
+    }
+}
+
+impl RenderCursor<'_> {
+    /// Number of mappings recorded so far.
+    pub(crate) fn mappings_count(&self) -> usize {
+        self.source_map
+            .as_ref()
+            .map_or(0, |source_map| source_map.mappings.len())
+    }
+
+    /// Removes the mappings recorded from `from`, for when the html they have
+    /// been recorded against is moved somewhere else.
+    pub(crate) fn take_mappings(&mut self, from: usize) -> Vec<Mapping> {
+        self.source_map
+            .as_mut()
+            .map(|source_map| source_map.mappings.split_off(from))
+            .unwrap_or_default()
+    }
+
+    /// Puts back mappings taken with `take_mappings`, once their html has been
+    /// inserted at `offset` in the buffer.
+    pub(crate) fn restore_mappings(&mut self, mappings: Vec<Mapping>, offset: usize) {
+        if let Some(source_map) = self.source_map.as_mut() {
+            source_map
+                .mappings
+                .extend(mappings.into_iter().map(|mut item| {
+                    item.output.start += offset;
+                    item.output.end += offset;
+                    item
+                }));
+        }
+    }
+
+    /// Records the element rendered last as the origin of the styles added to
+    /// the header from `from`, unless one of its children added them.
+    #[cfg(feature = "lint")]
+    fn add_style_origins(&mut self, from: usize) {
+        let count = self.header.styles().len();
+        let attributed = self.style_origins.last().map_or(0, |(index, _)| index + 1);
+        if let Some(mapping) = self
+            .source_map
+            .as_ref()
+            .and_then(|source_map| source_map.mappings.last())
+        {
+            for index in from.max(attributed)..count {
+                self.style_origins.push((index, mapping.clone()));
+            }
+        }
+    }
+
+    fn add_mapping(&mut self, tag: &str, location: &Location, start: usize) {
+        let end = self.buffer.len();
+        if let Some(source_map) = self.source_map.as_mut() {
+            source_map.mappings.push(Mapping {
+                tag: tag.to_string(),
+                output: Span { start, end },
+                origin: location.origin.clone(),
+                source: location.span,
+            });
+        }
+    }
+}
+
+/// Renderer wrapper recording what the inner renderer writes in the buffer.
+pub(crate) struct SourceMapped<'root, R> {
+    inner: R,
+    tag: &'root str,
+    location: &'root Location,
+}
+
+impl<'root, R: Render<'root>> Render<'root> for SourceMapped<'root, R> {
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.inner.context()
+    }
+
+    fn tag(&self) -> Option<&str> {
+        self.inner.tag()
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.inner.raw_attribute(key)
+    }
+
+    fn raw_extra_attribute(&self, key: &str) -> Option<&'root str> {
+        self.inner.raw_extra_attribute(key)
+    }
+
+    fn attribute_as_pixel(&self, name: &str) -> Option<Pixel> {
+        self.inner.attribute_as_pixel(name)
+    }
+
+    fn attribute_as_size(&self, name: &str) -> Option<Size> {
+        self.inner.attribute_as_size(name)
+    }
+
+    fn attribute_as_spacing(&self, name: &str) -> Option<Spacing> {
+        self.inner.attribute_as_spacing(name)
+    }
+
+    fn attribute_equals(&self, key: &str, value: &str) -> bool {
+        self.inner.attribute_equals(key, value)
+    }
+
+    fn attribute_exists(&self, key: &str) -> bool {
+        self.inner.attribute_exists(key)
+    }
+
+    fn get_border_left(&self) -> Option<Pixel> {
+        self.inner.get_border_left()
+    }
+
+    fn get_border_right(&self) -> Option<Pixel> {
+        self.inner.get_border_right()
+    }
+
+    fn get_border_horizontal(&self) -> Pixel {
+        self.inner.get_border_horizontal()
+    }
+
+    fn get_inner_border_left(&self) -> Option<Pixel> {
+        self.inner.get_inner_border_left()
+    }
+
+    fn get_inner_border_right(&self) -> Option<Pixel> {
+        self.inner.get_inner_border_right()
+    }
+
+    fn get_padding_top(&self) -> Option<Pixel> {
+        self.inner.get_padding_top()
+    }
+
+    fn get_padding_bottom(&self) -> Option<Pixel> {
+        self.inner.get_padding_bottom()
+    }
+
+    fn get_padding_left(&self) -> Option<Pixel> {
+        self.inner.get_padding_left()
+    }
+
+    fn get_padding_right(&self) -> Option<Pixel> {
+        self.inner.get_padding_right()
+    }
+
+    fn get_padding_horizontal(&self) -> Pixel {
+        self.inner.get_padding_horizontal()
+    }
+
+    fn get_padding_vertical(&self) -> Pixel {
+        self.inner.get_padding_vertical()
+    }
+
+    fn get_width(&self) -> Option<Size> {
+        self.inner.get_width()
+    }
+
+    fn default_attribute(&self, key: &str) -> Option<&'static str> {
+        self.inner.default_attribute(key)
+    }
+
+    fn attribute<'a>(&'a self, key: &str) -> Option<&'a str>
+    where
+        'root: 'a,
+    {
+        self.inner.attribute(key)
+    }
+
+    fn set_style<'a, 't>(&'a self, name: &str, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        self.inner.set_style(name, tag)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.inner.set_container_width(width);
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.inner.set_index(index);
+    }
+
+    fn set_siblings(&mut self, count: usize) {
+        self.inner.set_siblings(count);
+    }
+
+    fn set_raw_siblings(&mut self, count: usize) {
+        self.inner.set_raw_siblings(count);
+    }
+
+    fn add_extra_attribute(&mut self, key: &'root str, value: &'root str) {
+        self.inner.add_extra_attribute(key, value);
+    }
+
+    fn maybe_add_extra_attribute(&mut self, key: &'root str, value: Option<&'root str>) {
+        self.inner.maybe_add_extra_attribute(key, value);
+    }
+
+    fn render_fragment(&self, name: &str, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let start = cursor.buffer.len();
+        self.inner.render_fragment(name, cursor)?;
+        cursor.add_mapping(self.tag, self.location, start);
+        Ok(())
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let start = cursor.buffer.len();
+        #[cfg(feature = "lint")]
+        let styles = cursor.header.styles().len();
+        self.inner.render(cursor)?;
+        cursor.add_mapping(self.tag, self.location, start);
+        #[cfg(feature = "lint")]
+        cursor.add_style_origins(styles);
+        Ok(())
+    }
+}
+
+impl<'root, T: StaticTag, A, C, E> Renderer<'root, Component<PhantomData<T>, A, C>, E> {
+    /// Boxes the renderer, recording what it renders when the element has a
+    /// location.
+    pub(crate) fn boxed<'render>(self) -> Box<dyn Render<'root> + 'render>
+    where
+        Self: Render<'root> + 'render,
+        'root: 'render,
+    {
+        match self.element.location.as_ref() {
+            Some(location) => Box::new(SourceMapped {
+                inner: self,
+                tag: T::static_tag(),
+                location,
+            }),
+            None => Box::new(self),
+        }
+    }
+}
+
+impl<'root, C, E> Renderer<'root, Node<C>, E> {
+    /// Boxes the renderer, recording what it renders when the element has a
+    /// location.
+    pub(crate) fn boxed<'render>(self) -> Box<dyn Render<'root> + 'render>
+    where
+        Self: Render<'root> + 'render,
+        'root: 'render,
+    {
+        let element = self.element;
+        match element.location.as_ref() {
+            Some(location) => Box::new(SourceMapped {
+                inner: self,
+                tag: element.tag.as_str(),
+                location,
+            }),
+            None => Box::new(self),
+        }
+    }
+}
+
+impl<'root, E> Renderer<'root, MjIncludeBody, E> {
+    /// Boxes the renderer, recording what it renders when the element has a
+    /// location.
+    pub(crate) fn boxed<'render>(self) -> Box<dyn Render<'root> + 'render>
+    where
+        Self: Render<'root> + 'render,
+        'root: 'render,
+    {
+        match self.element.0.location.as_ref() {
+            Some(location) => Box::new(SourceMapped {
+                inner: self,
+                tag: crate::mj_include::NAME,
+                location,
+            }),
+            None => Box::new(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mapping, SourceMap};
+    use crate::mjml::Mjml;
+    use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+    use crate::prelude::parser::{Origin, ParserOptions};
+    use crate::prelude::render::RenderOptions;
+
+    fn source<'a>(template: &'a str, mapping: &Mapping) -> &'a str {
+        &template[mapping.source.start..mapping.source.end]
+    }
+
+    #[test]
+    fn should_map_html_to_elements() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-button href="https://example.com">Click</mj-button><mj-text>Hello</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let (html, source_map) = root
+            .element
+            .render_with_source_map(&RenderOptions::default())
+            .unwrap();
+        assert_eq!(
+            html,
+            root.element.render(&RenderOptions::default()).unwrap()
+        );
+
+        let first = &source_map.mappings[0];
+        assert_eq!(first.tag, "mjml");
+        assert_eq!((first.output.start, first.output.end), (0, html.len()));
+        assert_eq!(source(template, first), template);
+
+        let tags = source_map
+            .lookup(html.find("Hello").unwrap())
+            .map(|item| item.tag.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tags,
+            ["mjml", "mj-body", "mj-section", "mj-column", "mj-text"]
+        );
+
+        let button = source_map
+            .mappings
+            .iter()
+            .find(|item| item.tag == "mj-button")
+            .unwrap();
+        assert_eq!(
+            source(template, button),
+            r#"<mj-button href="https://example.com">Click</mj-button>"#
+        );
+        let output = &html[button.output.start..button.output.end];
+        assert!(output.contains(r#"href="https://example.com""#));
+        assert!(output.contains("Click"));
+        assert!(!output.contains("Hello"));
+    }
+
+    #[test]
+    fn should_map_whole_template() {
+        let template = include_str!("../../../resources/template/amario.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let (html, source_map) = root
+            .element
+            .render_with_source_map(&RenderOptions::default())
+            .unwrap();
+        assert_eq!(
+            html,
+            root.element.render(&RenderOptions::default()).unwrap()
+        );
+        for item in source_map.mappings.iter() {
+            assert!(html.get(item.output.start..item.output.end).is_some());
+            let source = source(template, item);
+            assert!(source.starts_with(&format!("<{}", item.tag)), "{source}");
+        }
+    }
+
+    #[test]
+    fn should_map_head_and_included_elements() {
+        let partial = "<mj-text>Included</mj-text>";
+        let opts = ParserOptions {
+            include_loader: Box::new(MemoryIncludeLoader::from(vec![("partial.mjml", partial)])),
+        };
+        let template = r#"<mjml><mj-head><mj-title>Title</mj-title></mj-head><mj-body><mj-include path="partial.mjml" /></mj-body></mjml>"#;
+        let root = Mjml::parse_with_options(template, &opts).unwrap();
+        let (html, source_map) = root
+            .element
+            .render_with_source_map(&RenderOptions::default())
+            .unwrap();
+
+        let tags = source_map
+            .lookup(html.find("Included").unwrap())
+            .map(|item| item.tag.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(tags, ["mjml", "mj-body", "mj-include", "mj-text"]);
+        let text = source_map
+            .lookup(html.find("Included").unwrap())
+            .last()
+            .unwrap();
+        assert_eq!(
+            text.origin,
+            Origin::Include {
+                path: "partial.mjml".into()
+            }
+        );
+        assert_eq!(source(partial, text), partial);
+
+        let head = source_map
+            .mappings
+            .iter()
+            .find(|item| item.tag == "mj-head")
+            .unwrap();
+        assert_eq!(head.origin, Origin::Root);
+        assert!(html[head.output.start..head.output.end].starts_with("<head>"));
+        let body = source_map
+            .mappings
+            .iter()
+            .find(|item| item.tag == "mj-body")
+            .unwrap();
+        assert!(head.output.end <= body.output.start);
+    }
+
+    #[test]
+    fn should_map_expanded_elements_to_custom_element() {
+        use crate::prelude::parser::component::{
+            ComponentElement, ComponentError, ComponentRegistry,
+        };
+        use crate::prelude::parser::MrmlParser;
+
+        fn card(element: &ComponentElement<'_>) -> Result<String, ComponentError> {
+            Ok(format!(
+                "<mj-section><mj-column><mj-text>{}</mj-text></mj-column></mj-section>",
+                element.content
+            ))
+        }
+
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts)
+            .with_components(ComponentRegistry::default().with_component("mx-card", card));
+        let template = r#"<mjml><mj-body><mx-card color="red">Hello</mx-card></mj-body></mjml>"#;
+        let root = parser.parse_template(template).unwrap();
+        let (html, source_map) = root
+            .element
+            .render_with_source_map(&RenderOptions::default())
+            .unwrap();
+        let mappings = source_map
+            .lookup(html.find("Hello").unwrap())
+            .collect::<Vec<_>>();
+        let tags = mappings
+            .iter()
+            .map(|item| item.tag.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tags,
+            ["mjml", "mj-body", "mj-section", "mj-column", "mj-text"]
+        );
+        for item in &mappings[2..] {
+            assert_eq!(item.origin, Origin::Root);
+            assert_eq!(
+                source(template, item),
+                r#"<mx-card color="red">Hello</mx-card>"#
+            );
+        }
+    }
+
+    #[test]
+    fn should_be_empty_when_html_is_rewritten() {
+        let template = r#"<mjml><mj-head><mj-html-attributes><mj-selector path=".foo div"><mj-html-attribute name="data-id">42</mj-html-attribute></mj-selector></mj-html-attributes></mj-head><mj-body><mj-section><mj-column><mj-text css-class="foo">Hello</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let (html, source_map) = root
+            .element
+            .render_with_source_map(&RenderOptions::default())
+            .unwrap();
+        assert!(html.contains(r#"data-id="42""#));
+        assert_eq!(source_map, SourceMap::default());
+    }
+
+    #[cfg(feature = "lint")]
+    #[test]
+    fn should_record_style_origins() {
+        use crate::prelude::render::{Header, RenderContext, RenderCursor, Renderable};
+
+        let template = r#"<mjml><mj-body><mj-accordion><mj-accordion-element><mj-accordion-title>Title</mj-accordion-title><mj-accordion-text>Text</mj-accordion-text></mj-accordion-element></mj-accordion><mj-navbar><mj-navbar-link href="/">Home</mj-navbar-link></mj-navbar></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let opts = RenderOptions::default();
+        let header = Header::new(None, "und", "auto");
+        let context = RenderContext::new(&opts, &header);
+        let mut cursor = RenderCursor {
+            source_map: Some(SourceMap::default()),
+            ..Default::default()
+        };
+        root.element.renderer(&context).render(&mut cursor).unwrap();
+        let origins = cursor
+            .style_origins
+            .iter()
+            .map(|(index, mapping)| (*index, mapping.tag.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(origins, vec![(0, "mj-accordion"), (1, "mj-navbar")]);
+        assert_eq!(cursor.header.styles().len(), 2);
+    }
+
+    #[test]
+    fn should_serialize() {
+        let template = "<mjml><mj-body></mj-body></mjml>";
+        let root = Mjml::parse(template).unwrap();
+        let (html, source_map) = root
+            .element
+            .render_with_source_map(&RenderOptions::default())
+            .unwrap();
+        let body = source_map
+            .lookup(html.find("<body").unwrap())
+            .last()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(body).unwrap(),
+            serde_json::json!({
+                "tag": "mj-body",
+                "output": body.output,
+                "origin": { "type": "root" },
+                "source": { "start": 6, "end": 25 },
+            })
+        );
+    }
+}
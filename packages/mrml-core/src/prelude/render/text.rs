@@ -0,0 +1,274 @@
+//! Plain text rendering of a template, to build the `text/plain` alternative
+//! of an email. Each component writes its text in the `text` module next to
+//! its html renderer.
+
+use std::borrow::Cow;
+
+use super::{rewrite_link, TextRenderOptions};
+use crate::prelude::AttributeMap;
+
+pub(crate) fn get_attribute<'a>(attributes: &'a AttributeMap, name: &str) -> Option<&'a str> {
+    attributes
+        .get(name)
+        .and_then(|value| value.as_deref())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// Replaces the most common html entities by the character they represent.
+pub(crate) fn decode_entities(input: &str) -> std::borrow::Cow<'_, str> {
+    if !input.contains('&') {
+        return std::borrow::Cow::Borrowed(input);
+    }
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(index) = rest.find('&') {
+        output.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let value = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| {
+                        entity
+                            .strip_prefix('#')
+                            .and_then(|dec| dec.parse::<u32>().ok())
+                    })
+                    .and_then(char::from_u32),
+            };
+            value.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                output.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    output.push_str(rest);
+    std::borrow::Cow::Owned(output)
+}
+
+fn wrap_line(output: &mut String, line: &str, width: Option<usize>) {
+    let Some(width) = width else {
+        output.push_str(line);
+        output.push('\n');
+        return;
+    };
+    let mut length = 0;
+    for word in line.split(' ').filter(|word| !word.is_empty()) {
+        let word_length = word.chars().count();
+        if length > 0 && length + 1 + word_length > width {
+            output.push('\n');
+            length = 0;
+        }
+        if length > 0 {
+            output.push(' ');
+            length += 1;
+        }
+        output.push_str(word);
+        length += word_length;
+    }
+    output.push('\n');
+}
+
+pub(crate) struct TextBuffer<'o> {
+    options: &'o TextRenderOptions,
+    output: String,
+    /// Content of the current block, with its line breaks.
+    block: String,
+    pending_space: bool,
+    /// Number of cells already written in the current table row.
+    row_cells: usize,
+}
+
+impl<'o> TextBuffer<'o> {
+    pub fn new(options: &'o TextRenderOptions) -> Self {
+        Self {
+            options,
+            output: String::new(),
+            block: String::new(),
+            pending_space: false,
+            row_cells: 0,
+        }
+    }
+
+    /// Adds some text to the current block, collapsing the whitespaces like a
+    /// browser would.
+    pub fn push_text(&mut self, value: &str) {
+        for c in value.chars() {
+            if c.is_whitespace() {
+                self.pending_space = !self.block.is_empty() && !self.block.ends_with('\n');
+            } else {
+                if self.pending_space {
+                    self.block.push(' ');
+                    self.pending_space = false;
+                }
+                self.block.push(c);
+            }
+        }
+    }
+
+    pub fn line_break(&mut self) {
+        self.block.push('\n');
+        self.pending_space = false;
+    }
+
+    /// Starts a new line, unless the current one is empty.
+    pub fn start_line(&mut self) {
+        if !self.block.is_empty() && !self.block.ends_with('\n') {
+            self.line_break();
+        }
+    }
+
+    /// Writes the current block in the output, followed by an empty line.
+    pub fn end_block(&mut self) {
+        let block = std::mem::take(&mut self.block);
+        self.pending_space = false;
+        let block = block.trim();
+        if block.is_empty() {
+            return;
+        }
+        if !self.output.is_empty() {
+            self.output.push('\n');
+        }
+        for line in block.lines() {
+            wrap_line(&mut self.output, line.trim(), self.options.line_width);
+        }
+    }
+
+    /// Position in the current block, to write a link after the content
+    /// written from there.
+    pub fn position(&self) -> usize {
+        self.block.len()
+    }
+
+    /// Content of the current block written from `start`.
+    pub fn written_since(&self, start: usize) -> &str {
+        &self.block[start.min(self.block.len())..]
+    }
+
+    /// Starts a row of a table, on a new line.
+    pub fn start_row(&mut self) {
+        self.start_line();
+        self.row_cells = 0;
+    }
+
+    /// Starts a cell of a table row, separated from the previous one.
+    pub fn start_cell(&mut self) {
+        if self.row_cells > 0 {
+            self.push_text(" | ");
+        }
+        self.row_cells += 1;
+    }
+
+    /// Writes the link to the given url, after its content that has been
+    /// written from `start`.
+    pub fn push_link(
+        &mut self,
+        start: usize,
+        tag: &str,
+        attributes: &AttributeMap,
+        href: Option<&str>,
+    ) {
+        let Some(href) = href.filter(|_| self.options.include_links) else {
+            return;
+        };
+        let href = decode_entities(href);
+        if href.starts_with('#') || self.written_since(start).trim() == href {
+            return;
+        }
+        let href = rewrite_link(
+            self.options.link_rewriter.as_deref(),
+            tag,
+            attributes,
+            &href,
+        )
+        .map(Cow::Owned)
+        .unwrap_or(href);
+        if self.block.len() > start {
+            self.push_text(" ");
+        }
+        self.push_text("(");
+        self.push_text(&href);
+        self.push_text(")");
+    }
+
+    pub fn into_string(mut self) -> String {
+        self.end_block();
+        self.output
+    }
+}
+
+pub(crate) trait TextRender {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>);
+}
+
+impl<T: TextRender> TextRender for Vec<T> {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>) {
+        for item in self.iter() {
+            item.render_text(buffer);
+        }
+    }
+}
+
+/// Writes the children in a block of their own.
+pub(crate) fn render_block_text<T: TextRender>(buffer: &mut TextBuffer<'_>, children: &Vec<T>) {
+    buffer.end_block();
+    children.render_text(buffer);
+    buffer.end_block();
+}
+
+/// Writes a horizontal line, like the one of a `mj-divider`.
+pub(crate) fn render_divider_text(buffer: &mut TextBuffer<'_>) {
+    buffer.end_block();
+    buffer.push_text("---");
+    buffer.end_block();
+}
+
+/// Writes the alternative text of an image, followed by its link.
+pub(crate) fn render_image_text(buffer: &mut TextBuffer<'_>, tag: &str, attributes: &AttributeMap) {
+    let Some(alt) = get_attribute(attributes, "alt") else {
+        return;
+    };
+    buffer.end_block();
+    buffer.push_text(&decode_entities(alt));
+    buffer.push_link(0, tag, attributes, get_attribute(attributes, "href"));
+    buffer.end_block();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_entities, wrap_line};
+
+    #[test]
+    fn should_decode_entities() {
+        assert_eq!(
+            decode_entities("a&nbsp;&amp;&lt;b&gt; &#39;&#x41;&unknown; & c"),
+            "a &<b> 'A&unknown; & c"
+        );
+    }
+
+    #[test]
+    fn should_wrap_lines() {
+        let mut output = String::new();
+        wrap_line(&mut output, "the quick brown fox jumps", Some(10));
+        assert_eq!(output, "the quick\nbrown fox\njumps\n");
+        let mut output = String::new();
+        wrap_line(&mut output, "https://example.com/a/long/url", Some(10));
+        assert_eq!(output, "https://example.com/a/long/url\n");
+    }
+}
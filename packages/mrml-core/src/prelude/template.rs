@@ -0,0 +1,302 @@
+//! Resolution of the `{{ variable }}` placeholders of a template against a
+//! [`serde_json::Value`] context.
+//!
+//! Placeholders are resolved in the text nodes, the attribute values and the
+//! content of `mj-raw` elements. A variable is referenced by its path in the
+//! context, using dots to access the fields of an object or the items of an
+//! array (`{{ user.name }}`, `{{ items.0.title }}`).
+//!
+//! The resolved values are html escaped, unless the placeholder uses triple
+//! braces (`{{{ content }}}`).
+//!
+//! ```rust
+//! use mrml::prelude::render::RenderOptions;
+//!
+//! let root = mrml::parse(r#"<mjml>
+//!   <mj-body>
+//!     <mj-text color="{{ color }}">Hello {{ user.name }}!</mj-text>
+//!   </mj-body>
+//! </mjml>"#).unwrap();
+//! let context = serde_json::json!({ "color": "red", "user": { "name": "Alice" } });
+//! let html = root
+//!     .element
+//!     .render_with_context(&RenderOptions::default(), &context)
+//!     .unwrap();
+//! assert!(html.contains("Hello Alice!"));
+//! ```
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use serde_json::Value;
+
+use super::render::Error;
+use super::{AttributeMap, Component, OneOrMany, Span, StaticTag};
+
+pub trait Template {
+    /// Replaces, in place, the placeholders with the matching values of the
+    /// context.
+    fn resolve(&mut self, context: &Value) -> Result<(), Error>;
+}
+
+fn lookup<'v>(context: &'v Value, path: &str) -> Option<&'v Value> {
+    path.split('.')
+        .try_fold(context, |current, key| match current {
+            Value::Object(map) => map.get(key),
+            Value::Array(list) => key.parse::<usize>().ok().and_then(|index| list.get(index)),
+            _ => None,
+        })
+}
+
+fn escape_into(output: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            other => output.push(other),
+        }
+    }
+}
+
+fn push_value(output: &mut String, value: &Value, escape: bool) {
+    let value = match value {
+        Value::Null => Cow::Borrowed(""),
+        Value::String(inner) => Cow::Borrowed(inner.as_str()),
+        other => Cow::Owned(other.to_string()),
+    };
+    if escape {
+        escape_into(output, &value);
+    } else {
+        output.push_str(&value);
+    }
+}
+
+impl Error {
+    /// Keeps the element containing the missing variable, unless a nested
+    /// element already did.
+    fn in_element(mut self, tag: &str) -> Self {
+        if let Self::MissingVariable { element, .. } = &mut self {
+            element.get_or_insert_with(|| tag.to_string());
+        }
+        self
+    }
+
+    /// Keeps the location of the text or element containing the missing
+    /// variable, unless a nested one already did.
+    #[cfg(feature = "span")]
+    pub(crate) fn at_location(mut self, value: Option<&super::Location>) -> Self {
+        if let Self::MissingVariable { location, .. } = &mut self {
+            if location.is_none() {
+                *location = value.cloned().map(Box::new);
+            }
+        }
+        self
+    }
+
+    /// Keeps the attribute containing the missing variable.
+    fn in_attribute(mut self, name: &str) -> Self {
+        if let Self::MissingVariable { attribute, .. } = &mut self {
+            attribute.get_or_insert_with(|| name.to_string());
+        }
+        self
+    }
+}
+
+/// Resolves the placeholders of the value of the attribute `name`.
+pub(crate) fn resolve_attribute<T: Template>(
+    name: &str,
+    value: &mut T,
+    context: &Value,
+) -> Result<(), Error> {
+    value.resolve(context).map_err(|err| err.in_attribute(name))
+}
+
+/// Resolves the placeholders of the given input.
+///
+/// When a variable is missing from the context, the returned error contains
+/// the position of the placeholder in the input, its `value_span`.
+pub fn resolve_str<'a>(input: &'a str, context: &Value) -> Result<Cow<'a, str>, Error> {
+    let Some(first) = input.find("{{") else {
+        return Ok(Cow::Borrowed(input));
+    };
+    let mut output = String::with_capacity(input.len());
+    output.push_str(&input[..first]);
+    let mut position = first;
+    while let Some(found) = input[position..].find("{{") {
+        let start = position + found;
+        output.push_str(&input[position..start]);
+        let (raw, opening, closing) = if input[start..].starts_with("{{{") {
+            (true, 3, "}}}")
+        } else {
+            (false, 2, "}}")
+        };
+        let Some(length) = input[start + opening..].find(closing) else {
+            // not a placeholder, the rest of the input is kept as is
+            position = start;
+            break;
+        };
+        let end = start + opening + length + closing.len();
+        let name = input[start + opening..start + opening + length].trim();
+        let value = lookup(context, name).ok_or_else(|| Error::MissingVariable {
+            name: name.to_string(),
+            value_span: Span { start, end },
+            element: None,
+            attribute: None,
+            #[cfg(feature = "span")]
+            location: None,
+        })?;
+        push_value(&mut output, value, !raw);
+        position = end;
+    }
+    output.push_str(&input[position..]);
+    Ok(Cow::Owned(output))
+}
+
+impl Template for String {
+    fn resolve(&mut self, context: &Value) -> Result<(), Error> {
+        if let Cow::Owned(value) = resolve_str(self, context)? {
+            *self = value;
+        }
+        Ok(())
+    }
+}
+
+impl Template for () {
+    fn resolve(&mut self, _context: &Value) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<T: Template> Template for Option<T> {
+    fn resolve(&mut self, context: &Value) -> Result<(), Error> {
+        match self {
+            Some(inner) => inner.resolve(context),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Template> Template for Vec<T> {
+    fn resolve(&mut self, context: &Value) -> Result<(), Error> {
+        self.iter_mut().try_for_each(|item| item.resolve(context))
+    }
+}
+
+impl<T: Template> Template for OneOrMany<T> {
+    fn resolve(&mut self, context: &Value) -> Result<(), Error> {
+        match self {
+            Self::One(inner) => inner.resolve(context),
+            Self::Many(inner) => inner.resolve(context),
+        }
+    }
+}
+
+impl Template for AttributeMap {
+    fn resolve(&mut self, context: &Value) -> Result<(), Error> {
+        self.iter_mut()
+            .try_for_each(|(name, value)| resolve_attribute(name, value, context))
+    }
+}
+
+impl<T: StaticTag, A: Template, C: Template> Template for Component<PhantomData<T>, A, C> {
+    fn resolve(&mut self, context: &Value) -> Result<(), Error> {
+        self.attributes
+            .resolve(context)
+            .and_then(|_| self.children.resolve(context))
+            .map_err(|err| {
+                #[cfg(feature = "span")]
+                let err = err.at_location(self.location.as_ref());
+                err.in_element(T::static_tag())
+            })
+    }
+}
+
+impl<A: Template, C: Template> Template for Component<String, A, C> {
+    fn resolve(&mut self, context: &Value) -> Result<(), Error> {
+        self.attributes
+            .resolve(context)
+            .and_then(|_| self.children.resolve(context))
+            .map_err(|err| {
+                #[cfg(feature = "span")]
+                let err = err.at_location(self.location.as_ref());
+                err.in_element(&self.tag)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::resolve_str;
+    use crate::prelude::render::Error;
+    use crate::prelude::Span;
+
+    #[test]
+    fn should_keep_input_without_placeholder() {
+        let result = resolve_str("Hello World", &json!({})).unwrap();
+        assert!(matches!(result, std::borrow::Cow::Borrowed("Hello World")));
+    }
+
+    #[test]
+    fn should_resolve_nested_values() {
+        let context = json!({
+            "user": { "name": "Alice", "age": 42, "admin": true },
+            "items": [{ "title": "first" }],
+            "empty": null,
+        });
+        assert_eq!(
+            resolve_str(
+                "{{user.name}} ({{ user.age }}, {{ user.admin }}) {{ items.0.title }}{{ empty }}",
+                &context
+            )
+            .unwrap(),
+            "Alice (42, true) first"
+        );
+    }
+
+    #[test]
+    fn should_escape_unless_triple_braces() {
+        let context = json!({ "content": "<b>\"bold\" & 'loud'</b>" });
+        assert_eq!(
+            resolve_str("{{ content }}", &context).unwrap(),
+            "&lt;b&gt;&quot;bold&quot; &amp; &#39;loud&#39;&lt;/b&gt;"
+        );
+        assert_eq!(
+            resolve_str("{{{ content }}}", &context).unwrap(),
+            "<b>\"bold\" & 'loud'</b>"
+        );
+    }
+
+    #[test]
+    fn should_keep_unclosed_placeholder() {
+        let context = json!({ "name": "Alice" });
+        assert_eq!(
+            resolve_str("{{ name }} {{ name", &context).unwrap(),
+            "Alice {{ name"
+        );
+    }
+
+    #[test]
+    fn should_fail_with_missing_variable() {
+        let err = resolve_str("Hello {{ user.name }}!", &json!({ "user": {} })).unwrap_err();
+        match err {
+            Error::MissingVariable {
+                name,
+                value_span,
+                element,
+                attribute,
+                ..
+            } => {
+                assert_eq!(name, "user.name");
+                assert_eq!(value_span, Span { start: 6, end: 21 });
+                assert_eq!(element, None);
+                assert_eq!(attribute, None);
+            }
+            other => panic!("unexpected error {other:?}"),
+        }
+    }
+}
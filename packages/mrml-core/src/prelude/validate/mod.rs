@@ -0,0 +1,920 @@
+//! Validation of a template against the rules of mjml: the children allowed in
+//! each component, the known attributes with the type of their values, and
+//! the attributes that are required.
+//!
+//! Like the `validationLevel` option of mjml, the [`ValidationLevel`] defines
+//! how strict the validation is.
+//!
+//! ```rust
+//! use mrml::prelude::validate::{Severity, ValidationLevel};
+//!
+//! let template = r#"<mjml><mj-body><mj-section><mj-image /></mj-section></mj-body></mjml>"#;
+//! let diagnostics = mrml::validate(template, ValidationLevel::Strict).unwrap();
+//! // mj-image is not allowed in mj-section and requires a src attribute
+//! assert_eq!(diagnostics.len(), 2);
+//! assert!(diagnostics.iter().all(|item| item.severity == Severity::Error));
+//! ```
+
+mod value;
+
+use std::marker::PhantomData;
+
+pub(crate) use self::value::AttributeType;
+pub use self::value::AttributeValue;
+use super::hash::Map;
+use super::parser::{Error, MrmlParser, Origin, ParserOptions};
+use super::visit::{walk, Visit, Visitor};
+use super::{Component, Location, Span, StaticTag};
+pub use crate::helper::color::Color;
+pub use crate::helper::size::{Percent, Pixel, Size};
+
+/// Attributes that are accepted by every component.
+pub(crate) const GLOBAL_ATTRIBUTES: [&str; 2] = ["css-class", "mj-class"];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidationLevel {
+    /// The issues are reported as errors, except the unknown elements and
+    /// attributes that are reported as warnings.
+    Strict,
+    /// All the issues are reported as warnings.
+    #[default]
+    Soft,
+    /// Nothing is checked.
+    Skip,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => f.write_str("error"),
+            Self::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// The element is neither a mjml component nor an html element allowed
+    /// at this position.
+    UnknownElement { tag: String },
+    /// The element is not allowed as a child of its parent.
+    UnexpectedChild { parent: String, tag: String },
+    /// The attribute is not supported by the element.
+    UnknownAttribute { tag: String, name: String },
+    /// The value of the attribute doesn't have the expected type.
+    InvalidAttributeValue {
+        tag: String,
+        name: String,
+        expected: String,
+    },
+    /// The attribute is required by the element.
+    MissingAttribute { tag: String, name: &'static str },
+}
+
+impl DiagnosticKind {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::UnknownElement { .. } => "unknown-element",
+            Self::UnexpectedChild { .. } => "unexpected-child",
+            Self::UnknownAttribute { .. } => "unknown-attribute",
+            Self::InvalidAttributeValue { .. } => "invalid-attribute-value",
+            Self::MissingAttribute { .. } => "missing-attribute",
+        }
+    }
+
+    fn severity(&self, level: ValidationLevel) -> Severity {
+        match (level, self) {
+            (ValidationLevel::Strict, Self::UnknownElement { .. })
+            | (ValidationLevel::Strict, Self::UnknownAttribute { .. }) => Severity::Warning,
+            (ValidationLevel::Strict, _) => Severity::Error,
+            _ => Severity::Warning,
+        }
+    }
+}
+
+impl std::fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownElement { tag } => write!(f, "unknown element {tag:?}"),
+            Self::UnexpectedChild { parent, tag } => {
+                write!(f, "element {tag:?} is not allowed in {parent:?}")
+            }
+            Self::UnknownAttribute { tag, name } => {
+                write!(f, "unknown attribute {name:?} for element {tag:?}")
+            }
+            Self::InvalidAttributeValue {
+                tag,
+                name,
+                expected,
+            } => write!(
+                f,
+                "invalid value for attribute {name:?} of element {tag:?}, expected {expected}"
+            ),
+            Self::MissingAttribute { tag, name } => {
+                write!(f, "missing attribute {name:?} in element {tag:?}")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub severity: Severity,
+    pub origin: Origin,
+    pub span: Span,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} in {} at position {}",
+            self.severity, self.kind, self.origin, self.span
+        )
+    }
+}
+
+/// What can be found in a component.
+pub(crate) enum Children {
+    /// The content is some html, or some text, that is not validated.
+    Raw,
+    /// Only the given components are allowed.
+    Only(&'static [&'static str]),
+    /// Default attributes for other components, like in `mj-attributes`.
+    Defaults,
+}
+
+/// What attributes a component accepts.
+pub(crate) enum Attributes {
+    Any,
+    Only(&'static [&'static [(&'static str, AttributeType)]]),
+}
+
+/// The validation rules of a component.
+pub(crate) struct Rule {
+    pub tag: &'static str,
+    pub attributes: Attributes,
+    pub required: &'static [&'static str],
+    pub children: Children,
+}
+
+impl Rule {
+    /// The attributes of the rule, without the ones accepted by every
+    /// component.
+    pub(crate) fn attribute_types(
+        &self,
+    ) -> impl Iterator<Item = &'static (&'static str, AttributeType)> {
+        let groups: &'static [&'static [(&'static str, AttributeType)]] = match self.attributes {
+            Attributes::Any => &[],
+            Attributes::Only(groups) => groups,
+        };
+        groups.iter().flat_map(|group| group.iter())
+    }
+
+    pub(crate) fn attribute_type(&self, name: &str) -> Option<Option<&AttributeType>> {
+        if GLOBAL_ATTRIBUTES.contains(&name) {
+            return Some(None);
+        }
+        match self.attributes {
+            Attributes::Any => Some(None),
+            Attributes::Only(groups) => groups
+                .iter()
+                .flat_map(|group| group.iter())
+                .find(|(key, _)| *key == name)
+                .map(|(_, kind)| Some(kind)),
+        }
+    }
+}
+
+pub(crate) const PADDING: &[(&str, AttributeType)] = &[
+    ("padding", AttributeType::Spacing),
+    ("padding-top", AttributeType::Size),
+    ("padding-right", AttributeType::Size),
+    ("padding-bottom", AttributeType::Size),
+    ("padding-left", AttributeType::Size),
+];
+
+pub(crate) const BORDER: &[(&str, AttributeType)] = &[
+    ("border", AttributeType::String),
+    ("border-top", AttributeType::String),
+    ("border-right", AttributeType::String),
+    ("border-bottom", AttributeType::String),
+    ("border-left", AttributeType::String),
+];
+
+pub(crate) const FONT: &[(&str, AttributeType)] = &[
+    ("color", AttributeType::Color),
+    ("font-family", AttributeType::String),
+    ("font-size", AttributeType::Pixel),
+    ("font-style", AttributeType::String),
+    ("font-weight", AttributeType::String),
+    ("letter-spacing", AttributeType::LetterSpacing),
+    ("line-height", AttributeType::LineHeight),
+    ("text-decoration", AttributeType::String),
+    ("text-transform", AttributeType::String),
+];
+
+pub(crate) const BODY_CONTENT: &[&str] = &[
+    crate::mj_accordion::NAME,
+    crate::mj_button::NAME,
+    crate::mj_carousel::NAME,
+    crate::mj_divider::NAME,
+    crate::mj_image::NAME,
+    crate::mj_navbar::NAME,
+    crate::mj_raw::NAME,
+    crate::mj_social::NAME,
+    crate::mj_spacer::NAME,
+    crate::mj_table::NAME,
+    crate::mj_text::NAME,
+];
+
+const RULES: &[&Rule] = &[
+    &crate::mjml::validate::RULE,
+    &crate::mj_accordion::validate::RULE,
+    &crate::mj_accordion_element::validate::RULE,
+    &crate::mj_accordion_text::validate::RULE,
+    &crate::mj_accordion_title::validate::RULE,
+    &crate::mj_attributes::validate::RULE,
+    &crate::mj_body::validate::RULE,
+    &crate::mj_breakpoint::validate::RULE,
+    &crate::mj_button::validate::RULE,
+    &crate::mj_carousel::validate::RULE,
+    &crate::mj_carousel_image::validate::RULE,
+    &crate::mj_column::validate::RULE,
+    &crate::mj_divider::validate::RULE,
+    &crate::mj_font::validate::RULE,
+    &crate::mj_group::validate::RULE,
+    &crate::mj_head::validate::RULE,
+    &crate::mj_hero::validate::RULE,
+    &crate::mj_html_attributes::validate::RULE,
+    &crate::mj_html_attributes::validate::SELECTOR_RULE,
+    &crate::mj_html_attributes::validate::ATTRIBUTE_RULE,
+    &crate::mj_image::validate::RULE,
+    &crate::mj_include::validate::RULE,
+    &crate::mj_navbar::validate::RULE,
+    &crate::mj_navbar_link::validate::RULE,
+    &crate::mj_preview::validate::RULE,
+    &crate::mj_raw::validate::RULE,
+    &crate::mj_section::validate::RULE,
+    &crate::mj_social::validate::RULE,
+    &crate::mj_social_element::validate::RULE,
+    &crate::mj_spacer::validate::RULE,
+    &crate::mj_style::validate::RULE,
+    &crate::mj_table::validate::RULE,
+    &crate::mj_text::validate::RULE,
+    &crate::mj_title::validate::RULE,
+    &crate::mj_wrapper::validate::RULE,
+];
+
+pub(crate) fn find_rule(tag: &str) -> Option<&'static Rule> {
+    RULES.iter().copied().find(|rule| rule.tag == tag)
+}
+
+/// Description of a mjml component, as known by the validation, for tools
+/// like the completion in an editor.
+///
+/// ```rust
+/// let button = mrml::prelude::validate::component("mj-button").unwrap();
+/// let align = button.attributes().find(|item| item.name == "align").unwrap();
+/// assert_eq!(align.values, &["left", "center", "right"]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct ComponentSpec(&'static Rule);
+
+impl ComponentSpec {
+    pub fn name(&self) -> &'static str {
+        self.0.tag
+    }
+
+    /// The components allowed as children, `None` when the content is not
+    /// restricted to some components, like the html of `mj-text` or the
+    /// default attributes of `mj-attributes`.
+    pub fn children(&self) -> Option<&'static [&'static str]> {
+        match self.0.children {
+            Children::Only(list) => Some(list),
+            Children::Raw | Children::Defaults => None,
+        }
+    }
+
+    /// Whether the component accepts any attribute, like `mj-raw`.
+    pub fn accepts_any_attribute(&self) -> bool {
+        matches!(self.0.attributes, Attributes::Any)
+    }
+
+    /// The attributes supported by the component, including the ones accepted
+    /// by every component.
+    pub fn attributes(&self) -> impl Iterator<Item = AttributeSpec> + '_ {
+        let required = self.0.required;
+        GLOBAL_ATTRIBUTES
+            .iter()
+            .map(|name| AttributeSpec {
+                name,
+                kind: &AttributeType::String,
+                expected: AttributeType::String.expected(),
+                values: &[],
+                required: false,
+            })
+            .chain(
+                self.0
+                    .attribute_types()
+                    .map(move |(name, kind)| AttributeSpec {
+                        name,
+                        kind,
+                        expected: kind.expected(),
+                        values: match kind {
+                            AttributeType::Enum(values) => values,
+                            _ => &[],
+                        },
+                        required: required.contains(name),
+                    }),
+            )
+    }
+}
+
+impl ComponentSpec {
+    /// Parses the values of the known attributes of the component, leaving
+    /// out the unknown attributes and the invalid values.
+    ///
+    /// ```rust
+    /// use mrml::mj_body::MjBodyChild;
+    /// use mrml::prelude::validate::{AttributeValue, Pixel};
+    ///
+    /// let root = mrml::parse(r#"<mjml><mj-body><mj-image src="logo.png" width="120px" height="12em" /></mj-body></mjml>"#).unwrap();
+    /// let body = root.element.children.body.unwrap();
+    /// let MjBodyChild::MjImage(image) = &body.children[0] else {
+    ///     unreachable!()
+    /// };
+    /// let attributes = mrml::prelude::validate::component("mj-image")
+    ///     .unwrap()
+    ///     .typed_attributes(&image.attributes);
+    /// assert_eq!(attributes.get("width"), Some(&AttributeValue::Pixel(Pixel::new(120.0))));
+    /// assert_eq!(attributes.get("height"), None);
+    /// ```
+    pub fn typed_attributes(
+        &self,
+        attributes: &Map<String, Option<String>>,
+    ) -> Map<String, AttributeValue> {
+        self.attributes()
+            .filter_map(|spec| {
+                let value = attributes.get(spec.name)?.as_deref()?;
+                Some((spec.name.to_string(), spec.parse(value)?))
+            })
+            .collect()
+    }
+}
+
+/// Description of an attribute of a [`ComponentSpec`].
+#[derive(Clone, Debug)]
+pub struct AttributeSpec {
+    pub name: &'static str,
+    kind: &'static AttributeType,
+    /// What the value should look like, like `a size in px`.
+    pub expected: String,
+    /// The accepted values, when they are limited to a list.
+    pub values: &'static [&'static str],
+    pub required: bool,
+}
+
+impl AttributeSpec {
+    /// Parses the value of the attribute, `None` when it doesn't look like
+    /// what is [`expected`](Self::expected).
+    pub fn parse(&self, value: &str) -> Option<AttributeValue> {
+        self.kind.parse(value)
+    }
+}
+
+/// Returns the description of a mjml component, `None` when the component is
+/// unknown.
+pub fn component(tag: &str) -> Option<ComponentSpec> {
+    find_rule(tag)
+        .or_else(|| find_defaults_rule(tag))
+        .map(ComponentSpec)
+}
+
+/// Lists the known mjml components.
+pub fn components() -> impl Iterator<Item = ComponentSpec> {
+    RULES.iter().map(|rule| ComponentSpec(rule))
+}
+
+/// Rules of the elements that can be found in `mj-attributes`.
+pub(crate) fn find_defaults_rule(tag: &str) -> Option<&'static Rule> {
+    match tag {
+        crate::mj_attributes_all::NAME => Some(&crate::mj_attributes_all::validate::RULE),
+        crate::mj_attributes_class::NAME => Some(&crate::mj_attributes_class::validate::RULE),
+        _ => None,
+    }
+}
+
+/// Values of the attributes of an element, by name, for the elements that
+/// don't keep them in an [`AttributeMap`](crate::prelude::AttributeMap).
+pub(crate) trait AttributeValues {
+    fn attribute_value(&self, name: &str) -> Option<&str>;
+}
+
+impl AttributeValues for () {
+    fn attribute_value(&self, _name: &str) -> Option<&str> {
+        None
+    }
+}
+
+impl AttributeValues for crate::prelude::AttributeMap {
+    fn attribute_value(&self, name: &str) -> Option<&str> {
+        self.get(name).and_then(|value| value.as_deref())
+    }
+}
+
+/// Parent of the elements being validated.
+struct Parent {
+    tag: &'static str,
+    children: &'static Children,
+}
+
+const ROOT: Parent = Parent {
+    tag: "",
+    children: &Children::Only(&[crate::mjml::NAME]),
+};
+
+/// Goes through a parsed template, the positions of the elements coming from
+/// their [`Location`](crate::prelude::Location).
+struct Validator {
+    level: ValidationLevel,
+    parents: Vec<Parent>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Validator {
+    fn new(level: ValidationLevel) -> Self {
+        Self {
+            level,
+            parents: vec![ROOT],
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn report(&mut self, location: Option<&Location>, kind: DiagnosticKind, span: Span) {
+        self.diagnostics.push(Diagnostic {
+            severity: kind.severity(self.level),
+            origin: location
+                .map(|item| item.origin.clone())
+                .unwrap_or(Origin::Root),
+            kind,
+            span,
+        });
+    }
+
+    /// Position of the start of the element, like `<mj-text`.
+    fn element_span(location: Option<&Location>, tag: &str) -> Span {
+        location
+            .map(|item| Span {
+                start: item.span.start,
+                end: item.span.start + 1 + tag.len(),
+            })
+            .unwrap_or(Span { start: 0, end: 0 })
+    }
+
+    fn validate_attributes<A: AttributeValues>(
+        &mut self,
+        rule: &Rule,
+        attributes: &A,
+        location: Option<&Location>,
+    ) {
+        let Some(found) = location.map(|item| &item.attributes) else {
+            return;
+        };
+        for (name, span) in found.iter() {
+            match rule.attribute_type(name) {
+                None => self.report(
+                    location,
+                    DiagnosticKind::UnknownAttribute {
+                        tag: rule.tag.to_string(),
+                        name: name.clone(),
+                    },
+                    *span,
+                ),
+                Some(Some(kind)) if !kind.accepts(attributes.attribute_value(name)) => self.report(
+                    location,
+                    DiagnosticKind::InvalidAttributeValue {
+                        tag: rule.tag.to_string(),
+                        name: name.clone(),
+                        expected: kind.expected(),
+                    },
+                    *span,
+                ),
+                Some(_) => {}
+            }
+        }
+        for required in rule.required {
+            if !found.contains_key(*required) {
+                self.report(
+                    location,
+                    DiagnosticKind::MissingAttribute {
+                        tag: rule.tag.to_string(),
+                        name: required,
+                    },
+                    Self::element_span(location, rule.tag),
+                );
+            }
+        }
+    }
+
+    /// Checks the element against the children allowed by its parent and
+    /// returns its rule when its content has to be validated.
+    fn validate_element<A: AttributeValues>(
+        &mut self,
+        tag: &str,
+        attributes: &A,
+        location: Option<&Location>,
+    ) -> Option<&'static Rule> {
+        let parent = self.parents.last().unwrap_or(&ROOT);
+        let (parent_tag, children) = (parent.tag, parent.children);
+        match children {
+            Children::Raw => None,
+            Children::Defaults => {
+                if let Some(rule) = find_defaults_rule(tag) {
+                    self.validate_attributes(rule, attributes, location);
+                }
+                None
+            }
+            Children::Only(allowed) => {
+                let Some(rule) = find_rule(tag) else {
+                    self.report(
+                        location,
+                        DiagnosticKind::UnknownElement {
+                            tag: tag.to_string(),
+                        },
+                        Self::element_span(location, tag),
+                    );
+                    return None;
+                };
+                if !allowed.contains(&tag) {
+                    self.report(
+                        location,
+                        DiagnosticKind::UnexpectedChild {
+                            parent: parent_tag.to_string(),
+                            tag: tag.to_string(),
+                        },
+                        Self::element_span(location, tag),
+                    );
+                }
+                self.validate_attributes(rule, attributes, location);
+                Some(rule)
+            }
+        }
+    }
+
+    fn validate_component<T, A, C>(&mut self, element: &Component<PhantomData<T>, A, C>)
+    where
+        T: StaticTag,
+        A: AttributeValues,
+        Component<PhantomData<T>, A, C>: Visit,
+    {
+        let location = element.location.as_ref();
+        if let Some(rule) = self.validate_element(T::static_tag(), &element.attributes, location) {
+            self.parents.push(Parent {
+                tag: rule.tag,
+                children: &rule.children,
+            });
+            walk(self, element);
+            self.parents.pop();
+        }
+    }
+
+    /// Validates the `mj-include` element and, for a mjml template, the
+    /// content it loaded, as if it was in place of the element.
+    fn validate_include<T, A, C>(&mut self, element: &Component<PhantomData<T>, A, C>, mjml: bool)
+    where
+        A: AttributeValues,
+        C: Visit,
+    {
+        let rule = &crate::mj_include::validate::RULE;
+        self.validate_attributes(rule, &element.attributes, element.location.as_ref());
+        if mjml {
+            walk(self, &element.children);
+        }
+    }
+}
+
+macro_rules! validate_components {
+    ($($method:ident: $type:ty),+ $(,)?) => {
+        $(
+            fn $method(&mut self, element: &$type) {
+                self.validate_component(element);
+            }
+        )+
+    };
+}
+
+impl Visitor for Validator {
+    validate_components!(
+        visit_mjml: crate::mjml::Mjml,
+        visit_mj_head: crate::mj_head::MjHead,
+        visit_mj_attributes: crate::mj_attributes::MjAttributes,
+        visit_mj_attributes_all: crate::mj_attributes_all::MjAttributesAll,
+        visit_mj_attributes_class: crate::mj_attributes_class::MjAttributesClass,
+        visit_mj_breakpoint: crate::mj_breakpoint::MjBreakpoint,
+        visit_mj_font: crate::mj_font::MjFont,
+        visit_mj_html_attributes: crate::mj_html_attributes::MjHtmlAttributes,
+        visit_mj_selector: crate::mj_html_attributes::MjSelector,
+        visit_mj_html_attribute: crate::mj_html_attributes::MjHtmlAttribute,
+        visit_mj_preview: crate::mj_preview::MjPreview,
+        visit_mj_style: crate::mj_style::MjStyle,
+        visit_mj_title: crate::mj_title::MjTitle,
+        visit_mj_body: crate::mj_body::MjBody,
+        visit_mj_accordion: crate::mj_accordion::MjAccordion,
+        visit_mj_accordion_element: crate::mj_accordion_element::MjAccordionElement,
+        visit_mj_accordion_title: crate::mj_accordion_title::MjAccordionTitle,
+        visit_mj_accordion_text: crate::mj_accordion_text::MjAccordionText,
+        visit_mj_button: crate::mj_button::MjButton,
+        visit_mj_carousel: crate::mj_carousel::MjCarousel,
+        visit_mj_carousel_image: crate::mj_carousel_image::MjCarouselImage,
+        visit_mj_column: crate::mj_column::MjColumn,
+        visit_mj_divider: crate::mj_divider::MjDivider,
+        visit_mj_group: crate::mj_group::MjGroup,
+        visit_mj_hero: crate::mj_hero::MjHero,
+        visit_mj_image: crate::mj_image::MjImage,
+        visit_mj_navbar: crate::mj_navbar::MjNavbar,
+        visit_mj_navbar_link: crate::mj_navbar_link::MjNavbarLink,
+        visit_mj_raw: crate::mj_raw::MjRaw,
+        visit_mj_section: crate::mj_section::MjSection,
+        visit_mj_social: crate::mj_social::MjSocial,
+        visit_mj_social_element: crate::mj_social_element::MjSocialElement,
+        visit_mj_spacer: crate::mj_spacer::MjSpacer,
+        visit_mj_table: crate::mj_table::MjTable,
+        visit_mj_text: crate::mj_text::MjText,
+        visit_mj_wrapper: crate::mj_wrapper::MjWrapper,
+    );
+
+    fn visit_mj_include_head(&mut self, element: &crate::mj_include::head::MjIncludeHead) {
+        let mjml = matches!(
+            element.0.attributes.kind,
+            crate::mj_include::head::MjIncludeHeadKind::Mjml
+        );
+        self.validate_include(&element.0, mjml);
+    }
+
+    fn visit_mj_include_body(&mut self, element: &crate::mj_include::body::MjIncludeBody) {
+        let mjml = matches!(
+            element.0.attributes.kind,
+            crate::mj_include::body::MjIncludeBodyKind::Mjml
+        );
+        self.validate_include(&element.0, mjml);
+    }
+
+    fn visit_node<T: Visit>(&mut self, element: &crate::node::Node<T>) {
+        // an html element is only reported, its content is not validated
+        self.validate_element(&element.tag, &element.attributes, element.location.as_ref());
+    }
+}
+
+impl MrmlParser<'_> {
+    /// Validates a template, with the [custom
+    /// components](crate::prelude::parser::component) of the parser being
+    /// validated once expanded.
+    ///
+    /// The template must be well formed, otherwise the error of the parser
+    /// is returned.
+    ///
+    /// ```rust
+    /// use mrml::prelude::parser::component::{ComponentElement, ComponentError, ComponentRegistry};
+    /// use mrml::prelude::parser::{MrmlParser, ParserOptions};
+    /// use mrml::prelude::validate::ValidationLevel;
+    ///
+    /// fn logo(_: &ComponentElement<'_>) -> Result<String, ComponentError> {
+    ///     Ok(r#"<mj-section><mj-column><mj-image src="logo.png" /></mj-column></mj-section>"#.into())
+    /// }
+    ///
+    /// let opts = ParserOptions::default();
+    /// let parser = MrmlParser::new(&opts)
+    ///     .with_components(ComponentRegistry::default().with_component("mx-logo", logo));
+    /// let template = r#"<mjml><mj-body><mx-logo /></mj-body></mjml>"#;
+    /// let diagnostics = parser.validate(template, ValidationLevel::Strict).unwrap();
+    /// assert!(diagnostics.is_empty());
+    /// ```
+    pub fn validate<T: AsRef<str>>(
+        &self,
+        input: T,
+        level: ValidationLevel,
+    ) -> Result<Vec<Diagnostic>, Error> {
+        if level == ValidationLevel::Skip {
+            return Ok(Vec::new());
+        }
+        let output = self.parse_template(input)?;
+        let mut validator = Validator::new(level);
+        output.element.visit(&mut validator);
+        Ok(validator.diagnostics)
+    }
+}
+
+/// Validates a template, loading the `mj-include` elements with the loader
+/// of the given options.
+///
+/// The template must be well formed, otherwise the error of the parser is
+/// returned.
+pub fn validate_with_options(
+    input: &str,
+    opts: &ParserOptions,
+    level: ValidationLevel,
+) -> Result<Vec<Diagnostic>, Error> {
+    MrmlParser::new(opts).validate(input, level)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_with_options, DiagnosticKind, Severity, ValidationLevel};
+    use crate::prelude::parser::component::{ComponentElement, ComponentError, ComponentRegistry};
+    use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+    use crate::prelude::parser::{MrmlParser, Origin, ParserOptions};
+    use crate::prelude::Span;
+
+    fn kinds(template: &str) -> Vec<DiagnosticKind> {
+        crate::validate(template, ValidationLevel::Strict)
+            .unwrap()
+            .into_iter()
+            .map(|item| item.kind)
+            .collect()
+    }
+
+    #[test]
+    fn should_accept_valid_template() {
+        let template = include_str!("../../../resources/template/amario.mjml");
+        let diagnostics = crate::validate(template, ValidationLevel::Strict).unwrap();
+        assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+    }
+
+    #[test]
+    fn should_detect_unexpected_child() {
+        let template =
+            "<mjml><mj-body><mj-section><mj-text>Hello</mj-text></mj-section></mj-body></mjml>";
+        let diagnostics = crate::validate(template, ValidationLevel::Strict).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].kind,
+            DiagnosticKind::UnexpectedChild {
+                parent: "mj-section".into(),
+                tag: "mj-text".into(),
+            }
+        );
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].span, Span { start: 27, end: 35 });
+    }
+
+    #[test]
+    fn should_detect_invalid_values() {
+        let template = r##"<mjml><mj-body><mj-section padding="10px 5%" background-color="#zzz"><mj-column width="33.33%" vertical-align="center"><mj-divider border-width="2em" /></mj-column></mj-section></mj-body></mjml>"##;
+        let result = kinds(template);
+        assert_eq!(
+            result,
+            vec![
+                DiagnosticKind::InvalidAttributeValue {
+                    tag: "mj-section".into(),
+                    name: "background-color".into(),
+                    expected: "a color".into(),
+                },
+                DiagnosticKind::InvalidAttributeValue {
+                    tag: "mj-column".into(),
+                    name: "vertical-align".into(),
+                    expected: "one of top, bottom, middle".into(),
+                },
+                DiagnosticKind::InvalidAttributeValue {
+                    tag: "mj-divider".into(),
+                    name: "border-width".into(),
+                    expected: "a size in px".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_detect_missing_and_unknown_attributes() {
+        let template = r#"<mjml><mj-head><mj-font name="Raleway" /><mj-attributes><mj-all foo="bar" /></mj-attributes></mj-head><mj-body><mj-section><mj-column><mj-image alt="logo" foo="bar" /></mj-column></mj-section></mj-body></mjml>"#;
+        let diagnostics = crate::validate(template, ValidationLevel::Strict).unwrap();
+        let result: Vec<_> = diagnostics
+            .iter()
+            .map(|item| (item.kind.as_str(), item.severity))
+            .collect();
+        assert_eq!(
+            result,
+            vec![
+                ("missing-attribute", Severity::Error),
+                ("unknown-attribute", Severity::Warning),
+                ("missing-attribute", Severity::Error),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_not_validate_html_content() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-text><p align="top">Hello<br></p><mj-image /></mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        assert!(kinds(template).is_empty());
+    }
+
+    #[test]
+    fn should_report_unknown_element() {
+        let template = r#"<mjml><mj-body><mj-section><mj-foo><mj-text /></mj-foo></mj-section></mj-body></mjml>"#;
+        assert_eq!(
+            kinds(template),
+            vec![DiagnosticKind::UnknownElement {
+                tag: "mj-foo".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn should_respect_level() {
+        let template = "<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>";
+        let diagnostics = crate::validate(template, ValidationLevel::Soft).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert!(crate::validate(template, ValidationLevel::Skip)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn should_validate_included_template() {
+        let loader = MemoryIncludeLoader::from(vec![(
+            "partial.mjml",
+            r#"<mj-column><mj-image /></mj-column>"#,
+        )]);
+        let opts = ParserOptions {
+            include_loader: Box::new(loader),
+        };
+        let template = r#"<mjml><mj-body><mj-section><mj-include path="partial.mjml" /></mj-section></mj-body></mjml>"#;
+        let diagnostics = validate_with_options(template, &opts, ValidationLevel::Strict).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].kind,
+            DiagnosticKind::MissingAttribute {
+                tag: "mj-image".into(),
+                name: "src"
+            }
+        );
+        assert!(
+            matches!(diagnostics[0].origin, Origin::Include { ref path } if path == "partial.mjml")
+        );
+        assert_eq!(diagnostics[0].span, Span { start: 11, end: 20 });
+    }
+
+    #[test]
+    fn should_validate_expanded_components() {
+        fn logo(_: &ComponentElement<'_>) -> Result<String, ComponentError> {
+            Ok(r#"<mj-section><mj-column><mj-image alt="logo" /></mj-column></mj-section>"#.into())
+        }
+
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts)
+            .with_components(ComponentRegistry::default().with_component("mx-logo", logo));
+        let template = r#"<mjml><mj-body><mx-logo /></mj-body></mjml>"#;
+        let diagnostics = parser.validate(template, ValidationLevel::Strict).unwrap();
+        // reported at the position of the custom element
+        assert_eq!(diagnostics[0].span.start, 15);
+        let result: Vec<_> = diagnostics.into_iter().map(|item| item.kind).collect();
+        assert_eq!(
+            result,
+            vec![DiagnosticKind::MissingAttribute {
+                tag: "mj-image".into(),
+                name: "src"
+            }]
+        );
+    }
+
+    #[test]
+    fn should_describe_components() {
+        let names = super::components()
+            .map(|item| item.name())
+            .collect::<Vec<_>>();
+        assert!(names.contains(&"mj-section"));
+
+        let section = super::component("mj-section").unwrap();
+        assert!(section.children().unwrap().contains(&"mj-column"));
+        assert!(!section.accepts_any_attribute());
+        let attributes = section.attributes().collect::<Vec<_>>();
+        assert!(attributes.iter().any(|item| item.name == "mj-class"));
+        let direction = attributes
+            .iter()
+            .find(|item| item.name == "direction")
+            .unwrap();
+        assert_eq!(direction.values, &["ltr", "rtl"]);
+
+        let image = super::component("mj-image").unwrap();
+        let src = image.attributes().find(|item| item.name == "src").unwrap();
+        assert!(src.required);
+
+        assert!(super::component("mj-text").unwrap().children().is_none());
+        assert!(super::component("mj-class").is_some());
+        assert!(super::component("mj-unknown").is_none());
+    }
+
+    #[test]
+    fn should_fail_with_invalid_template() {
+        assert!(crate::validate("<mjml><mj-body>", ValidationLevel::Strict).is_err());
+        assert!(crate::validate("<mj-body />", ValidationLevel::Strict).is_err());
+    }
+}
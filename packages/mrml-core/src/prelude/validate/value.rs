@@ -0,0 +1,199 @@
+use std::convert::TryFrom;
+
+use crate::helper::color::Color;
+use crate::helper::size::{Pixel, Size};
+
+/// The expected type of the value of an attribute.
+#[derive(Debug)]
+pub(crate) enum AttributeType {
+    String,
+    Boolean,
+    Integer,
+    Color,
+    Enum(&'static [&'static str]),
+    /// A size in pixels, like `10px`.
+    Pixel,
+    /// A size in pixels or `auto`.
+    PixelOrAuto,
+    /// A size in pixels or percents, like `10px` or `50%`.
+    Size,
+    /// A size in pixels, percents or `auto`.
+    SizeOrAuto,
+    /// From 1 to 4 sizes in pixels or percents, like `10px 5%`.
+    Spacing,
+    /// A size in pixels or percents, or a number without unit.
+    LineHeight,
+    /// A size in pixels or ems, that can be negative.
+    LetterSpacing,
+}
+
+/// The value of an attribute, parsed according to the type expected by its
+/// component.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttributeValue {
+    String(String),
+    Boolean(bool),
+    Integer(i64),
+    Color(Color),
+    /// One of the values accepted by the attribute, like `center`.
+    Enum(&'static str),
+    Auto,
+    /// A size in pixels, that can be negative for a letter spacing.
+    Pixel(Pixel),
+    /// A size in pixels or percents, or a number without unit for a line
+    /// height.
+    Size(Size),
+    /// From 1 to 4 sizes, starting from the top and going clockwise.
+    Spacing(Vec<Size>),
+    /// A letter spacing in ems.
+    Em(f32),
+}
+
+fn parse_size(value: &str) -> Option<Size> {
+    if value == "0" {
+        return Some(Size::pixel(0.0));
+    }
+    Size::try_from(value)
+        .ok()
+        .filter(|size| size.is_pixel() || size.is_percent())
+}
+
+impl AttributeType {
+    pub(crate) fn accepts(&self, value: Option<&str>) -> bool {
+        match value {
+            Some(value) => self.parse(value).is_some(),
+            None => matches!(self, Self::String),
+        }
+    }
+
+    /// Parses the value of an attribute, `None` when it doesn't have the
+    /// expected type.
+    pub(crate) fn parse(&self, value: &str) -> Option<AttributeValue> {
+        let value = value.trim();
+        match self {
+            Self::String => Some(AttributeValue::String(value.to_string())),
+            Self::Boolean => value.parse::<bool>().ok().map(AttributeValue::Boolean),
+            Self::Integer => value.parse::<i64>().ok().map(AttributeValue::Integer),
+            Self::Color => Color::try_from(value).ok().map(AttributeValue::Color),
+            Self::Enum(values) => values
+                .iter()
+                .find(|item| **item == value)
+                .map(|item| AttributeValue::Enum(item)),
+            Self::Pixel => Pixel::try_from(value).ok().map(AttributeValue::Pixel),
+            Self::PixelOrAuto if value == "auto" => Some(AttributeValue::Auto),
+            Self::PixelOrAuto => Pixel::try_from(value).ok().map(AttributeValue::Pixel),
+            Self::Size => parse_size(value).map(AttributeValue::Size),
+            Self::SizeOrAuto if value == "auto" => Some(AttributeValue::Auto),
+            Self::SizeOrAuto => parse_size(value).map(AttributeValue::Size),
+            Self::Spacing => {
+                let sizes = value
+                    .split_whitespace()
+                    .map(parse_size)
+                    .collect::<Option<Vec<_>>>()?;
+                (1..=4)
+                    .contains(&sizes.len())
+                    .then_some(AttributeValue::Spacing(sizes))
+            }
+            Self::LineHeight => Size::try_from(value).ok().map(AttributeValue::Size),
+            Self::LetterSpacing => match value.strip_suffix("em") {
+                Some(inner) => inner.parse::<f32>().ok().map(AttributeValue::Em),
+                None => Pixel::try_from(value).ok().map(AttributeValue::Pixel),
+            },
+        }
+    }
+
+    pub(crate) fn expected(&self) -> String {
+        match self {
+            Self::String => "a string".into(),
+            Self::Boolean => "a boolean".into(),
+            Self::Integer => "an integer".into(),
+            Self::Color => "a color".into(),
+            Self::Enum(values) => format!("one of {}", values.join(", ")),
+            Self::Pixel => "a size in px".into(),
+            Self::PixelOrAuto => "a size in px or auto".into(),
+            Self::Size => "a size in px or %".into(),
+            Self::SizeOrAuto => "a size in px or %, or auto".into(),
+            Self::Spacing => "1 to 4 sizes in px or %".into(),
+            Self::LineHeight => "a size in px or %, or a number".into(),
+            Self::LetterSpacing => "a size in px or em".into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AttributeType, AttributeValue};
+
+    #[test]
+    fn should_check_sizes() {
+        assert!(AttributeType::Pixel.accepts(Some("10px")));
+        assert!(AttributeType::Pixel.accepts(Some("0")));
+        assert!(!AttributeType::Pixel.accepts(Some("10%")));
+        assert!(AttributeType::Size.accepts(Some("10%")));
+        assert!(!AttributeType::Size.accepts(Some("10")));
+        assert!(AttributeType::SizeOrAuto.accepts(Some("auto")));
+        assert!(AttributeType::Spacing.accepts(Some("10px 0 5% 2px")));
+        assert!(!AttributeType::Spacing.accepts(Some("1px 2px 3px 4px 5px")));
+        assert!(AttributeType::LineHeight.accepts(Some("1.5")));
+        assert!(AttributeType::LetterSpacing.accepts(Some("-0.1em")));
+        assert!(!AttributeType::LetterSpacing.accepts(Some("1%")));
+    }
+
+    #[test]
+    fn should_check_colors() {
+        for value in [
+            "#fff",
+            "#ffff",
+            "#a1b2c3",
+            "#a1b2c3d4",
+            "rgb(0, 0, 0)",
+            "red",
+        ] {
+            assert!(AttributeType::Color.accepts(Some(value)), "{value}");
+        }
+        for value in ["#ff", "#ggg", "rgb(0, 0, 0", "red blue", ""] {
+            assert!(!AttributeType::Color.accepts(Some(value)), "{value}");
+        }
+    }
+
+    #[test]
+    fn should_parse_values() {
+        use crate::helper::color::Color;
+        use crate::helper::size::{Pixel, Size};
+
+        assert_eq!(
+            AttributeType::Spacing.parse("10px 5%"),
+            Some(AttributeValue::Spacing(vec![
+                Size::pixel(10.0),
+                Size::percent(5.0)
+            ]))
+        );
+        assert_eq!(
+            AttributeType::PixelOrAuto.parse("auto"),
+            Some(AttributeValue::Auto)
+        );
+        assert_eq!(
+            AttributeType::LetterSpacing.parse("-2px"),
+            Some(AttributeValue::Pixel(Pixel::new(-2.0)))
+        );
+        assert_eq!(
+            AttributeType::LetterSpacing.parse("0.1em"),
+            Some(AttributeValue::Em(0.1))
+        );
+        assert_eq!(
+            AttributeType::Enum(&["left", "right"]).parse(" left "),
+            Some(AttributeValue::Enum("left"))
+        );
+        assert_eq!(
+            AttributeType::Color.parse("#abc"),
+            Some(AttributeValue::Color(Color::rgb(170, 187, 204)))
+        );
+        assert_eq!(AttributeType::Size.parse("12em"), None);
+    }
+
+    #[test]
+    fn should_require_value() {
+        assert!(AttributeType::String.accepts(None));
+        assert!(!AttributeType::Boolean.accepts(None));
+    }
+}
@@ -0,0 +1,801 @@
+//! Walking through the elements of a template.
+//!
+//! A [`Visitor`] has a method for each kind of element. The default
+//! implementations go through the children of the element with [`walk`], so
+//! a visitor only needs to override the methods of the elements it cares
+//! about. To keep going through the children of an overridden element, call
+//! [`walk`] from the method. [`VisitorMut`] and [`walk_mut`] do the same with
+//! mutable references, to update the template in place.
+//!
+//! The content of the `mj-include` elements is visited like the rest of the
+//! template.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! use mrml::mj_button::MjButton;
+//! use mrml::mj_image::MjImage;
+//! use mrml::prelude::visit::{Visit, VisitMut, Visitor, VisitorMut};
+//!
+//! #[derive(Default)]
+//! struct Links(Vec<String>);
+//!
+//! impl Visitor for Links {
+//!     fn visit_mj_button(&mut self, element: &MjButton) {
+//!         if let Some(Some(href)) = element.attributes.get("href") {
+//!             self.0.push(href.clone());
+//!         }
+//!     }
+//! }
+//!
+//! struct Cdn;
+//!
+//! impl VisitorMut for Cdn {
+//!     fn visit_mj_image(&mut self, element: &mut MjImage) {
+//!         if let Some(Some(src)) = element.attributes.get_mut("src") {
+//!             *src = format!("https://cdn.example.com/{src}");
+//!         }
+//!     }
+//! }
+//!
+//! let template = r#"<mjml>
+//!   <mj-body>
+//!     <mj-image src="logo.png" />
+//!     <mj-button href="https://example.com">Visit</mj-button>
+//!   </mj-body>
+//! </mjml>"#;
+//! let mut root = mrml::parse(template).unwrap().element;
+//!
+//! let mut links = Links::default();
+//! root.visit(&mut links);
+//! assert_eq!(links.0, ["https://example.com"]);
+//!
+//! root.visit_mut(&mut Cdn);
+//! # }
+//! ```
+
+use crate::comment::Comment;
+use crate::conditional_comment::ConditionalComment;
+use crate::mj_accordion::{MjAccordion, MjAccordionChild};
+use crate::mj_accordion_element::MjAccordionElement;
+use crate::mj_accordion_text::MjAccordionText;
+use crate::mj_accordion_title::MjAccordionTitle;
+use crate::mj_attributes::{MjAttributes, MjAttributesChild};
+use crate::mj_attributes_all::MjAttributesAll;
+use crate::mj_attributes_class::MjAttributesClass;
+use crate::mj_attributes_element::MjAttributesElement;
+use crate::mj_body::{MjBody, MjBodyChild};
+use crate::mj_breakpoint::MjBreakpoint;
+use crate::mj_button::MjButton;
+use crate::mj_carousel::{MjCarousel, MjCarouselChild};
+use crate::mj_carousel_image::MjCarouselImage;
+use crate::mj_column::MjColumn;
+use crate::mj_divider::MjDivider;
+use crate::mj_font::MjFont;
+use crate::mj_group::MjGroup;
+use crate::mj_head::{MjHead, MjHeadChild};
+use crate::mj_hero::MjHero;
+use crate::mj_html_attributes::{MjHtmlAttribute, MjHtmlAttributes, MjSelector};
+use crate::mj_image::MjImage;
+use crate::mj_include::body::{MjIncludeBody, MjIncludeBodyChild};
+use crate::mj_include::head::{MjIncludeHead, MjIncludeHeadChild};
+use crate::mj_navbar::{MjNavbar, MjNavbarChild};
+use crate::mj_navbar_link::MjNavbarLink;
+use crate::mj_preview::{MjPreview, MjPreviewChild};
+use crate::mj_raw::{MjRaw, MjRawChild};
+use crate::mj_section::MjSection;
+use crate::mj_social::{MjSocial, MjSocialChild};
+use crate::mj_social_element::MjSocialElement;
+use crate::mj_spacer::MjSpacer;
+use crate::mj_style::MjStyle;
+use crate::mj_table::MjTable;
+use crate::mj_text::MjText;
+use crate::mj_title::MjTitle;
+use crate::mj_wrapper::MjWrapper;
+use crate::mjml::Mjml;
+use crate::node::Node;
+use crate::prelude::OneOrMany;
+use crate::text::Text;
+
+/// An element that can be visited.
+pub trait Visit {
+    /// Calls the method of the visitor matching the element.
+    fn visit<V: Visitor + ?Sized>(&self, visitor: &mut V);
+    /// Visits the children of the element.
+    fn visit_children<V: Visitor + ?Sized>(&self, visitor: &mut V);
+}
+
+/// An element that can be visited and updated.
+pub trait VisitMut {
+    /// Calls the method of the visitor matching the element.
+    fn visit_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V);
+    /// Visits the children of the element.
+    fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V);
+}
+
+/// Visits the children of the element.
+pub fn walk<V: Visitor + ?Sized, E: Visit + ?Sized>(visitor: &mut V, element: &E) {
+    element.visit_children(visitor);
+}
+
+/// Visits the children of the element, with mutable references.
+pub fn walk_mut<V: VisitorMut + ?Sized, E: VisitMut + ?Sized>(visitor: &mut V, element: &mut E) {
+    element.visit_children_mut(visitor);
+}
+
+macro_rules! visitor_trait {
+    ($(#[$meta:meta])* $name:ident, $walk:ident, $bound:ident, $($ref:tt)+) => {
+        $(#[$meta])*
+        #[allow(unused_variables)]
+        pub trait $name {
+            fn visit_mjml(&mut self, element: $($ref)+ Mjml) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_head(&mut self, element: $($ref)+ MjHead) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_attributes(&mut self, element: $($ref)+ MjAttributes) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_attributes_all(&mut self, element: $($ref)+ MjAttributesAll) {}
+
+            fn visit_mj_attributes_class(&mut self, element: $($ref)+ MjAttributesClass) {}
+
+            fn visit_mj_attributes_element(&mut self, element: $($ref)+ MjAttributesElement) {}
+
+            fn visit_mj_breakpoint(&mut self, element: $($ref)+ MjBreakpoint) {}
+
+            fn visit_mj_font(&mut self, element: $($ref)+ MjFont) {}
+
+            fn visit_mj_html_attributes(&mut self, element: $($ref)+ MjHtmlAttributes) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_selector(&mut self, element: $($ref)+ MjSelector) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_html_attribute(&mut self, element: $($ref)+ MjHtmlAttribute) {}
+
+            fn visit_mj_include_head(&mut self, element: $($ref)+ MjIncludeHead) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_preview(&mut self, element: $($ref)+ MjPreview) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_style(&mut self, element: $($ref)+ MjStyle) {}
+
+            fn visit_mj_title(&mut self, element: $($ref)+ MjTitle) {}
+
+            fn visit_mj_body(&mut self, element: $($ref)+ MjBody) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_accordion(&mut self, element: $($ref)+ MjAccordion) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_accordion_element(&mut self, element: $($ref)+ MjAccordionElement) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_accordion_title(&mut self, element: $($ref)+ MjAccordionTitle) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_accordion_text(&mut self, element: $($ref)+ MjAccordionText) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_button(&mut self, element: $($ref)+ MjButton) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_carousel(&mut self, element: $($ref)+ MjCarousel) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_carousel_image(&mut self, element: $($ref)+ MjCarouselImage) {}
+
+            fn visit_mj_column(&mut self, element: $($ref)+ MjColumn) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_divider(&mut self, element: $($ref)+ MjDivider) {}
+
+            fn visit_mj_group(&mut self, element: $($ref)+ MjGroup) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_hero(&mut self, element: $($ref)+ MjHero) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_image(&mut self, element: $($ref)+ MjImage) {}
+
+            fn visit_mj_include_body(&mut self, element: $($ref)+ MjIncludeBody) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_navbar(&mut self, element: $($ref)+ MjNavbar) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_navbar_link(&mut self, element: $($ref)+ MjNavbarLink) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_raw(&mut self, element: $($ref)+ MjRaw) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_section(&mut self, element: $($ref)+ MjSection) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_social(&mut self, element: $($ref)+ MjSocial) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_social_element(&mut self, element: $($ref)+ MjSocialElement) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_spacer(&mut self, element: $($ref)+ MjSpacer) {}
+
+            fn visit_mj_table(&mut self, element: $($ref)+ MjTable) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_text(&mut self, element: $($ref)+ MjText) {
+                $walk(self, element)
+            }
+
+            fn visit_mj_wrapper(&mut self, element: $($ref)+ MjWrapper) {
+                $walk(self, element)
+            }
+
+            /// Visits an html element, in the body or in the content of an
+            /// element like `mj-text`.
+            fn visit_node<T: $bound>(&mut self, element: $($ref)+ Node<T>) {
+                $walk(self, element)
+            }
+
+            fn visit_comment(&mut self, element: $($ref)+ Comment) {}
+
+            fn visit_conditional_comment(&mut self, element: $($ref)+ ConditionalComment) {}
+
+            fn visit_text(&mut self, element: $($ref)+ Text) {}
+        }
+    };
+}
+
+visitor_trait!(
+    /// Goes through the elements of a template.
+    Visitor,
+    walk,
+    Visit,
+    &
+);
+
+visitor_trait!(
+    /// Goes through the elements of a template, with mutable references.
+    VisitorMut,
+    walk_mut,
+    VisitMut,
+    &mut
+);
+
+/// Implements the visit of an element, going through the given fields.
+macro_rules! impl_visit {
+    ($type:ty, $method:ident $(, $field:tt)*) => {
+        impl Visit for $type {
+            fn visit<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+                visitor.$method(self);
+            }
+
+            fn visit_children<V: Visitor + ?Sized>(&self, _visitor: &mut V) {
+                $(self.$field.visit_children(_visitor);)*
+            }
+        }
+
+        impl VisitMut for $type {
+            fn visit_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+                visitor.$method(self);
+            }
+
+            fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, _visitor: &mut V) {
+                $(self.$field.visit_children_mut(_visitor);)*
+            }
+        }
+    };
+}
+
+/// Implements the visit of an enum, delegating to its variants.
+macro_rules! impl_visit_enum {
+    ($type:ty, $($variant:ident),+) => {
+        impl Visit for $type {
+            fn visit<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+                match self {
+                    $(Self::$variant(inner) => inner.visit(visitor),)+
+                }
+            }
+
+            fn visit_children<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+                match self {
+                    $(Self::$variant(inner) => inner.visit_children(visitor),)+
+                }
+            }
+        }
+
+        impl VisitMut for $type {
+            fn visit_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+                match self {
+                    $(Self::$variant(inner) => inner.visit_mut(visitor),)+
+                }
+            }
+
+            fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+                match self {
+                    $(Self::$variant(inner) => inner.visit_children_mut(visitor),)+
+                }
+            }
+        }
+    };
+}
+
+// The containers visit each of their items, their children being the items
+// themselves.
+
+impl<T: Visit> Visit for Vec<T> {
+    fn visit<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        self.visit_children(visitor);
+    }
+
+    fn visit_children<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        self.iter().for_each(|item| item.visit(visitor));
+    }
+}
+
+impl<T: VisitMut> VisitMut for Vec<T> {
+    fn visit_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.visit_children_mut(visitor);
+    }
+
+    fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.iter_mut().for_each(|item| item.visit_mut(visitor));
+    }
+}
+
+impl<T: Visit> Visit for Option<T> {
+    fn visit<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        self.visit_children(visitor);
+    }
+
+    fn visit_children<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(item) = self {
+            item.visit(visitor);
+        }
+    }
+}
+
+impl<T: VisitMut> VisitMut for Option<T> {
+    fn visit_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.visit_children_mut(visitor);
+    }
+
+    fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(item) = self {
+            item.visit_mut(visitor);
+        }
+    }
+}
+
+impl<T: Visit> Visit for OneOrMany<T> {
+    fn visit<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        self.visit_children(visitor);
+    }
+
+    fn visit_children<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        self.iter().for_each(|item| item.visit(visitor));
+    }
+}
+
+impl<T: VisitMut> VisitMut for OneOrMany<T> {
+    fn visit_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.visit_children_mut(visitor);
+    }
+
+    fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        match self {
+            Self::One(item) => item.visit_mut(visitor),
+            Self::Many(items) => items.visit_children_mut(visitor),
+        }
+    }
+}
+
+impl<T: Visit> Visit for Node<T> {
+    fn visit<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_node(self);
+    }
+
+    fn visit_children<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        self.children.visit_children(visitor);
+    }
+}
+
+impl<T: VisitMut> VisitMut for Node<T> {
+    fn visit_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_node(self);
+    }
+
+    fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.children.visit_children_mut(visitor);
+    }
+}
+
+impl Visit for MjIncludeBody {
+    fn visit<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_mj_include_body(self);
+    }
+
+    fn visit_children<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        self.0.children.visit_children(visitor);
+    }
+}
+
+impl VisitMut for MjIncludeBody {
+    fn visit_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_mj_include_body(self);
+    }
+
+    fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.0.children.visit_children_mut(visitor);
+    }
+}
+
+impl Visit for MjIncludeHead {
+    fn visit<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_mj_include_head(self);
+    }
+
+    fn visit_children<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        self.0.children.visit_children(visitor);
+    }
+}
+
+impl VisitMut for MjIncludeHead {
+    fn visit_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_mj_include_head(self);
+    }
+
+    fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.0.children.visit_children_mut(visitor);
+    }
+}
+
+impl Visit for Mjml {
+    fn visit<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_mjml(self);
+    }
+
+    fn visit_children<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        self.children.head.visit(visitor);
+        self.children.body.visit(visitor);
+    }
+}
+
+impl VisitMut for Mjml {
+    fn visit_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_mjml(self);
+    }
+
+    fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.children.head.visit_mut(visitor);
+        self.children.body.visit_mut(visitor);
+    }
+}
+
+impl Visit for MjAccordionElement {
+    fn visit<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_mj_accordion_element(self);
+    }
+
+    fn visit_children<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        self.children.title.visit(visitor);
+        self.children.text.visit(visitor);
+    }
+}
+
+impl VisitMut for MjAccordionElement {
+    fn visit_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_mj_accordion_element(self);
+    }
+
+    fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.children.title.visit_mut(visitor);
+        self.children.text.visit_mut(visitor);
+    }
+}
+
+impl_visit!(MjHead, visit_mj_head, children);
+impl_visit!(MjAttributes, visit_mj_attributes, children);
+impl_visit!(MjAttributesAll, visit_mj_attributes_all);
+impl_visit!(MjAttributesClass, visit_mj_attributes_class);
+impl_visit!(MjAttributesElement, visit_mj_attributes_element);
+impl_visit!(MjBreakpoint, visit_mj_breakpoint);
+impl_visit!(MjFont, visit_mj_font);
+impl_visit!(MjHtmlAttributes, visit_mj_html_attributes, children);
+impl_visit!(MjSelector, visit_mj_selector, children);
+impl_visit!(MjHtmlAttribute, visit_mj_html_attribute);
+impl_visit!(MjPreview, visit_mj_preview, children);
+impl_visit!(MjStyle, visit_mj_style);
+impl_visit!(MjTitle, visit_mj_title);
+impl_visit!(MjBody, visit_mj_body, children);
+impl_visit!(MjAccordion, visit_mj_accordion, children);
+impl_visit!(MjAccordionTitle, visit_mj_accordion_title, children);
+impl_visit!(MjAccordionText, visit_mj_accordion_text, children);
+impl_visit!(MjButton, visit_mj_button, children);
+impl_visit!(MjCarousel, visit_mj_carousel, children);
+impl_visit!(MjCarouselImage, visit_mj_carousel_image);
+impl_visit!(MjColumn, visit_mj_column, children);
+impl_visit!(MjDivider, visit_mj_divider);
+impl_visit!(MjGroup, visit_mj_group, children);
+impl_visit!(MjHero, visit_mj_hero, children);
+impl_visit!(MjImage, visit_mj_image);
+impl_visit!(MjNavbar, visit_mj_navbar, children);
+impl_visit!(MjNavbarLink, visit_mj_navbar_link, children);
+impl_visit!(MjRaw, visit_mj_raw, children);
+impl_visit!(MjSection, visit_mj_section, children);
+impl_visit!(MjSocial, visit_mj_social, children);
+impl_visit!(MjSocialElement, visit_mj_social_element, children);
+impl_visit!(MjSpacer, visit_mj_spacer);
+impl_visit!(MjTable, visit_mj_table, children);
+impl_visit!(MjText, visit_mj_text, children);
+impl_visit!(MjWrapper, visit_mj_wrapper, children);
+impl_visit!(Comment, visit_comment);
+impl_visit!(ConditionalComment, visit_conditional_comment);
+impl_visit!(Text, visit_text);
+
+impl_visit_enum!(
+    MjHeadChild,
+    Comment,
+    MjAttributes,
+    MjBreakpoint,
+    MjFont,
+    MjHtmlAttributes,
+    MjInclude,
+    MjPreview,
+    MjRaw,
+    MjStyle,
+    MjTitle
+);
+impl_visit_enum!(
+    MjIncludeHeadChild,
+    Comment,
+    MjAttributes,
+    MjBreakpoint,
+    MjFont,
+    MjHtmlAttributes,
+    MjPreview,
+    MjRaw,
+    MjStyle,
+    MjTitle,
+    Text
+);
+impl_visit_enum!(
+    MjAttributesChild,
+    MjAttributesAll,
+    MjAttributesClass,
+    MjAttributesElement
+);
+impl_visit_enum!(MjPreviewChild, Comment, Text);
+impl_visit_enum!(
+    MjBodyChild,
+    Comment,
+    MjAccordion,
+    MjButton,
+    MjCarousel,
+    MjColumn,
+    MjDivider,
+    MjGroup,
+    MjHero,
+    MjInclude,
+    MjImage,
+    MjNavbar,
+    MjRaw,
+    MjSection,
+    MjSocial,
+    MjSpacer,
+    MjTable,
+    MjText,
+    MjWrapper,
+    Node,
+    Text
+);
+impl_visit_enum!(
+    MjIncludeBodyChild,
+    Comment,
+    MjAccordion,
+    MjButton,
+    MjCarousel,
+    MjColumn,
+    MjDivider,
+    MjGroup,
+    MjHero,
+    MjImage,
+    MjNavbar,
+    MjRaw,
+    MjSection,
+    MjSocial,
+    MjSpacer,
+    MjTable,
+    MjText,
+    MjWrapper,
+    Node,
+    Text
+);
+impl_visit_enum!(MjRawChild, ConditionalComment, Comment, Node, Text);
+impl_visit_enum!(MjAccordionChild, Comment, MjAccordionElement);
+impl_visit_enum!(MjCarouselChild, Comment, MjCarouselImage);
+impl_visit_enum!(MjNavbarChild, Comment, MjNavbarLink);
+impl_visit_enum!(MjSocialChild, Comment, MjSocialElement);
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::{walk, walk_mut, Visit, VisitMut, Visitor, VisitorMut};
+    use crate::mj_button::MjButton;
+    use crate::mj_image::MjImage;
+    use crate::mj_include::body::{MjIncludeBody, MjIncludeBodyChild};
+    use crate::node::Node;
+    use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+    use crate::prelude::parser::ParserOptions;
+    use crate::text::Text;
+
+    #[derive(Default)]
+    struct Collector {
+        links: Vec<String>,
+        texts: Vec<String>,
+    }
+
+    impl Visitor for Collector {
+        fn visit_mj_button(&mut self, element: &MjButton) {
+            if let Some(Some(href)) = element.attributes.get("href") {
+                self.links.push(href.clone());
+            }
+            walk(self, element);
+        }
+
+        fn visit_node<T: Visit>(&mut self, element: &Node<T>) {
+            if element.tag == "a" {
+                if let Some(Some(href)) = element.attributes.get("href") {
+                    self.links.push(href.clone());
+                }
+            }
+            walk(self, element);
+        }
+
+        fn visit_text(&mut self, element: &Text) {
+            let value = element.inner_str().trim();
+            if !value.is_empty() {
+                self.texts.push(value.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn should_visit_every_element() {
+        let template = r#"<mjml>
+  <mj-head><mj-title>Title</mj-title><mj-preview>Preview</mj-preview></mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-button href="https://example.com/button">Button</mj-button>
+        <mj-text><p>Some <a href="https://example.com/text">link</a></p></mj-text>
+      </mj-column>
+    </mj-section>
+    <mj-include path="footer.mjml" />
+  </mj-body>
+</mjml>"#;
+        let resolver = MemoryIncludeLoader::from(vec![(
+            "footer.mjml",
+            r#"<mj-accordion><mj-accordion-element><mj-accordion-title>Question</mj-accordion-title><mj-accordion-text><a href="https://example.com/footer">Answer</a></mj-accordion-text></mj-accordion-element></mj-accordion>"#,
+        )]);
+        let opts = ParserOptions {
+            include_loader: Box::new(resolver),
+        };
+        let root = crate::mjml::Mjml::parse_with_options(template, &opts)
+            .unwrap()
+            .element;
+        let mut collector = Collector::default();
+        root.visit(&mut collector);
+        assert_eq!(
+            collector.links,
+            [
+                "https://example.com/button",
+                "https://example.com/text",
+                "https://example.com/footer"
+            ]
+        );
+        assert_eq!(
+            collector.texts,
+            ["Preview", "Button", "Some", "link", "Question", "Answer"]
+        );
+    }
+
+    struct Cdn;
+
+    impl VisitorMut for Cdn {
+        fn visit_mj_image(&mut self, element: &mut MjImage) {
+            if let Some(Some(src)) = element.attributes.get_mut("src") {
+                *src = format!("https://cdn.example.com/{src}");
+            }
+        }
+    }
+
+    struct SkipIncludes(usize);
+
+    impl VisitorMut for SkipIncludes {
+        fn visit_mj_include_body(&mut self, _element: &mut MjIncludeBody) {}
+
+        fn visit_mj_image(&mut self, element: &mut MjImage) {
+            self.0 += 1;
+            walk_mut(self, element);
+        }
+    }
+
+    #[test]
+    fn should_update_elements() {
+        let template = r#"<mjml><mj-body><mj-hero><mj-image src="hero.png" /></mj-hero><mj-image src="logo.png" /><mj-include path="footer.mjml" /></mj-body></mjml>"#;
+        let resolver =
+            MemoryIncludeLoader::from(vec![("footer.mjml", r#"<mj-image src="footer.png" />"#)]);
+        let opts = ParserOptions {
+            include_loader: Box::new(resolver),
+        };
+        let mut root = crate::mjml::Mjml::parse_with_options(template, &opts)
+            .unwrap()
+            .element;
+
+        let mut counter = SkipIncludes(0);
+        root.visit_mut(&mut counter);
+        assert_eq!(counter.0, 2);
+
+        root.visit_mut(&mut Cdn);
+        let sources = root
+            .body()
+            .unwrap()
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                crate::mj_body::MjBodyChild::MjImage(image) => image.attributes.get("src"),
+                crate::mj_body::MjBodyChild::MjHero(hero) => match hero.children.first() {
+                    Some(crate::mj_body::MjBodyChild::MjImage(image)) => {
+                        image.attributes.get("src")
+                    }
+                    _ => None,
+                },
+                crate::mj_body::MjBodyChild::MjInclude(include) => {
+                    match include.0.children.first() {
+                        Some(MjIncludeBodyChild::MjImage(image)) => image.attributes.get("src"),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .filter_map(|value| value.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sources,
+            [
+                "https://cdn.example.com/hero.png",
+                "https://cdn.example.com/logo.png",
+                "https://cdn.example.com/footer.png"
+            ]
+        );
+    }
+}
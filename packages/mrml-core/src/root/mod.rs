@@ -7,6 +7,7 @@ mod parse;
 mod render;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "span", allow(clippy::large_enum_variant))]
 enum RootChild {
     Mjml(Mjml),
     #[allow(dead_code)]
@@ -1,7 +1,8 @@
 use super::RootChild;
 use crate::comment::Comment;
+use crate::mjml::Mjml;
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseOutput, ParserOptions,
+    Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseOutput,
 };
 
 impl crate::prelude::parser::ParseChildren<Vec<RootChild>> for MrmlParser<'_> {
@@ -12,7 +13,7 @@ impl crate::prelude::parser::ParseChildren<Vec<RootChild>> for MrmlParser<'_> {
         while let Some(token) = cursor.next_token() {
             match token? {
                 MrmlToken::Comment(inner) => {
-                    result.push(RootChild::Comment(Comment::from(inner.text.as_str())));
+                    result.push(RootChild::Comment(Comment::from_token(cursor, &inner)));
                 }
                 MrmlToken::ElementStart(inner) if inner.local.eq("mjml") => {
                     result.push(RootChild::Mjml(self.parse(cursor, inner.local)?));
@@ -45,7 +46,7 @@ impl crate::prelude::parser::AsyncParseChildren<Vec<RootChild>>
         while let Some(token) = cursor.next_token() {
             match token? {
                 MrmlToken::Comment(inner) => {
-                    result.push(RootChild::Comment(Comment::from(inner.text.as_str())));
+                    result.push(RootChild::Comment(Comment::from_token(cursor, &inner)));
                 }
                 MrmlToken::ElementStart(inner) if inner.local.eq("mjml") => {
                     let element = self.async_parse(cursor, inner.local).await?;
@@ -63,35 +64,38 @@ impl crate::prelude::parser::AsyncParseChildren<Vec<RootChild>>
     }
 }
 
-impl super::Root {
-    /// Function to parse a raw mjml template with some parsing
-    /// [options](crate::prelude::parser::ParserOptions).
-    pub(crate) fn parse_with_options<T: AsRef<str>>(
-        value: T,
-        opts: &ParserOptions,
-    ) -> Result<ParseOutput<Self>, Error> {
-        let parser = MrmlParser::new(opts);
-        let mut cursor = MrmlCursor::new(value.as_ref());
-        let element = Self(parser.parse_children(&mut cursor)?);
+impl ParseOutput<super::Root> {
+    fn into_mjml(self) -> Result<ParseOutput<Mjml>, Error> {
         Ok(ParseOutput {
-            element,
-            warnings: cursor.warnings(),
+            element: self.element.into_mjml().ok_or(Error::NoRootNode)?,
+            warnings: self.warnings,
+            includes: self.includes,
         })
     }
+}
 
-    #[cfg(feature = "async")]
-    pub(crate) async fn async_parse_with_options<T: AsRef<str>>(
+impl MrmlParser<'_> {
+    /// Function to parse a raw mjml template, expanding the
+    /// [custom components](crate::prelude::parser::component) of the parser.
+    pub fn parse_template<T: AsRef<str>>(&self, value: T) -> Result<ParseOutput<Mjml>, Error> {
+        let mut cursor = MrmlCursor::new(value.as_ref());
+        let element = super::Root(self.parse_children(&mut cursor)?);
+        cursor.output(element).into_mjml()
+    }
+}
+
+#[cfg(feature = "async")]
+impl crate::prelude::parser::AsyncMrmlParser {
+    /// Function to parse asynchronously a raw mjml template, expanding the
+    /// [custom components](crate::prelude::parser::component) of the parser.
+    pub async fn parse_template<T: AsRef<str>>(
+        &self,
         value: T,
-        opts: std::sync::Arc<crate::prelude::parser::AsyncParserOptions>,
-    ) -> Result<ParseOutput<Self>, Error> {
-        use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren};
+    ) -> Result<ParseOutput<Mjml>, Error> {
+        use crate::prelude::parser::AsyncParseChildren;
 
-        let parser = AsyncMrmlParser::new(opts);
         let mut cursor = MrmlCursor::new(value.as_ref());
-        let element = Self(parser.async_parse_children(&mut cursor).await?);
-        Ok(ParseOutput {
-            element,
-            warnings: cursor.warnings(),
-        })
+        let element = super::Root(self.async_parse_children(&mut cursor).await?);
+        cursor.output(element).into_mjml()
     }
 }
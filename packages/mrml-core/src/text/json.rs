@@ -10,7 +10,7 @@ impl Serialize for Text {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.0)
+        serializer.serialize_str(&self.value)
     }
 }
 
@@ -28,7 +28,7 @@ impl Visitor<'_> for TextVisitor {
     where
         E: Error,
     {
-        Ok(Text(value.to_string()))
+        Ok(Text::from(value))
     }
 }
 
@@ -47,15 +47,15 @@ mod tests {
 
     #[test]
     fn serialize() {
-        let elt = Text("Hello World".to_string());
+        let elt = Text::from("Hello World");
         assert_eq!(serde_json::to_string(&elt).unwrap(), r#""Hello World""#);
     }
 
     #[test]
     fn deserialize() {
-        let elt = Text("Hello World".to_string());
+        let elt = Text::from("Hello World");
         let json = serde_json::to_string(&elt).unwrap();
         let res: Text = serde_json::from_str(&json).unwrap();
-        assert_eq!(res.0, elt.0);
+        assert_eq!(res.value, elt.value);
     }
 }
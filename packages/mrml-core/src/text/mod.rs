@@ -1,27 +1,55 @@
 #[cfg(feature = "json")]
 mod json;
+#[cfg(feature = "parse")]
+mod parse;
 #[cfg(feature = "print")]
 mod print;
 #[cfg(feature = "render")]
 mod render;
+#[cfg(feature = "template")]
+mod template;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
-pub struct Text(String);
+#[derive(Clone, Debug, Default)]
+pub struct Text {
+    value: String,
+    #[cfg(feature = "span")]
+    location: Option<crate::prelude::Location>,
+}
+
+// Like for the elements, the location is left out.
+impl PartialEq for Text {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Text {}
 
 impl Text {
     pub fn inner_str(&self) -> &str {
-        &self.0
+        &self.value
+    }
+
+    /// Where the text has been parsed from, `None` when it has been built
+    /// another way.
+    #[cfg(feature = "span")]
+    pub fn location(&self) -> Option<&crate::prelude::Location> {
+        self.location.as_ref()
     }
 }
 
 impl AsRef<str> for Text {
     fn as_ref(&self) -> &str {
-        self.0.as_str()
+        self.value.as_str()
     }
 }
 
 impl<V: Into<String>> From<V> for Text {
     fn from(value: V) -> Self {
-        Self(value.into())
+        Self {
+            value: value.into(),
+            #[cfg(feature = "span")]
+            location: None,
+        }
     }
 }
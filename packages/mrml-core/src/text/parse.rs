@@ -0,0 +1,18 @@
+use super::Text;
+use crate::prelude::parser::MrmlCursor;
+
+impl Text {
+    /// Builds the text node from its token, keeping its location with the
+    /// `span` feature.
+    #[cfg_attr(not(feature = "span"), allow(unused_variables))]
+    pub(crate) fn from_token(
+        cursor: &MrmlCursor<'_>,
+        token: &crate::prelude::parser::Text<'_>,
+    ) -> Self {
+        Self {
+            value: token.text.to_string(),
+            #[cfg(feature = "span")]
+            location: Some(cursor.token_location(token.text.into())),
+        }
+    }
+}
@@ -3,9 +3,11 @@ use crate::prelude::print::{Printable, Printer};
 
 impl Printable for Text {
     fn print<P: Printer>(&self, printer: &mut P) -> std::fmt::Result {
-        printer.push_indent();
-        printer.push_str(self.0.as_str());
-        printer.push_new_line();
+        #[cfg(feature = "span")]
+        if printer.print_source(self.location(), || self.print_dense())? {
+            return Ok(());
+        }
+        printer.push_text(self.value.as_str());
         Ok(())
     }
 }
@@ -1,4 +1,5 @@
 use super::Text;
+use crate::prelude::render::text::{decode_entities, TextBuffer, TextRender};
 use crate::prelude::render::*;
 
 impl<'root> Render<'root> for Renderer<'root, Text, ()> {
@@ -24,3 +25,9 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for Text {
         Box::new(Renderer::new(context, self, ()))
     }
 }
+
+impl TextRender for Text {
+    fn render_text(&self, buffer: &mut TextBuffer<'_>) {
+        buffer.push_text(&decode_entities(self.inner_str()));
+    }
+}
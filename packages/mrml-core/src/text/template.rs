@@ -0,0 +1,27 @@
+use super::Text;
+use crate::prelude::render::Error;
+use crate::prelude::template::Template;
+
+impl Template for Text {
+    fn resolve(&mut self, context: &serde_json::Value) -> Result<(), Error> {
+        let result = self.value.resolve(context);
+        #[cfg(feature = "span")]
+        return result.map_err(|err| err.at_location(self.location.as_ref()));
+        #[cfg(not(feature = "span"))]
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::template::Template;
+    use crate::text::Text;
+
+    #[test]
+    fn should_resolve() {
+        let mut item = Text::from("Hello {{ name }}!");
+        item.resolve(&serde_json::json!({ "name": "World" }))
+            .unwrap();
+        assert_eq!(item.inner_str(), "Hello World!");
+    }
+}
@@ -0,0 +1,169 @@
+use lsp_types::{CompletionItem, CompletionItemKind, CompletionTextEdit, Documentation, TextEdit};
+use mrml::prelude::validate::{self, ComponentSpec};
+use mrml::prelude::Span;
+
+use crate::document::Document;
+use crate::syntax::{self, Part, Tag};
+use crate::{definition, hover};
+
+fn item(document: &Document, label: &str, kind: CompletionItemKind, span: Span) -> CompletionItem {
+    CompletionItem {
+        label: label.to_string(),
+        kind: Some(kind),
+        text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+            document.range(span),
+            label.to_string(),
+        ))),
+        ..Default::default()
+    }
+}
+
+/// The components that can be written at the position of the tag.
+fn components(document: &Document, tag: &Tag<'_>) -> Vec<CompletionItem> {
+    let parents = syntax::parents(&document.text, tag.span.start);
+    let allowed: Vec<ComponentSpec> = match parents.last() {
+        None => validate::component("mjml").into_iter().collect(),
+        Some(parent) => match validate::component(parent).and_then(|spec| spec.children()) {
+            Some(children) => children
+                .iter()
+                .filter_map(|name| validate::component(name))
+                .collect(),
+            None => validate::components()
+                .filter(|spec| spec.name() != "mjml")
+                .collect(),
+        },
+    };
+    allowed
+        .into_iter()
+        .map(|spec| CompletionItem {
+            documentation: Some(Documentation::MarkupContent(hover::component(&spec))),
+            ..item(
+                document,
+                spec.name(),
+                CompletionItemKind::CLASS,
+                tag.name_span,
+            )
+        })
+        .collect()
+}
+
+/// The attributes of the component that are not defined yet.
+fn attributes(document: &Document, tag: &Tag<'_>, span: Span) -> Vec<CompletionItem> {
+    let Some(spec) = validate::component(tag.name) else {
+        return Vec::new();
+    };
+    spec.attributes()
+        .filter(|attribute| {
+            tag.attributes
+                .iter()
+                .all(|item| item.name != attribute.name || item.name_span == span)
+        })
+        .map(|attribute| CompletionItem {
+            detail: Some(attribute.expected.clone()),
+            documentation: Some(Documentation::MarkupContent(hover::attribute(
+                &spec, &attribute,
+            ))),
+            ..item(document, attribute.name, CompletionItemKind::PROPERTY, span)
+        })
+        .collect()
+}
+
+/// The classes defined with `mj-class`, or the values accepted by the
+/// attribute.
+fn values(
+    document: &Document,
+    tag: &Tag<'_>,
+    name: &str,
+    value: &str,
+    span: Span,
+    offset: usize,
+) -> Vec<CompletionItem> {
+    if name == "mj-class" {
+        let (_, span) = syntax::word_at(value, span, offset);
+        return definition::classes(document)
+            .into_iter()
+            .map(|(name, _)| item(document, &name, CompletionItemKind::VALUE, span))
+            .collect();
+    }
+    validate::component(tag.name)
+        .and_then(|spec| spec.attributes().find(|attribute| attribute.name == name))
+        .map(|attribute| {
+            attribute
+                .values
+                .iter()
+                .map(|value| item(document, value, CompletionItemKind::ENUM_MEMBER, span))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub(crate) fn completion(document: &Document, offset: usize) -> Vec<CompletionItem> {
+    let Some(tag) = syntax::tag_at(&document.text, offset) else {
+        return Vec::new();
+    };
+    match tag.part_at(offset) {
+        Part::Name => components(document, &tag),
+        Part::AttributeName(attribute) => attributes(document, &tag, attribute.name_span),
+        Part::AttributeValue(attribute) => match attribute.value {
+            Some((value, span)) => values(document, &tag, attribute.name, value, span, offset),
+            None => Vec::new(),
+        },
+        Part::Blank => attributes(
+            document,
+            &tag,
+            Span {
+                start: offset,
+                end: offset,
+            },
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::Url;
+
+    use crate::document::Document;
+
+    fn labels(text: &str) -> Vec<String> {
+        let offset = text.find('|').unwrap();
+        let document = Document::new(
+            Url::parse("untitled:template.mjml").unwrap(),
+            text.replace('|', ""),
+        );
+        super::completion(&document, offset)
+            .into_iter()
+            .map(|item| item.label)
+            .collect()
+    }
+
+    #[test]
+    fn should_complete_components() {
+        assert_eq!(labels("<|"), ["mjml"]);
+        assert_eq!(
+            labels("<mjml><mj-body><mj-section><mj-c|"),
+            ["mj-column", "mj-group", "mj-raw"]
+        );
+        assert!(labels("<mjml><mj-body><mj-text><p>Hello</p><|").contains(&"mj-button".into()));
+    }
+
+    #[test]
+    fn should_complete_attributes() {
+        let found = labels(r#"<mjml><mj-body><mj-button href="" |"#);
+        assert!(found.contains(&"align".to_string()));
+        assert!(!found.contains(&"href".to_string()));
+        let found = labels(r#"<mjml><mj-body><mj-button hr| align="left">"#);
+        assert!(found.contains(&"href".to_string()));
+        assert!(labels(r#"<mj-unknown |"#).is_empty());
+    }
+
+    #[test]
+    fn should_complete_values() {
+        assert_eq!(
+            labels(r#"<mjml><mj-body><mj-section direction="|""#),
+            ["ltr", "rtl"]
+        );
+        let text = r#"<mjml><mj-head><mj-attributes><mj-class name="blue" color="blue" /><mj-class name="big" font-size="20px" /></mj-attributes></mj-head><mj-body><mj-text mj-class="blue |"#;
+        assert_eq!(labels(text), ["blue", "big"]);
+    }
+}
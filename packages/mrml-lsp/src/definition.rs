@@ -0,0 +1,67 @@
+use lsp_types::{Location, Position, Range, Url};
+
+use crate::document::Document;
+use crate::syntax::{self, Part};
+
+/// Lists the classes defined with `mj-class` in the template.
+fn local_classes(document: &Document) -> Vec<(String, Location)> {
+    syntax::tags(&document.text, "mj-class")
+        .filter_map(|tag| {
+            let (name, span) = tag.attribute("name")?.value?;
+            Some((
+                name.to_string(),
+                Location::new(document.uri.clone(), document.range(span)),
+            ))
+        })
+        .collect()
+}
+
+/// Loads the templates included in the given one, that are on the
+/// filesystem.
+fn includes(document: &Document) -> Vec<Document> {
+    syntax::tags(&document.text, "mj-include")
+        .filter_map(|tag| {
+            let (path, _) = tag.attribute("path")?.value?;
+            let path = document.include_path(path)?;
+            let text = std::fs::read_to_string(&path).ok()?;
+            let uri = Url::from_file_path(path).ok()?;
+            Some(Document::new(uri, text))
+        })
+        .collect()
+}
+
+/// Lists the classes defined with `mj-class`, in the template and in the
+/// templates it includes.
+pub(crate) fn classes(document: &Document) -> Vec<(String, Location)> {
+    let mut result = local_classes(document);
+    for included in includes(document) {
+        result.extend(local_classes(&included));
+    }
+    result
+}
+
+pub(crate) fn definition(document: &Document, offset: usize) -> Option<Location> {
+    let tag = syntax::tag_at(&document.text, offset)?;
+    let Part::AttributeValue(attribute) = tag.part_at(offset) else {
+        return None;
+    };
+    let (value, span) = attribute.value?;
+    match (tag.name, attribute.name) {
+        ("mj-include", "path") => {
+            let path = document.include_path(value).filter(|path| path.is_file())?;
+            let uri = Url::from_file_path(path).ok()?;
+            Some(Location::new(
+                uri,
+                Range::new(Position::new(0, 0), Position::new(0, 0)),
+            ))
+        }
+        (_, "mj-class") => {
+            let (name, _) = syntax::word_at(value, span, offset);
+            classes(document)
+                .into_iter()
+                .find(|(item, _)| item == name)
+                .map(|(_, location)| location)
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,190 @@
+use std::error::Error as _;
+
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Range};
+use mrml::mjml::Mjml;
+use mrml::prelude::parser::{Error as ParserError, Origin};
+use mrml::prelude::validate::{Severity, ValidationLevel};
+use mrml::prelude::Span;
+
+use crate::document::Document;
+use crate::syntax;
+
+const SOURCE: &str = "mrml";
+
+/// The range of the content of the given origin. The issues found in an
+/// included template are reported on the `mj-include` element.
+fn origin_range(document: &Document, origin: &Origin, span: Span) -> Range {
+    match origin {
+        Origin::Root => document.range(span),
+        Origin::Include { path } => syntax::tags(&document.text, "mj-include")
+            .find(|tag| {
+                tag.attribute("path")
+                    .and_then(|attribute| attribute.value)
+                    .is_some_and(|(value, _)| value == path)
+            })
+            .map(|tag| document.range(tag.span))
+            .unwrap_or_default(),
+    }
+}
+
+fn message(origin: &Origin, message: String) -> String {
+    match origin {
+        Origin::Root => message,
+        Origin::Include { path } => format!("in {path:?}: {message}"),
+    }
+}
+
+fn parser_error(document: &Document, error: &ParserError) -> Diagnostic {
+    let (origin, span) = match error {
+        ParserError::UnexpectedElement { origin, position }
+        | ParserError::UnexpectedToken { origin, position }
+        | ParserError::MissingAttribute {
+            origin, position, ..
+        }
+        | ParserError::InvalidAttribute { origin, position }
+        | ParserError::InvalidFormat { origin, position }
+        | ParserError::IncludeLoaderError {
+            origin, position, ..
+        }
+        | ParserError::UnsupportedIncludeKind {
+            origin, position, ..
+        }
+        | ParserError::ComponentError {
+            origin, position, ..
+        } => (Some(origin), *position),
+        ParserError::ParserError { origin, source } => {
+            let position = source.pos();
+            let offset = match origin {
+                Origin::Root => document.text_position(position.row, position.col),
+                Origin::Include { .. } => 0,
+            };
+            (
+                Some(origin),
+                Span {
+                    start: offset,
+                    end: offset,
+                },
+            )
+        }
+        ParserError::EndOfStream { origin } | ParserError::SizeLimit { origin } => (
+            Some(origin),
+            Span {
+                start: document.text.len(),
+                end: document.text.len(),
+            },
+        ),
+        ParserError::NoRootNode => (None, Span { start: 0, end: 0 }),
+    };
+    let text = match error.source() {
+        Some(source) => format!("{error}: {source}"),
+        None => error.to_string(),
+    };
+    Diagnostic {
+        range: origin
+            .map(|origin| origin_range(document, origin, span))
+            .unwrap_or_default(),
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some(SOURCE.into()),
+        message: match origin {
+            Some(origin) => message(origin, text),
+            None => text,
+        },
+        ..Default::default()
+    }
+}
+
+/// Parses and validates the template, to list its issues.
+pub(crate) fn diagnostics(document: &Document, level: ValidationLevel) -> Vec<Diagnostic> {
+    let options = document.parser_options();
+    let output = match Mjml::parse_with_options(&document.text, &options) {
+        Ok(output) => output,
+        Err(error) => return vec![parser_error(document, &error)],
+    };
+    let warnings = output.warnings.iter().map(|warning| Diagnostic {
+        range: origin_range(document, &warning.origin, warning.span),
+        severity: Some(DiagnosticSeverity::WARNING),
+        code: Some(NumberOrString::String(warning.kind.as_str().into())),
+        source: Some(SOURCE.into()),
+        message: message(&warning.origin, warning.kind.to_string()),
+        ..Default::default()
+    });
+    let rules = mrml::validate_with_options(&document.text, &options, level)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|item| Diagnostic {
+            range: origin_range(document, &item.origin, item.span),
+            severity: Some(match item.severity {
+                Severity::Error => DiagnosticSeverity::ERROR,
+                Severity::Warning => DiagnosticSeverity::WARNING,
+            }),
+            code: Some(NumberOrString::String(item.kind.as_str().into())),
+            source: Some(SOURCE.into()),
+            message: message(&item.origin, item.kind.to_string()),
+            ..Default::default()
+        });
+    warnings.chain(rules).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{DiagnosticSeverity, NumberOrString, Position, Range, Url};
+    use mrml::prelude::validate::ValidationLevel;
+
+    use crate::document::Document;
+
+    fn document(text: &str) -> Document {
+        Document::new(
+            Url::parse("untitled:template.mjml").unwrap(),
+            text.to_string(),
+        )
+    }
+
+    #[test]
+    fn should_report_parser_error() {
+        let document = document("<mjml><mj-body><mj-text>Hello</mj-body></mjml>");
+        let diagnostics = super::diagnostics(&document, ValidationLevel::Soft);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+    }
+
+    #[test]
+    fn should_report_warnings_and_rules() {
+        let document = document(
+            r#"<mjml><mj-body><mj-section foo="bar" foo="baz"><mj-image /></mj-section></mj-body></mjml>"#,
+        );
+        let diagnostics = super::diagnostics(&document, ValidationLevel::Strict);
+        let codes = diagnostics
+            .iter()
+            .map(|item| match item.code {
+                Some(NumberOrString::String(ref code)) => code.as_str(),
+                _ => "",
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            [
+                "duplicate-attribute",
+                // the duplicate attribute is only validated once
+                "unknown-attribute",
+                "unexpected-child",
+                "missing-attribute"
+            ]
+        );
+        assert_eq!(
+            diagnostics[0].range,
+            Range::new(Position::new(0, 37), Position::new(0, 46))
+        );
+    }
+
+    #[test]
+    fn should_report_missing_include() {
+        let document =
+            document(r#"<mjml><mj-body><mj-include path="./missing.mjml" /></mj-body></mjml>"#);
+        let diagnostics = super::diagnostics(&document, ValidationLevel::Soft);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0]
+            .message
+            .contains("unable to load included template"));
+        assert_eq!(diagnostics[0].range.start, Position::new(0, 16));
+    }
+}
@@ -0,0 +1,129 @@
+use std::path::PathBuf;
+
+use lsp_types::{Position, Range, Url};
+use mrml::prelude::parser::local_loader::LocalIncludeLoader;
+use mrml::prelude::parser::ParserOptions;
+use mrml::prelude::Span;
+
+/// A template opened in the editor.
+#[derive(Debug)]
+pub(crate) struct Document {
+    pub uri: Url,
+    pub text: String,
+    /// Byte offset of the beginning of each line.
+    lines: Vec<usize>,
+}
+
+impl Document {
+    pub fn new(uri: Url, text: String) -> Self {
+        let lines = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self { uri, text, lines }
+    }
+
+    /// The directory of the template, where the included templates are
+    /// looked up.
+    pub fn directory(&self) -> Option<PathBuf> {
+        let path = self.uri.to_file_path().ok()?;
+        path.parent()?.canonicalize().ok()
+    }
+
+    /// Resolves the `path` of an `mj-include` element.
+    pub fn include_path(&self, path: &str) -> Option<PathBuf> {
+        let path = path.trim_start_matches("file:///");
+        self.directory().map(|directory| directory.join(path))
+    }
+
+    pub fn parser_options(&self) -> ParserOptions {
+        match self.directory() {
+            Some(directory) => ParserOptions {
+                include_loader: Box::new(LocalIncludeLoader::new(directory)),
+            },
+            None => ParserOptions::default(),
+        }
+    }
+
+    /// Converts a byte offset to a position, where the characters are counted
+    /// in UTF-16 code units, like the protocol expects by default.
+    pub fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.text.len());
+        let line = self.lines.partition_point(|start| *start <= offset) - 1;
+        let start = self.lines[line];
+        let character = self
+            .text
+            .get(start..offset)
+            .map(|value| value.encode_utf16().count())
+            .unwrap_or_default();
+        Position::new(line as u32, character as u32)
+    }
+
+    /// Converts a position to a byte offset, stopping at the end of the line.
+    pub fn offset(&self, position: Position) -> usize {
+        let Some(start) = self.lines.get(position.line as usize).copied() else {
+            return self.text.len();
+        };
+        let mut remaining = position.character as usize;
+        for (index, character) in self.text[start..].char_indices() {
+            if remaining == 0 || character == '\n' {
+                return start + index;
+            }
+            remaining = remaining.saturating_sub(character.len_utf16());
+        }
+        self.text.len()
+    }
+
+    /// Converts a position given by the parser, where the row and the column
+    /// start at 1 and the column is counted in characters.
+    pub fn text_position(&self, row: u32, column: u32) -> usize {
+        let Some(start) = self.lines.get(row.saturating_sub(1) as usize).copied() else {
+            return self.text.len();
+        };
+        self.text[start..]
+            .char_indices()
+            .nth(column.saturating_sub(1) as usize)
+            .map(|(index, _)| start + index)
+            .unwrap_or(self.text.len())
+    }
+
+    pub fn range(&self, span: Span) -> Range {
+        Range::new(self.position(span.start), self.position(span.end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{Position, Url};
+
+    use super::Document;
+
+    #[test]
+    fn should_convert_positions() {
+        let document = Document::new(
+            Url::parse("file:///tmp/template.mjml").unwrap(),
+            "<mjml>\n  <mj-text>été 😀 ok</mj-text>\n</mjml>".to_string(),
+        );
+        let offset = document.text.find("ok").unwrap();
+        // the emoji takes 2 code units
+        assert_eq!(document.position(offset), Position::new(1, 18));
+        assert_eq!(document.offset(Position::new(1, 18)), offset);
+        assert_eq!(document.position(0), Position::new(0, 0));
+        assert_eq!(document.offset(Position::new(0, 42)), 6);
+        assert_eq!(document.offset(Position::new(5, 0)), document.text.len());
+        assert_eq!(
+            document.text_position(2, 3),
+            document.text.find("<mj-text").unwrap()
+        );
+    }
+
+    #[test]
+    fn should_resolve_include_path() {
+        let directory = std::env::current_dir().unwrap().canonicalize().unwrap();
+        let uri = Url::from_file_path(directory.join("template.mjml")).unwrap();
+        let document = Document::new(uri, String::new());
+        assert_eq!(
+            document.include_path("./partials/header.mjml"),
+            Some(directory.join("./partials/header.mjml"))
+        );
+    }
+}
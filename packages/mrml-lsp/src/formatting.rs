@@ -0,0 +1,231 @@
+use std::fmt::{Display, Write};
+
+use lsp_types::TextEdit;
+use mrml::mjml::Mjml;
+use mrml::prelude::parser::loader::{IncludeLoader, IncludeLoaderError};
+use mrml::prelude::parser::{Origin, ParserOptions};
+use mrml::prelude::print::{PrintAttribute, Printable, Printer};
+use mrml::prelude::{Location, Span};
+
+use crate::document::Document;
+
+/// The included templates are printed as `mj-include` elements, so there is
+/// no need to load them to format a template.
+#[derive(Debug)]
+struct EmptyIncludeLoader;
+
+impl IncludeLoader for EmptyIncludeLoader {
+    fn resolve(&self, _path: &str) -> Result<String, IncludeLoaderError> {
+        Ok(String::new())
+    }
+}
+
+const INDENT_SIZE: usize = 2;
+
+/// The elements whose content is some html or some text, kept as written
+/// because changing its whitespaces could change the rendered html.
+const ENDING_TAGS: &[&str] = &[
+    "mj-accordion-text",
+    "mj-accordion-title",
+    "mj-button",
+    "mj-html-attribute",
+    "mj-navbar-link",
+    "mj-preview",
+    "mj-raw",
+    "mj-social-element",
+    "mj-style",
+    "mj-table",
+    "mj-text",
+    "mj-title",
+];
+
+fn is_ending_tag(source: &str) -> bool {
+    source
+        .strip_prefix('<')
+        .and_then(|value| {
+            value
+                .split(|c: char| c.is_whitespace() || c == '>' || c == '/')
+                .next()
+        })
+        .is_some_and(|tag| ENDING_TAGS.contains(&tag))
+}
+
+/// Indents the elements like the pretty printer of mrml, but trims the texts,
+/// like the whitespaces around the elements, so that formatting a template
+/// again changes nothing. The elements with an [ending tag](ENDING_TAGS) are
+/// printed as written in the template.
+#[derive(Debug)]
+struct FormattingPrinter<'a> {
+    source: &'a str,
+    level: usize,
+    buffer: String,
+}
+
+impl<'a> FormattingPrinter<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            level: 0,
+            buffer: String::new(),
+        }
+    }
+}
+
+impl Printer for FormattingPrinter<'_> {
+    fn push_new_line(&mut self) {
+        self.buffer.push('\n');
+    }
+
+    fn push_indent(&mut self) {
+        self.buffer
+            .extend(std::iter::repeat_n(' ', self.level * INDENT_SIZE));
+    }
+
+    fn increase_indent(&mut self) {
+        self.level += 1;
+    }
+
+    fn decrease_indent(&mut self) {
+        self.level -= 1;
+    }
+
+    fn push(&mut self, value: char) {
+        self.buffer.push(value);
+    }
+
+    fn push_str(&mut self, value: &str) {
+        self.buffer.push_str(value);
+    }
+
+    fn push_text(&mut self, value: &str) {
+        let value = value.trim();
+        if !value.is_empty() {
+            self.push_indent();
+            self.push_str(value);
+            self.push_new_line();
+        }
+    }
+
+    fn open_tag<N: Display + ?Sized>(&mut self, name: &N) -> std::fmt::Result {
+        write!(&mut self.buffer, "<{name}")
+    }
+
+    fn push_attribute<N, V>(&mut self, name: N, value: V) -> std::fmt::Result
+    where
+        PrintAttribute<N, V>: Display,
+    {
+        write!(&mut self.buffer, " {}", PrintAttribute::from((name, value)))
+    }
+
+    fn end_tag<N: Display + ?Sized>(&mut self, name: &N) -> std::fmt::Result {
+        write!(&mut self.buffer, "</{name}>")
+    }
+
+    fn print_source<F>(
+        &mut self,
+        location: Option<&Location>,
+        _dense: F,
+    ) -> Result<bool, std::fmt::Error>
+    where
+        F: FnOnce() -> Result<String, std::fmt::Error>,
+    {
+        let Some(source) = location
+            .filter(|item| matches!(item.origin, Origin::Root))
+            .and_then(|item| self.source.get(item.span.start..item.span.end))
+            .filter(|value| is_ending_tag(value))
+        else {
+            return Ok(false);
+        };
+        self.push_indent();
+        self.push_str(source);
+        self.push_new_line();
+        Ok(true)
+    }
+
+    fn inner(self) -> String {
+        self.buffer
+    }
+}
+
+/// Prints the template indented, `None` when the template cannot be parsed.
+pub(crate) fn formatting(document: &Document) -> Option<Vec<TextEdit>> {
+    let options = ParserOptions {
+        include_loader: Box::new(EmptyIncludeLoader),
+    };
+    let output = Mjml::parse_with_options(&document.text, &options).ok()?;
+    let mut printer = FormattingPrinter::new(&document.text);
+    output.element.print(&mut printer).ok()?;
+    let formatted = printer.inner();
+    if formatted == document.text {
+        return Some(Vec::new());
+    }
+    let range = document.range(Span {
+        start: 0,
+        end: document.text.len(),
+    });
+    Some(vec![TextEdit::new(range, formatted)])
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::Url;
+
+    use crate::document::Document;
+
+    fn document(text: &str) -> Document {
+        Document::new(
+            Url::parse("untitled:template.mjml").unwrap(),
+            text.to_string(),
+        )
+    }
+
+    #[test]
+    fn should_format_template() {
+        let text = r#"<mjml><mj-body><mj-include path="./header.mjml" /><mj-text>Hello</mj-text></mj-body></mjml>"#;
+        let edits = crate::formatting::formatting(&document(text)).unwrap();
+        assert_eq!(edits.len(), 1);
+        let formatted = edits[0].new_text.as_str();
+        assert!(formatted.contains("<mj-include path=\"./header.mjml\" />"));
+        // formatting again changes nothing
+        assert!(crate::formatting::formatting(&document(formatted))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn should_keep_content_of_ending_tags() {
+        let text = "<mjml><mj-head><mj-title> Hello </mj-title></mj-head><mj-body><mj-section><mj-column><mj-text align=\"left\">\n  Hello <b>World</b>  </mj-text><mj-button>Click\n me</mj-button></mj-column></mj-section></mj-body></mjml>";
+        let edits = crate::formatting::formatting(&document(text)).unwrap();
+        let formatted = edits[0].new_text.as_str();
+        assert!(formatted.contains("    <mj-title> Hello </mj-title>\n"));
+        assert!(formatted
+            .contains("        <mj-text align=\"left\">\n  Hello <b>World</b>  </mj-text>\n"));
+        assert!(formatted.contains("        <mj-button>Click\n me</mj-button>\n"));
+        assert!(crate::formatting::formatting(&document(formatted))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn should_render_the_same_once_formatted() {
+        use mrml::prelude::render::RenderOptions;
+
+        let text = r#"<mjml><mj-head><mj-style>.red { color: red; }</mj-style></mj-head><mj-body><mj-section><mj-column><mj-text><pre>  Hello
+    World</pre>  <span>a</span> <span>b</span></mj-text><mj-table><tr><td> 1 </td></tr></mj-table><mj-raw><p>  raw  </p></mj-raw></mj-column></mj-section></mj-body></mjml>"#;
+        let edits = crate::formatting::formatting(&document(text)).unwrap();
+        let formatted = edits[0].new_text.as_str();
+        let render = |value: &str| {
+            mrml::parse(value)
+                .unwrap()
+                .element
+                .render(&RenderOptions::default())
+                .unwrap()
+        };
+        assert_eq!(render(text), render(formatted));
+    }
+
+    #[test]
+    fn should_not_format_invalid_template() {
+        assert!(crate::formatting::formatting(&document("<mjml><mj-body>")).is_none());
+    }
+}
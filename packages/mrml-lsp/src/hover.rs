@@ -0,0 +1,117 @@
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind};
+use mrml::prelude::validate::{self, AttributeSpec, ComponentSpec};
+
+use crate::document::Document;
+use crate::syntax::{self, Part};
+
+fn markdown(value: String) -> MarkupContent {
+    MarkupContent {
+        kind: MarkupKind::Markdown,
+        value,
+    }
+}
+
+fn code_list(items: impl Iterator<Item = &'static str>) -> String {
+    items
+        .map(|item| format!("`{item}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(crate) fn component(spec: &ComponentSpec) -> MarkupContent {
+    let mut value = format!("**{}**\n\n", spec.name());
+    if let Some(children) = spec.children() {
+        if children.is_empty() {
+            value.push_str("Doesn't accept any child.\n\n");
+        } else {
+            value.push_str(&format!(
+                "Children: {}\n\n",
+                code_list(children.iter().copied())
+            ));
+        }
+    }
+    if spec.accepts_any_attribute() {
+        value.push_str("Accepts any attribute.\n\n");
+    } else {
+        let required = code_list(
+            spec.attributes()
+                .filter(|attribute| attribute.required)
+                .map(|attribute| attribute.name),
+        );
+        if !required.is_empty() {
+            value.push_str(&format!("Required attributes: {required}\n\n"));
+        }
+    }
+    value.push_str(&format!(
+        "[Documentation](https://documentation.mjml.io/#{})",
+        spec.name()
+    ));
+    markdown(value)
+}
+
+pub(crate) fn attribute(spec: &ComponentSpec, attribute: &AttributeSpec) -> MarkupContent {
+    let mut value = format!(
+        "**{}** of `{}`\n\nExpects {}.",
+        attribute.name,
+        spec.name(),
+        attribute.expected
+    );
+    if attribute.required {
+        value.push_str(" Required.");
+    }
+    markdown(value)
+}
+
+pub(crate) fn hover(document: &Document, offset: usize) -> Option<Hover> {
+    let tag = syntax::tag_at(&document.text, offset)?;
+    let spec = validate::component(tag.name)?;
+    let (contents, span) = match tag.part_at(offset) {
+        Part::Name => (component(&spec), tag.name_span),
+        Part::AttributeName(item) => {
+            let found = spec
+                .attributes()
+                .find(|attribute| attribute.name == item.name)?;
+            (attribute(&spec, &found), item.name_span)
+        }
+        Part::AttributeValue(_) | Part::Blank => return None,
+    };
+    Some(Hover {
+        contents: HoverContents::Markup(contents),
+        range: Some(document.range(span)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{HoverContents, Url};
+
+    use crate::document::Document;
+
+    fn hover(text: &str, pattern: &str) -> Option<String> {
+        let document = Document::new(
+            Url::parse("untitled:template.mjml").unwrap(),
+            text.to_string(),
+        );
+        let offset = text.find(pattern).unwrap() + 1;
+        super::hover(&document, offset).map(|found| match found.contents {
+            HoverContents::Markup(inner) => inner.value,
+            _ => unreachable!(),
+        })
+    }
+
+    #[test]
+    fn should_describe_component() {
+        let text = r#"<mjml><mj-body><mj-image src="foo.png" align="left" /></mj-body></mjml>"#;
+        let found = hover(text, "mj-image").unwrap();
+        assert!(found.starts_with("**mj-image**"));
+        assert!(found.contains("Required attributes: `src`"));
+        assert!(found.contains("https://documentation.mjml.io/#mj-image"));
+
+        let found = hover(text, "align").unwrap();
+        assert_eq!(
+            found,
+            "**align** of `mj-image`\n\nExpects one of left, center, right."
+        );
+        assert!(hover(text, "foo.png").is_none());
+    }
+}
@@ -0,0 +1,29 @@
+//! Language server for the MJML templates, communicating over stdio.
+//!
+//! It reports the parsing errors and the issues found by the validation,
+//! completes the component names and their attributes, describes them on
+//! hover, goes to the templates included with `mj-include` and to the
+//! definitions of `mj-class`, and formats the templates.
+
+mod completion;
+mod definition;
+mod diagnostics;
+mod document;
+mod formatting;
+mod hover;
+mod server;
+mod syntax;
+
+use lsp_server::Connection;
+
+fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // stdout is used by the protocol, the logs are written to stderr
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn"))
+        .target(env_logger::Target::Stderr)
+        .init();
+    let (connection, io_threads) = Connection::stdio();
+    server::run(&connection)?;
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as NotificationTrait, PublishDiagnostics,
+};
+use lsp_types::request::{
+    Completion, Formatting, GotoDefinition, HoverRequest, Request as RequestTrait,
+};
+use lsp_types::{
+    CompletionOptions, CompletionResponse, GotoDefinitionResponse, HoverProviderCapability,
+    InitializeParams, OneOf, PublishDiagnosticsParams, ServerCapabilities,
+    TextDocumentPositionParams, TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+use mrml::prelude::validate::ValidationLevel;
+
+use crate::document::Document;
+use crate::{completion, definition, diagnostics, formatting, hover};
+
+fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["<".into(), " ".into(), "\"".into()]),
+            ..Default::default()
+        }),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+/// Reads the `validationLevel` of the initialization options, `soft` by
+/// default, like mjml.
+fn validation_level(params: &InitializeParams) -> ValidationLevel {
+    let level = params
+        .initialization_options
+        .as_ref()
+        .and_then(|options| options.get("validationLevel"))
+        .and_then(|value| value.as_str());
+    match level {
+        Some("strict") => ValidationLevel::Strict,
+        Some("skip") => ValidationLevel::Skip,
+        _ => ValidationLevel::Soft,
+    }
+}
+
+fn handle<R: RequestTrait>(
+    request: Request,
+    handler: impl FnOnce(R::Params) -> R::Result,
+) -> Response {
+    match serde_json::from_value::<R::Params>(request.params) {
+        Ok(params) => Response::new_ok(request.id, handler(params)),
+        Err(error) => Response::new_err(
+            request.id,
+            ErrorCode::InvalidParams as i32,
+            error.to_string(),
+        ),
+    }
+}
+
+struct Server {
+    level: ValidationLevel,
+    documents: HashMap<Url, Document>,
+}
+
+impl Server {
+    fn new(params: &InitializeParams) -> Self {
+        Self {
+            level: validation_level(params),
+            documents: HashMap::new(),
+        }
+    }
+
+    /// Finds the document and the offset of the position.
+    fn locate(&self, params: &TextDocumentPositionParams) -> Option<(&Document, usize)> {
+        let document = self.documents.get(&params.text_document.uri)?;
+        Some((document, document.offset(params.position)))
+    }
+
+    fn handle_request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            Completion::METHOD => handle::<Completion>(request, |params| {
+                let (document, offset) = self.locate(&params.text_document_position)?;
+                Some(CompletionResponse::Array(completion::completion(
+                    document, offset,
+                )))
+            }),
+            HoverRequest::METHOD => handle::<HoverRequest>(request, |params| {
+                let (document, offset) = self.locate(&params.text_document_position_params)?;
+                hover::hover(document, offset)
+            }),
+            GotoDefinition::METHOD => handle::<GotoDefinition>(request, |params| {
+                let (document, offset) = self.locate(&params.text_document_position_params)?;
+                definition::definition(document, offset).map(GotoDefinitionResponse::Scalar)
+            }),
+            Formatting::METHOD => handle::<Formatting>(request, |params| {
+                let document = self.documents.get(&params.text_document.uri)?;
+                formatting::formatting(document)
+            }),
+            _ => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unknown method {:?}", request.method),
+            ),
+        }
+    }
+
+    fn publish_diagnostics(&self, uri: Url) -> Notification {
+        let diagnostics = self
+            .documents
+            .get(&uri)
+            .map(|document| diagnostics::diagnostics(document, self.level))
+            .unwrap_or_default();
+        Notification::new(
+            PublishDiagnostics::METHOD.into(),
+            PublishDiagnosticsParams::new(uri, diagnostics, None),
+        )
+    }
+
+    /// Updates the documents, returning the diagnostics to publish.
+    fn handle_notification(
+        &mut self,
+        notification: Notification,
+    ) -> Result<Option<Notification>, serde_json::Error> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: <DidOpenTextDocument as NotificationTrait>::Params =
+                    serde_json::from_value(notification.params)?;
+                let uri = params.text_document.uri;
+                self.documents.insert(
+                    uri.clone(),
+                    Document::new(uri.clone(), params.text_document.text),
+                );
+                Ok(Some(self.publish_diagnostics(uri)))
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: <DidChangeTextDocument as NotificationTrait>::Params =
+                    serde_json::from_value(notification.params)?;
+                let uri = params.text_document.uri;
+                // the documents are synchronized in full
+                let Some(change) = params.content_changes.into_iter().last() else {
+                    return Ok(None);
+                };
+                self.documents
+                    .insert(uri.clone(), Document::new(uri.clone(), change.text));
+                Ok(Some(self.publish_diagnostics(uri)))
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: <DidCloseTextDocument as NotificationTrait>::Params =
+                    serde_json::from_value(notification.params)?;
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                Ok(Some(Notification::new(
+                    PublishDiagnostics::METHOD.into(),
+                    PublishDiagnosticsParams::new(uri, Vec::new(), None),
+                )))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Runs the server until the client asks for a shutdown.
+pub(crate) fn run(connection: &Connection) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let capabilities = serde_json::to_value(capabilities())?;
+    let params = connection.initialize(capabilities)?;
+    let params: InitializeParams = serde_json::from_value(params)?;
+    let mut server = Server::new(&params);
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    return Ok(());
+                }
+                log::debug!("received request {:?}", request.method);
+                let response = server.handle_request(request);
+                connection.sender.send(Message::Response(response))?;
+            }
+            Message::Notification(notification) => {
+                log::debug!("received notification {:?}", notification.method);
+                match server.handle_notification(notification) {
+                    Ok(Some(reply)) => connection.sender.send(Message::Notification(reply))?,
+                    Ok(None) => {}
+                    Err(error) => log::warn!("invalid notification: {error}"),
+                }
+            }
+            Message::Response(_) => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,306 @@
+//! Light analysis of the template around a position. Unlike the parser, it
+//! keeps working on the incomplete templates that are being written.
+
+use htmlparser::{ElementEnd, Token, Tokenizer};
+use mrml::prelude::Span;
+
+fn contains(span: Span, offset: usize) -> bool {
+    span.start <= offset && offset <= span.end
+}
+
+fn is_name_end(value: u8) -> bool {
+    value.is_ascii_whitespace() || matches!(value, b'=' | b'>' | b'<' | b'/' | b'"' | b'\'')
+}
+
+#[derive(Debug)]
+pub(crate) struct Attribute<'a> {
+    pub name: &'a str,
+    pub name_span: Span,
+    /// The value, without the quotes.
+    pub value: Option<(&'a str, Span)>,
+}
+
+/// An opening tag, like `<mj-button href="...">`.
+#[derive(Debug)]
+pub(crate) struct Tag<'a> {
+    pub name: &'a str,
+    pub name_span: Span,
+    pub attributes: Vec<Attribute<'a>>,
+    pub span: Span,
+}
+
+/// The part of a tag at a given position.
+#[derive(Debug)]
+pub(crate) enum Part<'t, 'a> {
+    Name,
+    AttributeName(&'t Attribute<'a>),
+    AttributeValue(&'t Attribute<'a>),
+    /// Between the attributes, where a new attribute can be written.
+    Blank,
+}
+
+impl<'a> Tag<'a> {
+    /// Reads the opening tag starting with the `<` at `start`.
+    fn read(text: &'a str, start: usize) -> Option<Self> {
+        let bytes = text.as_bytes();
+        let skip_whitespaces = |mut position: usize| {
+            while position < bytes.len() && bytes[position].is_ascii_whitespace() {
+                position += 1;
+            }
+            position
+        };
+        let read_name = |mut position: usize| {
+            while position < bytes.len() && !is_name_end(bytes[position]) {
+                position += 1;
+            }
+            position
+        };
+
+        if bytes.get(start) != Some(&b'<')
+            || matches!(bytes.get(start + 1), Some(b'/' | b'!' | b'?'))
+        {
+            return None;
+        }
+        let name_end = read_name(start + 1);
+        let mut tag = Tag {
+            name: &text[start + 1..name_end],
+            name_span: Span {
+                start: start + 1,
+                end: name_end,
+            },
+            attributes: Vec::new(),
+            span: Span {
+                start,
+                end: text.len(),
+            },
+        };
+        let mut position = name_end;
+        loop {
+            position = skip_whitespaces(position);
+            match bytes.get(position) {
+                None => break,
+                Some(b'>') => {
+                    tag.span.end = position + 1;
+                    break;
+                }
+                Some(b'<') => {
+                    tag.span.end = position;
+                    break;
+                }
+                Some(b'/' | b'=' | b'"' | b'\'') => {
+                    position += 1;
+                    continue;
+                }
+                Some(_) => {}
+            }
+            let name_end = read_name(position);
+            let mut attribute = Attribute {
+                name: &text[position..name_end],
+                name_span: Span {
+                    start: position,
+                    end: name_end,
+                },
+                value: None,
+            };
+            position = skip_whitespaces(name_end);
+            if bytes.get(position) == Some(&b'=') {
+                position = skip_whitespaces(position + 1);
+                let (value_start, value_end) = match bytes.get(position) {
+                    Some(quote @ (b'"' | b'\'')) => {
+                        let value_start = position + 1;
+                        let value_end = text[value_start..]
+                            .find(*quote as char)
+                            .map(|index| value_start + index)
+                            .unwrap_or(text.len());
+                        position = (value_end + 1).min(text.len());
+                        (value_start, value_end)
+                    }
+                    _ => {
+                        let value_end = read_name(position);
+                        let value = (position, value_end);
+                        position = value_end;
+                        value
+                    }
+                };
+                attribute.value = Some((
+                    &text[value_start..value_end],
+                    Span {
+                        start: value_start,
+                        end: value_end,
+                    },
+                ));
+            }
+            tag.attributes.push(attribute);
+        }
+        Some(tag)
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&Attribute<'a>> {
+        self.attributes.iter().find(|item| item.name == name)
+    }
+
+    pub fn part_at(&self, offset: usize) -> Part<'_, 'a> {
+        if contains(self.name_span, offset) {
+            return Part::Name;
+        }
+        for attribute in self.attributes.iter() {
+            if contains(attribute.name_span, offset) {
+                return Part::AttributeName(attribute);
+            }
+            if let Some((_, span)) = attribute.value {
+                if contains(span, offset) {
+                    return Part::AttributeValue(attribute);
+                }
+            }
+        }
+        Part::Blank
+    }
+}
+
+/// Finds the opening tag around the given offset.
+pub(crate) fn tag_at(text: &str, offset: usize) -> Option<Tag<'_>> {
+    let start = text.get(..offset)?.rfind('<')?;
+    let tag = Tag::read(text, start)?;
+    (offset < tag.span.end || (offset == tag.span.end && !text[..offset].ends_with('>')))
+        .then_some(tag)
+}
+
+/// Lists the opening tags of the elements with the given name.
+pub(crate) fn tags<'a>(text: &'a str, name: &'a str) -> impl Iterator<Item = Tag<'a>> + 'a {
+    text.match_indices('<')
+        .filter_map(|(start, _)| Tag::read(text, start))
+        .filter(move |tag| tag.name == name)
+}
+
+/// Finds the word containing the offset in a list separated by spaces, like
+/// the value of `mj-class`.
+pub(crate) fn word_at(value: &str, span: Span, offset: usize) -> (&str, Span) {
+    let index = offset.saturating_sub(span.start).min(value.len());
+    let start = value[..index]
+        .rfind(char::is_whitespace)
+        .map(|found| found + 1)
+        .unwrap_or(0);
+    let end = value[index..]
+        .find(char::is_whitespace)
+        .map(|found| index + found)
+        .unwrap_or(value.len());
+    (
+        &value[start..end],
+        Span {
+            start: span.start + start,
+            end: span.start + end,
+        },
+    )
+}
+
+/// Lists the mjml elements that are still open at the given offset, from the
+/// root to the closest one. The html elements, found in `mj-raw` or
+/// `mj-text`, are ignored.
+pub(crate) fn parents(text: &str, offset: usize) -> Vec<&str> {
+    let mut stack = Vec::new();
+    let mut current = None;
+    for token in Tokenizer::from(&text[..offset.min(text.len())]) {
+        match token {
+            Ok(Token::ElementStart { prefix, local, .. }) => {
+                current =
+                    (prefix.is_empty() && local.as_str().starts_with("mj")).then(|| local.as_str());
+            }
+            Ok(Token::ElementEnd {
+                end: ElementEnd::Open,
+                ..
+            }) => {
+                if let Some(name) = current.take() {
+                    stack.push(name);
+                }
+            }
+            Ok(Token::ElementEnd {
+                end: ElementEnd::Empty,
+                ..
+            }) => {
+                current = None;
+            }
+            Ok(Token::ElementEnd {
+                end: ElementEnd::Close(_, local),
+                ..
+            }) => {
+                if let Some(index) = stack.iter().rposition(|name| *name == local.as_str()) {
+                    stack.truncate(index);
+                }
+            }
+            Ok(_) => {}
+            Err(_) => break,
+        }
+    }
+    stack
+}
+
+#[cfg(test)]
+mod tests {
+    use mrml::prelude::Span;
+
+    use super::Part;
+
+    #[test]
+    fn should_read_tag() {
+        let text = r#"<mj-button href='https://x' mj-class="a b" disabled>Hello</mj-button>"#;
+        let tag = super::tag_at(text, 3).unwrap();
+        assert_eq!(tag.name, "mj-button");
+        assert_eq!(tag.span, Span { start: 0, end: 52 });
+        let attributes = tag
+            .attributes
+            .iter()
+            .map(|item| (item.name, item.value.map(|(value, _)| value)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attributes,
+            [
+                ("href", Some("https://x")),
+                ("mj-class", Some("a b")),
+                ("disabled", None)
+            ]
+        );
+        assert!(matches!(tag.part_at(3), Part::Name));
+        assert!(matches!(tag.part_at(12), Part::AttributeName(item) if item.name == "href"));
+        assert!(matches!(tag.part_at(40), Part::AttributeValue(item) if item.name == "mj-class"));
+        assert!(super::tag_at(text, 55).is_none());
+    }
+
+    #[test]
+    fn should_read_incomplete_tag() {
+        let text = "<mjml><mj-body><mj-section><mj-col";
+        let tag = super::tag_at(text, text.len()).unwrap();
+        assert_eq!(tag.name, "mj-col");
+        assert!(matches!(tag.part_at(text.len()), Part::Name));
+
+        let text = "<mjml><mj-body><mj-image src=\"\" ";
+        let tag = super::tag_at(text, text.len()).unwrap();
+        assert_eq!(tag.name, "mj-image");
+        assert!(matches!(tag.part_at(text.len()), Part::Blank));
+        assert!(
+            matches!(tag.part_at(text.len() - 2), Part::AttributeValue(item) if item.name == "src")
+        );
+    }
+
+    #[test]
+    fn should_find_word() {
+        let span = Span { start: 10, end: 21 };
+        assert_eq!(
+            super::word_at("blue large", span, 17),
+            ("large", Span { start: 15, end: 20 })
+        );
+        assert_eq!(
+            super::word_at("blue large", span, 10),
+            ("blue", Span { start: 10, end: 14 })
+        );
+    }
+
+    #[test]
+    fn should_find_parents() {
+        let text = r#"<mjml><mj-head><mj-title>Hi</mj-title></mj-head><mj-body><mj-section><mj-column><mj-text><p>Hello<br></p></mj-text><mj-image src="foo" /><"#;
+        assert_eq!(
+            super::parents(text, text.len() - 1),
+            ["mjml", "mj-body", "mj-section", "mj-column"]
+        );
+        assert!(super::parents(text, 0).is_empty());
+    }
+}
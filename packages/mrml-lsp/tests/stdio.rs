@@ -0,0 +1,186 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::PathBuf;
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+use serde_json::{json, Value};
+
+/// A client talking to the server over stdio, like an editor does.
+struct Client {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    next_id: i64,
+}
+
+impl Client {
+    fn spawn() -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_mrml-lsp"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stdin = child.stdin.take().unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        Self {
+            child,
+            stdin,
+            stdout,
+            next_id: 0,
+        }
+    }
+
+    fn send(&mut self, message: Value) {
+        let body = message.to_string();
+        write!(self.stdin, "Content-Length: {}\r\n\r\n{body}", body.len()).unwrap();
+        self.stdin.flush().unwrap();
+    }
+
+    fn receive(&mut self) -> Value {
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            self.stdout.read_line(&mut line).unwrap();
+            let line = line.trim();
+            if line.is_empty() {
+                break;
+            }
+            if let Some(value) = line.strip_prefix("Content-Length: ") {
+                length = value.parse().unwrap();
+            }
+        }
+        let mut body = vec![0; length];
+        self.stdout.read_exact(&mut body).unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        self.next_id += 1;
+        let id = self.next_id;
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+        loop {
+            let message = self.receive();
+            if message["id"] == id {
+                assert!(message.get("error").is_none(), "{message}");
+                return message["result"].clone();
+            }
+        }
+    }
+
+    fn notify(&mut self, method: &str, params: Value) {
+        self.send(json!({ "jsonrpc": "2.0", "method": method, "params": params }));
+    }
+
+    fn notification(&mut self, method: &str) -> Value {
+        loop {
+            let message = self.receive();
+            if message["method"] == method {
+                return message["params"].clone();
+            }
+        }
+    }
+}
+
+/// The position of the pattern in the text, moved by `shift` characters.
+fn position(text: &str, pattern: &str, shift: usize) -> Value {
+    let offset = text.find(pattern).unwrap() + shift;
+    let line = text[..offset].matches('\n').count();
+    let character = offset
+        - text[..offset]
+            .rfind('\n')
+            .map(|index| index + 1)
+            .unwrap_or(0);
+    json!({ "line": line, "character": character })
+}
+
+#[test]
+fn should_serve_template_features() {
+    let directory = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("resources")
+        .canonicalize()
+        .unwrap();
+    let path = directory.join("template.mjml");
+    let text = std::fs::read_to_string(&path).unwrap();
+    let uri = lsp_types::Url::from_file_path(&path).unwrap().to_string();
+    let head_uri = lsp_types::Url::from_file_path(directory.join("head.mjml"))
+        .unwrap()
+        .to_string();
+    let document = json!({ "uri": uri });
+
+    let mut client = Client::spawn();
+    let result = client.request(
+        "initialize",
+        json!({
+            "processId": null,
+            "rootUri": null,
+            "capabilities": {},
+            "initializationOptions": { "validationLevel": "strict" }
+        }),
+    );
+    assert_eq!(result["capabilities"]["hoverProvider"], true);
+    client.notify("initialized", json!({}));
+
+    client.notify(
+        "textDocument/didOpen",
+        json!({
+            "textDocument": { "uri": uri, "languageId": "mjml", "version": 1, "text": text }
+        }),
+    );
+    let params = client.notification("textDocument/publishDiagnostics");
+    let diagnostics = params["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics.len(), 1, "{params}");
+    assert_eq!(diagnostics[0]["code"], "unknown-attribute");
+    assert_eq!(diagnostics[0]["range"]["start"], position(&text, "foo=", 0));
+
+    let result = client.request(
+        "textDocument/completion",
+        json!({ "textDocument": document, "position": position(&text, " foo=", 0) }),
+    );
+    let labels = result
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["label"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert!(labels.contains(&"align"));
+    assert!(!labels.contains(&"mj-class"));
+
+    let result = client.request(
+        "textDocument/hover",
+        json!({ "textDocument": document, "position": position(&text, "mj-text", 2) }),
+    );
+    assert!(result["contents"]["value"]
+        .as_str()
+        .unwrap()
+        .starts_with("**mj-text**"));
+
+    let result = client.request(
+        "textDocument/definition",
+        json!({ "textDocument": document, "position": position(&text, "blue", 2) }),
+    );
+    assert_eq!(result["uri"], head_uri);
+    assert_eq!(
+        result["range"]["start"],
+        json!({ "line": 1, "character": 18 })
+    );
+
+    let result = client.request(
+        "textDocument/definition",
+        json!({ "textDocument": document, "position": position(&text, "head.mjml", 0) }),
+    );
+    assert_eq!(result["uri"], head_uri);
+
+    let result = client.request(
+        "textDocument/formatting",
+        json!({ "textDocument": document, "options": { "tabSize": 2, "insertSpaces": true } }),
+    );
+    // the template is formatted already, the content of mj-text being kept
+    assert!(result.as_array().unwrap().is_empty(), "{result}");
+
+    client.notify("textDocument/didClose", json!({ "textDocument": document }));
+    let params = client.notification("textDocument/publishDiagnostics");
+    assert!(params["diagnostics"].as_array().unwrap().is_empty());
+
+    assert_eq!(client.request("shutdown", Value::Null), Value::Null);
+    client.notify("exit", Value::Null);
+    assert!(client.child.wait().unwrap().success());
+}
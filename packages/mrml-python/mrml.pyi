@@ -77,6 +77,21 @@ class RenderOptions:
     @property
     def fonts(self) -> Dict[str, str] | None: ...
 
+class TextRenderOptions:
+    """TextRenderOptions configures the plain text rendering, including the line wrapping and whether to write the links and the preview."""
+    def __init__(
+        self,
+        line_width: int | None = 78,
+        include_links: bool = True,
+        include_preview: bool = True,
+    ) -> None: ...
+    @property
+    def line_width(self) -> int | None: ...
+    @property
+    def include_links(self) -> bool: ...
+    @property
+    def include_preview(self) -> bool: ...
+
 class Warning:
     @property
     def origin(self) -> Optional[str]: ...
@@ -88,7 +103,7 @@ class Warning:
     def end(self) -> int: ...
 
 class Output:
-    """to_html result, containing content, title, and preview fields and warnings"""
+    """to_html and to_text result, containing content, title, and preview fields and warnings"""
     @property
     def content(self) -> str: ...
     @property
@@ -106,3 +121,11 @@ def to_html(
 ) -> Output:
     """Function to convert input a MJML string to HTML using optional parser and render configurations."""
     ...
+
+def to_text(
+    input: str,
+    parser_options: Optional[ParserOptions] = None,
+    text_options: Optional[TextRenderOptions] = None,
+) -> Output:
+    """Function to convert input a MJML string to plain text using optional parser and text render configurations."""
+    ...
@@ -171,23 +171,72 @@ impl RenderOptions {
 
 impl From<RenderOptions> for mrml::prelude::render::RenderOptions {
     fn from(value: RenderOptions) -> Self {
-        let mut opts = mrml::prelude::render::RenderOptions {
-            disable_comments: value.disable_comments,
-            ..Default::default()
-        };
+        let mut opts = mrml::prelude::render::RenderOptions::default()
+            .with_disable_comments(value.disable_comments);
         if let Some(social) = value.social_icon_origin {
-            opts.social_icon_origin = Some(Cow::Owned(social));
+            opts = opts.with_social_icon_origin(social);
         }
         if let Some(fonts) = value.fonts {
-            opts.fonts = fonts
-                .into_iter()
-                .map(|(name, value)| (name, Cow::Owned(value)))
-                .collect();
+            opts = opts.with_fonts(
+                fonts
+                    .into_iter()
+                    .map(|(name, value)| (name, Cow::Owned(value)))
+                    .collect(),
+            );
         }
         opts
     }
 }
 
+#[pyclass(frozen, from_py_object)]
+#[derive(Clone, Debug)]
+pub struct TextRenderOptions {
+    #[pyo3(get)]
+    pub line_width: Option<usize>,
+    #[pyo3(get)]
+    pub include_links: bool,
+    #[pyo3(get)]
+    pub include_preview: bool,
+}
+
+impl Default for TextRenderOptions {
+    fn default() -> Self {
+        Self::from(mrml::prelude::render::TextRenderOptions::default())
+    }
+}
+
+#[pymethods]
+impl TextRenderOptions {
+    #[new]
+    #[pyo3(signature = (line_width=Some(78), include_links=true, include_preview=true))]
+    pub fn new(line_width: Option<usize>, include_links: bool, include_preview: bool) -> Self {
+        Self {
+            line_width,
+            include_links,
+            include_preview,
+        }
+    }
+}
+
+impl From<mrml::prelude::render::TextRenderOptions> for TextRenderOptions {
+    fn from(value: mrml::prelude::render::TextRenderOptions) -> Self {
+        Self {
+            line_width: value.line_width,
+            include_links: value.include_links,
+            include_preview: value.include_preview,
+        }
+    }
+}
+
+impl From<TextRenderOptions> for mrml::prelude::render::TextRenderOptions {
+    fn from(value: TextRenderOptions) -> Self {
+        Self::default()
+            .with_line_width(value.line_width)
+            .with_include_links(value.include_links)
+            .with_include_preview(value.include_preview)
+    }
+}
+
 #[pyclass(frozen, from_py_object)]
 #[derive(Clone, Debug, Default)]
 pub struct Warning {
@@ -260,6 +309,29 @@ fn to_html(
     })
 }
 
+#[pyfunction]
+#[pyo3(name = "to_text", signature = (input, parser_options=None, text_options=None))]
+fn to_text(
+    input: String,
+    parser_options: Option<ParserOptions>,
+    text_options: Option<TextRenderOptions>,
+) -> PyResult<Output> {
+    let parser_options = parser_options.unwrap_or_default().into();
+    let parsed = mrml::parse_with_options(input, &parser_options)
+        .map_err(|err| PyIOError::new_err(err.to_string()))?;
+
+    let text_options = text_options.unwrap_or_default().into();
+    let content = parsed.element.render_text(&text_options);
+    let title = parsed.element.get_title();
+    let preview = parsed.element.get_preview();
+    Ok(Output {
+        content,
+        title,
+        preview,
+        warnings: Warning::from_vec(parsed.warnings),
+    })
+}
+
 #[pymodule]
 #[pyo3(name = "mrml")]
 fn register(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -270,9 +342,11 @@ fn register(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<HttpIncludeLoaderOptionsMode>()?;
     m.add_class::<ParserOptions>()?;
     m.add_class::<RenderOptions>()?;
+    m.add_class::<TextRenderOptions>()?;
     m.add_class::<Output>()?;
     m.add_class::<Warning>()?;
     m.add_function(wrap_pyfunction!(to_html, m)?)?;
+    m.add_function(wrap_pyfunction!(to_text, m)?)?;
     m.add_function(wrap_pyfunction!(noop_loader, m)?)?;
     m.add_function(wrap_pyfunction!(local_loader, m)?)?;
     m.add_function(wrap_pyfunction!(http_loader, m)?)?;
@@ -43,3 +43,28 @@ def test_preview_present():
 def test_preview_absent():
     result = mrml.to_html("<mjml></mjml>")
     assert result.preview is None
+
+def test_to_text():
+    result = mrml.to_text("""<mjml>
+        <mj-head>
+            <mj-preview>Hello MJML</mj-preview>
+        </mj-head>
+        <mj-body>
+            <mj-text>Hello World</mj-text>
+            <mj-button href="https://mjml.io">Click</mj-button>
+        </mj-body>
+    </mjml>""")
+    assert result.content == "Hello MJML\n\nHello World\n\nClick (https://mjml.io)\n"
+    assert result.preview == "Hello MJML"
+
+def test_to_text_with_options():
+    options = mrml.TextRenderOptions(line_width=None, include_links=False, include_preview=False)
+    result = mrml.to_text("""<mjml>
+        <mj-head>
+            <mj-preview>Hello MJML</mj-preview>
+        </mj-head>
+        <mj-body>
+            <mj-button href="https://mjml.io">Click</mj-button>
+        </mj-body>
+    </mjml>""", text_options=options)
+    assert result.content == "Click\n"
@@ -0,0 +1,5 @@
+fn main() {
+    let output = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("mjml.d.ts");
+    std::fs::write(output, mrml::prelude::json::schema::typescript()).unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+}
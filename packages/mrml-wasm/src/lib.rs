@@ -10,6 +10,10 @@ use wasm_bindgen::prelude::*;
 pub use crate::parser::*;
 pub use crate::render::*;
 
+/// Types of the json templates, generated from the schema.
+#[wasm_bindgen(typescript_custom_section)]
+const MJML_TYPES: &str = include_str!(concat!(env!("OUT_DIR"), "/mjml.d.ts"));
+
 #[inline]
 fn to_html(
     input: &str,
@@ -21,6 +25,27 @@ fn to_html(
     Ok((html, Warning::from_vec(element.warnings)))
 }
 
+#[inline]
+fn to_text(
+    input: &str,
+    parser_options: &mrml::prelude::parser::ParserOptions,
+    text_options: &mrml::prelude::render::TextRenderOptions,
+) -> Result<(String, Vec<Warning>), ToHtmlError> {
+    let element = mrml::parse_with_options(input, parser_options)?;
+    let text = element.element.render_text(text_options);
+    Ok((text, Warning::from_vec(element.warnings)))
+}
+
+#[inline]
+fn json_to_html(
+    input: &str,
+    render_options: &mrml::prelude::render::RenderOptions,
+) -> Result<(String, Vec<Warning>), ToHtmlError> {
+    let element = mrml::prelude::json::schema::from_str(input)?;
+    let html = element.render(render_options)?;
+    Ok((html, Vec::new()))
+}
+
 #[cfg(feature = "async")]
 #[inline]
 async fn to_html_async(
@@ -40,6 +65,7 @@ pub struct Engine {
     #[cfg(feature = "async")]
     async_parser: std::sync::Arc<mrml::prelude::parser::AsyncParserOptions>,
     render: mrml::prelude::render::RenderOptions,
+    text: mrml::prelude::render::TextRenderOptions,
 }
 
 #[wasm_bindgen]
@@ -70,6 +96,12 @@ impl Engine {
         self.render = value.into();
     }
 
+    /// Defines the plain text rendering options.
+    #[wasm_bindgen(js_name = "setTextRenderOptions")]
+    pub fn set_text_render_options(&mut self, value: TextRenderOptions) {
+        self.text = value.into();
+    }
+
     /// Renders the mjml input into html.
     #[wasm_bindgen(js_name = "toHtml")]
     pub fn to_html(&self, input: &str) -> ToHtmlResult {
@@ -79,6 +111,25 @@ impl Engine {
         }
     }
 
+    /// Renders the json input, checked against the schema of the `Mjml`
+    /// type, into html.
+    #[wasm_bindgen(js_name = "jsonToHtml")]
+    pub fn json_to_html(&self, input: &str) -> ToHtmlResult {
+        match json_to_html(input, &self.render) {
+            Ok((content, warnings)) => ToHtmlResult::Success { content, warnings },
+            Err(error) => ToHtmlResult::Error(error),
+        }
+    }
+
+    /// Renders the mjml input into plain text.
+    #[wasm_bindgen(js_name = "toText")]
+    pub fn to_text(&self, input: &str) -> ToTextResult {
+        match to_text(input, &self.parser, &self.text) {
+            Ok((content, warnings)) => ToTextResult::Success { content, warnings },
+            Err(error) => ToTextResult::Error(error),
+        }
+    }
+
     /// Renders the mjml input into html.
     #[cfg(feature = "async")]
     #[wasm_bindgen(js_name = "toHtmlAsync")]
@@ -101,6 +152,20 @@ pub enum ToHtmlError {
     Render {
         message: String,
     },
+    Json {
+        message: String,
+        /// JSON pointer of the value that doesn't match the schema.
+        pointer: String,
+    },
+}
+
+impl From<mrml::prelude::json::schema::Error> for ToHtmlError {
+    fn from(value: mrml::prelude::json::schema::Error) -> Self {
+        ToHtmlError::Json {
+            message: value.message,
+            pointer: value.pointer,
+        }
+    }
 }
 
 impl From<mrml::prelude::parser::Error> for ToHtmlError {
@@ -140,6 +205,26 @@ impl ToHtmlResult {
     }
 }
 
+#[derive(Debug, serde::Serialize, tsify::Tsify)]
+#[serde(rename_all = "camelCase", tag = "type")]
+#[tsify(into_wasm_abi)]
+pub enum ToTextResult {
+    Success {
+        content: String,
+        warnings: Vec<Warning>,
+    },
+    Error(ToHtmlError),
+}
+
+impl ToTextResult {
+    pub fn into_success(self) -> String {
+        match self {
+            Self::Success { content, .. } => content,
+            Self::Error(inner) => panic!("unexpected error {inner:?}"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(dead_code)]
@@ -149,7 +234,7 @@ mod tests {
 
     use wasm_bindgen_test::wasm_bindgen_test;
 
-    use crate::{Engine, ToHtmlResult};
+    use crate::{Engine, ToHtmlResult, ToTextResult};
 
     #[wasm_bindgen_test]
     fn it_should_render() {
@@ -184,6 +269,63 @@ mod tests {
         let result = opts.to_html(template);
         assert!(matches!(result, ToHtmlResult::Success { .. }));
     }
+
+    #[wasm_bindgen_test]
+    fn it_should_render_json() {
+        let template =
+            r#"{"type":"mjml","children":[{"type":"mj-body","children":[{"type":"mj-section"}]}]}"#;
+        let opts = Engine::new();
+        let result = opts.json_to_html(template);
+        assert!(matches!(result, ToHtmlResult::Success { .. }));
+    }
+
+    #[wasm_bindgen_test]
+    fn it_should_point_to_json_error() {
+        let template =
+            r#"{"type":"mjml","children":[{"type":"mj-body","children":[{"type":"mj-text"}]}]}"#;
+        let opts = Engine::new();
+        match opts.json_to_html(template) {
+            ToHtmlResult::Error(crate::ToHtmlError::Json { pointer, .. }) => {
+                assert_eq!(pointer, "/children/0/children/0");
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn it_should_render_text() {
+        let template =
+            "<mjml><mj-body><mj-button href=\"https://mjml.io\">Click</mj-button></mj-body></mjml>";
+        let mut opts = Engine::new();
+        assert_eq!(
+            opts.to_text(template).into_success(),
+            "Click (https://mjml.io)\n"
+        );
+        opts.set_text_render_options(crate::TextRenderOptions {
+            line_width: None,
+            include_links: false,
+            include_preview: true,
+        });
+        assert_eq!(opts.to_text(template).into_success(), "Click\n");
+    }
+
+    #[wasm_bindgen_test]
+    fn it_should_default_missing_text_options() {
+        let value: crate::TextRenderOptions =
+            serde_wasm_bindgen::from_value(js_sys::Object::new().into()).unwrap();
+        let expected = mrml::prelude::render::TextRenderOptions::default();
+        assert_eq!(value.line_width, expected.line_width);
+        assert_eq!(value.include_links, expected.include_links);
+        assert_eq!(value.include_preview, expected.include_preview);
+    }
+
+    #[wasm_bindgen_test]
+    fn it_should_error_on_text() {
+        let template = "<mjml><mj-body><mj-text>Hello World";
+        let opts = Engine::new();
+        let result = opts.to_text(template);
+        assert!(matches!(result, ToTextResult::Error(_)));
+    }
 }
 
 #[cfg(all(test, feature = "async"))]
@@ -136,6 +136,12 @@ pub enum ParserError {
         origin: super::Origin,
         position: super::Span,
     },
+    ComponentError {
+        tag: String,
+        origin: super::Origin,
+        position: super::Span,
+        source: String,
+    },
 }
 
 impl From<mrml::prelude::parser::Error> for ParserError {
@@ -197,6 +203,17 @@ impl From<mrml::prelude::parser::Error> for ParserError {
                 origin: origin.into(),
                 position: position.into(),
             },
+            Error::ComponentError {
+                tag,
+                origin,
+                position,
+                source,
+            } => Self::ComponentError {
+                tag,
+                origin: origin.into(),
+                position: position.into(),
+                source: source.to_string(),
+            },
         }
     }
 }
@@ -241,6 +258,7 @@ pub enum WarningKind {
     UnexpectedAttributes,
     DuplicateAttribute,
     InlineStyleUnsupported,
+    InvalidAttributeValue,
 }
 
 impl From<mrml::prelude::parser::WarningKind> for WarningKind {
@@ -251,6 +269,9 @@ impl From<mrml::prelude::parser::WarningKind> for WarningKind {
             mrml::prelude::parser::WarningKind::InlineStyleUnsupported => {
                 Self::InlineStyleUnsupported
             }
+            mrml::prelude::parser::WarningKind::InvalidAttributeValue => {
+                Self::InvalidAttributeValue
+            }
         }
     }
 }
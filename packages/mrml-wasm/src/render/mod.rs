@@ -19,14 +19,54 @@ pub struct RenderOptions {
 
 impl From<RenderOptions> for mrml::prelude::render::RenderOptions {
     fn from(value: RenderOptions) -> Self {
+        let opts = Self::default()
+            .with_disable_comments(value.disable_comments)
+            .with_fonts(
+                value
+                    .fonts
+                    .into_iter()
+                    .map(|(key, value)| (key, Cow::Owned(value)))
+                    .collect(),
+            );
+        match value.social_icon_origin {
+            Some(origin) => opts.with_social_icon_origin(origin),
+            None => opts,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, tsify::Tsify)]
+#[serde(default, rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+/// Plain text rendering options
+pub struct TextRenderOptions {
+    /// Maximum length of a line, the text is not wrapped when not defined. 78
+    /// by default.
+    pub line_width: Option<usize>,
+    /// If enabled, the url of the links is written after their content.
+    /// Enabled by default.
+    pub include_links: bool,
+    /// If enabled, the text starts with the content of `mj-preview`. Enabled
+    /// by default.
+    pub include_preview: bool,
+}
+
+impl Default for TextRenderOptions {
+    fn default() -> Self {
+        let value = mrml::prelude::render::TextRenderOptions::default();
         Self {
-            disable_comments: value.disable_comments,
-            social_icon_origin: value.social_icon_origin.map(Cow::Owned),
-            fonts: value
-                .fonts
-                .into_iter()
-                .map(|(key, value)| (key, Cow::Owned(value)))
-                .collect(),
+            line_width: value.line_width,
+            include_links: value.include_links,
+            include_preview: value.include_preview,
         }
     }
 }
+
+impl From<TextRenderOptions> for mrml::prelude::render::TextRenderOptions {
+    fn from(value: TextRenderOptions) -> Self {
+        Self::default()
+            .with_line_width(value.line_width)
+            .with_include_links(value.include_links)
+            .with_include_preview(value.include_preview)
+    }
+}
@@ -199,3 +199,11 @@ async fn it_should_use_memory_include_loader_by_default_async() {
     let content = result.into_success();
     assert_eq!(content.matches("Hello World").count(), 1);
 }
+
+#[wasm_bindgen_test]
+fn it_should_render_text() {
+    let template = "<mjml><mj-body><mj-text>Hello World</mj-text></mj-body></mjml>";
+    let engine = mrml_wasm::Engine::new();
+    let result = engine.to_text(template);
+    assert_eq!(result.into_success(), "Hello World\n");
+}